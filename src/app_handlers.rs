@@ -1,12 +1,55 @@
 use super::*;
-use crate::utils::debug_eprintln;
 use crate::utils::file_scanner::SUPPORTED_EXTENSIONS;
+use std::collections::HashMap;
 
 impl App {
     /// Check if modal overlays (settings, delete confirmation) are blocking main window interactions
     /// Note: Menu bar state is handled separately via escape key
     pub(crate) fn is_modal_open(&self) -> bool {
-        self.show_settings || self.pending_delete.is_some()
+        self.show_settings
+            || self.pending_delete.is_some()
+            || self.pending_quit.is_some()
+            || self.pending_bake.is_some()
+            || self.zoom_entry.is_some()
+            || self.go_to_entry.is_some()
+            || self.go_to_frame_entry.is_some()
+            || self.rename_entry.is_some()
+            || matches!(self.organize, Some(OrganizeStage::Preview(_)))
+            || self.batch_job.is_some()
+    }
+
+    /// Transparency to draw overlays with.  While the settings window is
+    /// open its unsaved value wins, so the stepper previews live.
+    pub(crate) fn overlay_transparency(&self, cx: &gpui::App) -> u8 {
+        if self.show_settings {
+            self.settings_window
+                .read(cx)
+                .working_settings
+                .appearance
+                .overlay_transparency
+        } else {
+            self.settings.appearance.overlay_transparency
+        }
+    }
+
+    /// EXIF details of the current image, read once per file
+    pub(crate) fn current_photo_info(&mut self) -> Option<utils::exif::PhotoInfo> {
+        let path = self.app_state.current_image()?;
+        self.photo_info_cache
+            .entry(path.clone())
+            .or_insert_with(|| utils::exif::read_photo_info(path))
+            .clone()
+    }
+
+    /// Text chunks of the current image when it's a PNG, read once per file
+    pub(crate) fn current_png_text(&mut self) -> Vec<(String, String)> {
+        let Some(path) = self.app_state.current_image() else {
+            return Vec::new();
+        };
+        self.png_text_cache
+            .entry(path.clone())
+            .or_insert_with(|| utils::image_loader::read_png_text(path))
+            .clone()
     }
 
     pub(crate) fn handle_escape(&mut self, window: &mut Window, cx: &mut Context<Self>) {
@@ -20,7 +63,78 @@ impl App {
             }
         }
 
-        // Dismiss delete confirmation first (highest priority)
+        // Cancel a running batch job first; its overlay stays up until the
+        // worker finishes the file in flight
+        if self.batch_job.is_some() {
+            self.handle_cancel_batch(cx);
+            return;
+        }
+
+        // Dismiss the zoom prompt (normally its own Escape closes it first)
+        if self.zoom_entry.is_some() {
+            self.close_zoom_entry(window, cx);
+            return;
+        }
+
+        // Same for the go-to prompt and the rename prompt
+        if self.go_to_entry.is_some() {
+            self.close_go_to_entry(window, cx);
+            return;
+        }
+        if self.go_to_frame_entry.is_some() {
+            self.close_go_to_frame_entry(window, cx);
+            return;
+        }
+        if self.rename_entry.is_some() {
+            self.close_rename_entry(window, cx);
+            return;
+        }
+
+        // Dismiss the session restore offer
+        if self.pending_session_restore.take().is_some() {
+            cx.notify();
+            return;
+        }
+
+        // Dismiss quit confirmation next
+        if self.pending_quit.is_some() {
+            self.pending_quit = None;
+            self.toast = Some(ToastState {
+                message: "Quit cancelled".into(),
+                detail: None,
+                is_error: false,
+                created_at: Instant::now(),
+            });
+            cx.notify();
+            return;
+        }
+
+        // Dismiss the Organize by Date preview
+        if matches!(self.organize, Some(OrganizeStage::Preview(_))) {
+            self.organize = None;
+            self.toast = Some(ToastState {
+                message: "Organize cancelled".into(),
+                detail: None,
+                is_error: false,
+                created_at: Instant::now(),
+            });
+            cx.notify();
+            return;
+        }
+
+        // Dismiss apply-filters confirmation next
+        if self.pending_bake.take().is_some() {
+            self.toast = Some(ToastState {
+                message: "Apply filters cancelled".into(),
+                detail: None,
+                is_error: false,
+                created_at: Instant::now(),
+            });
+            cx.notify();
+            return;
+        }
+
+        // Dismiss delete confirmation next
         if self.pending_delete.is_some() {
             self.pending_delete = None;
             self.toast = Some(ToastState {
@@ -33,25 +147,44 @@ impl App {
             return;
         }
 
-        // If help, debug, settings, or filter overlay is open, close it instead of counting toward quit
-        if self.show_help {
-            self.show_help = false;
-            self.focus_handle.focus(window);
+        // Close the montage
+        if self.montage.take().is_some() {
             cx.notify();
             return;
         }
-        if self.show_debug {
-            self.show_debug = false;
-            self.focus_handle.focus(window);
+
+        // Leave eyedropper mode
+        if self.eyedropper_active {
+            self.eyedropper_active = false;
+            self.eyedropper_sample = None;
+            cx.notify();
+            return;
+        }
+
+        // Leave crop mode
+        if self.crop_mode {
+            self.crop_mode = false;
+            self.viewer.crop_drag = None;
             cx.notify();
             return;
         }
+
+        // If settings or an overlay is open, close the topmost instead of
+        // counting toward quit.  Settings draws over everything else.
         if self.show_settings {
             self.show_settings = false;
             self.focus_handle.focus(window);
             cx.notify();
             return;
         }
+        if let Some(closed) = self.overlays.close_front() {
+            if closed == Overlay::Histogram {
+                self.viewer.clear_histogram();
+            }
+            self.focus_handle.focus(window);
+            cx.notify();
+            return;
+        }
 
         self.register_escape_press(cx);
     }
@@ -71,7 +204,82 @@ impl App {
         self.escape_presses.push(now);
         if self.escape_presses.len() >= 3 {
             self.escape_presses.clear();
-            self.close_own_window(cx);
+            if self.should_confirm_quit() {
+                self.pending_quit = Some(QuitScope::Window);
+                cx.notify();
+            } else {
+                self.close_own_window(cx);
+            }
+        }
+    }
+
+    /// Whether quitting now should first ask for confirmation: the setting is
+    /// on and this window holds filter edits that have not been saved.
+    pub(crate) fn should_confirm_quit(&self) -> bool {
+        if !self
+            .settings
+            .viewer_behavior
+            .confirm_quit_with_unsaved_filters
+        {
+            return false;
+        }
+        let defaults = self.default_filters();
+        self.viewer.image_state.has_unsaved_filter_changes(defaults)
+            || self.app_state.has_unsaved_filter_changes(defaults)
+    }
+
+    /// Carry out the quit the user confirmed via the unsaved-filters card.
+    pub(crate) fn handle_confirm_quit(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        match self.pending_quit.take() {
+            Some(QuitScope::App) => cx.quit(),
+            Some(QuitScope::Window) => self.close_own_window(cx),
+            None => {}
+        }
+    }
+
+    /// Persist this window's image list as the session to offer on the
+    /// next no-argument launch.
+    pub(crate) fn save_session(&self) {
+        let session = settings_io::SessionState {
+            image_paths: self.app_state.image_paths.clone(),
+            current_index: self.app_state.current_index,
+        };
+        if let Err(e) = settings_io::save_session(&session) {
+            log::error!("Error saving session: {}", e);
+        }
+    }
+
+    /// Replace the current list with the last session's, as offered by the
+    /// restore card.
+    pub(crate) fn handle_restore_session(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(session) = self.pending_session_restore.take() else {
+            return;
+        };
+        self.save_current_image_state();
+        let count = session.image_paths.len();
+        self.app_state.image_paths = session.image_paths;
+        self.app_state.current_index = session.current_index;
+        self.viewer.no_images_path = None;
+        self.update_viewer(window, cx);
+        self.update_window_title(window);
+        self.toast = Some(ToastState {
+            message: format!("Restored {} images from last session", count),
+            detail: None,
+            is_error: false,
+            created_at: Instant::now(),
+        });
+        cx.notify();
+    }
+
+    /// Record `filters` as saved for `path` so they no longer count as
+    /// unsaved.  The user may have navigated away while the save dialog was
+    /// open, so update whichever copy of the image's state is live.
+    fn mark_filters_saved(&mut self, path: &Path, filters: state::image_state::FilterSettings) {
+        if self.app_state.current_image().is_some_and(|p| p == path) {
+            self.viewer.image_state.saved_filters = Some(filters);
+            self.save_current_image_state();
+        } else if let Some(state) = self.app_state.image_states.get_mut(path) {
+            state.saved_filters = Some(filters);
         }
     }
 
@@ -89,151 +297,596 @@ impl App {
     }
 
     pub(crate) fn handle_toggle_help(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
-        self.show_help = !self.show_help;
+        // Help and settings both fill the window; settings wins while open
+        if self.show_settings {
+            return;
+        }
+        self.overlays.toggle(Overlay::Help);
         cx.notify();
     }
 
     pub(crate) fn handle_toggle_debug(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
-        self.show_debug = !self.show_debug;
+        self.overlays.toggle(Overlay::Debug);
         cx.notify();
     }
 
-    pub(crate) fn handle_toggle_zoom_indicator(
+    /// Enter or leave fullscreen.  The windowed bounds are kept so leaving
+    /// can put the window back as it was.
+    pub(crate) fn handle_toggle_fullscreen(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if !window.is_fullscreen() {
+            self.windowed_bounds = Some(window.bounds());
+        }
+        window.toggle_fullscreen();
+        cx.notify();
+    }
+
+    /// Follow the window in and out of fullscreen: the info overlays are
+    /// put away going in and come back coming out, and the window gets
+    /// its old size back.  Only the size: GPUI can resize a window but not
+    /// move it, so off macOS its position is up to the system.  The image
+    /// refits as the viewport changes.
+    pub(crate) fn track_fullscreen(&mut self, window: &mut Window) {
+        let fullscreen = window.is_fullscreen();
+        if fullscreen == self.was_fullscreen {
+            return;
+        }
+        self.was_fullscreen = fullscreen;
+        if fullscreen {
+            self.overlays_before_fullscreen = Some(self.overlays.hide_all());
+            return;
+        }
+        if let Some(hidden) = self.overlays_before_fullscreen.take() {
+            self.overlays.restore(hidden);
+        }
+        let bounds = self.windowed_bounds.take();
+        // macOS restores the whole frame itself as its exit animation
+        // finishes
+        if let Some(bounds) = bounds.filter(|_| cfg!(not(target_os = "macos")))
+            && window.bounds().size != bounds.size
+        {
+            window.resize(bounds.size);
+        }
+    }
+
+    pub(crate) fn handle_toggle_histogram(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        self.overlays.toggle(Overlay::Histogram);
+        if !self.overlays.is_open(Overlay::Histogram) {
+            // Counted only while visible; drop it so a stale one isn't shown
+            self.viewer.clear_histogram();
+        }
+        cx.notify();
+    }
+
+    pub(crate) fn handle_toggle_generation_info(
         &mut self,
         _window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        self.show_zoom_indicator = !self.show_zoom_indicator;
+        self.overlays.toggle(Overlay::Generation);
         cx.notify();
     }
 
-    pub(crate) fn handle_toggle_background(
+    /// Add the current image to the montage picks, or take it back out
+    pub(crate) fn handle_toggle_montage_pick(
         &mut self,
         _window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        self.settings.appearance.use_light_background =
-            !self.settings.appearance.use_light_background;
-        if let Err(e) = settings_io::save_settings(&self.settings) {
-            eprintln!("Error saving settings: {}", e);
-        }
-        cx.notify();
-    }
-
-    pub(crate) fn handle_toggle_settings(&mut self, window: &mut Window, cx: &mut Context<Self>) {
-        self.show_settings = !self.show_settings;
-
-        if self.show_settings {
-            // Focus the settings window when opening
-            self.settings_window.update(cx, |settings, inner_cx| {
-                let handle = settings.focus_handle(inner_cx);
-                handle.focus(window);
-            });
-        } else {
-            // Restore focus to the main app when hiding settings
-            self.focus_handle.focus(window);
+        if self.is_modal_open() {
+            return;
         }
-
+        let Some(path) = self.app_state.current_image().cloned() else {
+            return;
+        };
+        let max = utils::montage::MAX_PANES;
+        let (message, is_error) =
+            if let Some(at) = self.montage_picks.iter().position(|p| *p == path) {
+                self.montage_picks.remove(at);
+                (
+                    format!(
+                        "Removed from montage ({} of {})",
+                        self.montage_picks.len(),
+                        max
+                    ),
+                    false,
+                )
+            } else if self.montage_picks.len() >= max {
+                (format!("The montage holds {} images", max), true)
+            } else {
+                self.montage_picks.push(path);
+                (
+                    format!("Added to montage ({} of {})", self.montage_picks.len(), max),
+                    false,
+                )
+            };
+        self.toast = Some(ToastState {
+            message,
+            detail: None,
+            is_error,
+            created_at: Instant::now(),
+        });
         cx.notify();
     }
 
-    pub(crate) fn handle_close_settings(&mut self, window: &mut Window, cx: &mut Context<Self>) {
-        // Get current settings from the settings window and save to disk
-        let new_settings = self.settings_window.update(cx, |sw, _cx| sw.get_settings());
-
-        // Save settings to disk
-        if let Err(e) = settings_io::save_settings(&new_settings) {
-            eprintln!("Error saving settings: {}", e);
-        } else {
-            println!("Settings saved successfully");
+    /// Show the picked images side by side, or close the montage.  It
+    /// needs at least two picks.
+    pub(crate) fn handle_toggle_montage(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        if self.montage.take().is_none() {
+            if self.is_modal_open() {
+                return;
+            }
+            if self.montage_picks.len() < 2 {
+                self.toast = Some(ToastState {
+                    message: "Pick 2 to 4 images for the montage with M".to_string(),
+                    detail: None,
+                    is_error: true,
+                    created_at: Instant::now(),
+                });
+            } else {
+                let picks = self.montage_picks.clone();
+                self.montage = Some(cx.new(|_cx| MontageView::new(picks)));
+            }
         }
-
-        // Update app settings
-        self.settings = new_settings;
-
-        // Close the settings window
-        self.show_settings = false;
-        self.focus_handle.focus(window);
-
-        // Immediately apply new window title format
-        self.update_window_title(window);
-
         cx.notify();
     }
 
-    pub(crate) fn handle_reset_settings_to_defaults(
+    /// Switch the montage between linked and independent pane views
+    pub(crate) fn handle_toggle_montage_link(
         &mut self,
         _window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        // Reset settings window to defaults
-        self.settings_window.update(cx, |sw, cx| {
-            sw.reset_to_defaults(cx);
+        let Some(montage) = self.montage.clone() else {
+            return;
+        };
+        let linked = montage.update(cx, |montage, cx| {
+            montage.set_linked(!montage.linked());
+            cx.notify();
+            montage.linked()
+        });
+        self.toast = Some(ToastState {
+            message: if linked {
+                "Montage views linked".to_string()
+            } else {
+                "Montage views independent".to_string()
+            },
+            detail: None,
+            is_error: false,
+            created_at: Instant::now(),
         });
+        cx.notify();
+    }
 
+    /// `Tab`: bring the next open overlay (help, debug, histogram,
+    /// generation info) to the front, or close it when it's the only one
+    pub(crate) fn handle_cycle_overlays(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_modal_open() {
+            return;
+        }
+        match self.overlays.cycle() {
+            OverlayCycle::None => return,
+            OverlayCycle::Raised(_) => {}
+            OverlayCycle::Closed(closed) => {
+                if closed == Overlay::Histogram {
+                    self.viewer.clear_histogram();
+                }
+                self.focus_handle.focus(window);
+            }
+        }
         cx.notify();
     }
 
-    pub(crate) fn handle_load_oversized_image_anyway(
+    pub(crate) fn handle_toggle_eyedropper(
         &mut self,
         _window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        // Get the current image path from the oversized_image state
-        if let Some((ref path, _, _, _)) = self.viewer.oversized_image {
-            let path = path.clone();
-
-            // Set the override flag in the image state cache
-            let mut state = self
-                .app_state
-                .image_states
-                .get(&path)
-                .cloned()
-                .unwrap_or_else(state::ImageState::new);
-            state.override_size_limit = true;
-            self.app_state.image_states.insert(path.clone(), state);
-
-            // Reload the image with force_load = true
-            let max_dim = Some(self.settings.performance.max_image_dimension);
-            self.viewer.load_image_async(path, max_dim, true);
-
-            cx.notify();
+        if self.is_modal_open() {
+            return;
         }
+        self.eyedropper_active = !self.eyedropper_active;
+        self.eyedropper_sample = None;
+        self.toast = Some(ToastState {
+            message: if self.eyedropper_active {
+                "Eyedropper on: click to copy a color".into()
+            } else {
+                "Eyedropper off".into()
+            },
+            detail: None,
+            is_error: false,
+            created_at: Instant::now(),
+        });
+        cx.notify();
     }
 
-    pub(crate) fn handle_toggle_filters(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
-        if self.filter_window.is_some() {
-            self.close_filter_window(cx);
-        } else {
-            self.open_filter_window(cx);
+    pub(crate) fn handle_toggle_crop_mode(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_modal_open() {
+            return;
         }
+        self.crop_mode = !self.crop_mode;
+        self.viewer.crop_drag = None;
+        self.toast = Some(ToastState {
+            message: if self.crop_mode {
+                "Crop on: drag to select, release to save".into()
+            } else {
+                "Crop off".into()
+            },
+            detail: self
+                .crop_mode
+                .then(|| "Hold Shift to keep the image's proportions".into()),
+            is_error: false,
+            created_at: Instant::now(),
+        });
         cx.notify();
     }
 
-    /// Spawn the floating, always-on-top filter window. Does nothing if one is already open.
-    pub(crate) fn open_filter_window(&mut self, cx: &mut Context<Self>) {
-        if self.filter_window.is_some() {
+    /// Finish a crop drag: save the selected part of what's displayed
+    /// through a Save dialog.  Selections off the image do nothing.
+    pub(crate) fn handle_crop_release(&mut self, cx: &mut Context<Self>) {
+        let Some(drag) = self.viewer.crop_drag.take() else {
             return;
-        }
-        let bounds = self
+        };
+        cx.notify();
+        let Some(current_path) = self.app_state.current_image().cloned() else {
+            return;
+        };
+        let grid = self.settings.keyboard_mouse.crop_grid;
+        let Some(selection) = self.viewer.crop_selection(drag, grid) else {
+            return;
+        };
+        let Some(image) = self.viewer.crop_display(selection) else {
+            return;
+        };
+        let stem = current_path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("image");
+        let suggested_name = format!("{}_crop.png", stem);
+        let directory: Option<PathBuf> = self
             .settings
-            .appearance
-            .filter_window_bounds
-            .map(|b| b.to_bounds())
-            .unwrap_or_else(|| {
-                gpui::Bounds::centered(None, gpui::size(gpui::px(360.0), gpui::px(320.0)), cx)
-            });
-
-        let filter_controls = self.filter_controls.clone();
-        let weak_app = cx.weak_entity();
+            .file_operations
+            .default_save_directory
+            .clone()
+            .or_else(|| current_path.parent().map(PathBuf::from));
 
-        let result = cx.open_window(
-            gpui::WindowOptions {
-                window_bounds: Some(gpui::WindowBounds::Windowed(bounds)),
-                kind: gpui::WindowKind::Floating,
-                is_resizable: true,
-                is_movable: true,
-                titlebar: Some(gpui::TitlebarOptions {
+        cx.spawn(async move |_this, _cx| {
+            let mut dialog = rfd::AsyncFileDialog::new()
+                .add_filter("PNG", &["png"])
+                .add_filter("JPEG", &["jpg", "jpeg"])
+                .add_filter("BMP", &["bmp"])
+                .add_filter("TIFF", &["tiff", "tif"])
+                .add_filter("WEBP", &["webp"])
+                .set_file_name(&suggested_name)
+                .set_title("Save Crop");
+            if let Some(dir) = directory {
+                dialog = dialog.set_directory(dir);
+            }
+            let Some(handle) = dialog.save_file().await else {
+                return;
+            };
+            let save_path = handle.path().to_path_buf();
+            match save_dynamic_image_to_path(&image::DynamicImage::ImageRgba8(image), &save_path) {
+                Ok(()) => log::info!(
+                    "Crop ({}) saved to: {}",
+                    selection.size_label(),
+                    save_path.display()
+                ),
+                Err(e) => log::error!("Failed to save crop: {}", e),
+            }
+        })
+        .detach();
+    }
+
+    /// Copy the hex color under `position` (an eyedropper click); clicks
+    /// off the image do nothing
+    pub(crate) fn handle_eyedropper_click(
+        &mut self,
+        position: Point<Pixels>,
+        cx: &mut Context<Self>,
+    ) {
+        if self.is_modal_open() {
+            return;
+        }
+        let Some(sample) = self
+            .viewer
+            .sample_pixel(position.x.into(), position.y.into())
+        else {
+            return;
+        };
+        cx.write_to_clipboard(ClipboardItem::new_string(sample.hex()));
+        self.toast = Some(ToastState {
+            message: format!("Copied {}", sample.hex()),
+            detail: Some(format!(
+                "{} at {}, {}",
+                sample.rgb_label(),
+                sample.pixel.0,
+                sample.pixel.1
+            )),
+            is_error: false,
+            created_at: Instant::now(),
+        });
+        cx.notify();
+    }
+
+    pub(crate) fn handle_toggle_zoom_indicator(
+        &mut self,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.show_zoom_indicator = !self.show_zoom_indicator;
+        cx.notify();
+    }
+
+    pub(crate) fn handle_toggle_navigator(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        self.show_navigator = !self.show_navigator;
+        cx.notify();
+    }
+
+    /// The navigator's thumbnail size and the part of the image on screen,
+    /// or None when it shouldn't show: turned off, in a montage, or with
+    /// the whole image in view
+    pub(crate) fn navigator_view(&self) -> Option<((f32, f32), utils::navigator::ThumbRect)> {
+        if !self.show_navigator || self.montage.is_some() {
+            return None;
+        }
+        let image = self.viewer.layout_dimensions()?;
+        let viewport = self.viewer.viewport_size?;
+        let thumb_size = utils::navigator::thumbnail_size(image, utils::navigator::THUMBNAIL_SIDE);
+        let visible = utils::navigator::visible_rect(
+            image,
+            self.viewer.image_state.zoom,
+            self.viewer.image_state.pan,
+            (viewport.width.into(), viewport.height.into()),
+            thumb_size,
+        )?;
+        Some((thumb_size, visible))
+    }
+
+    /// Center the main view on `point` of the navigator thumbnail
+    pub(crate) fn center_view_on_navigator_point(
+        &mut self,
+        point: (f32, f32),
+        cx: &mut Context<Self>,
+    ) {
+        let (Some(image), Some(viewport)) =
+            (self.viewer.layout_dimensions(), self.viewer.viewport_size)
+        else {
+            return;
+        };
+        let thumb_size = utils::navigator::thumbnail_size(image, utils::navigator::THUMBNAIL_SIDE);
+        let (pan_x, pan_y) = utils::navigator::pan_to_center(
+            image,
+            self.viewer.image_state.zoom,
+            (viewport.width.into(), viewport.height.into()),
+            thumb_size,
+            point,
+        );
+        let (old_x, old_y) = self.viewer.image_state.pan;
+        // `pan` keeps the result within the usual limits
+        self.viewer.pan(pan_x - old_x, pan_y - old_y);
+        self.viewer.notify_svg_zoom_pan_changed();
+        cx.notify();
+    }
+
+    pub(crate) fn handle_toggle_background(
+        &mut self,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.settings.appearance.use_light_background =
+            !self.settings.appearance.use_light_background;
+        if let Err(e) = settings_io::save_settings(&self.settings) {
+            log::error!("Error saving settings: {}", e);
+        }
+        cx.notify();
+    }
+
+    /// Step the session backdrop override; the saved background is untouched
+    pub(crate) fn handle_cycle_backdrop(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_modal_open() {
+            return;
+        }
+        self.viewer.backdrop = self.viewer.backdrop.next();
+        self.toast = Some(ToastState {
+            message: format!("Backdrop: {}", self.viewer.backdrop.label()),
+            detail: None,
+            is_error: false,
+            created_at: Instant::now(),
+        });
+        cx.notify();
+    }
+
+    pub(crate) fn handle_toggle_settings(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.show_settings = !self.show_settings;
+
+        if self.show_settings {
+            // Only one full-window panel at a time
+            self.overlays.close(Overlay::Help);
+            // Focus the settings window when opening
+            self.settings_window.update(cx, |settings, inner_cx| {
+                let handle = settings.focus_handle(inner_cx);
+                handle.focus(window);
+            });
+        } else {
+            // Restore focus to the main app when hiding settings
+            self.focus_handle.focus(window);
+        }
+
+        cx.notify();
+    }
+
+    pub(crate) fn handle_close_settings(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        // Get current settings from the settings window and save to disk
+        let mut new_settings = self.settings_window.update(cx, |sw, _cx| sw.get_settings());
+
+        // Presets are managed from the filter window, not this one
+        new_settings.filters.filter_presets = self.settings.filters.filter_presets.clone();
+
+        // The window's copy predates any Sort menu picks since it opened.
+        // Keep the latest one, unless a new default was chosen to start in.
+        new_settings.sort_navigation.last_sort_mode =
+            if new_settings.sort_navigation.default_sort_mode
+                == self.settings.sort_navigation.default_sort_mode
+            {
+                self.settings.sort_navigation.last_sort_mode
+            } else {
+                None
+            };
+        new_settings.sort_navigation.sort_descending =
+            self.settings.sort_navigation.sort_descending;
+        new_settings.file_operations.last_directory =
+            self.settings.file_operations.last_directory.clone();
+
+        // Save settings to disk
+        if let Err(e) = settings_io::save_settings(&new_settings) {
+            log::error!("Error saving settings: {}", e);
+        } else {
+            log::info!("Settings saved successfully");
+        }
+
+        // Update app settings
+        utils::scratch::set_scratch_dir(new_settings.file_operations.scratch_directory.as_deref());
+        utils::style::Colors::set_high_contrast(new_settings.appearance.high_contrast);
+        // A changed setting overrides this session's Alt+E toggling
+        let auto_exposure_changed =
+            new_settings.filters.auto_exposure != self.settings.filters.auto_exposure;
+        self.settings = new_settings;
+
+        if auto_exposure_changed {
+            self.viewer.auto_exposure = self.settings.filters.auto_exposure;
+            self.viewer.update_auto_exposure();
+        }
+
+        // An SVG on screen is drawn again on the new background
+        let svg_background = self.settings.appearance.svg_background.fill();
+        if svg_background != self.viewer.svg_background {
+            self.viewer.svg_background = svg_background;
+            self.viewer.decode_cache.clear();
+            let showing_svg = self
+                .viewer
+                .current_image
+                .as_ref()
+                .is_some_and(|img| img.svg_tree.is_some());
+            if showing_svg {
+                self.update_viewer(window, cx);
+            }
+        }
+
+        // Re-fit with the new padding / pixel aspect if the image is fitted
+        self.viewer.fit_padding = self.settings.viewer_behavior.fit_padding;
+        self.viewer.honor_pixel_aspect = self.settings.viewer_behavior.honor_pixel_aspect;
+        if self.viewer.image_state.is_fit_to_window {
+            self.viewer.fit_to_window();
+        }
+        self.update_spread_partner();
+
+        // Close the settings window
+        self.show_settings = false;
+        self.focus_handle.focus(window);
+
+        // Immediately apply new window title format
+        self.update_window_title(window);
+
+        cx.notify();
+    }
+
+    pub(crate) fn handle_reset_settings_to_defaults(
+        &mut self,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        // Reset settings window to defaults
+        self.settings_window.update(cx, |sw, cx| {
+            sw.reset_to_defaults(cx);
+        });
+
+        cx.notify();
+    }
+
+    pub(crate) fn handle_load_oversized_image_anyway(
+        &mut self,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        // Get the current image path from the oversized_image state
+        if let Some((ref path, _, _, _)) = self.viewer.oversized_image {
+            let path = path.clone();
+
+            // Set the override flag in the image state cache
+            let mut state = self
+                .app_state
+                .image_states
+                .get(&path)
+                .cloned()
+                .unwrap_or_else(state::ImageState::new);
+            state.override_size_limit = true;
+            self.app_state.image_states.insert(path.clone(), state);
+
+            // Reload the image with force_load = true
+            let max_dim = Some(self.settings.performance.max_image_dimension);
+            self.viewer.load_image_async(path, max_dim, true);
+
+            cx.notify();
+        }
+    }
+
+    /// Load the current file again from disk (offered when it looked cut
+    /// short, in case a download has since finished)
+    pub(crate) fn handle_reload_image(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.update_viewer(window, cx);
+        cx.notify();
+    }
+
+    /// `cmd-f` shows or hides the filter panel.  `cmd-1`/`cmd-2` are the
+    /// separate on/off switch for the filters themselves.  With
+    /// `filters.panel_enables_filters` set, opening the panel also flips
+    /// that switch back on (the same as `cmd-2`) so slider changes are
+    /// visible straight away.  Closing the panel never disables filters:
+    /// it only hides the sliders, and `cmd-1` is still how to view the
+    /// original image.
+    pub(crate) fn handle_toggle_filters(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        use utils::filters::{FilterPanelState, toggle_filter_panel};
+        let current = FilterPanelState {
+            panel_open: self.filter_window.is_some(),
+            filters_enabled: self.viewer.image_state.filters_enabled,
+        };
+        let next = toggle_filter_panel(current, self.settings.filters.panel_enables_filters);
+
+        if next.panel_open {
+            self.open_filter_window(cx);
+        } else {
+            self.close_filter_window(cx);
+        }
+        if next.filters_enabled && !current.filters_enabled {
+            self.handle_enable_filters(window, cx);
+        }
+        cx.notify();
+    }
+
+    /// Spawn the floating, always-on-top filter window. Does nothing if one is already open.
+    pub(crate) fn open_filter_window(&mut self, cx: &mut Context<Self>) {
+        if self.filter_window.is_some() {
+            return;
+        }
+        let bounds = self
+            .settings
+            .appearance
+            .filter_window_bounds
+            .map(|b| b.to_bounds())
+            .unwrap_or_else(|| {
+                gpui::Bounds::centered(None, gpui::size(gpui::px(360.0), gpui::px(680.0)), cx)
+            });
+
+        let filter_controls = self.filter_controls.clone();
+        let weak_app = cx.weak_entity();
+
+        let result = cx.open_window(
+            gpui::WindowOptions {
+                window_bounds: Some(gpui::WindowBounds::Windowed(bounds)),
+                kind: gpui::WindowKind::Floating,
+                is_resizable: true,
+                is_movable: true,
+                titlebar: Some(gpui::TitlebarOptions {
                     title: Some("Filters".into()),
                     ..Default::default()
                 }),
@@ -300,7 +953,7 @@ impl App {
                 let _ = crate::utils::settings_io::save_settings(&self.settings);
             }
             Err(e) => {
-                eprintln!("Failed to open filter window: {:?}", e);
+                log::error!("Failed to open filter window: {:?}", e);
             }
         }
     }
@@ -428,7 +1081,7 @@ impl App {
                 self.settings.appearance.gpu_pipeline_window_open = true;
                 let _ = crate::utils::settings_io::save_settings(&self.settings);
             }
-            Err(e) => eprintln!("Failed to open GPU Pipeline window: {:?}", e),
+            Err(e) => log::error!("Failed to open GPU Pipeline window: {:?}", e),
         }
     }
 
@@ -441,19 +1094,32 @@ impl App {
     }
 
     pub(crate) fn handle_disable_filters(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
-        self.viewer.clear_active_slot();
         self.viewer.image_state.filters_enabled = false;
-        self.viewer.update_filtered_cache();
-        self.viewer.set_gpu_pipeline_enabled(false);
-        self.save_current_image_state();
-        cx.notify();
+        self.apply_filters_enabled(cx);
     }
 
     pub(crate) fn handle_enable_filters(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
-        self.viewer.clear_active_slot();
         self.viewer.image_state.filters_enabled = true;
+        self.apply_filters_enabled(cx);
+    }
+
+    /// `` ` ``: one key for what `1` and `2` do separately.
+    pub(crate) fn handle_toggle_filters_enabled(
+        &mut self,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.viewer.image_state.toggle_filters_enabled();
+        self.apply_filters_enabled(cx);
+    }
+
+    /// Bring the filtered render and GPU pipeline in line with a change to
+    /// the filters on/off switch.
+    fn apply_filters_enabled(&mut self, cx: &mut Context<Self>) {
+        let enabled = self.viewer.image_state.filters_enabled;
+        self.viewer.clear_active_slot();
         self.viewer.update_filtered_cache();
-        self.viewer.set_gpu_pipeline_enabled(true);
+        self.viewer.set_gpu_pipeline_enabled(enabled);
         self.save_current_image_state();
         cx.notify();
     }
@@ -480,12 +1146,11 @@ impl App {
 
     pub(crate) fn handle_reset_filters(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
         // Reset to default values from settings
-        let default_filters = state::image_state::FilterSettings {
-            brightness: self.settings.filters.default_brightness,
-            contrast: self.settings.filters.default_contrast,
-            gamma: self.settings.filters.default_gamma,
-        };
+        let default_filters = self.default_filters();
 
+        if self.viewer.image_state.filters != default_filters {
+            self.filter_history.record(self.viewer.image_state.filters);
+        }
         self.viewer.image_state.filters = default_filters;
         self.viewer.update_filtered_cache();
         self.save_current_image_state();
@@ -498,35 +1163,190 @@ impl App {
         cx.notify();
     }
 
-    fn adjust_filter(
-        &mut self,
-        f: impl FnOnce(&mut state::image_state::FilterSettings),
-        cx: &mut Context<Self>,
-    ) {
+    pub(crate) fn handle_undo_filter(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
         if self.is_modal_open() {
             return;
         }
-        f(&mut self.viewer.image_state.filters);
-        self.viewer.update_filtered_cache();
-        self.save_current_image_state();
-        cx.notify();
-    }
-
-    pub(crate) fn handle_brightness_up(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
-        self.adjust_filter(|f| f.brightness = (f.brightness + 5.0).min(100.0), cx);
+        if let Some(filters) = self.filter_history.undo(self.viewer.image_state.filters) {
+            self.restore_filters(filters, cx);
+        }
     }
 
-    pub(crate) fn handle_brightness_down(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
-        self.adjust_filter(|f| f.brightness = (f.brightness - 5.0).max(-100.0), cx);
+    pub(crate) fn handle_redo_filter(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_modal_open() {
+            return;
+        }
+        if let Some(filters) = self.filter_history.redo(self.viewer.image_state.filters) {
+            self.restore_filters(filters, cx);
+        }
     }
 
-    pub(crate) fn handle_contrast_up(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
-        self.adjust_filter(|f| f.contrast = (f.contrast + 5.0).min(100.0), cx);
+    /// Put back filter values from the undo history, sliders included
+    fn restore_filters(
+        &mut self,
+        filters: state::image_state::FilterSettings,
+        cx: &mut Context<Self>,
+    ) {
+        self.viewer.image_state.filters = filters;
+        self.viewer.update_filtered_cache();
+        self.filter_controls.update(cx, |controls, cx| {
+            controls.update_from_filters(filters, cx);
+        });
+        self.save_current_image_state();
+        cx.notify();
     }
 
-    pub(crate) fn handle_contrast_down(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
-        self.adjust_filter(|f| f.contrast = (f.contrast - 5.0).max(-100.0), cx);
-    }
+    /// Ask to write the current filters into the file on disk.  Shows the
+    /// confirmation card, or a toast saying why this image can't be baked.
+    pub(crate) fn handle_request_bake_filters(
+        &mut self,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.is_modal_open() {
+            return;
+        }
+        let Some(path) = self.app_state.current_image().cloned() else {
+            return;
+        };
+
+        let state = &self.viewer.image_state;
+        let refusal = if !state.filters_enabled || state.filters == self.default_filters() {
+            Some("No filters to apply".to_string())
+        } else if state.animation.is_some() {
+            Some("Can't apply filters to animated images".to_string())
+        } else if !can_rewrite_in_place(&path) {
+            let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+            Some(format!("Can't write {} files", ext.to_uppercase()))
+        } else {
+            None
+        };
+
+        if let Some(message) = refusal {
+            self.toast = Some(ToastState {
+                message,
+                detail: None,
+                is_error: false,
+                created_at: Instant::now(),
+            });
+        } else {
+            self.pending_bake = Some(path);
+        }
+        cx.notify();
+    }
+
+    /// Overwrite the file with the current filters applied, as a background
+    /// batch job behind the progress overlay.  `finish_bake` resets the
+    /// live filters and reloads it once the file is written.
+    pub(crate) fn handle_confirm_bake_filters(
+        &mut self,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(path) = self.pending_bake.take() else {
+            return;
+        };
+        let filters = self.viewer.image_state.filters;
+        self.batch_job = Some(utils::batch::start_batch(vec![path.clone()], move |path| {
+            bake_filters_into_file(path, filters)
+        }));
+        self.baking = Some(path);
+        cx.notify();
+    }
+
+    /// Wrap up an apply-filters job: on success the old values are in the
+    /// file, so reset the live filters and reload it
+    fn finish_bake(
+        &mut self,
+        path: PathBuf,
+        summary: &utils::batch::BatchSummary,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let filename = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("Unknown")
+            .to_string();
+        let full_path = path.display().to_string();
+
+        if summary.completed == 0 && summary.failed.is_empty() {
+            self.toast = Some(ToastState {
+                message: "Apply filters cancelled".into(),
+                detail: None,
+                is_error: false,
+                created_at: Instant::now(),
+            });
+            cx.notify();
+            return;
+        }
+        let defaults = self.default_filters();
+
+        match summary.failed.first() {
+            None => {
+                // The baked image is the one on screen: the overlay blocks
+                // navigating away while the job runs
+                self.viewer.image_state.filters_baked(defaults);
+                // The old values are in the file now; undoing would apply
+                // them twice
+                self.filter_history.clear();
+                self.save_current_image_state();
+                self.filter_controls.update(cx, |controls, cx| {
+                    controls.update_from_filters(defaults, cx);
+                });
+                self.toast = Some(ToastState {
+                    message: format!("Filters applied to {}", filename),
+                    detail: Some(full_path),
+                    is_error: false,
+                    created_at: Instant::now(),
+                });
+                self.update_viewer(window, cx);
+            }
+            Some((_, e)) => {
+                self.toast = Some(ToastState {
+                    message: format!("Apply filters failed: {}", e),
+                    detail: Some(full_path),
+                    is_error: true,
+                    created_at: Instant::now(),
+                });
+            }
+        }
+        cx.notify();
+    }
+
+    fn adjust_filter(
+        &mut self,
+        f: impl FnOnce(&mut state::image_state::FilterSettings),
+        cx: &mut Context<Self>,
+    ) {
+        if self.is_modal_open() {
+            return;
+        }
+        let before = self.viewer.image_state.filters;
+        f(&mut self.viewer.image_state.filters);
+        if self.viewer.image_state.filters != before {
+            self.filter_history.record(before);
+        }
+        self.viewer.update_filtered_cache();
+        self.save_current_image_state();
+        cx.notify();
+    }
+
+    pub(crate) fn handle_brightness_up(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        self.adjust_filter(|f| f.brightness = (f.brightness + 5.0).min(100.0), cx);
+    }
+
+    pub(crate) fn handle_brightness_down(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        self.adjust_filter(|f| f.brightness = (f.brightness - 5.0).max(-100.0), cx);
+    }
+
+    pub(crate) fn handle_contrast_up(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        self.adjust_filter(|f| f.contrast = (f.contrast + 5.0).min(100.0), cx);
+    }
+
+    pub(crate) fn handle_contrast_down(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        self.adjust_filter(|f| f.contrast = (f.contrast - 5.0).max(-100.0), cx);
+    }
 
     pub(crate) fn handle_gamma_up(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
         self.adjust_filter(|f| f.gamma = (f.gamma + 0.1).min(10.0), cx);
@@ -536,6 +1356,98 @@ impl App {
         self.adjust_filter(|f| f.gamma = (f.gamma - 0.1).max(0.1), cx);
     }
 
+    /// Flip color inversion, keeping the filter window's checkbox in step.
+    pub(crate) fn handle_toggle_invert(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_modal_open() {
+            return;
+        }
+        self.adjust_filter(|f| f.invert = !f.invert, cx);
+        let filters = self.viewer.image_state.filters;
+        self.filter_controls.update(cx, |controls, cx| {
+            controls.update_from_filters(filters, cx);
+        });
+    }
+
+    /// Save the current filter values as a preset, persisting it and
+    /// refreshing the filter window's preset bar.
+    pub(crate) fn save_filter_preset(&mut self, name: &str, cx: &mut Context<Self>) {
+        let filters = self.viewer.image_state.filters;
+        let saved_as = self.settings.filters.add_preset(name, &filters);
+        self.persist_filter_presets(cx);
+        self.toast = Some(ToastState {
+            message: format!("Saved preset \u{201C}{}\u{201D}", saved_as),
+            detail: None,
+            is_error: false,
+            created_at: Instant::now(),
+        });
+        cx.notify();
+    }
+
+    /// Remove the preset at `index` (a stale index is ignored)
+    pub(crate) fn delete_filter_preset(&mut self, index: usize, cx: &mut Context<Self>) {
+        if index >= self.settings.filters.filter_presets.len() {
+            return;
+        }
+        self.settings.filters.filter_presets.remove(index);
+        self.persist_filter_presets(cx);
+        cx.notify();
+    }
+
+    fn persist_filter_presets(&mut self, cx: &mut Context<Self>) {
+        if let Err(e) = settings_io::save_settings(&self.settings) {
+            log::error!("Error saving settings: {}", e);
+        }
+        let presets = self.settings.filters.filter_presets.clone();
+        self.filter_controls.update(cx, |controls, cx| {
+            controls.set_presets(presets, cx);
+        });
+    }
+
+    /// Flip grayscale conversion, keeping the filter window's checkbox in step.
+    pub(crate) fn handle_toggle_grayscale(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_modal_open() {
+            return;
+        }
+        self.adjust_filter(|f| f.grayscale = !f.grayscale, cx);
+        let filters = self.viewer.image_state.filters;
+        self.filter_controls.update(cx, |controls, cx| {
+            controls.update_from_filters(filters, cx);
+        });
+    }
+
+    pub(crate) fn handle_rotate_clockwise(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        self.rotate_current_image(true, cx);
+    }
+
+    pub(crate) fn handle_rotate_counter_clockwise(
+        &mut self,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.rotate_current_image(false, cx);
+    }
+
+    /// Turn the current image a quarter turn; the rotation is kept with
+    /// the image's other per-image state
+    fn rotate_current_image(&mut self, clockwise: bool, cx: &mut Context<Self>) {
+        if self.is_modal_open() || self.viewer.current_image.is_none() {
+            return;
+        }
+        if !self.viewer.supports_rotation() {
+            self.toast = Some(ToastState {
+                message: "Animated images and SVGs can't be rotated".into(),
+                detail: None,
+                is_error: true,
+                created_at: Instant::now(),
+            });
+            cx.notify();
+            return;
+        }
+        self.viewer.rotate(clockwise);
+        self.save_current_image_state();
+        cx.notify();
+    }
+
     pub(crate) fn handle_open_file(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         if self.is_modal_open() {
             return;
@@ -543,27 +1455,27 @@ impl App {
         // Open native file dialog for image selection (single file)
         let mut file_dialog = rfd::FileDialog::new()
             .add_filter("Images", SUPPORTED_EXTENSIONS)
+            .add_filter("Comic Archives", utils::archive::ARCHIVE_EXTENSIONS)
             .set_title("Open Image");
-
-        // Set default directory to current image's parent directory if available,
-        // or to the no_images_path directory if we're showing the empty directory notice
-        if let Some(current_path) = self.app_state.current_image() {
-            if let Some(parent) = current_path.parent() {
-                file_dialog = file_dialog.set_directory(parent);
-            }
-        } else if let Some(ref no_images_dir) = self.viewer.no_images_path {
-            file_dialog = file_dialog.set_directory(no_images_dir);
+        if let Some(dir) = self.dialog_start_directory() {
+            file_dialog = file_dialog.set_directory(dir);
         }
 
         // Get selected file (single selection)
         if let Some(file) = file_dialog.pick_file() {
+            if utils::archive::is_archive(&file) {
+                self.open_archive(&file, window, cx);
+                return;
+            }
             // Use process_dropped_path to scan the entire directory
             // and find the index of the selected file
-            match utils::file_scanner::process_dropped_path(&file) {
+            let recursive = self.settings.sort_navigation.recursive_scan;
+            match utils::file_scanner::process_dropped_path(&file, recursive) {
                 Ok((all_images, start_index)) => {
                     // Replace the current image list with all images from the directory
                     self.app_state.image_paths = all_images;
                     self.app_state.current_index = start_index;
+                    self.archive = None;
 
                     // Re-sort according to the active sort mode
                     self.app_state.sort_images();
@@ -574,7 +1486,7 @@ impl App {
                     cx.notify();
                 }
                 Err(e) => {
-                    eprintln!("Error opening file: {:?}", e);
+                    log::error!("Error opening file: {:?}", e);
                     self.viewer.error_message = Some(format!("Error opening file: {}", e));
                     cx.notify();
                 }
@@ -582,6 +1494,131 @@ impl App {
         }
     }
 
+    pub(crate) fn handle_open_folder(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_modal_open() {
+            return;
+        }
+        let mut folder_dialog = rfd::FileDialog::new().set_title("Open Folder");
+        if let Some(dir) = self.dialog_start_directory() {
+            folder_dialog = folder_dialog.set_directory(dir);
+        }
+
+        let Some(dir) = folder_dialog.pick_folder() else {
+            return;
+        };
+        let recursive = self.settings.sort_navigation.recursive_scan;
+        match utils::file_scanner::scan_directory_with(&dir, recursive) {
+            Ok(images) => {
+                self.app_state.set_images_from_folder(images);
+                self.archive = None;
+                // Clears the viewer when the folder had no images
+                self.update_viewer(window, cx);
+                if self.app_state.image_paths.is_empty() {
+                    // Show the friendly "no images here" notice, not an error
+                    self.viewer.no_images_path = Some(dir.canonicalize().unwrap_or(dir));
+                }
+                self.update_window_title(window);
+                cx.notify();
+            }
+            Err(e) => {
+                log::error!("Error opening folder: {:?}", e);
+                self.viewer.error_message = Some(format!("Error opening folder: {}", e));
+                cx.notify();
+            }
+        }
+    }
+
+    /// Replace the list with the images of the folder one level up from the
+    /// one being browsed (from an archive, the folder holding it).  Works
+    /// from an empty folder too, so it can be pressed repeatedly to climb.
+    pub(crate) fn handle_open_parent_folder(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.is_modal_open() {
+            return;
+        }
+        let came_from = match (&self.archive, self.app_state.current_image()) {
+            (Some(archive), Some(current)) if archive.pages.contains(current) => {
+                Some(archive.source.clone())
+            }
+            (_, Some(current)) => current.parent().map(PathBuf::from),
+            (_, None) => self.viewer.no_images_path.clone(),
+        };
+        let Some((came_from, parent)) = came_from.and_then(|dir| {
+            let parent = dir.parent()?.to_path_buf();
+            Some((dir, parent))
+        }) else {
+            return;
+        };
+        let recursive = self.settings.sort_navigation.recursive_scan;
+        match utils::file_scanner::scan_directory_with(&parent, recursive) {
+            Ok(images) => {
+                self.app_state.set_images_from_parent(images, &came_from);
+                self.archive = None;
+                self.update_viewer(window, cx);
+                if self.app_state.image_paths.is_empty() {
+                    self.viewer.no_images_path = Some(parent);
+                }
+                self.update_window_title(window);
+                cx.notify();
+            }
+            Err(e) => {
+                log::error!("Error opening folder: {:?}", e);
+                self.viewer.error_message = Some(format!("Error opening folder: {}", e));
+                cx.notify();
+            }
+        }
+    }
+
+    /// Browse the images inside a ZIP/CBZ archive as pages, first page
+    /// first.  They are unpacked to a temp directory kept until the list
+    /// is replaced or the window closes.  Pages stay in reading order
+    /// rather than being re-sorted by the active sort mode.
+    pub(crate) fn open_archive(
+        &mut self,
+        path: &Path,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        match utils::archive::extract_archive(path) {
+            Ok(archive) => {
+                self.app_state.image_paths = archive.pages.clone();
+                self.app_state.current_index = 0;
+                self.archive = Some(archive);
+                self.update_viewer(window, cx);
+                self.update_window_title(window);
+                self.focus_handle.focus(window);
+                cx.notify();
+            }
+            Err(e) => {
+                log::error!("Error opening archive: {:?}", e);
+                self.viewer.error_message = Some(format!("Error opening archive: {}", e));
+                cx.notify();
+            }
+        }
+    }
+
+    /// Where Open File / Open Folder dialogs should start: the current
+    /// image's directory (the archive's, for an archive page), or the
+    /// directory named by the empty-directory notice if that is what's
+    /// showing.
+    fn dialog_start_directory(&self) -> Option<PathBuf> {
+        if let Some(archive) = &self.archive
+            && self
+                .app_state
+                .current_image()
+                .is_some_and(|current| archive.pages.contains(current))
+        {
+            return archive.source.parent().map(PathBuf::from);
+        }
+        match self.app_state.current_image() {
+            Some(current_path) => current_path.parent().map(PathBuf::from),
+            None => self.viewer.no_images_path.clone(),
+        }
+    }
+
     pub(crate) fn handle_save_file(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
         if self.is_modal_open() {
             return;
@@ -602,6 +1639,573 @@ impl App {
         self.handle_save_file_impl(downloads_dir, cx);
     }
 
+    /// Export every image in the list to a chosen folder, in the default
+    /// save format and with the current filters applied if they're enabled.
+    /// Runs as a background batch job behind the progress overlay.  Files a
+    /// cancelled or crashed export to the same folder already finished (per
+    /// its manifest) are skipped.
+    pub(crate) fn handle_export_all(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_modal_open() || self.app_state.image_paths.is_empty() {
+            return;
+        }
+        let mut folder_dialog = rfd::FileDialog::new().set_title("Export All To Folder");
+        if let Some(dir) = self
+            .settings
+            .file_operations
+            .default_save_directory
+            .clone()
+            .or_else(|| self.dialog_start_directory())
+        {
+            folder_dialog = folder_dialog.set_directory(dir);
+        }
+        let Some(output_dir) = folder_dialog.pick_folder() else {
+            return;
+        };
+
+        let format = self.settings.file_operations.default_save_format;
+        let filters = self
+            .viewer
+            .image_state
+            .filters_enabled
+            .then_some(self.viewer.image_state.filters);
+        let paths = self.app_state.image_paths.clone();
+        let manifest =
+            utils::batch::BatchManifest::load(&output_dir, &format!("{:?} {:?}", format, filters));
+        let destinations = export_destinations(&paths, &output_dir, format, filters.is_some());
+        self.batch_job = Some(utils::batch::start_resumable_batch(
+            paths,
+            manifest,
+            move |path| export_image_to(path, &destinations[path], filters),
+        ));
+        cx.notify();
+    }
+
+    /// Average every image in the list into one frame (noise reduction,
+    /// astrophotography), save it where the user picks, then show it.
+    /// Images that don't match the first one's size are skipped.  Runs as a
+    /// background batch job behind the progress overlay.
+    pub(crate) fn handle_stack_average(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_modal_open() || self.app_state.image_paths.is_empty() {
+            return;
+        }
+        let mut save_dialog = rfd::FileDialog::new()
+            .set_title("Save Stacked Image")
+            .set_file_name("stack_average.png")
+            .add_filter("PNG", &["png"]);
+        if let Some(dir) = self.dialog_start_directory() {
+            save_dialog = save_dialog.set_directory(dir);
+        }
+        let Some(output) = save_dialog.save_file() else {
+            return;
+        };
+
+        let stack =
+            std::sync::Arc::new(std::sync::Mutex::new(utils::stack::StackAccumulator::new()));
+        let worker_stack = stack.clone();
+        let paths = self.app_state.image_paths.clone();
+        self.batch_job = Some(utils::batch::start_batch(paths, move |path| {
+            let image = utils::image_loader::load_image(path).map_err(|e| e.to_string())?;
+            worker_stack
+                .lock()
+                .map_err(|_| "Stack lock poisoned".to_string())?
+                .add(&image)
+        }));
+        self.pending_stack = Some((stack, output));
+        cx.notify();
+    }
+
+    /// Sort every image in the list into date folders under a chosen folder
+    /// (pattern from settings).  First plans where each file goes as a
+    /// batch job; the result is shown as a preview to confirm.
+    pub(crate) fn handle_organize_by_date(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_modal_open() || self.app_state.image_paths.is_empty() {
+            return;
+        }
+        let mut folder_dialog = rfd::FileDialog::new().set_title("Organize by Date Into Folder");
+        if let Some(dir) = self.dialog_start_directory() {
+            folder_dialog = folder_dialog.set_directory(dir);
+        }
+        let Some(output_dir) = folder_dialog.pick_folder() else {
+            return;
+        };
+
+        let file_ops = &self.settings.file_operations;
+        let pattern = if file_ops.organize_pattern.trim().is_empty() {
+            utils::organize::DEFAULT_PATTERN.to_string()
+        } else {
+            file_ops.organize_pattern.clone()
+        };
+        let plan = std::sync::Arc::new(std::sync::Mutex::new(utils::organize::OrganizePlan {
+            output_dir: output_dir.clone(),
+            move_files: file_ops.organize_moves_files,
+            moves: Vec::new(),
+        }));
+        let worker_plan = plan.clone();
+        let paths = self.app_state.image_paths.clone();
+        self.batch_job = Some(utils::batch::start_batch(paths, move |path| {
+            let planned = utils::organize::plan_move(path, &output_dir, &pattern)?;
+            worker_plan
+                .lock()
+                .map_err(|_| "Plan lock poisoned".to_string())?
+                .moves
+                .push(planned);
+            Ok(())
+        }));
+        self.organize = Some(OrganizeStage::Planning(plan));
+        cx.notify();
+    }
+
+    /// Carry out the previewed Organize by Date plan as a batch job
+    pub(crate) fn handle_confirm_organize(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        let Some(OrganizeStage::Preview(plan)) = self.organize.take() else {
+            return;
+        };
+        let destinations: HashMap<PathBuf, utils::organize::PlannedMove> = plan
+            .moves
+            .iter()
+            .map(|m| (m.source.clone(), m.clone()))
+            .collect();
+        let sources = plan.moves.iter().map(|m| m.source.clone()).collect();
+        let move_files = plan.move_files;
+        self.batch_job = Some(utils::batch::start_batch(sources, move |path| {
+            let planned = destinations.get(path).ok_or("Not in the plan")?;
+            utils::organize::apply_move(planned, move_files)
+        }));
+        self.organize = Some(OrganizeStage::Applying(plan));
+        cx.notify();
+    }
+
+    /// Handle the end of an Organize by Date batch job: a finished plan
+    /// becomes the preview, a finished apply becomes a summary toast (and,
+    /// for moves, the list follows the files to their new folders).
+    fn finish_organize_stage(
+        &mut self,
+        stage: OrganizeStage,
+        summary: &utils::batch::BatchSummary,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let first_failure = summary.failed.first().map(|(path, e)| {
+            let name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            format!("{} failed (first: {}: {})", summary.failed.len(), name, e)
+        });
+        let toast = |message: String, detail: Option<String>, is_error: bool| ToastState {
+            message,
+            detail,
+            is_error,
+            created_at: Instant::now(),
+        };
+
+        match stage {
+            OrganizeStage::Planning(plan) => {
+                let plan = plan.lock().map(|p| p.clone());
+                match plan {
+                    _ if summary.cancelled => {
+                        self.toast = Some(toast("Organize cancelled".into(), None, false));
+                    }
+                    Ok(plan) if !plan.moves.is_empty() => {
+                        self.organize = Some(OrganizeStage::Preview(plan));
+                    }
+                    _ => {
+                        self.toast = Some(toast("Nothing to organize".into(), first_failure, true));
+                    }
+                }
+            }
+            // No batch job runs while the preview is up; leave it showing
+            OrganizeStage::Preview(plan) => self.organize = Some(OrganizeStage::Preview(plan)),
+            OrganizeStage::Applying(plan) => {
+                let verb = if plan.move_files { "Moved" } else { "Copied" };
+                if plan.move_files {
+                    // Files run in plan order, so everything up to where the
+                    // job stopped moved unless it's listed as failed
+                    let processed = summary.completed + summary.failed.len();
+                    let renames: HashMap<PathBuf, PathBuf> = plan
+                        .moves
+                        .iter()
+                        .take(processed)
+                        .filter(|m| !summary.failed.iter().any(|(p, _)| p == &m.source))
+                        .map(|m| (m.source.clone(), m.destination.clone()))
+                        .collect();
+                    let current_moved = self
+                        .app_state
+                        .current_image()
+                        .is_some_and(|p| renames.contains_key(p));
+                    self.app_state.rename_paths(&renames);
+                    if current_moved {
+                        self.update_viewer(window, cx);
+                        self.update_window_title(window);
+                    }
+                }
+                let message = if summary.cancelled {
+                    format!(
+                        "Organize cancelled \u{2014} {} of {} {}",
+                        summary.completed,
+                        summary.total,
+                        verb.to_lowercase()
+                    )
+                } else {
+                    format!("{} {} of {} files", verb, summary.completed, summary.total)
+                };
+                let is_error = first_failure.is_some();
+                self.toast = Some(toast(message, first_failure, is_error));
+            }
+        }
+    }
+
+    /// Save a finished stack and switch to it.  Returns the toast text.
+    fn finish_stack(
+        &mut self,
+        stack: &utils::stack::StackAccumulator,
+        output: &Path,
+        summary: &utils::batch::BatchSummary,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Result<String, String> {
+        if summary.cancelled {
+            return Ok("Stack cancelled".to_string());
+        }
+        let image = stack.finish().ok_or("No images could be stacked")?;
+        save_dynamic_image_to_path(&image, output)?;
+        self.import_image_paths(&[output.to_path_buf()], window, cx);
+        Ok(format!(
+            "Stacked {} of {} images",
+            stack.count(),
+            summary.total
+        ))
+    }
+
+    /// Stop the running batch job after the file it's working on.
+    pub(crate) fn handle_cancel_batch(&mut self, cx: &mut Context<Self>) {
+        if let Some(ref job) = self.batch_job {
+            job.cancel();
+            cx.notify();
+        }
+    }
+
+    /// Drain progress from the running batch job.  Once it finishes the
+    /// overlay is dropped and a summary toast takes its place.  Returns
+    /// whether a job is still running.
+    pub(crate) fn poll_batch_job(&mut self, window: &mut Window, cx: &mut Context<Self>) -> bool {
+        let Some(ref mut job) = self.batch_job else {
+            return false;
+        };
+        let Some(summary) = job.poll() else {
+            return true;
+        };
+        self.batch_job = None;
+
+        let first_failure = summary.failed.first().map(|(path, e)| {
+            let name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            (name, e)
+        });
+
+        if let Some(stage) = self.organize.take() {
+            self.finish_organize_stage(stage, &summary, window, cx);
+            return false;
+        }
+
+        if let Some(path) = self.baking.take() {
+            self.finish_bake(path, &summary, window, cx);
+            return false;
+        }
+
+        if let Some((stack, output)) = self.pending_stack.take() {
+            let result = match stack.lock() {
+                Ok(stack) => self.finish_stack(&stack, &output, &summary, window, cx),
+                Err(_) => Err("Stack lock poisoned".to_string()),
+            };
+            // Skipped images are expected (odd sizes), so only a failed
+            // save is an error
+            self.toast = Some(match result {
+                Ok(message) => ToastState {
+                    message,
+                    detail: first_failure.map(|(name, e)| {
+                        format!("{} skipped (first: {}: {})", summary.failed.len(), name, e)
+                    }),
+                    is_error: false,
+                    created_at: Instant::now(),
+                },
+                Err(e) => ToastState {
+                    message: "Stack failed".to_string(),
+                    detail: Some(e),
+                    is_error: true,
+                    created_at: Instant::now(),
+                },
+            });
+            return false;
+        }
+
+        let message = if summary.cancelled {
+            format!(
+                "Export cancelled \u{2014} {} of {} exported",
+                summary.completed, summary.total
+            )
+        } else {
+            format!("Exported {} of {} images", summary.completed, summary.total)
+        };
+        let message = if summary.skipped > 0 {
+            format!("{} ({} already done)", message, summary.skipped)
+        } else {
+            message
+        };
+        let detail = first_failure
+            .map(|(name, e)| format!("{} failed (first: {}: {})", summary.failed.len(), name, e));
+        self.toast = Some(ToastState {
+            message,
+            is_error: detail.is_some(),
+            detail,
+            created_at: Instant::now(),
+        });
+        false
+    }
+
+    /// Copy every keyboard shortcut to the clipboard as a Markdown cheat
+    /// sheet (the help overlay's Copy button).
+    pub(crate) fn handle_copy_shortcut_sheet(&mut self, cx: &mut Context<Self>) {
+        let sheet = app_keybindings::shortcut_sheet(&app_keybindings::key_bindings());
+        cx.write_to_clipboard(ClipboardItem::new_string(sheet));
+        self.toast = Some(ToastState {
+            message: "Copied shortcut list to clipboard".into(),
+            detail: None,
+            is_error: false,
+            created_at: Instant::now(),
+        });
+        cx.notify();
+    }
+
+    /// Copy what's on screen (the visible part of the image at the current
+    /// zoom, with filters) to the clipboard as a PNG.
+    pub(crate) fn handle_copy_viewport_image(
+        &mut self,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.is_modal_open() {
+            return;
+        }
+        let Some(image) = self
+            .viewer
+            .capture_viewport(self.settings.file_operations.resize_filter)
+        else {
+            return;
+        };
+        self.toast = Some(match png_clipboard_item(image) {
+            Ok(item) => {
+                cx.write_to_clipboard(item);
+                ToastState {
+                    message: "Copied visible area to clipboard".into(),
+                    detail: None,
+                    is_error: false,
+                    created_at: Instant::now(),
+                }
+            }
+            Err(e) => ToastState {
+                message: "Couldn't copy visible area".into(),
+                detail: Some(e.to_string()),
+                is_error: true,
+                created_at: Instant::now(),
+            },
+        });
+        cx.notify();
+    }
+
+    /// Copy the whole current image to the clipboard as a PNG, as displayed
+    /// (filters, GPU pipeline or recalled slot included).  Where the
+    /// clipboard can't hold images the file path is copied instead.
+    pub(crate) fn handle_copy_image_to_clipboard(
+        &mut self,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.is_modal_open() {
+            return;
+        }
+        let Some(path) = self.app_state.current_image().cloned() else {
+            return;
+        };
+        let image_item = if IMAGE_CLIPBOARD {
+            self.capture_display_rgba()
+                .ok_or_else(|| "Couldn't read the image".to_string())
+                .and_then(|image| png_clipboard_item(image).map_err(|e| e.to_string()))
+        } else {
+            Err("This clipboard doesn't take images".to_string())
+        };
+        let (item, message, detail) = match image_item {
+            Ok(item) => (item, "Copied image to clipboard", None),
+            Err(reason) => (
+                ClipboardItem::new_string(path.display().to_string()),
+                "Copied file path to clipboard",
+                Some(reason),
+            ),
+        };
+        cx.write_to_clipboard(item);
+        self.toast = Some(ToastState {
+            message: message.into(),
+            detail,
+            is_error: false,
+            created_at: Instant::now(),
+        });
+        cx.notify();
+    }
+
+    /// Copy the current file's full (canonical) path to the clipboard.
+    pub(crate) fn handle_copy_path(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_modal_open() {
+            return;
+        }
+        let Some(path) = self.app_state.current_image() else {
+            return;
+        };
+        let path = path.canonicalize().unwrap_or_else(|_| path.clone());
+        let path = path.display().to_string();
+        cx.write_to_clipboard(ClipboardItem::new_string(path.clone()));
+        self.toast = Some(ToastState {
+            message: "Copied path to clipboard".into(),
+            detail: Some(path),
+            is_error: false,
+            created_at: Instant::now(),
+        });
+        cx.notify();
+    }
+
+    /// The image as displayed, in RGBA: the recalled slot, GPU pipeline or
+    /// filtered render when there is one, otherwise the decoded source.
+    fn capture_display_rgba(&mut self) -> Option<image::RgbaImage> {
+        let snapshot = self.viewer.capture_current_display()?;
+        let mut rgba = snapshot.render.as_bytes(0)?.to_vec();
+        for px in rgba.chunks_exact_mut(4) {
+            px.swap(0, 2);
+        }
+        image::RgbaImage::from_raw(snapshot.width, snapshot.height, rgba)
+    }
+
+    /// Save what's on screen (see `handle_copy_viewport_image`) through a
+    /// Save dialog.
+    pub(crate) fn handle_save_viewport_image(
+        &mut self,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.is_modal_open() {
+            return;
+        }
+        let Some(current_path) = self.app_state.current_image().cloned() else {
+            return;
+        };
+        let Some(image) = self
+            .viewer
+            .capture_viewport(self.settings.file_operations.resize_filter)
+        else {
+            return;
+        };
+        let stem = current_path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("image");
+        let suggested_name = format!("{}_view.png", stem);
+        let directory: Option<PathBuf> = self
+            .settings
+            .file_operations
+            .default_save_directory
+            .clone()
+            .or_else(|| current_path.parent().map(PathBuf::from));
+
+        cx.spawn(async move |_this, _cx| {
+            let mut dialog = rfd::AsyncFileDialog::new()
+                .add_filter("PNG", &["png"])
+                .add_filter("JPEG", &["jpg", "jpeg"])
+                .add_filter("BMP", &["bmp"])
+                .add_filter("TIFF", &["tiff", "tif"])
+                .add_filter("WEBP", &["webp"])
+                .set_file_name(&suggested_name)
+                .set_title("Save Visible Area");
+            if let Some(dir) = directory {
+                dialog = dialog.set_directory(dir);
+            }
+            let Some(handle) = dialog.save_file().await else {
+                return;
+            };
+            let save_path = handle.path().to_path_buf();
+            match save_dynamic_image_to_path(&image::DynamicImage::ImageRgba8(image), &save_path) {
+                Ok(()) => log::info!("Visible area saved to: {}", save_path.display()),
+                Err(e) => log::error!("Failed to save visible area: {}", e),
+            }
+        })
+        .detach();
+    }
+
+    /// Save the animation frame on screen as a still through a Save
+    /// dialog, with the filters baked in while they're on.  The frame is
+    /// decoded from the file once a name is picked.
+    pub(crate) fn handle_export_current_frame(
+        &mut self,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.is_modal_open() {
+            return;
+        }
+        let Some(current_path) = self.app_state.current_image().cloned() else {
+            return;
+        };
+        let state = &self.viewer.image_state;
+        let Some(frame) = state
+            .animation
+            .as_ref()
+            .filter(|anim| anim.frame_count > 1)
+            .map(|anim| anim.current_frame)
+        else {
+            return;
+        };
+        let filters = state.filters_enabled.then_some(state.filters);
+        let stem = current_path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("image");
+        let suggested_name = format!("{}_frame{}.png", stem, frame + 1);
+        let directory: Option<PathBuf> = self
+            .settings
+            .file_operations
+            .default_save_directory
+            .clone()
+            .or_else(|| current_path.parent().map(PathBuf::from));
+
+        cx.spawn(async move |_this, _cx| {
+            let mut dialog = rfd::AsyncFileDialog::new()
+                .add_filter("PNG", &["png"])
+                .add_filter("JPEG", &["jpg", "jpeg"])
+                .set_file_name(&suggested_name)
+                .set_title("Export Frame");
+            if let Some(dir) = directory {
+                dialog = dialog.set_directory(dir);
+            }
+            let Some(handle) = dialog.save_file().await else {
+                return;
+            };
+            let save_path = handle.path().to_path_buf();
+            let result = utils::image_loader::load_animation_frame(&current_path, frame)
+                .map_err(|e| e.to_string())
+                .and_then(|image| {
+                    let image = match filters {
+                        Some(f) => utils::filters::apply_filters(&image, &f),
+                        None => image,
+                    };
+                    save_dynamic_image_to_path(&image, &save_path)
+                });
+            match result {
+                Ok(()) => log::info!("Frame {} saved to: {}", frame + 1, save_path.display()),
+                Err(e) => log::error!("Failed to export frame: {}", e),
+            }
+        })
+        .detach();
+    }
+
     fn handle_save_file_impl(&mut self, default_dir: Option<PathBuf>, cx: &mut Context<Self>) {
         // Only save if we have a current image
         let Some(current_path) = self.app_state.current_image().cloned() else {
@@ -633,27 +2237,18 @@ impl App {
                 .current_image
                 .as_ref()
                 .is_some_and(|i| i.filtered_render.is_some());
-        let any_processing = active_slot || gpu_pipeline_active || filters_active;
-
-        // Determine extension from settings when any processing is active
+        // A rotated image is re-encoded turned, never copied
+        let rotated = self.viewer.image_state.rotation != 0;
+        let any_processing = active_slot || gpu_pipeline_active || filters_active || rotated;
+
+        // The configured format picks the suggested extension; Same as
+        // Loaded keeps the source's own, unless it has to be encoded in a
+        // format we can't write
+        let format = self.settings.file_operations.default_save_format;
         let save_ext = if any_processing {
-            use crate::state::settings::SaveFormat;
-            match self.settings.file_operations.default_save_format {
-                SaveFormat::SameAsLoaded => current_path
-                    .extension()
-                    .and_then(|e| e.to_str())
-                    .unwrap_or("png"),
-                SaveFormat::Png => "png",
-                SaveFormat::Jpeg => "jpg",
-                SaveFormat::Bmp => "bmp",
-                SaveFormat::Tiff => "tiff",
-                SaveFormat::Webp => "webp",
-            }
+            encoded_extension(format, &current_path)
         } else {
-            current_path
-                .extension()
-                .and_then(|e| e.to_str())
-                .unwrap_or("png")
+            save_extension(format, &current_path)
         };
 
         // Generate suggested filename with `_filtered` suffix when processing is active.
@@ -674,54 +2269,44 @@ impl App {
         // so AppKit can dispatch other actions (incl. NextImage on right
         // arrow) which would race with a re-borrow of `self`.
         enum SaveSource {
-            Processed {
-                rgba: Vec<u8>,
-                width: u32,
-                height: u32,
-            },
+            Processed(image::RgbaImage),
             OriginalCopy {
                 source_path: PathBuf,
+                svg_background: Option<[u8; 3]>,
             },
         }
         let source: Option<SaveSource> = if any_processing {
-            self.viewer.capture_current_display().and_then(|snapshot| {
-                snapshot.render.as_bytes(0).map(|bgra| {
-                    let mut rgba = bgra.to_vec();
-                    for px in rgba.chunks_exact_mut(4) {
-                        px.swap(0, 2);
-                    }
-                    SaveSource::Processed {
-                        rgba,
-                        width: snapshot.width,
-                        height: snapshot.height,
-                    }
-                })
-            })
+            self.capture_display_rgba().map(SaveSource::Processed)
         } else {
             self.viewer
                 .current_image
                 .as_ref()
                 .map(|img| SaveSource::OriginalCopy {
                     source_path: img.path.clone(),
+                    svg_background: img.svg_background,
                 })
         };
         let Some(source) = source else {
-            eprintln!("[Save] Nothing to save (no current image / no display data)");
+            log::warn!("[Save] Nothing to save (no current image / no display data)");
             return;
         };
+        // Filter values baked into this save, so a successful write can
+        // clear the unsaved-changes state that gates the quit prompt.
+        let saved_filters = filters_active.then_some(self.viewer.image_state.filters);
 
         // Hand off the dialog + write to a foreground task.  Returns
         // immediately, releasing `&mut self` — when AppKit dispatches a key
         // event during the modal it goes straight to NSSavePanel (which
         // consumes arrow keys for file-list navigation) instead of trying
         // to re-enter our action handlers.
-        cx.spawn(async move |_, _cx| {
-            let mut dialog = rfd::AsyncFileDialog::new()
-                .add_filter("PNG", &["png"])
-                .add_filter("JPEG", &["jpg", "jpeg"])
-                .add_filter("BMP", &["bmp"])
-                .add_filter("TIFF", &["tiff", "tif"])
-                .add_filter("WEBP", &["webp"])
+        cx.spawn(async move |this, cx| {
+            // The dialog starts on its first filter, so the configured
+            // format goes first
+            let mut dialog = save_dialog_filters(format)
+                .into_iter()
+                .fold(rfd::AsyncFileDialog::new(), |dialog, (name, extensions)| {
+                    dialog.add_filter(name, extensions)
+                })
                 .set_file_name(&suggested_name)
                 .set_title("Save Image");
             if let Some(dir) = directory {
@@ -733,18 +2318,23 @@ impl App {
             let save_path = handle.path().to_path_buf();
 
             let result: Result<(), String> = match source {
-                SaveSource::Processed {
-                    rgba,
-                    width,
-                    height,
-                } => match image::RgbaImage::from_raw(width, height, rgba) {
-                    Some(img) => save_dynamic_image_to_path(
-                        &image::DynamicImage::ImageRgba8(img),
-                        &save_path,
-                    ),
-                    None => Err("Display buffer size mismatch".to_string()),
-                },
-                SaveSource::OriginalCopy { source_path } => {
+                SaveSource::Processed(img) => {
+                    save_dynamic_image_to_path(&image::DynamicImage::ImageRgba8(img), &save_path)
+                }
+                // With a format chosen, a file saved under another
+                // extension than the source's is converted to it rather
+                // than copied byte for byte
+                SaveSource::OriginalCopy {
+                    source_path,
+                    svg_background,
+                } if format != state::settings::SaveFormat::SameAsLoaded
+                    && !same_image_format(&source_path, &save_path) =>
+                {
+                    utils::image_loader::load_source_image(&source_path, svg_background)
+                        .map_err(|e| e.to_string())
+                        .and_then(|image| save_dynamic_image_to_path(&image, &save_path))
+                }
+                SaveSource::OriginalCopy { source_path, .. } => {
                     let parent = save_path.parent().unwrap_or(&save_path);
                     tempfile::NamedTempFile::new_in(parent)
                         .map_err(|e| format!("Failed to create temp file: {}", e))
@@ -760,14 +2350,189 @@ impl App {
             };
 
             match result {
-                Ok(()) => println!("Image saved to: {}", save_path.display()),
-                Err(e) => eprintln!("Failed to save image: {}", e),
+                Ok(()) => {
+                    log::info!("Image saved to: {}", save_path.display());
+                    if let Some(filters) = saved_filters {
+                        let _ = this.update(cx, |app, _cx| {
+                            app.mark_filters_saved(&current_path, filters);
+                        });
+                    }
+                }
+                Err(e) => log::error!("Failed to save image: {}", e),
             }
         })
         .detach();
     }
 }
 
+/// Whether gpui puts image data on this platform's clipboard; its Linux
+/// backends only ever offer the text of a clipboard item.
+const IMAGE_CLIPBOARD: bool = cfg!(any(target_os = "macos", target_os = "windows"));
+
+/// `image` encoded as a PNG clipboard item
+fn png_clipboard_item(image: image::RgbaImage) -> Result<ClipboardItem, image::ImageError> {
+    let mut png = Vec::new();
+    image::DynamicImage::ImageRgba8(image)
+        .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)?;
+    Ok(ClipboardItem::new_image(&gpui::Image::from_bytes(
+        gpui::ImageFormat::Png,
+        png,
+    )))
+}
+
+/// File extension to write for `format`.  `SameAsLoaded` keeps the
+/// source's own extension.
+fn save_extension(format: state::settings::SaveFormat, source_path: &Path) -> &str {
+    use crate::state::settings::SaveFormat;
+    match format {
+        SaveFormat::SameAsLoaded => source_path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("png"),
+        SaveFormat::Png => "png",
+        SaveFormat::Jpeg => "jpg",
+        SaveFormat::Bmp => "bmp",
+        SaveFormat::Tiff => "tiff",
+        SaveFormat::Webp => "webp",
+    }
+}
+
+/// Like `save_extension`, for a file that is encoded rather than copied:
+/// a source format we can't write (SVG, GIF, ICO, …) becomes PNG.
+fn encoded_extension(format: state::settings::SaveFormat, source_path: &Path) -> &str {
+    let ext = save_extension(format, source_path);
+    if ["png", "jpg", "jpeg", "bmp", "tiff", "tif", "webp"]
+        .iter()
+        .any(|w| ext.eq_ignore_ascii_case(w))
+    {
+        ext
+    } else {
+        "png"
+    }
+}
+
+/// The save dialog's file type filters, with `format`'s first.  Same as
+/// Loaded keeps PNG first.
+fn save_dialog_filters(
+    format: state::settings::SaveFormat,
+) -> Vec<(&'static str, &'static [&'static str])> {
+    use crate::state::settings::SaveFormat;
+    let mut filters: Vec<(&'static str, &'static [&'static str])> = vec![
+        ("PNG", &["png"]),
+        ("JPEG", &["jpg", "jpeg"]),
+        ("BMP", &["bmp"]),
+        ("TIFF", &["tiff", "tif"]),
+        ("WEBP", &["webp"]),
+    ];
+    let first = match format {
+        SaveFormat::SameAsLoaded | SaveFormat::Png => 0,
+        SaveFormat::Jpeg => 1,
+        SaveFormat::Bmp => 2,
+        SaveFormat::Tiff => 3,
+        SaveFormat::Webp => 4,
+    };
+    let filter = filters.remove(first);
+    filters.insert(0, filter);
+    filters
+}
+
+/// Whether two paths' extensions name the same image format (`jpg` and
+/// `jpeg`, `tif` and `tiff` are alike)
+fn same_image_format(a: &Path, b: &Path) -> bool {
+    let format = |path: &Path| {
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+        match ext.as_str() {
+            "jpeg" => "jpg".to_string(),
+            "tif" => "tiff".to_string(),
+            _ => ext,
+        }
+    };
+    format(a) == format(b)
+}
+
+/// Whether `save_dynamic_image_to_path` can write `path` back in its own
+/// format (anything else would silently become PNG bytes under the old
+/// extension).
+fn can_rewrite_in_place(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .is_some_and(|e| {
+            matches!(
+                e.as_str(),
+                "png" | "jpg" | "jpeg" | "bmp" | "tiff" | "tif" | "webp"
+            )
+        })
+}
+
+/// Re-encode `path` in place with `filters` applied, in the file's own
+/// format.  The image crate writes pixels only, so embedded metadata (EXIF,
+/// ICC profile) is not carried over.
+fn bake_filters_into_file(
+    path: &Path,
+    filters: state::image_state::FilterSettings,
+) -> Result<(), String> {
+    let image = utils::image_loader::load_image(path).map_err(|e| e.to_string())?;
+    let baked = utils::filters::apply_filters(&image, &filters);
+    save_dynamic_image_to_path(&baked, path)
+}
+
+/// Where Export All writes each of `sources` in `output_dir`: the stem
+/// (with `_filtered` when filters apply) and `format`'s extension, made
+/// unique across the batch.  Never the source itself — exporting into the
+/// image's own folder in the same format gets an `_export` suffix.
+fn export_destinations(
+    sources: &[PathBuf],
+    output_dir: &Path,
+    format: state::settings::SaveFormat,
+    filtered: bool,
+) -> HashMap<PathBuf, PathBuf> {
+    let suffix = if filtered { "_filtered" } else { "" };
+    let names: Vec<(String, String)> = sources
+        .iter()
+        .map(|source| {
+            let stem = source
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("image");
+            let ext = encoded_extension(format, source);
+            let mut stem = format!("{}{}", stem, suffix);
+            if output_dir.join(format!("{}.{}", stem, ext)) == *source {
+                stem.push_str("_export");
+            }
+            (stem, ext.to_string())
+        })
+        .collect();
+    sources
+        .iter()
+        .cloned()
+        .zip(
+            utils::batch::unique_file_names(&names)
+                .into_iter()
+                .map(|name| output_dir.join(name)),
+        )
+        .collect()
+}
+
+/// Batch-export worker: decode `source`, apply `filters` if given, and write
+/// it to `save_path` (see `export_destinations`).
+fn export_image_to(
+    source: &Path,
+    save_path: &Path,
+    filters: Option<state::image_state::FilterSettings>,
+) -> Result<(), String> {
+    let mut image = utils::image_loader::load_image(source).map_err(|e| e.to_string())?;
+    if let Some(f) = filters {
+        image = utils::filters::apply_filters(&image, &f);
+    }
+
+    save_dynamic_image_to_path(&image, save_path)
+}
+
 /// Write `image_data` to `save_path` atomically (temp file + rename) using
 /// the format inferred from `save_path`'s extension.  Free function — has
 /// no `&self` dependency, callable from spawned futures that don't hold an
@@ -822,7 +2587,8 @@ impl App {
         }
         if let Some(current_path) = self.app_state.current_image() {
             if let Err(e) = self.open_in_system_viewer(current_path) {
-                eprintln!("Failed to open image in external viewer: {}", e);
+                log::error!("Failed to open image in external viewer: {}", e);
+                self.show_launch_error("Couldn't open in external viewer", e);
             }
         }
         cx.notify();
@@ -838,7 +2604,9 @@ impl App {
         }
         if let Some(current_path) = self.app_state.current_image() {
             if let Err(e) = self.open_in_system_viewer(current_path) {
-                eprintln!("Failed to open image in external viewer: {}", e);
+                log::error!("Failed to open image in external viewer: {}", e);
+                self.show_launch_error("Couldn't open in external viewer", e);
+                cx.notify();
             } else {
                 // Only quit if we successfully opened the image
                 cx.quit();
@@ -846,6 +2614,49 @@ impl App {
         }
     }
 
+    pub(crate) fn handle_open_in_default_app(
+        &mut self,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.is_modal_open() {
+            return;
+        }
+        self.open_current_in_default_app();
+        cx.notify();
+    }
+
+    pub(crate) fn handle_open_in_default_app_and_quit(
+        &mut self,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.is_modal_open() {
+            return;
+        }
+        if self.open_current_in_default_app() {
+            cx.quit();
+        } else {
+            cx.notify();
+        }
+    }
+
+    /// Hand the current file to the OS default app for its type, skipping
+    /// the configured viewers.  Failure is shown as a toast.
+    fn open_current_in_default_app(&mut self) -> bool {
+        let Some(path) = self.app_state.current_image().cloned() else {
+            return false;
+        };
+        match utils::default_app::open_in_default_app(&path) {
+            Ok(()) => true,
+            Err(e) => {
+                log::error!("Failed to open image in default app: {}", e);
+                self.show_launch_error("Couldn't open in default app", e);
+                false
+            }
+        }
+    }
+
     pub(crate) fn handle_open_in_external_editor(
         &mut self,
         _window: &mut Window,
@@ -856,7 +2667,8 @@ impl App {
         }
         if let Some(current_path) = self.app_state.current_image() {
             if let Err(e) = self.open_in_external_editor(current_path) {
-                eprintln!("Failed to open image in external editor: {}", e);
+                log::error!("Failed to open image in external editor: {}", e);
+                self.show_launch_error("Couldn't open in external editor", e);
             }
         }
         cx.notify();
@@ -866,10 +2678,17 @@ impl App {
         if self.is_modal_open() {
             return;
         }
-        if let Some(current_path) = self.app_state.current_image() {
-            if let Err(e) = self.reveal_in_finder(current_path) {
-                eprintln!("Failed to reveal in file manager: {}", e);
-            }
+        if !self.settings.external_tools.enable_file_manager_integration {
+            log::debug!("Reveal in file manager is turned off in settings");
+            return;
+        }
+        let Some(current_path) = self.app_state.current_image() else {
+            log::debug!("Reveal in file manager: no current image");
+            return;
+        };
+        if let Err(e) = utils::reveal::reveal_in_file_manager(current_path) {
+            log::error!("Failed to reveal in file manager: {}", e);
+            self.show_launch_error("Couldn't reveal in file manager", e);
         }
         cx.notify();
     }
@@ -957,45 +2776,14 @@ impl App {
         cx.notify();
     }
 
-    #[allow(clippy::needless_return)]
-    fn reveal_in_finder(&self, path: &std::path::Path) -> Result<(), String> {
-        #[cfg(target_os = "macos")]
-        {
-            std::process::Command::new("open")
-                .arg("-R")
-                .arg(path)
-                .spawn()
-                .map_err(|e| format!("Failed to reveal in Finder: {}", e))?;
-            return Ok(());
-        }
-
-        #[cfg(target_os = "windows")]
-        {
-            std::process::Command::new("explorer")
-                .arg("/select,")
-                .arg(path)
-                .spawn()
-                .map_err(|e| format!("Failed to reveal in Explorer: {}", e))?;
-            return Ok(());
-        }
-
-        #[cfg(target_os = "linux")]
-        {
-            // Try to get the parent directory and open it
-            if let Some(parent) = path.parent() {
-                std::process::Command::new("xdg-open")
-                    .arg(parent)
-                    .spawn()
-                    .map_err(|e| format!("Failed to open file manager: {}", e))?;
-                return Ok(());
-            }
-            return Err("Could not determine parent directory".to_string());
-        }
-
-        #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
-        {
-            Err("Reveal in file manager not supported on this platform".to_string())
-        }
+    /// Error toast for an external program that wouldn't start
+    fn show_launch_error(&mut self, message: &str, detail: String) {
+        self.toast = Some(ToastState {
+            message: message.to_string(),
+            detail: Some(detail),
+            is_error: true,
+            created_at: Instant::now(),
+        });
     }
 
     #[allow(clippy::needless_return)]
@@ -1023,18 +2811,18 @@ impl App {
 
             match result {
                 Ok(_) => {
-                    debug_eprintln!("Opened image with: {}", viewer_config.name);
+                    log::debug!("Opened image with: {}", viewer_config.name);
                     return Ok(());
                 }
                 Err(e) => {
-                    eprintln!("Failed to launch {}: {}", viewer_config.name, e);
+                    log::warn!("Failed to launch {}: {}", viewer_config.name, e);
                     // Continue to next viewer
                 }
             }
         }
 
         // All configured viewers failed, try platform defaults as fallback
-        debug_eprintln!("All configured viewers failed, trying platform defaults...");
+        log::debug!("All configured viewers failed, trying platform defaults...");
 
         #[cfg(target_os = "macos")]
         {
@@ -1093,7 +2881,7 @@ impl App {
                 .spawn()
                 .map_err(|e| format!("Failed to launch {}: {}", editor_config.name, e))?;
 
-            debug_eprintln!("Opened image in external editor: {}", editor_config.name);
+            log::debug!("Opened image in external editor: {}", editor_config.name);
             Ok(())
         } else {
             Err("No external editor configured. Please set one in Settings (Cmd+,)".to_string())
@@ -1106,13 +2894,17 @@ impl App {
     /// A single file expands to its whole containing directory (so navigation
     /// works), a single directory to its contents.  Several paths at once are
     /// taken as an explicit set and start at the first.  Returns `None` when
-    /// nothing usable was found.
+    /// nothing usable was found.  With `recursive`, directories include their
+    /// subfolders.
     ///
     /// Kept separate from `import_image_paths` because "Open With" now builds a
     /// brand-new window from this list rather than mutating an existing one.
-    pub(crate) fn resolve_import_paths(paths: &[PathBuf]) -> Option<(Vec<PathBuf>, usize)> {
+    pub(crate) fn resolve_import_paths(
+        paths: &[PathBuf],
+        recursive: bool,
+    ) -> Option<(Vec<PathBuf>, usize)> {
         if paths.len() == 1 {
-            return utils::file_scanner::process_dropped_path(&paths[0]).ok();
+            return utils::file_scanner::process_dropped_path(&paths[0], recursive).ok();
         }
 
         let mut all_images: Vec<PathBuf> = Vec::new();
@@ -1120,7 +2912,7 @@ impl App {
             if path.is_file() && utils::file_scanner::is_supported_image(path) {
                 all_images.push(path.to_path_buf());
             } else if path.is_dir() {
-                if let Ok(dir_images) = utils::file_scanner::scan_directory(path) {
+                if let Ok(dir_images) = utils::file_scanner::scan_directory_with(path, recursive) {
                     all_images.extend(dir_images);
                 }
             }
@@ -1142,13 +2934,21 @@ impl App {
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        let Some((all_images, target_index)) = Self::resolve_import_paths(paths) else {
+        if let [path] = paths
+            && utils::archive::is_archive(path)
+        {
+            self.open_archive(path, window, cx);
+            return;
+        }
+        let recursive = self.settings.sort_navigation.recursive_scan;
+        let Some((all_images, target_index)) = Self::resolve_import_paths(paths, recursive) else {
             return;
         };
 
         // Set the paths and a temporary index pointing at the target file
         self.app_state.image_paths = all_images;
         self.app_state.current_index = target_index;
+        self.archive = None;
 
         // Re-sort according to the active sort mode (process_dropped_path
         // always sorts alphabetically; this corrects for ModifiedDate mode).
@@ -1171,27 +2971,347 @@ impl App {
         self.import_image_paths(&dropped, window, cx);
     }
 
+    /// Right arrow: the next page, or the previous one when reading right
+    /// to left
     pub(crate) fn handle_next_image(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.arrow_step(true, window, cx);
+    }
+
+    /// Left arrow: the previous page, or the next one when reading right
+    /// to left
+    pub(crate) fn handle_previous_image(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.arrow_step(false, window, cx);
+    }
+
+    /// An arrow press: one step, or with double-tap enabled, a second quick
+    /// tap jumps to the end of the list that arrow heads for
+    fn arrow_step(&mut self, right_arrow: bool, window: &mut Window, cx: &mut Context<Self>) {
         if self.is_modal_open() {
             return;
         }
+        let nav = &self.settings.sort_navigation;
+        let forward = nav.arrow_moves_forward(right_arrow);
+        let tap_window = Duration::from_secs_f32(nav.double_tap_secs.max(0.0));
+        let doubled = nav.double_tap_to_end
+            && self
+                .arrow_taps
+                .press(right_arrow, Instant::now(), tap_window);
+        match (doubled, forward) {
+            (true, true) => self.handle_last_image(window, cx),
+            (true, false) => self.handle_first_image(window, cx),
+            (false, _) => self.step_page(forward, window, cx),
+        }
+    }
+
+    /// Move one image, or one spread in two-page spread mode
+    fn step_page(&mut self, forward: bool, window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_modal_open() {
+            return;
+        }
+        let nav = &self.settings.sort_navigation;
+        let wrap = nav.wrap_navigation;
+        let before = self.app_state.current_index;
+        if nav.two_page_spread {
+            self.app_state
+                .step_spread(forward, wrap, nav.spread_cover_alone);
+        } else if forward {
+            self.app_state.next_image_with_wrap(wrap);
+        } else {
+            self.app_state.previous_image_with_wrap(wrap);
+        }
+        if !wrap && !self.app_state.image_paths.is_empty() && self.app_state.current_index == before
+        {
+            self.step_past_end(forward);
+        }
+        self.update_viewer(window, cx);
+        self.update_window_title(window);
+        cx.notify();
+    }
+
+    /// A step that went nowhere because the list ended: stop, say so, or
+    /// move on to the adjacent folder, as set
+    fn step_past_end(&mut self, forward: bool) {
+        let nav = &self.settings.sort_navigation;
+        // An archive's pages have no sibling folders to move on to
+        let end = match nav.end_of_list {
+            state::EndOfList::AdjacentFolder if self.archive.is_some() => state::EndOfList::Notify,
+            end => end,
+        };
+        let recursive = nav.recursive_scan;
+        let message = match self.app_state.step_past_end(forward, end, recursive) {
+            state::EndOfListStep::Stayed => return,
+            state::EndOfListStep::Notify if forward => "End of folder".to_string(),
+            state::EndOfListStep::Notify => "Start of folder".to_string(),
+            state::EndOfListStep::OpenedFolder(folder) => {
+                self.archive = None;
+                let name = folder
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_else(|| folder.display().to_string());
+                format!("Opened {}", name)
+            }
+            state::EndOfListStep::NoMoreFolders => "No more folders".to_string(),
+        };
+        self.toast = Some(ToastState {
+            message,
+            detail: None,
+            is_error: false,
+            created_at: Instant::now(),
+        });
+    }
+
+    pub(crate) fn handle_first_image(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_modal_open() || self.app_state.image_paths.is_empty() {
+            return;
+        }
+        self.app_state.first_image();
+        self.update_viewer(window, cx);
+        self.update_window_title(window);
+        cx.notify();
+    }
+
+    pub(crate) fn handle_last_image(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_modal_open() || self.app_state.image_paths.is_empty() {
+            return;
+        }
+        self.app_state.last_image();
+        self.update_viewer(window, cx);
+        self.update_window_title(window);
+        cx.notify();
+    }
 
+    pub(crate) fn handle_page_forward(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_modal_open() {
+            return;
+        }
+        let stride = self.settings.sort_navigation.page_stride;
         let wrap = self.settings.sort_navigation.wrap_navigation;
-        self.app_state.next_image_with_wrap(wrap);
+        self.app_state.advance_by(stride, wrap);
         self.update_viewer(window, cx);
         self.update_window_title(window);
         cx.notify();
     }
 
-    pub(crate) fn handle_previous_image(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+    pub(crate) fn handle_next_different_format(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.jump_to_different_format(true, window, cx);
+    }
+
+    pub(crate) fn handle_previous_different_format(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.jump_to_different_format(false, window, cx);
+    }
+
+    /// Skip to the nearest image whose format differs from the current one;
+    /// stays put (with a toast) when there is none in that direction.
+    fn jump_to_different_format(
+        &mut self,
+        forward: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.is_modal_open() {
+            return;
+        }
+        let wrap = self.settings.sort_navigation.wrap_navigation;
+        match self.app_state.different_format_neighbor(forward, wrap) {
+            Some(index) => {
+                self.app_state.current_index = index;
+                self.update_viewer(window, cx);
+                self.update_window_title(window);
+            }
+            None => {
+                self.toast = Some(ToastState {
+                    message: "No image of a different format".into(),
+                    detail: None,
+                    is_error: false,
+                    created_at: Instant::now(),
+                });
+            }
+        }
+        cx.notify();
+    }
+
+    /// Jump `decile` tenths of the way through the image list (alt-5 →
+    /// halfway), for sampling a large folder.
+    pub(crate) fn handle_jump_to_decile(
+        &mut self,
+        decile: u8,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.is_modal_open() {
+            return;
+        }
+        self.app_state.jump_to_fraction(f32::from(decile) / 10.0);
+        self.update_viewer(window, cx);
+        self.update_window_title(window);
+        cx.notify();
+    }
+
+    pub(crate) fn handle_page_backward(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_modal_open() {
+            return;
+        }
+        let stride = self.settings.sort_navigation.page_stride;
+        let wrap = self.settings.sort_navigation.wrap_navigation;
+        self.app_state.retreat_by(stride, wrap);
+        self.update_viewer(window, cx);
+        self.update_window_title(window);
+        cx.notify();
+    }
+
+    /// Start or stop advancing to the next image every
+    /// `slideshow_interval_secs`.  Navigating by hand or opening an overlay
+    /// stops it too (see `tick_slideshow`).
+    pub(crate) fn handle_toggle_slideshow(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
         if self.is_modal_open() {
             return;
         }
-        let wrap = self.settings.sort_navigation.wrap_navigation;
-        self.app_state.previous_image_with_wrap(wrap);
-        self.update_viewer(window, cx);
-        self.update_window_title(window);
-        cx.notify();
+        self.idle_slideshow_started = None;
+        let message = if self.slideshow.take().is_some() {
+            "Slideshow stopped".to_string()
+        } else {
+            let secs =
+                utils::slideshow::interval(self.settings.viewer_behavior.slideshow_interval_secs)
+                    .as_secs_f32();
+            self.slideshow = Some(utils::slideshow::Slideshow::start(
+                self.app_state.current_index,
+                Instant::now(),
+            ));
+            format!("Slideshow: every {}s", secs)
+        };
+        self.toast = Some(ToastState {
+            message,
+            detail: None,
+            is_error: false,
+            created_at: Instant::now(),
+        });
+        cx.notify();
+    }
+
+    /// Advance a running slideshow once its interval is up.  It ends when
+    /// the image changed under it (manual navigation), an overlay is open,
+    /// or the next image is the current one (end of list without wrap).
+    /// While it runs, a timer is kept set for when the interval is next up.
+    pub(crate) fn tick_slideshow(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        use utils::slideshow::{Slideshow, SlideshowTick};
+        let Some(show) = self.slideshow else {
+            self.slideshow_wakeup = None;
+            return;
+        };
+        let overlay_open = self.is_modal_open() || self.overlays.any_open();
+        // One that started by itself gives way to any input at all
+        let interrupted = self
+            .idle_slideshow_started
+            .is_some_and(|started| self.last_activity > started);
+        let behavior = &self.settings.viewer_behavior;
+        let animation_pending = behavior.slideshow_let_animations_finish
+            && self
+                .viewer
+                .image_state
+                .animation
+                .as_ref()
+                .is_some_and(|a| a.finite_run_pending());
+        let tick = show.tick(
+            self.app_state.current_index,
+            behavior.slideshow_interval_secs,
+            animation_pending,
+            Instant::now(),
+        );
+        match tick {
+            _ if overlay_open || interrupted => self.slideshow = None,
+            SlideshowTick::Stop => self.slideshow = None,
+            SlideshowTick::Wait => {}
+            SlideshowTick::Advance => {
+                self.handle_next_image(window, cx);
+                let index = self.app_state.current_index;
+                if index == show.index {
+                    self.slideshow = None;
+                    self.toast = Some(ToastState {
+                        message: "Slideshow finished".to_string(),
+                        detail: None,
+                        is_error: false,
+                        created_at: Instant::now(),
+                    });
+                } else {
+                    self.slideshow = Some(Slideshow::start(index, Instant::now()));
+                    self.slideshow_wakeup = None;
+                }
+            }
+        }
+        let Some(show) = self.slideshow else {
+            self.slideshow_wakeup = None;
+            return;
+        };
+        if self.slideshow_wakeup.is_none() {
+            let due =
+                utils::slideshow::interval(self.settings.viewer_behavior.slideshow_interval_secs)
+                    .saturating_sub(show.shown_at.elapsed());
+            // Past due means it's holding for an animation, whose own
+            // frames keep render coming
+            if due.is_zero() {
+                return;
+            }
+            let executor = cx.background_executor().clone();
+            self.slideshow_wakeup = Some(cx.spawn(async move |this, cx| {
+                executor.timer(due).await;
+                let _ = this.update(cx, |this, cx| {
+                    this.slideshow_wakeup = None;
+                    cx.notify();
+                });
+            }));
+        }
+    }
+
+    /// Start the slideshow once `idle_slideshow_secs` pass without input,
+    /// when that's enabled.  Until then, keep a timer set for the moment
+    /// it would be due so an untouched window still gets there.
+    pub(crate) fn tick_idle_slideshow(&mut self, cx: &mut Context<Self>) {
+        let behavior = &self.settings.viewer_behavior;
+        if !behavior.idle_slideshow
+            || self.slideshow.is_some()
+            || self.app_state.image_paths.is_empty()
+            || self.is_modal_open()
+            || self.overlays.any_open()
+        {
+            self.idle_slideshow_wakeup = None;
+            return;
+        }
+        let now = Instant::now();
+        let timeout_secs = behavior.idle_slideshow_secs;
+        if utils::slideshow::idle_start_due(
+            self.last_activity,
+            self.idle_slideshow_started,
+            timeout_secs,
+            now,
+        ) {
+            self.idle_slideshow_wakeup = None;
+            self.idle_slideshow_started = Some(now);
+            self.slideshow = Some(utils::slideshow::Slideshow::start(
+                self.app_state.current_index,
+                now,
+            ));
+            return;
+        }
+        if self.idle_slideshow_wakeup.is_none() {
+            let due = utils::slideshow::interval(timeout_secs)
+                .saturating_sub(now.duration_since(self.last_activity));
+            let executor = cx.background_executor().clone();
+            self.idle_slideshow_wakeup = Some(cx.spawn(async move |this, cx| {
+                executor.timer(due).await;
+                let _ = this.update(cx, |this, cx| {
+                    this.idle_slideshow_wakeup = None;
+                    cx.notify();
+                });
+            }));
+        }
     }
 
     pub(crate) fn handle_toggle_animation(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
@@ -1205,44 +3325,99 @@ impl App {
             if anim_state.is_playing {
                 // Reset timer when starting playback
                 self.last_frame_update = Instant::now();
+                // A play-once animation that has finished plays again
+                let finished = anim_state.loop_mode == utils::animation::LoopMode::PlayOnce
+                    && anim_state.current_frame + 1 >= anim_state.frame_count;
+                if finished {
+                    self.viewer.set_current_frame(0);
+                }
             }
             cx.notify();
         }
     }
 
-    pub(crate) fn handle_next_frame(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+    /// Step the current animation through loop / play once / ping-pong
+    pub(crate) fn handle_cycle_loop_mode(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
         if self.is_modal_open() {
             return;
         }
-        if let Some(ref mut anim_state) = self.viewer.image_state.animation {
-            // Pause animation when manually navigating frames
-            anim_state.is_playing = false;
-        }
-        if let Some(ref anim) = self.viewer.image_state.animation {
-            let next = (anim.current_frame + 1) % anim.frame_count;
-            // Goes through set_current_frame so the rescale fires when the
-            // new frame's effective dimensions differ.
-            self.viewer.set_current_frame(next);
+        let Some(ref mut anim_state) = self.viewer.image_state.animation else {
+            return;
+        };
+        anim_state.loop_mode = anim_state.loop_mode.next();
+        anim_state.reversed = false;
+        self.toast = Some(ToastState {
+            message: format!("Playback: {}", anim_state.loop_mode.label()),
+            detail: None,
+            is_error: false,
+            created_at: Instant::now(),
+        });
+        cx.notify();
+    }
+
+    /// Double (`faster`) or halve the current animation's playback speed
+    pub(crate) fn handle_animation_speed(
+        &mut self,
+        faster: bool,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.is_modal_open() {
+            return;
         }
-        // GPU pipeline output is per-frame; re-run on the new frame.
-        self.reapply_gpu_pipeline_if_active(cx);
+        let Some(ref mut anim_state) = self.viewer.image_state.animation else {
+            return;
+        };
+        anim_state.change_speed(faster);
+        self.toast = Some(ToastState {
+            message: format!("Animation speed: {}x", anim_state.speed),
+            detail: None,
+            is_error: false,
+            created_at: Instant::now(),
+        });
         cx.notify();
     }
 
+    pub(crate) fn handle_next_frame(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        self.step_frame(utils::animation::FrameStep::Forward(1), cx);
+    }
+
     pub(crate) fn handle_previous_frame(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        self.step_frame(utils::animation::FrameStep::Backward(1), cx);
+    }
+
+    pub(crate) fn handle_next_frames(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        let n = self.settings.viewer_behavior.frame_step;
+        self.step_frame(utils::animation::FrameStep::Forward(n), cx);
+    }
+
+    pub(crate) fn handle_previous_frames(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        let n = self.settings.viewer_behavior.frame_step;
+        self.step_frame(utils::animation::FrameStep::Backward(n), cx);
+    }
+
+    pub(crate) fn handle_first_frame(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        self.step_frame(utils::animation::FrameStep::First, cx);
+    }
+
+    pub(crate) fn handle_last_frame(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        self.step_frame(utils::animation::FrameStep::Last, cx);
+    }
+
+    /// Show another frame of the current animation or page stack
+    fn step_frame(&mut self, step: utils::animation::FrameStep, cx: &mut Context<Self>) {
         if self.is_modal_open() {
             return;
         }
         if let Some(ref mut anim_state) = self.viewer.image_state.animation {
+            // Pause animation when manually navigating frames
             anim_state.is_playing = false;
         }
         if let Some(ref anim) = self.viewer.image_state.animation {
-            let prev = if anim.current_frame == 0 {
-                anim.frame_count - 1
-            } else {
-                anim.current_frame - 1
-            };
-            self.viewer.set_current_frame(prev);
+            let target = step.target(anim.current_frame, anim.frame_count);
+            // Goes through set_current_frame so the rescale fires when the
+            // new frame's effective dimensions differ.
+            self.viewer.set_current_frame(target);
         }
         // GPU pipeline output is per-frame; re-run on the new frame.
         self.reapply_gpu_pipeline_if_active(cx);
@@ -1253,7 +3428,7 @@ impl App {
         if self.is_modal_open() {
             return;
         }
-        self.app_state.set_sort_mode(state::SortMode::Alphabetical);
+        self.change_sort_mode(state::SortMode::Alphabetical);
         self.update_viewer(window, cx);
         self.update_window_title(window);
         cx.notify();
@@ -1263,7 +3438,7 @@ impl App {
         if self.is_modal_open() {
             return;
         }
-        self.app_state.set_sort_mode(state::SortMode::ModifiedDate);
+        self.change_sort_mode(state::SortMode::ModifiedDate);
         self.update_viewer(window, cx);
         self.update_window_title(window);
         cx.notify();
@@ -1283,13 +3458,57 @@ impl App {
             state::SortMode::TypeAlpha => state::SortMode::TypeModified,
             state::SortMode::TypeModified => state::SortMode::TypeAlpha,
         };
-        self.app_state.set_sort_mode(next);
+        self.change_sort_mode(next);
         self.update_viewer(window, cx);
         self.update_window_title(window);
         cx.notify();
     }
 
-    fn do_zoom(&mut self, zoom_fn: impl FnOnce(&mut ImageViewer), cx: &mut Context<Self>) {
+    /// Flip the list to run the other way under the same sort mode,
+    /// remembered for the next launch
+    pub(crate) fn handle_toggle_sort_direction(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.is_modal_open() {
+            return;
+        }
+        let descending = !self.app_state.sort_descending;
+        self.app_state.set_sort_descending(descending);
+        self.settings.sort_navigation.sort_descending = descending;
+        settings_io::save_settings_debounced(&self.settings);
+        self.update_viewer(window, cx);
+        self.update_window_title(window);
+        cx.notify();
+    }
+
+    /// Note the folder being browsed, for the next launch to reopen
+    pub(crate) fn remember_directory(&mut self) {
+        if !self.settings.file_operations.remember_last_directory {
+            return;
+        }
+        let dir = self.dialog_start_directory();
+        if dir.is_some() && self.settings.file_operations.last_directory != dir {
+            self.settings.file_operations.last_directory = dir;
+            settings_io::save_settings_debounced(&self.settings);
+        }
+    }
+
+    /// Re-sort the list and remember the choice for the next launch.
+    fn change_sort_mode(&mut self, mode: state::SortMode) {
+        self.app_state.set_sort_mode(mode);
+        if self.settings.sort_navigation.last_sort_mode != Some(mode) {
+            self.settings.sort_navigation.last_sort_mode = Some(mode);
+            settings_io::save_settings_debounced(&self.settings);
+        }
+    }
+
+    pub(crate) fn do_zoom(
+        &mut self,
+        zoom_fn: impl FnOnce(&mut ImageViewer),
+        cx: &mut Context<Self>,
+    ) {
         if self.is_modal_open() {
             return;
         }
@@ -1307,7 +3526,133 @@ impl App {
     }
 
     pub(crate) fn handle_zoom_reset(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
-        self.do_zoom(|v| v.reset_zoom(), cx);
+        let stops = self.settings.viewer_behavior.zero_key_zoom_stops();
+        self.do_zoom(|v| v.reset_zoom(&stops), cx);
+    }
+
+    /// Fit-lock: keep every image at fit-to-window across resizes and
+    /// navigation, ignoring saved per-image zoom.  Zooming by hand still
+    /// works until the next resize or image change.
+    pub(crate) fn handle_toggle_fit_lock(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_modal_open() {
+            return;
+        }
+        self.viewer.fit_locked = !self.viewer.fit_locked;
+        if self.viewer.fit_locked {
+            self.viewer.fit_to_window();
+            self.save_current_image_state();
+        }
+        self.toast = Some(ToastState {
+            message: if self.viewer.fit_locked {
+                "Fit locked".to_string()
+            } else {
+                "Fit unlocked".to_string()
+            },
+            detail: None,
+            is_error: false,
+            created_at: Instant::now(),
+        });
+        cx.notify();
+    }
+
+    /// Filter-lock: carry the current filters to every image as you
+    /// navigate, leaving each image's saved filters untouched.  Unlocking
+    /// brings back the current image's own filters.
+    pub(crate) fn handle_toggle_filter_lock(
+        &mut self,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.is_modal_open() {
+            return;
+        }
+        if self.viewer.filters_locked {
+            self.viewer.filters_locked = false;
+            self.load_current_image_state(cx);
+            // The locked filters may still be baked into the render
+            self.viewer.update_filtered_cache();
+        } else {
+            // Cache the image's own filters before the live ones take over
+            self.save_current_image_state();
+            self.viewer.filters_locked = true;
+        }
+        self.toast = Some(ToastState {
+            message: if self.viewer.filters_locked {
+                "Filters locked".to_string()
+            } else {
+                "Filters unlocked".to_string()
+            },
+            detail: None,
+            is_error: false,
+            created_at: Instant::now(),
+        });
+        cx.notify();
+    }
+
+    /// Auto exposure on/off for this session (the setting picks the state
+    /// each window starts in).  Only the display changes; the image's
+    /// filters are left as they are.
+    pub(crate) fn handle_toggle_auto_exposure(
+        &mut self,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.is_modal_open() {
+            return;
+        }
+        self.viewer.auto_exposure = !self.viewer.auto_exposure;
+        self.viewer.update_auto_exposure();
+        let amount = self.viewer.auto_exposure_amount();
+        self.toast = Some(ToastState {
+            message: if self.viewer.auto_exposure {
+                "Auto exposure on".to_string()
+            } else {
+                "Auto exposure off".to_string()
+            },
+            detail: (self.viewer.auto_exposure && amount.abs() >= 0.05)
+                .then(|| format!("Brightness {:+.0}", amount)),
+            is_error: false,
+            created_at: Instant::now(),
+        });
+        cx.notify();
+    }
+
+    /// Remember the current zoom/pan so it can be pasted onto another image
+    pub(crate) fn handle_copy_view(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_modal_open() || self.viewer.current_image.is_none() {
+            return;
+        }
+        let snapshot = self.viewer.view_snapshot();
+        self.copied_view = Some(snapshot);
+        self.toast = Some(ToastState {
+            message: "View copied".to_string(),
+            detail: Some(utils::zoom::format_zoom_percentage(snapshot.zoom)),
+            is_error: false,
+            created_at: Instant::now(),
+        });
+        cx.notify();
+    }
+
+    /// Apply the copied zoom/pan to the current image, for lining up the
+    /// same crop of two shots
+    pub(crate) fn handle_paste_view(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_modal_open() {
+            return;
+        }
+        let Some(snapshot) = self.copied_view else {
+            self.toast = Some(ToastState {
+                message: "No view copied".to_string(),
+                detail: None,
+                is_error: false,
+                created_at: Instant::now(),
+            });
+            cx.notify();
+            return;
+        };
+        if self.viewer.apply_view_snapshot(snapshot) {
+            self.save_current_image_state();
+            cx.notify();
+        }
     }
 
     pub(crate) fn handle_zoom_reset_and_center(
@@ -1318,6 +3663,263 @@ impl App {
         self.do_zoom(|v| v.reset_zoom_and_pan(), cx);
     }
 
+    /// Show the "Zoom to" field, prefilled with the current zoom so the
+    /// number can simply be typed over.
+    pub(crate) fn handle_zoom_to_percent(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_modal_open() || self.viewer.current_image.is_none() {
+            return;
+        }
+        let current = format!("{:.0}", self.viewer.image_state.zoom * 100.0);
+        let input = cx.new(|cx| {
+            TextInput::new(cx)
+                .with_value(current)
+                .placeholder("250%")
+                .select_on_focus(true)
+                .input_filter(|c| c.is_ascii_digit() || c == '.' || c == '%')
+        });
+        cx.subscribe_in(
+            &input,
+            window,
+            |this, input, event: &TextInputEvent, window, cx| match event {
+                TextInputEvent::Enter => {
+                    let text = input.read(cx).content().to_string();
+                    this.apply_zoom_entry(&text, window, cx);
+                }
+                TextInputEvent::Escape | TextInputEvent::Blur => {
+                    this.close_zoom_entry(window, cx);
+                }
+                _ => {}
+            },
+        )
+        .detach();
+        input.read(cx).focus_handle().clone().focus(window);
+        self.zoom_entry = Some(input);
+        cx.notify();
+    }
+
+    /// Zoom to the typed percentage; text that isn't a positive number just
+    /// closes the field and leaves the zoom alone
+    fn apply_zoom_entry(&mut self, text: &str, window: &mut Window, cx: &mut Context<Self>) {
+        self.close_zoom_entry(window, cx);
+        if let Some(zoom) = utils::zoom::parse_zoom_percent(text) {
+            self.do_zoom(|v| v.set_zoom_percent(zoom * 100.0), cx);
+        }
+    }
+
+    pub(crate) fn close_zoom_entry(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.zoom_entry.take().is_some() {
+            self.focus_handle.focus(window);
+            cx.notify();
+        }
+    }
+
+    /// Show the "Go to image" field, prefilled with the current image's
+    /// number beside the total so the number can simply be typed over.
+    pub(crate) fn handle_go_to_image(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_modal_open() || self.app_state.image_paths.is_empty() {
+            return;
+        }
+        let current = (self.app_state.current_index + 1).to_string();
+        let input = cx.new(|cx| {
+            TextInput::new(cx)
+                .with_value(current)
+                .placeholder("1")
+                .select_on_focus(true)
+                .input_filter(|c| c.is_ascii_digit())
+        });
+        cx.subscribe_in(
+            &input,
+            window,
+            |this, input, event: &TextInputEvent, window, cx| match event {
+                TextInputEvent::Enter => {
+                    let text = input.read(cx).content().to_string();
+                    this.apply_go_to_entry(&text, window, cx);
+                }
+                TextInputEvent::Escape | TextInputEvent::Blur => {
+                    this.close_go_to_entry(window, cx);
+                }
+                _ => {}
+            },
+        )
+        .detach();
+        input.read(cx).focus_handle().clone().focus(window);
+        self.go_to_entry = Some(input);
+        cx.notify();
+    }
+
+    /// Jump to the typed 1-based image number, clamped to the list; text
+    /// that isn't a number just closes the field
+    fn apply_go_to_entry(&mut self, text: &str, window: &mut Window, cx: &mut Context<Self>) {
+        self.close_go_to_entry(window, cx);
+        let Ok(number) = text.trim().parse::<usize>() else {
+            return;
+        };
+        if self.app_state.go_to(number.saturating_sub(1)) {
+            self.update_viewer(window, cx);
+            self.update_window_title(window);
+            cx.notify();
+        }
+    }
+
+    pub(crate) fn close_go_to_entry(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.go_to_entry.take().is_some() {
+            self.focus_handle.focus(window);
+            cx.notify();
+        }
+    }
+
+    /// Show the "Go to frame" field for an animation or page stack,
+    /// prefilled with the current frame's number.  Stills and single-frame
+    /// images have nothing to jump between, so it doesn't open for them.
+    pub(crate) fn handle_go_to_frame(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_modal_open() {
+            return;
+        }
+        let Some(anim) = self
+            .viewer
+            .image_state
+            .animation
+            .as_ref()
+            .filter(|anim| anim.frame_count > 1)
+        else {
+            return;
+        };
+        let current = (anim.current_frame + 1).to_string();
+        let input = cx.new(|cx| {
+            TextInput::new(cx)
+                .with_value(current)
+                .placeholder("1")
+                .select_on_focus(true)
+                .input_filter(|c| c.is_ascii_digit())
+        });
+        cx.subscribe_in(
+            &input,
+            window,
+            |this, input, event: &TextInputEvent, window, cx| match event {
+                TextInputEvent::Enter => {
+                    let text = input.read(cx).content().to_string();
+                    this.apply_go_to_frame_entry(&text, window, cx);
+                }
+                TextInputEvent::Escape | TextInputEvent::Blur => {
+                    this.close_go_to_frame_entry(window, cx);
+                }
+                _ => {}
+            },
+        )
+        .detach();
+        input.read(cx).focus_handle().clone().focus(window);
+        self.go_to_frame_entry = Some(input);
+        cx.notify();
+    }
+
+    /// Show the typed 1-based frame, clamped to the sequence and paused
+    /// there like any other frame step; text that isn't a number just
+    /// closes the field
+    fn apply_go_to_frame_entry(&mut self, text: &str, window: &mut Window, cx: &mut Context<Self>) {
+        self.close_go_to_frame_entry(window, cx);
+        let Ok(number) = text.trim().parse::<usize>() else {
+            return;
+        };
+        self.step_frame(
+            utils::animation::FrameStep::To(number.saturating_sub(1)),
+            cx,
+        );
+    }
+
+    pub(crate) fn close_go_to_frame_entry(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.go_to_frame_entry.take().is_some() {
+            self.focus_handle.focus(window);
+            cx.notify();
+        }
+    }
+
+    /// Open the rename prompt, pre-filled with the current file's stem.
+    /// The extension is kept; only the name before it changes.
+    pub(crate) fn handle_rename_image(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_modal_open() {
+            return;
+        }
+        let Some(stem) = self
+            .app_state
+            .current_image()
+            .and_then(|p| p.file_stem())
+            .map(|s| s.to_string_lossy().into_owned())
+        else {
+            return;
+        };
+        let input = cx.new(|cx| {
+            TextInput::new(cx)
+                .with_value(stem)
+                .select_on_focus(true)
+                .input_filter(|c| c != '/' && c != '\\')
+        });
+        cx.subscribe_in(
+            &input,
+            window,
+            |this, input, event: &TextInputEvent, window, cx| match event {
+                TextInputEvent::Enter => {
+                    let text = input.read(cx).content().to_string();
+                    this.apply_rename_entry(&text, window, cx);
+                }
+                TextInputEvent::Escape | TextInputEvent::Blur => {
+                    this.close_rename_entry(window, cx);
+                }
+                _ => {}
+            },
+        )
+        .detach();
+        input.read(cx).focus_handle().clone().focus(window);
+        self.rename_entry = Some(input);
+        cx.notify();
+    }
+
+    /// Rename the current file on disk to the typed stem and follow it in
+    /// the image list.  A refused name or a failed rename shows in the
+    /// viewer's error display and leaves the file as it was.
+    fn apply_rename_entry(&mut self, text: &str, window: &mut Window, cx: &mut Context<Self>) {
+        self.close_rename_entry(window, cx);
+        let Some(path) = self.app_state.current_image().cloned() else {
+            return;
+        };
+        if path.file_stem().is_some_and(|stem| stem == text.trim()) {
+            return;
+        }
+        // Cache the view first so it moves to the new name with the file
+        self.save_current_image_state();
+        match utils::rename::rename_file(&path, text) {
+            Ok(new_path) => {
+                let name = new_path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                self.app_state
+                    .rename_paths(&HashMap::from([(path, new_path)]));
+                self.update_viewer(window, cx);
+                self.update_window_title(window);
+                self.toast = Some(ToastState {
+                    message: format!("Renamed to {}", name),
+                    detail: None,
+                    is_error: false,
+                    created_at: Instant::now(),
+                });
+            }
+            Err(e) => {
+                log::error!("Rename failed: {}", e);
+                self.viewer.error_message = Some(format!("Rename failed: {}", e));
+                self.viewer.error_path = Some(path);
+                self.viewer.error_is_incomplete = false;
+            }
+        }
+        cx.notify();
+    }
+
+    pub(crate) fn close_rename_entry(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.rename_entry.take().is_some() {
+            self.focus_handle.focus(window);
+            cx.notify();
+        }
+    }
+
     pub(crate) fn handle_zoom_in_fast(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
         self.do_zoom(|v| v.zoom_in(utils::zoom::ZOOM_STEP_FAST), cx);
     }
@@ -1346,6 +3948,7 @@ impl App {
                 );
                 v.image_state.zoom = new_zoom;
                 v.image_state.is_fit_to_window = false;
+                v.image_state.view_adjusted = true;
             },
             cx,
         );
@@ -1363,6 +3966,7 @@ impl App {
                 );
                 v.image_state.zoom = new_zoom;
                 v.image_state.is_fit_to_window = false;
+                v.image_state.view_adjusted = true;
             },
             cx,
         );
@@ -1449,33 +4053,56 @@ impl App {
         // Only save state if enabled in settings
         if self.settings.viewer_behavior.remember_per_image_state {
             let state = self.viewer.get_image_state();
-            self.app_state.save_current_state(state);
+            let defaults = self.default_filters();
+            self.app_state
+                .save_shown_state(state, self.viewer.filters_locked, defaults);
         }
     }
 
-    pub(crate) fn load_current_image_state(&mut self, cx: &mut Context<Self>) {
-        let default_filters = state::image_state::FilterSettings {
+    /// Filter values new images start from, as configured in settings.
+    pub(crate) fn default_filters(&self) -> state::image_state::FilterSettings {
+        state::image_state::FilterSettings {
             brightness: self.settings.filters.default_brightness,
             contrast: self.settings.filters.default_contrast,
             gamma: self.settings.filters.default_gamma,
-        };
+            saturation: self.settings.filters.default_saturation,
+            invert: false,
+            grayscale: false,
+            red_offset: self.settings.filters.default_red_offset,
+            green_offset: self.settings.filters.default_green_offset,
+            blue_offset: self.settings.filters.default_blue_offset,
+        }
+    }
+
+    pub(crate) fn load_current_image_state(&mut self, cx: &mut Context<Self>) {
+        // Filter history belongs to the image it was made on
+        self.filter_history.clear();
+        self.pan_glide = None;
+        let default_filters = self.default_filters();
         let state = self.app_state.get_current_state(default_filters);
-        let filters = state.filters;
-        let filters_enabled = state.filters_enabled;
         self.viewer.set_image_state(state); // move, no clone
+        // Read back after filter-lock has had its say
+        let filters = self.viewer.image_state.filters;
+        let filters_enabled = self.viewer.image_state.filters_enabled;
+        let rotation = self.viewer.image_state.rotation;
 
         // Update filter controls UI to reflect the loaded filter values
         self.filter_controls.update(cx, |controls, cx| {
             controls.update_from_filters(filters, cx);
         });
 
-        // Re-apply filters to the newly-loaded image if they're non-default.
-        // This costs one LUT pass (fast in-memory) — no longer requires disk I/O.
-        if filters_enabled
+        // Re-apply filters (and any rotation) to the newly-loaded image if
+        // they're non-default.  This costs one LUT pass (fast in-memory) —
+        // no longer requires disk I/O.
+        let filters_active = filters_enabled
             && (filters.brightness.abs() >= 0.001
                 || filters.contrast.abs() >= 0.001
-                || (filters.gamma - 1.0).abs() >= 0.001)
-        {
+                || (filters.gamma - 1.0).abs() >= 0.001
+                || filters.saturation.abs() >= 0.001
+                || filters.invert
+                || filters.grayscale
+                || filters.channel_offsets() != [0.0; 3]);
+        if filters_active || rotation != 0 {
             self.viewer.update_filtered_cache();
         }
     }
@@ -1499,7 +4126,7 @@ impl App {
         self.viewer.update_gpu_pipeline(params);
     }
 
-    pub(crate) fn update_viewer(&mut self, window: &mut Window, _cx: &mut Context<Self>) {
+    pub(crate) fn update_viewer(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         if let Some(path) = self.app_state.current_image().cloned() {
             // Ensure viewport size is set before loading
             let viewport_size = window.viewport_size();
@@ -1516,6 +4143,9 @@ impl App {
             // Load the image asynchronously (non-blocking)
             let max_dim = Some(self.settings.performance.max_image_dimension);
             self.viewer.load_image_async(path, max_dim, force_load);
+            self.update_spread_partner();
+            self.preload_neighbours(cx);
+            self.remember_directory();
 
             // State will be loaded when async load completes (in render loop)
         } else {
@@ -1523,14 +4153,110 @@ impl App {
         }
     }
 
+    /// How many images either side of the current one to preload, sized
+    /// from the current image (see `Performance::effective_preload_radius`)
+    pub(crate) fn preload_radius(&self) -> usize {
+        let image_bytes = self
+            .viewer
+            .current_image
+            .as_ref()
+            .map_or(0, |loaded| loaded.width as u64 * loaded.height as u64 * 4);
+        self.settings
+            .performance
+            .effective_preload_radius(image_bytes)
+    }
+
+    /// Run the images within `preload_radius` of the current one through
+    /// the loader in the background, into the viewer's decode cache (sized
+    /// to match), so stepping to them needn't wait.  With preloading off
+    /// the cache is let go instead.
+    fn preload_neighbours(&mut self, cx: &mut Context<Self>) {
+        if !self.settings.performance.preload_adjacent_images {
+            self.preload_tasks.clear();
+            self.viewer.decode_cache.clear();
+            return;
+        }
+        let radius = self.preload_radius();
+        self.viewer
+            .decode_cache
+            .set_max_entries(utils::decode_cache::entries_for_radius(radius));
+        let wrap = self.settings.sort_navigation.wrap_navigation;
+        let mut neighbours: Vec<PathBuf> = self
+            .app_state
+            .preload_indices(radius, wrap)
+            .into_iter()
+            .filter_map(|i| self.app_state.image_paths.get(i).cloned())
+            .collect();
+        neighbours.retain(|path| !self.viewer.decode_cache.touch(path));
+
+        let max_dim = Some(self.settings.performance.max_image_dimension);
+        let svg_background = self.viewer.svg_background;
+        let executor = cx.background_executor().clone();
+        self.preload_tasks = neighbours
+            .into_iter()
+            .map(|path| {
+                let executor = executor.clone();
+                cx.spawn(async move |this, cx| {
+                    let loading = path.clone();
+                    let Some(data) = executor
+                        .spawn(async move {
+                            utils::image_loader::preload_image_data(
+                                loading,
+                                max_dim,
+                                svg_background,
+                            )
+                        })
+                        .await
+                    else {
+                        return;
+                    };
+                    let _ = this.update(cx, |this, _cx| {
+                        let bytes = data.decoded_bytes();
+                        this.viewer.decode_cache.insert(path, data, bytes);
+                    });
+                })
+            })
+            .collect();
+    }
+
+    /// Pair the current image with its facing page in two-page spread
+    /// mode.  Only the partner's header is read here; `img` decodes it.
+    pub(crate) fn update_spread_partner(&mut self) {
+        let nav = &self.settings.sort_navigation;
+        let partner = nav
+            .two_page_spread
+            .then(|| self.app_state.spread_partner(nav.spread_cover_alone))
+            .flatten()
+            .and_then(|index| {
+                let path = self.app_state.image_paths[index].clone();
+                let (width, height) = image::image_dimensions(&path).ok()?;
+                // The later page goes right, or left when reading right to left
+                let follows = index > self.app_state.current_index;
+                Some(SpreadPage {
+                    path,
+                    width,
+                    height,
+                    on_left: follows == nav.right_to_left,
+                })
+            });
+        self.viewer.set_spread_partner(partner);
+    }
+
     pub(crate) fn update_window_title(&mut self, window: &mut Window) {
         let title = crate::window_title::format_window_title(
             self.app_state.current_image().map(|p| p.as_path()),
             self.app_state.current_index,
             self.app_state.image_paths.len(),
             self.app_state.sort_mode,
+            crate::window_title::TitleView {
+                zoom: self.viewer.image_state.zoom,
+                dimensions: self.viewer.display_dimensions(),
+            },
             &self.settings,
         );
-        window.set_window_title(&title);
+        if title != self.window_title {
+            window.set_window_title(&title);
+            self.window_title = title;
+        }
     }
 }
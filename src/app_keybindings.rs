@@ -1,31 +1,70 @@
 use super::*;
 
+/// Context for bindings that only make sense over the image: every
+/// unmodified or shift/alt-only key.  Text fields (the widgets' own
+/// `CcfTextInput` context) are excluded so typing `w` or `h` into one
+/// inserts the letter instead of panning or opening help.  Cmd/Ctrl
+/// shortcuts stay global.
+pub(crate) const VIEWER_KEYS: &str = "ImageViewer && !CcfTextInput";
+
 pub(crate) fn setup_key_bindings(cx: &mut gpui::App) {
-    cx.bind_keys([
+    cx.bind_keys(key_bindings());
+}
+
+/// The app's keymap, kept separate from `setup_key_bindings` so tests can
+/// build a `Keymap` from it without an app.
+pub(crate) fn key_bindings() -> Vec<KeyBinding> {
+    vec![
         KeyBinding::new("cmd-w", CloseWindow, None),
         KeyBinding::new("cmd-q", Quit, None),
         KeyBinding::new("escape", EscapePressed, None),
-        KeyBinding::new("right", NextImage, Some("ImageViewer")),
-        KeyBinding::new("left", PreviousImage, Some("ImageViewer")),
+        KeyBinding::new("right", NextImage, Some(VIEWER_KEYS)),
+        KeyBinding::new("left", PreviousImage, Some(VIEWER_KEYS)),
+        KeyBinding::new("pagedown", PageForward, Some(VIEWER_KEYS)),
+        KeyBinding::new("pageup", PageBackward, Some(VIEWER_KEYS)),
+        KeyBinding::new("home", FirstImage, Some(VIEWER_KEYS)),
+        KeyBinding::new("end", LastImage, Some(VIEWER_KEYS)),
+        KeyBinding::new("shift-right", NextDifferentFormat, Some(VIEWER_KEYS)),
+        KeyBinding::new("shift-left", PreviousDifferentFormat, Some(VIEWER_KEYS)),
+        KeyBinding::new("f2", RenameImage, Some(VIEWER_KEYS)),
+        KeyBinding::new("p", ToggleSlideshow, Some(VIEWER_KEYS)),
         // Animation controls
-        KeyBinding::new("o", ToggleAnimationPlayPause, None),
-        KeyBinding::new("]", NextFrame, None),
-        KeyBinding::new("[", PreviousFrame, None),
+        KeyBinding::new("o", ToggleAnimationPlayPause, Some(VIEWER_KEYS)),
+        KeyBinding::new("shift-o", CycleLoopMode, Some(VIEWER_KEYS)),
+        KeyBinding::new("]", NextFrame, Some(VIEWER_KEYS)),
+        KeyBinding::new("[", PreviousFrame, Some(VIEWER_KEYS)),
+        KeyBinding::new("shift-]", NextFrames, Some(VIEWER_KEYS)),
+        KeyBinding::new("}", NextFrames, Some(VIEWER_KEYS)), // Shift+] on US keyboards
+        KeyBinding::new("shift-[", PreviousFrames, Some(VIEWER_KEYS)),
+        KeyBinding::new("{", PreviousFrames, Some(VIEWER_KEYS)), // Shift+[ on US keyboards
+        KeyBinding::new("alt-[", FirstFrame, Some(VIEWER_KEYS)),
+        KeyBinding::new("alt-]", LastFrame, Some(VIEWER_KEYS)),
+        KeyBinding::new("shift-g", GoToFrame, Some(VIEWER_KEYS)),
+        KeyBinding::new(".", AnimationSpeedUp, Some(VIEWER_KEYS)),
+        KeyBinding::new(",", AnimationSpeedDown, Some(VIEWER_KEYS)),
         KeyBinding::new("shift-cmd-a", SortAlphabetical, None),
         KeyBinding::new("shift-cmd-m", SortByModified, None),
         KeyBinding::new("shift-cmd-t", SortByTypeToggle, None),
+        KeyBinding::new("shift-cmd-d", ToggleSortDirection, None),
         KeyBinding::new("shift-cmd-g", ToggleGpuPipeline, None),
+        KeyBinding::new("cmd-g", GoToImage, None),
         // Zoom controls - base (normal speed)
-        KeyBinding::new("=", ZoomIn, None), // = key (same as +)
-        KeyBinding::new("+", ZoomIn, None),
-        KeyBinding::new("-", ZoomOut, None),
-        KeyBinding::new("0", ZoomReset, None),
+        KeyBinding::new("+", ZoomIn, Some(VIEWER_KEYS)),
+        KeyBinding::new("=", ZoomIn, Some(VIEWER_KEYS)), // = key (same as +)
+        KeyBinding::new("-", ZoomOut, Some(VIEWER_KEYS)),
+        KeyBinding::new("0", ZoomReset, Some(VIEWER_KEYS)),
         KeyBinding::new("cmd-0", ZoomResetAndCenter, None),
+        KeyBinding::new("shift-z", ZoomToPercent, Some(VIEWER_KEYS)),
+        KeyBinding::new("alt-f", ToggleFitLock, Some(VIEWER_KEYS)),
+        KeyBinding::new("alt-c", CopyView, Some(VIEWER_KEYS)),
+        KeyBinding::new("alt-v", PasteView, Some(VIEWER_KEYS)),
+        KeyBinding::new("r", RotateClockwise, Some(VIEWER_KEYS)),
+        KeyBinding::new("shift-r", RotateCounterClockwise, Some(VIEWER_KEYS)),
         // Zoom controls - fast (with Shift)
-        KeyBinding::new("shift-=", ZoomInFast, None),
-        KeyBinding::new("shift-+", ZoomInFast, None),
-        KeyBinding::new("shift--", ZoomOutFast, None),
-        KeyBinding::new("_", ZoomOutFast, None), // Shift+- produces _ on US keyboard
+        KeyBinding::new("shift-=", ZoomInFast, Some(VIEWER_KEYS)),
+        KeyBinding::new("shift-+", ZoomInFast, Some(VIEWER_KEYS)),
+        KeyBinding::new("shift--", ZoomOutFast, Some(VIEWER_KEYS)),
+        KeyBinding::new("_", ZoomOutFast, Some(VIEWER_KEYS)), // Shift+- produces _ on US keyboard
         // Zoom controls - slow (with Cmd/Ctrl)
         KeyBinding::new("cmd-=", ZoomInSlow, None),
         KeyBinding::new("cmd-+", ZoomInSlow, None),
@@ -36,65 +75,95 @@ pub(crate) fn setup_key_bindings(cx: &mut gpui::App) {
         KeyBinding::new("shift-cmd--", ZoomOutIncremental, None),
         KeyBinding::new("cmd-_", ZoomOutIncremental, None), // Shift+Cmd+- produces Cmd+_ on US keyboard
         // Pan controls with WASD (base speed: 10px)
-        KeyBinding::new("w", PanUp, None),
-        KeyBinding::new("a", PanLeft, None),
-        KeyBinding::new("s", PanDown, None),
-        KeyBinding::new("d", PanRight, None),
+        KeyBinding::new("w", PanUp, Some(VIEWER_KEYS)),
+        KeyBinding::new("a", PanLeft, Some(VIEWER_KEYS)),
+        KeyBinding::new("s", PanDown, Some(VIEWER_KEYS)),
+        KeyBinding::new("d", PanRight, Some(VIEWER_KEYS)),
         // Pan controls with IJKL (base speed: 10px)
-        KeyBinding::new("i", PanUp, None),
-        KeyBinding::new("j", PanLeft, None),
-        KeyBinding::new("k", PanDown, None),
-        KeyBinding::new("l", PanRight, None),
+        KeyBinding::new("i", PanUp, Some(VIEWER_KEYS)),
+        KeyBinding::new("j", PanLeft, Some(VIEWER_KEYS)),
+        KeyBinding::new("k", PanDown, Some(VIEWER_KEYS)),
+        KeyBinding::new("l", PanRight, Some(VIEWER_KEYS)),
         // Fast pan with Shift (3x speed: 30px)
-        KeyBinding::new("shift-w", PanUpFast, None),
-        KeyBinding::new("shift-a", PanLeftFast, None),
-        KeyBinding::new("shift-s", PanDownFast, None),
-        KeyBinding::new("shift-d", PanRightFast, None),
-        KeyBinding::new("shift-i", PanUpFast, None),
-        KeyBinding::new("shift-j", PanLeftFast, None),
-        KeyBinding::new("shift-k", PanDownFast, None),
-        KeyBinding::new("shift-l", PanRightFast, None),
+        KeyBinding::new("shift-w", PanUpFast, Some(VIEWER_KEYS)),
+        KeyBinding::new("shift-a", PanLeftFast, Some(VIEWER_KEYS)),
+        KeyBinding::new("shift-s", PanDownFast, Some(VIEWER_KEYS)),
+        KeyBinding::new("shift-d", PanRightFast, Some(VIEWER_KEYS)),
+        KeyBinding::new("shift-i", PanUpFast, Some(VIEWER_KEYS)),
+        KeyBinding::new("shift-j", PanLeftFast, Some(VIEWER_KEYS)),
+        KeyBinding::new("shift-k", PanDownFast, Some(VIEWER_KEYS)),
+        KeyBinding::new("shift-l", PanRightFast, Some(VIEWER_KEYS)),
         // Slow pan with Alt (1px) - using Alt to avoid conflicts with Cmd/Ctrl shortcuts
-        KeyBinding::new("alt-w", PanUpSlow, None),
-        KeyBinding::new("alt-a", PanLeftSlow, None),
-        KeyBinding::new("alt-s", PanDownSlow, None),
-        KeyBinding::new("alt-d", PanRightSlow, None),
-        KeyBinding::new("alt-i", PanUpSlow, None),
-        KeyBinding::new("alt-j", PanLeftSlow, None),
-        KeyBinding::new("alt-k", PanDownSlow, None),
-        KeyBinding::new("alt-l", PanRightSlow, None),
+        KeyBinding::new("alt-w", PanUpSlow, Some(VIEWER_KEYS)),
+        KeyBinding::new("alt-a", PanLeftSlow, Some(VIEWER_KEYS)),
+        KeyBinding::new("alt-s", PanDownSlow, Some(VIEWER_KEYS)),
+        KeyBinding::new("alt-d", PanRightSlow, Some(VIEWER_KEYS)),
+        KeyBinding::new("alt-i", PanUpSlow, Some(VIEWER_KEYS)),
+        KeyBinding::new("alt-j", PanLeftSlow, Some(VIEWER_KEYS)),
+        KeyBinding::new("alt-k", PanDownSlow, Some(VIEWER_KEYS)),
+        KeyBinding::new("alt-l", PanRightSlow, Some(VIEWER_KEYS)),
         // Help and debug overlays
-        KeyBinding::new("h", ToggleHelp, None),
-        KeyBinding::new("?", ToggleHelp, None),
-        KeyBinding::new("f1", ToggleHelp, None),
-        KeyBinding::new("f12", ToggleDebug, None),
-        KeyBinding::new("t", ToggleZoomIndicator, None),
-        KeyBinding::new("b", ToggleBackground, None),
+        KeyBinding::new("h", ToggleHelp, Some(VIEWER_KEYS)),
+        KeyBinding::new("?", ToggleHelp, Some(VIEWER_KEYS)),
+        KeyBinding::new("f1", ToggleHelp, Some(VIEWER_KEYS)),
+        KeyBinding::new("f12", ToggleDebug, Some(VIEWER_KEYS)),
+        KeyBinding::new("f11", ToggleFullscreen, None),
+        KeyBinding::new("shift-h", ToggleHistogram, Some(VIEWER_KEYS)),
+        KeyBinding::new("shift-p", ToggleGenerationInfo, Some(VIEWER_KEYS)),
+        KeyBinding::new("m", ToggleMontagePick, Some(VIEWER_KEYS)),
+        KeyBinding::new("shift-m", ToggleMontage, Some(VIEWER_KEYS)),
+        KeyBinding::new("alt-m", ToggleMontageLink, Some(VIEWER_KEYS)),
+        KeyBinding::new("e", ToggleEyedropper, Some(VIEWER_KEYS)),
+        KeyBinding::new("c", ToggleCropMode, Some(VIEWER_KEYS)),
+        KeyBinding::new("tab", CycleOverlays, Some(VIEWER_KEYS)),
+        KeyBinding::new("t", ToggleZoomIndicator, Some(VIEWER_KEYS)),
+        KeyBinding::new("v", ToggleNavigator, Some(VIEWER_KEYS)),
+        KeyBinding::new("b", ToggleBackground, Some(VIEWER_KEYS)),
+        KeyBinding::new("shift-b", CycleBackdrop, Some(VIEWER_KEYS)),
         // Settings window
         KeyBinding::new("cmd-,", ToggleSettings, None),
         KeyBinding::new("escape", CloseSettings, Some("SettingsWindow")),
         KeyBinding::new("cmd-enter", CloseSettings, Some("SettingsWindow")),
         // Filter controls
         KeyBinding::new("cmd-f", ToggleFilters, None),
-        KeyBinding::new("1", DisableFilters, Some("ImageViewer")),
-        KeyBinding::new("2", EnableFilters, Some("ImageViewer")),
-        KeyBinding::new("3", RecallSlot3, Some("ImageViewer")),
-        KeyBinding::new("4", RecallSlot4, Some("ImageViewer")),
-        KeyBinding::new("5", RecallSlot5, Some("ImageViewer")),
-        KeyBinding::new("6", RecallSlot6, Some("ImageViewer")),
-        KeyBinding::new("7", RecallSlot7, Some("ImageViewer")),
-        KeyBinding::new("8", RecallSlot8, Some("ImageViewer")),
-        KeyBinding::new("9", RecallSlot9, Some("ImageViewer")),
-        KeyBinding::new("ctrl-3", StoreSlot3, Some("ImageViewer")),
-        KeyBinding::new("ctrl-4", StoreSlot4, Some("ImageViewer")),
-        KeyBinding::new("ctrl-5", StoreSlot5, Some("ImageViewer")),
-        KeyBinding::new("ctrl-6", StoreSlot6, Some("ImageViewer")),
-        KeyBinding::new("ctrl-7", StoreSlot7, Some("ImageViewer")),
-        KeyBinding::new("ctrl-8", StoreSlot8, Some("ImageViewer")),
-        KeyBinding::new("ctrl-9", StoreSlot9, Some("ImageViewer")),
+        KeyBinding::new("1", DisableFilters, Some(VIEWER_KEYS)),
+        KeyBinding::new("2", EnableFilters, Some(VIEWER_KEYS)),
+        KeyBinding::new("`", ToggleFiltersEnabled, Some(VIEWER_KEYS)),
+        KeyBinding::new("alt-g", ToggleFilterLock, Some(VIEWER_KEYS)),
+        KeyBinding::new("alt-e", ToggleAutoExposure, Some(VIEWER_KEYS)),
+        KeyBinding::new("n", ToggleInvert, Some(VIEWER_KEYS)),
+        KeyBinding::new("g", ToggleGrayscale, Some(VIEWER_KEYS)),
+        KeyBinding::new("3", RecallSlot3, Some(VIEWER_KEYS)),
+        KeyBinding::new("4", RecallSlot4, Some(VIEWER_KEYS)),
+        KeyBinding::new("5", RecallSlot5, Some(VIEWER_KEYS)),
+        KeyBinding::new("6", RecallSlot6, Some(VIEWER_KEYS)),
+        KeyBinding::new("7", RecallSlot7, Some(VIEWER_KEYS)),
+        KeyBinding::new("8", RecallSlot8, Some(VIEWER_KEYS)),
+        KeyBinding::new("9", RecallSlot9, Some(VIEWER_KEYS)),
+        KeyBinding::new("ctrl-3", StoreSlot3, Some(VIEWER_KEYS)),
+        KeyBinding::new("ctrl-4", StoreSlot4, Some(VIEWER_KEYS)),
+        KeyBinding::new("ctrl-5", StoreSlot5, Some(VIEWER_KEYS)),
+        KeyBinding::new("ctrl-6", StoreSlot6, Some(VIEWER_KEYS)),
+        KeyBinding::new("ctrl-7", StoreSlot7, Some(VIEWER_KEYS)),
+        KeyBinding::new("ctrl-8", StoreSlot8, Some(VIEWER_KEYS)),
+        KeyBinding::new("ctrl-9", StoreSlot9, Some(VIEWER_KEYS)),
+        // Plain digits are filters/slots; alt-digit jumps through the list
+        KeyBinding::new("alt-1", JumpToDecile1, Some(VIEWER_KEYS)),
+        KeyBinding::new("alt-2", JumpToDecile2, Some(VIEWER_KEYS)),
+        KeyBinding::new("alt-3", JumpToDecile3, Some(VIEWER_KEYS)),
+        KeyBinding::new("alt-4", JumpToDecile4, Some(VIEWER_KEYS)),
+        KeyBinding::new("alt-5", JumpToDecile5, Some(VIEWER_KEYS)),
+        KeyBinding::new("alt-6", JumpToDecile6, Some(VIEWER_KEYS)),
+        KeyBinding::new("alt-7", JumpToDecile7, Some(VIEWER_KEYS)),
+        KeyBinding::new("alt-8", JumpToDecile8, Some(VIEWER_KEYS)),
+        KeyBinding::new("alt-9", JumpToDecile9, Some(VIEWER_KEYS)),
         KeyBinding::new("shift-cmd-r", ResetFilters, None),
+        KeyBinding::new("cmd-z", UndoFilter, None),
+        KeyBinding::new("shift-cmd-z", RedoFilter, None),
         // File operations
         KeyBinding::new("cmd-o", OpenFile, None),
+        KeyBinding::new("shift-cmd-o", OpenFolder, None),
+        KeyBinding::new("cmd-up", OpenParentFolder, None),
         KeyBinding::new("cmd-s", SaveFile, None),
         // Most apps use Cmd+Shift+S for "Save As..."; rpview's `Save File`
         // already opens a Save As dialog every time, so we accept the same
@@ -103,10 +172,19 @@ pub(crate) fn setup_key_bindings(cx: &mut gpui::App) {
         // shortcut), just available.
         KeyBinding::new("shift-cmd-s", SaveFile, None),
         KeyBinding::new("cmd-alt-s", SaveFileToDownloads, None),
+        KeyBinding::new("shift-cmd-e", ExportAll, None),
+        KeyBinding::new("cmd-alt-c", CopyViewportImage, None),
+        KeyBinding::new("shift-cmd-c", CopyImageToClipboard, None),
+        KeyBinding::new("shift-cmd-alt-c", CopyPath, None),
+        KeyBinding::new("shift-cmd-alt-s", SaveViewportImage, None),
+        KeyBinding::new("cmd-alt-f", ExportCurrentFrame, None),
         KeyBinding::new("cmd-r", RevealInFinder, None),
         // External viewer
         KeyBinding::new("cmd-alt-v", OpenInExternalViewer, None),
         KeyBinding::new("shift-cmd-alt-v", OpenInExternalViewerAndQuit, None),
+        // OS default app
+        KeyBinding::new("cmd-alt-o", OpenInDefaultApp, None),
+        KeyBinding::new("shift-cmd-alt-o", OpenInDefaultAppAndQuit, None),
         // External editor
         KeyBinding::new("cmd-e", OpenInExternalEditor, None),
         // Delete operations
@@ -124,8 +202,12 @@ pub(crate) fn setup_key_bindings(cx: &mut gpui::App) {
         #[cfg(not(target_os = "macos"))]
         KeyBinding::new("shift-ctrl-t", SortByTypeToggle, None),
         #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("shift-ctrl-d", ToggleSortDirection, None),
+        #[cfg(not(target_os = "macos"))]
         KeyBinding::new("ctrl-0", ZoomResetAndCenter, None),
         #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-g", GoToImage, None),
+        #[cfg(not(target_os = "macos"))]
         KeyBinding::new("ctrl-=", ZoomInSlow, None),
         #[cfg(not(target_os = "macos"))]
         KeyBinding::new("ctrl-+", ZoomInSlow, None),
@@ -148,26 +230,50 @@ pub(crate) fn setup_key_bindings(cx: &mut gpui::App) {
         #[cfg(not(target_os = "macos"))]
         KeyBinding::new("shift-ctrl-r", ResetFilters, None),
         #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-z", UndoFilter, None),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("shift-ctrl-z", RedoFilter, None),
+        #[cfg(not(target_os = "macos"))]
         KeyBinding::new("ctrl-o", OpenFile, None),
         #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("shift-ctrl-o", OpenFolder, None),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-up", OpenParentFolder, None),
+        #[cfg(not(target_os = "macos"))]
         KeyBinding::new("ctrl-s", SaveFile, None),
         #[cfg(not(target_os = "macos"))]
         KeyBinding::new("shift-ctrl-s", SaveFile, None),
         #[cfg(not(target_os = "macos"))]
         KeyBinding::new("ctrl-alt-s", SaveFileToDownloads, None),
         #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("shift-ctrl-e", ExportAll, None),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-alt-c", CopyViewportImage, None),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("shift-ctrl-c", CopyImageToClipboard, None),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("shift-ctrl-alt-c", CopyPath, None),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("shift-ctrl-alt-s", SaveViewportImage, None),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-alt-f", ExportCurrentFrame, None),
+        #[cfg(not(target_os = "macos"))]
         KeyBinding::new("ctrl-r", RevealInFinder, None),
         #[cfg(not(target_os = "macos"))]
         KeyBinding::new("ctrl-alt-v", OpenInExternalViewer, None),
         #[cfg(not(target_os = "macos"))]
         KeyBinding::new("shift-ctrl-alt-v", OpenInExternalViewerAndQuit, None),
         #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-alt-o", OpenInDefaultApp, None),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("shift-ctrl-alt-o", OpenInDefaultAppAndQuit, None),
+        #[cfg(not(target_os = "macos"))]
         KeyBinding::new("ctrl-e", OpenInExternalEditor, None),
         #[cfg(not(target_os = "macos"))]
         KeyBinding::new("ctrl-backspace", RequestDelete, None),
         #[cfg(not(target_os = "macos"))]
         KeyBinding::new("shift-ctrl-backspace", RequestPermanentDelete, None),
-    ]);
+    ]
 }
 
 /// Set up native application menus (macOS menu bar, Windows/Linux menus)
@@ -194,13 +300,25 @@ pub(crate) fn setup_menus(cx: &mut gpui::App) {
             name: "File".into(),
             items: vec![
                 MenuItem::action("Open File...", OpenFile),
+                MenuItem::action("Open Folder...", OpenFolder),
+                MenuItem::action("Open Enclosing Folder", OpenParentFolder),
                 MenuItem::action("Save File...", SaveFile),
                 MenuItem::action("Save to Downloads...", SaveFileToDownloads),
+                MenuItem::action("Export All...", ExportAll),
+                MenuItem::action("Stack Average...", StackAverage),
+                MenuItem::action("Organize by Date...", OrganizeByDate),
+                MenuItem::action("Copy Image", CopyImageToClipboard),
+                MenuItem::action("Copy Path", CopyPath),
+                MenuItem::action("Copy Visible Area", CopyViewportImage),
+                MenuItem::action("Save Visible Area...", SaveViewportImage),
                 MenuItem::separator(),
                 MenuItem::action("Reveal in Finder", RevealInFinder),
                 MenuItem::action("Open in External Viewer", OpenInExternalViewer),
                 MenuItem::action("Open in Viewer and Quit", OpenInExternalViewerAndQuit),
+                MenuItem::action("Open in Default App", OpenInDefaultApp),
+                MenuItem::action("Open in Default App and Quit", OpenInDefaultAppAndQuit),
                 MenuItem::action("Open in External Editor", OpenInExternalEditor),
+                MenuItem::action("Rename...", RenameImage),
                 MenuItem::separator(),
                 MenuItem::action("Delete File...", RequestDelete),
                 MenuItem::action("Permanently Delete File...", RequestPermanentDelete),
@@ -214,19 +332,45 @@ pub(crate) fn setup_menus(cx: &mut gpui::App) {
                 MenuItem::action("Zoom In", ZoomIn),
                 MenuItem::action("Zoom Out", ZoomOut),
                 MenuItem::action("Reset Zoom", ZoomReset),
+                MenuItem::action("Zoom To...", ZoomToPercent),
+                MenuItem::action("Lock Fit to Window", ToggleFitLock),
+                MenuItem::action("Copy View", CopyView),
+                MenuItem::action("Paste View", PasteView),
+                MenuItem::action("Rotate Clockwise", RotateClockwise),
+                MenuItem::action("Rotate Counter-Clockwise", RotateCounterClockwise),
                 MenuItem::separator(),
                 MenuItem::action("Toggle Filters", ToggleFilters),
                 MenuItem::action("Disable Filters", DisableFilters),
                 MenuItem::action("Enable Filters", EnableFilters),
+                MenuItem::action("Filters On/Off", ToggleFiltersEnabled),
+                MenuItem::action("Lock Filters Across Images", ToggleFilterLock),
+                MenuItem::action("Auto Exposure", ToggleAutoExposure),
+                MenuItem::action("Invert Colors", ToggleInvert),
+                MenuItem::action("Grayscale", ToggleGrayscale),
+                MenuItem::action("Undo Filter Change", UndoFilter),
+                MenuItem::action("Redo Filter Change", RedoFilter),
                 MenuItem::action("Reset Filters", ResetFilters),
+                MenuItem::action("Apply Filters to File...", BakeFilters),
                 MenuItem::separator(),
                 MenuItem::action("GPU Pipeline...", ToggleGpuPipeline),
                 MenuItem::action("Reset GPU Pipeline", ResetGpuPipeline),
                 MenuItem::separator(),
                 MenuItem::action("Toggle Help", ToggleHelp),
                 MenuItem::action("Toggle Debug", ToggleDebug),
+                MenuItem::action("Toggle Histogram", ToggleHistogram),
+                MenuItem::action("Toggle Generation Info", ToggleGenerationInfo),
+                MenuItem::action("Pick for Montage", ToggleMontagePick),
+                MenuItem::action("Toggle Montage", ToggleMontage),
+                MenuItem::action("Link Montage Views", ToggleMontageLink),
+                MenuItem::action("Cycle Overlays", CycleOverlays),
+                MenuItem::action("Eyedropper", ToggleEyedropper),
+                MenuItem::action("Crop to Selection", ToggleCropMode),
                 MenuItem::action("Toggle Zoom Indicator", ToggleZoomIndicator),
+                MenuItem::action("Toggle Navigator", ToggleNavigator),
                 MenuItem::action("Toggle Background", ToggleBackground),
+                MenuItem::action("Cycle Backdrop", CycleBackdrop),
+                MenuItem::separator(),
+                MenuItem::action("Toggle Full Screen", ToggleFullscreen),
             ],
         },
         Menu {
@@ -234,19 +378,270 @@ pub(crate) fn setup_menus(cx: &mut gpui::App) {
             items: vec![
                 MenuItem::action("Next Image", NextImage),
                 MenuItem::action("Previous Image", PreviousImage),
+                MenuItem::action("Jump Forward", PageForward),
+                MenuItem::action("Jump Backward", PageBackward),
+                MenuItem::action("First Image", FirstImage),
+                MenuItem::action("Last Image", LastImage),
+                MenuItem::action("Go to Image...", GoToImage),
+                MenuItem::action("Next Different Format", NextDifferentFormat),
+                MenuItem::action("Previous Different Format", PreviousDifferentFormat),
+                MenuItem::action("Slideshow", ToggleSlideshow),
                 MenuItem::separator(),
                 MenuItem::action("Sort Alphabetically", SortAlphabetical),
                 MenuItem::action("Sort by Modified Date", SortByModified),
                 MenuItem::action("Sort by Type (Toggle A/M)", SortByTypeToggle),
+                MenuItem::action("Reverse Sort Order", ToggleSortDirection),
             ],
         },
         Menu {
             name: "Animation".into(),
             items: vec![
                 MenuItem::action("Play/Pause", ToggleAnimationPlayPause),
+                MenuItem::action("Cycle Loop Mode", CycleLoopMode),
                 MenuItem::action("Next Frame", NextFrame),
                 MenuItem::action("Previous Frame", PreviousFrame),
+                MenuItem::action("Jump Frames Forward", NextFrames),
+                MenuItem::action("Jump Frames Backward", PreviousFrames),
+                MenuItem::action("First Frame", FirstFrame),
+                MenuItem::action("Last Frame", LastFrame),
+                MenuItem::action("Go to Frame...", GoToFrame),
+                MenuItem::action("Export Frame...", ExportCurrentFrame),
+                MenuItem::action("Faster Playback", AnimationSpeedUp),
+                MenuItem::action("Slower Playback", AnimationSpeedDown),
             ],
         },
     ]);
 }
+
+/// Every binding in `bindings` as a Markdown cheat sheet, one table per
+/// category.  Actions on a menu are grouped and named as the menu bar shows
+/// them; the rest go under Zoom, Pan or Other by name.
+pub(crate) fn shortcut_sheet(bindings: &[KeyBinding]) -> String {
+    use components::menu_bar::shortcut_label;
+
+    // (category, label, keys) in menu order, then binding order
+    let mut rows: Vec<(String, String, Vec<String>)> = Vec::new();
+    let menus = components::MenuBar::menu_definitions(bindings);
+    for binding in bindings {
+        let action = binding.action();
+        let key = shortcut_label(binding.keystrokes()[0].inner());
+        let menu_item = menus.iter().find_map(|menu| {
+            menu.items
+                .iter()
+                .find(|item| item.action.as_ref().is_some_and(|a| a.partial_eq(action)))
+                .map(|item| (menu.name.as_str(), item.label.trim_end_matches("...")))
+        });
+        let (category, label) = match menu_item {
+            Some((category, label)) => (category.to_string(), label.to_string()),
+            None => {
+                let name = action.name().rsplit("::").next().unwrap_or_default();
+                let category = if name.starts_with("Zoom") {
+                    "Zoom"
+                } else if name.starts_with("Pan") {
+                    "Pan"
+                } else {
+                    "Other"
+                };
+                (category.to_string(), humanize_action_name(name))
+            }
+        };
+        match rows
+            .iter_mut()
+            .find(|(c, l, _)| *c == category && *l == label)
+        {
+            Some((_, _, keys)) if keys.contains(&key) => {}
+            Some((_, _, keys)) => keys.push(key),
+            None => rows.push((category, label, vec![key])),
+        }
+    }
+
+    let mut categories: Vec<&str> = menus.iter().map(|m| m.name.as_str()).collect();
+    categories.extend(["Zoom", "Pan", "Other"]);
+    let mut sheet = String::from("# rpview Keyboard Shortcuts\n");
+    for category in categories {
+        let in_category: Vec<_> = rows.iter().filter(|(c, ..)| c == category).collect();
+        if in_category.is_empty() {
+            continue;
+        }
+        sheet.push_str(&format!(
+            "\n## {}\n\n| Key | Action |\n|-----|--------|\n",
+            category
+        ));
+        for (_, label, keys) in in_category {
+            let keys: Vec<String> = keys.iter().map(|k| markdown_code(k)).collect();
+            sheet.push_str(&format!("| {} | {} |\n", keys.join(" / "), label));
+        }
+    }
+    sheet
+}
+
+/// `PanUpFast` → `Pan up fast`
+fn humanize_action_name(name: &str) -> String {
+    let mut words = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if i > 0 && c.is_uppercase() {
+            words.push(' ');
+            words.extend(c.to_lowercase());
+        } else {
+            words.push(c);
+        }
+    }
+    words
+}
+
+/// `key` as inline code that survives a table cell, backtick and pipe
+/// keys included
+fn markdown_code(key: &str) -> String {
+    let key = key.replace('|', "\\|");
+    if key.contains('`') {
+        format!("`` {} ``", key)
+    } else {
+        format!("`{}`", key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        PanUp, ToggleHelp, ZoomIn, humanize_action_name, key_bindings, markdown_code,
+        shortcut_sheet,
+    };
+    use crate::components::MenuBar;
+    use crate::components::menu_bar::shortcut_label;
+    use gpui::{Action, KeyContext, Keymap, Keystroke};
+
+    /// Actions the keymap would dispatch for `key` with `contexts` (root
+    /// first) on the dispatch path
+    fn actions_for(key: &str, contexts: &[&str]) -> Vec<Box<dyn Action>> {
+        let keymap = Keymap::new(key_bindings());
+        let stack: Vec<KeyContext> = contexts
+            .iter()
+            .map(|c| KeyContext::parse(c).unwrap())
+            .collect();
+        let (bindings, _pending) =
+            keymap.bindings_for_input(&[Keystroke::parse(key).unwrap()], &stack);
+        bindings.iter().map(|b| b.action().boxed_clone()).collect()
+    }
+
+    #[test]
+    fn menu_shortcuts_fire_their_actions() {
+        // Every shortcut the menu bar shows, typed over the image, must
+        // dispatch that item's action
+        let bindings = key_bindings();
+        for menu in MenuBar::menu_definitions(&bindings) {
+            for item in menu.items {
+                let (Some(action), Some(shortcut)) = (&item.action, &item.shortcut) else {
+                    continue;
+                };
+                let binding = bindings
+                    .iter()
+                    .filter(|b| b.action().partial_eq(action.as_ref()))
+                    .find(|b| shortcut_label(b.keystrokes()[0].inner()) == *shortcut)
+                    .unwrap_or_else(|| {
+                        panic!("{} shows {shortcut}, which isn't bound", item.label)
+                    });
+                let key = binding.keystrokes()[0].inner().unparse();
+                assert!(
+                    actions_for(&key, &["ImageViewer"])
+                        .iter()
+                        .any(|a| a.partial_eq(action.as_ref())),
+                    "{} shows {shortcut}, but {key} doesn't fire it",
+                    item.label
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn menu_shows_filter_switches_as_bound() {
+        let menus = MenuBar::menu_definitions(&key_bindings());
+        let shortcut = |label: &str| {
+            menus
+                .iter()
+                .flat_map(|m| &m.items)
+                .find(|item| item.label == label)
+                .and_then(|item| item.shortcut.clone())
+        };
+        assert_eq!(shortcut("Disable Filters").as_deref(), Some("1"));
+        assert_eq!(shortcut("Zoom In").as_deref(), Some("+"));
+        assert_eq!(shortcut("Organize by Date..."), None);
+    }
+
+    #[test]
+    fn pan_keys_bound_over_the_image() {
+        let actions = actions_for("w", &["ImageViewer"]);
+        assert!(actions.iter().any(|a| a.partial_eq(&PanUp)));
+    }
+
+    #[test]
+    fn pan_keys_not_bound_in_text_input() {
+        for key in ["w", "a", "s", "d", "i", "j", "k", "l", "shift-w", "alt-w"] {
+            let actions = actions_for(key, &["ImageViewer", "CcfTextInput"]);
+            assert!(actions.is_empty(), "{key} should type, not pan");
+        }
+    }
+
+    #[test]
+    fn single_key_shortcuts_not_bound_in_text_input() {
+        let stack = ["ImageViewer", "CcfTextInput"];
+        assert!(
+            !actions_for("h", &stack)
+                .iter()
+                .any(|a| a.partial_eq(&ToggleHelp))
+        );
+        assert!(
+            !actions_for("=", &stack)
+                .iter()
+                .any(|a| a.partial_eq(&ZoomIn))
+        );
+    }
+
+    #[test]
+    fn shortcut_sheet_lists_every_binding() {
+        // Arrange
+        let bindings = key_bindings();
+        let menus = MenuBar::menu_definitions(&bindings);
+
+        // Act
+        let sheet = shortcut_sheet(&bindings);
+
+        // Assert - each binding's key sits on a row naming its action
+        for binding in &bindings {
+            let action = binding.action();
+            let key = markdown_code(&shortcut_label(binding.keystrokes()[0].inner()));
+            let menu_label = menus
+                .iter()
+                .flat_map(|m| &m.items)
+                .find(|item| item.action.as_ref().is_some_and(|a| a.partial_eq(action)))
+                .map(|item| item.label.trim_end_matches("...").to_string());
+            let label = menu_label.unwrap_or_else(|| {
+                humanize_action_name(action.name().rsplit("::").next().unwrap())
+            });
+            assert!(
+                sheet
+                    .lines()
+                    .any(|line| line.contains(&key) && line.contains(&format!("| {label} |"))),
+                "{} ({key}) missing from the sheet",
+                action.name()
+            );
+        }
+    }
+
+    #[test]
+    fn shortcut_sheet_groups_by_category() {
+        let sheet = shortcut_sheet(&key_bindings());
+        let navigate = sheet.find("## Navigate").unwrap();
+        let pan = sheet.find("## Pan").unwrap();
+        let next = sheet.find("| `\u{2192}` | Next Image |").unwrap();
+        let pan_up = sheet.find("| Pan up |").unwrap();
+        assert!(navigate < next && next < pan && pan < pan_up);
+        assert!(sheet.contains("| `+` / `=` | Zoom In |"));
+        assert!(sheet.contains("| `` ` `` |"), "backtick key stays readable");
+    }
+
+    #[test]
+    fn humanize_action_name_splits_words() {
+        assert_eq!(humanize_action_name("PanUpFast"), "Pan up fast");
+        assert_eq!(humanize_action_name("Quit"), "Quit");
+    }
+}
@@ -1,5 +1,6 @@
 use super::*;
-use crate::utils::debug_eprintln;
+use crate::components::processing_indicator::ProcessingIndicator;
+use crate::utils::crop::CropDrag;
 
 impl Render for App {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
@@ -24,15 +25,26 @@ impl Render for App {
             }
         }
 
+        // Slideshow: each sets a timer to come back when it's next due
+        self.tick_idle_slideshow(cx);
+        self.tick_slideshow(window, cx);
+
+        // Pick up batch job progress; keep frames coming while it runs
+        if self.poll_batch_job(window, cx) {
+            window.request_animation_frame();
+        }
+
         // Check if async image loading has completed
         if self.viewer.check_async_load() {
             // Image loaded successfully or failed - load state and setup animation
             if let Some(path) = self.app_state.current_image().cloned() {
+                self.caption_shown_at = Some(Instant::now());
+
                 // Load cached state FIRST so the restored current_frame is
                 // available before we cache it / before LC reapply uses it.
-                if self.settings.viewer_behavior.remember_per_image_state
-                    && self.app_state.image_states.contains_key(&path)
-                {
+                let restored = self.settings.viewer_behavior.remember_per_image_state
+                    && self.app_state.image_states.contains_key(&path);
+                if restored {
                     self.load_current_image_state(cx);
                 }
 
@@ -57,10 +69,15 @@ impl Render for App {
                 // or every stage is identity with resize=1× (nothing to do).
                 self.reapply_gpu_pipeline_if_active(cx);
 
-                if !(self.settings.viewer_behavior.remember_per_image_state
-                    && self.app_state.image_states.contains_key(&path))
-                {
-                    // Apply default zoom mode from settings for new images
+                // Auto exposure is worked out per image, once it's decoded
+                self.viewer.update_auto_exposure();
+
+                // Only a view the user adjusted by hand is worth restoring;
+                // anything else gets the default zoom mode again so it fits
+                // the current window rather than the one it was last seen in.
+                if self.viewer.fit_locked {
+                    self.viewer.fit_to_window();
+                } else if !restored || !self.app_state.has_adjusted_view() {
                     use crate::state::settings::ZoomMode;
                     match self.settings.viewer_behavior.default_zoom_mode {
                         ZoomMode::FitToWindow => {
@@ -70,25 +87,31 @@ impl Render for App {
                             self.viewer.set_one_hundred_percent();
                         }
                     }
+                }
 
-                    // Reset filter controls to default (no filters)
-                    let default_filters = state::image_state::FilterSettings {
-                        brightness: 0.0,
-                        contrast: 0.0,
-                        gamma: 1.0,
-                    };
+                if !restored {
+                    // Nothing remembered: the configured defaults, or under
+                    // filter-lock the live filters.  Set on the viewer first
+                    // so the sliders echoing them back change nothing.
+                    let locked = self
+                        .viewer
+                        .filters_locked
+                        .then_some(self.viewer.image_state.filters);
+                    let filters = state::AppState::opening_filters(self.default_filters(), locked);
+                    self.filter_history.clear();
+                    self.viewer.image_state.filters = filters;
+                    self.viewer.update_filtered_cache();
                     self.filter_controls.update(cx, |controls, cx| {
-                        controls.update_from_filters(default_filters, cx);
+                        controls.update_from_filters(filters, cx);
                     });
                 }
 
                 // Apply animation auto-play setting
                 if let Some(ref mut anim_state) = self.viewer.image_state.animation {
                     // Set is_playing based on settings (unless we loaded cached state)
-                    if !self.settings.viewer_behavior.remember_per_image_state
-                        || !self.app_state.image_states.contains_key(&path)
-                    {
-                        anim_state.is_playing = self.settings.viewer_behavior.animation_auto_play;
+                    if !restored {
+                        anim_state.is_playing =
+                            anim_state.timed && self.settings.viewer_behavior.animation_auto_play;
                     }
 
                     if anim_state.is_playing {
@@ -157,16 +180,17 @@ impl Render for App {
         let viewport_size = window.viewport_size();
         self.viewer.update_viewport_size(viewport_size);
 
-        // Set preload paths for next/previous images to prime GPU cache
+        // Set preload paths for the images around this one to prime GPU cache
         // This must happen in render() so images are preloaded BEFORE navigation occurs
         // This eliminates black flashing by ensuring textures are already in GPU memory
-        self.viewer.preload_paths.clear();
-        if let Some(next_path) = self.app_state.next_image_path() {
-            self.viewer.preload_paths.push(next_path.clone());
-        }
-        if let Some(prev_path) = self.app_state.previous_image_path() {
-            self.viewer.preload_paths.push(prev_path.clone());
-        }
+        let radius = self.preload_radius();
+        let wrap = self.settings.sort_navigation.wrap_navigation;
+        self.viewer.preload_paths = self
+            .app_state
+            .preload_indices(radius, wrap)
+            .into_iter()
+            .filter_map(|i| self.app_state.image_paths.get(i).cloned())
+            .collect();
 
         // Update animation frame if playing (GPUI's suggested pattern).
         // Playback runs even when LC is mid-batch; unfilled frames fall back
@@ -194,31 +218,37 @@ impl Render for App {
 
             // Compute frame advancement under a short-lived borrow, then
             // apply it via `set_current_frame` so the size-aware rescale
-            // fires when frame dimensions differ.
-            let advance: Option<usize> = {
-                if let Some(ref anim_state) = self.viewer.image_state.animation {
+            // fires when frame dimensions differ.  A play-once animation
+            // stops at its last frame instead.
+            let advance: Option<usize> =
+                if let Some(ref mut anim_state) = self.viewer.image_state.animation {
                     let now = Instant::now();
                     let elapsed = now.duration_since(self.last_frame_update).as_millis() as u32;
-                    let frame_duration = anim_state
-                        .frame_durations
-                        .get(anim_state.current_frame)
-                        .copied()
-                        .unwrap_or(100);
-                    if elapsed >= frame_duration {
-                        let next = (anim_state.current_frame + 1) % anim_state.frame_count;
-                        debug_eprintln!(
+                    if elapsed < anim_state.frame_duration_ms() {
+                        None
+                    } else if let Some((next, reversed)) = utils::animation::playback_step(
+                        anim_state.current_frame,
+                        anim_state.frame_count,
+                        anim_state.loop_mode,
+                        anim_state.reversed,
+                    ) {
+                        log::trace!(
                             "[ANIMATION] Advancing from frame {} to frame {}",
                             anim_state.current_frame,
                             next
                         );
+                        anim_state.reversed = reversed;
+                        if next == 0 {
+                            anim_state.passes_played += 1;
+                        }
                         Some(next)
                     } else {
+                        anim_state.is_playing = false;
                         None
                     }
                 } else {
                     None
-                }
-            };
+                };
             if let Some(next) = advance {
                 self.last_frame_update = Instant::now();
                 let frame_changed = self.viewer.set_current_frame(next);
@@ -243,17 +273,243 @@ impl Render for App {
 
         // (Spacebar-drag removed: click-and-drag pans directly now.)
 
+        // Carry a released drag-pan on until it slows to a stop or the
+        // pan limits hold it
+        if let Some((mut glide, last_step)) = self.pan_glide.take() {
+            let now = Instant::now();
+            let before = self.viewer.image_state.pan;
+            let gliding = match glide.step(now.duration_since(last_step)) {
+                Some((dx, dy)) => {
+                    self.viewer.pan(dx, dy);
+                    self.viewer.notify_svg_zoom_pan_changed();
+                    // Up against the limits on both axes, it can't go further
+                    (dx, dy) == (0.0, 0.0) || self.viewer.image_state.pan != before
+                }
+                None => false,
+            };
+            if gliding {
+                self.pan_glide = Some((glide, now));
+                window.request_animation_frame();
+            } else {
+                self.save_current_image_state();
+            }
+        }
+
+        self.track_fullscreen(window);
+
+        // `{zoom}` and `{dimensions}` in the title change with the view
+        self.update_window_title(window);
+
+        // Presenting is fullscreen or a slideshow.  Only then does the safe
+        // area keep the image clear of the screen edges.
+        let presenting = window.is_fullscreen() || self.slideshow.is_some();
+        let safe_area = if presenting {
+            self.settings.viewer_behavior.safe_area_percent
+        } else {
+            0.0
+        };
+        self.viewer.set_safe_area_percent(safe_area);
+
+        // Auto-hide the cursor while presenting once the mouse goes still.
+        // A timer wakes render when it's due; a mouse move brings it back.
+        let auto_hide = self.settings.viewer_behavior.auto_hide_cursor && presenting;
+        let hide_delay = Duration::from_secs_f32(
+            self.settings
+                .viewer_behavior
+                .cursor_hide_delay_secs
+                .max(0.0),
+        );
+        let since_move = self.last_mouse_move.elapsed();
+        self.cursor_hidden = utils::cursor::should_hide_cursor(auto_hide, since_move, hide_delay);
+        if !auto_hide || self.cursor_hidden {
+            self.cursor_hide_wakeup = None;
+        } else if self.cursor_hide_wakeup.is_none() {
+            let due = hide_delay.saturating_sub(since_move);
+            let executor = cx.background_executor().clone();
+            self.cursor_hide_wakeup = Some(cx.spawn(async move |this, cx| {
+                executor.timer(due).await;
+                let _ = this.update(cx, |this, cx| {
+                    this.cursor_hide_wakeup = None;
+                    cx.notify();
+                });
+            }));
+        }
+
+        // Presentation filename caption: full strength when the image
+        // appears, then fades out.  Keep frames coming until it's gone.
+        let caption_opacity = self
+            .caption_shown_at
+            .filter(|_| self.settings.viewer_behavior.show_presentation_caption && presenting)
+            .map(|shown_at| {
+                utils::caption::caption_opacity(
+                    shown_at.elapsed(),
+                    utils::caption::CAPTION_HOLD,
+                    utils::caption::CAPTION_FADE,
+                )
+            })
+            .unwrap_or(0.0);
+        let caption = (caption_opacity > 0.0)
+            .then(|| self.app_state.current_image())
+            .flatten()
+            .and_then(|path| path.file_name())
+            .map(|name| name.to_string_lossy().into_owned());
+        if caption.is_some() {
+            window.request_animation_frame();
+        }
+
         // Calculate background color once
         let active_bg = self.settings.appearance.active_background_color();
+        let overlay_transparency = self.overlay_transparency(cx);
         let bg_color = rgb(((active_bg[0] as u32) << 16)
             | ((active_bg[1] as u32) << 8)
             | (active_bg[2] as u32));
 
+        // Help, debug and histogram, back to front: `Tab` changes which is on top
+        let info_overlays: Vec<AnyElement> = self
+            .overlays
+            .back_to_front()
+            .to_vec()
+            .into_iter()
+            .map(|overlay| match overlay {
+                Overlay::Help => {
+                    self.help_overlay.update(cx, |overlay, _cx| {
+                        overlay.set_appearance(
+                            overlay_transparency,
+                            self.settings.appearance.font_size_scale,
+                        );
+                    });
+                    self.help_overlay.clone().into_any_element()
+                }
+                Overlay::Debug => {
+                    let image_dimensions = self
+                        .viewer
+                        .current_image
+                        .as_ref()
+                        .map(|img| (img.width, img.height));
+                    let photo_info = self.current_photo_info();
+                    let png_text = self.current_png_text();
+                    self.debug_overlay.update(cx, |overlay, _cx| {
+                        overlay.update_config(DebugOverlayConfig {
+                            current_path: self.app_state.current_image().cloned(),
+                            current_index: self.app_state.current_index,
+                            total_images: self.app_state.image_paths.len(),
+                            zoom: self.viewer.image_state.zoom,
+                            pan: self.viewer.image_state.pan,
+                            is_fit_to_window: self.viewer.image_state.is_fit_to_window,
+                            image_dimensions,
+                            viewport_size: self.viewer.viewport_size,
+                            sort_mode: self.app_state.sort_mode,
+                            photo_info,
+                            png_text,
+                            animation_frame: self
+                                .viewer
+                                .image_state
+                                .animation
+                                .as_ref()
+                                .map(|anim| (anim.current_frame, anim.frame_count)),
+                            animation_speed: self
+                                .viewer
+                                .image_state
+                                .animation
+                                .as_ref()
+                                .map(|anim| anim.speed),
+                            preloaded: (!self.viewer.decode_cache.is_empty()).then(|| {
+                                let cache = &self.viewer.decode_cache;
+                                (cache.len(), cache.total_bytes())
+                            }),
+                            overlay_transparency,
+                            font_size_scale: self.settings.appearance.font_size_scale,
+                        });
+                    });
+                    self.debug_overlay.clone().into_any_element()
+                }
+                Overlay::Histogram => {
+                    // Recounts only when the image or its filtered render changed
+                    self.viewer.refresh_histogram();
+                    let histogram = self.viewer.histogram.clone();
+                    self.histogram_overlay.update(cx, |overlay, _cx| {
+                        overlay.update(
+                            histogram,
+                            overlay_transparency,
+                            self.settings.appearance.font_size_scale,
+                        );
+                    });
+                    self.histogram_overlay.clone().into_any_element()
+                }
+                Overlay::Generation => {
+                    let info = utils::generation_params::generation_info(&self.current_png_text());
+                    self.generation_overlay.update(cx, |overlay, _cx| {
+                        overlay.update(
+                            info,
+                            overlay_transparency,
+                            self.settings.appearance.font_size_scale,
+                        );
+                    });
+                    self.generation_overlay.clone().into_any_element()
+                }
+            })
+            .collect();
+
+        if let Some(ref montage) = self.montage {
+            let viewport = self.viewer.viewport_size;
+            let zoom_step = self.settings.keyboard_mouse.scroll_wheel_sensitivity;
+            let scroll_pans = self.settings.keyboard_mouse.scroll_pans;
+            let font_size_scale = self.settings.appearance.font_size_scale;
+            montage.update(cx, |montage, _cx| {
+                montage.update(
+                    viewport,
+                    active_bg,
+                    zoom_step,
+                    scroll_pans,
+                    overlay_transparency,
+                    font_size_scale,
+                );
+            });
+        }
+
+        let navigator = self.navigator_view().map(|(thumb_size, visible)| {
+            let source = self.viewer.navigator_source();
+            self.navigator.update(cx, |navigator, _cx| {
+                navigator.update(source, thumb_size, Some(visible), overlay_transparency);
+            });
+            self.navigator.clone()
+        });
+
+        let frame_count = self
+            .viewer
+            .image_state
+            .animation
+            .as_ref()
+            .map_or(0, |anim| anim.frame_count);
+        let go_to_prompt = self
+            .go_to_entry
+            .clone()
+            .map(|input| ("Go to image", input, self.app_state.image_paths.len()))
+            .or_else(|| {
+                self.go_to_frame_entry
+                    .clone()
+                    .map(|input| ("Go to frame", input, frame_count))
+            });
+
+        let crop_outline = self.viewer.crop_drag.and_then(|drag| {
+            let selection = self
+                .viewer
+                .crop_selection(drag, self.settings.keyboard_mouse.crop_grid)?;
+            Some((
+                self.viewer.selection_on_screen(selection)?,
+                selection.size_label(),
+            ))
+        });
+
         // Main content area (takes remaining space after menu bar)
         let content = div()
             .flex_1()
             .min_h_0() // Allow shrinking below content size
             .bg(bg_color)
+            .when(self.cursor_hidden, |div| div.cursor(CursorStyle::None))
+            .when(self.eyedropper_active || self.crop_mode, |div| {
+                div.cursor(CursorStyle::Crosshair)
+            })
             .when(self.drag_over, |div| {
                 // Show highlighted border when dragging files over the window
                 div.border_4().border_color(gpui::rgb(0x50fa7b)) // Green highlight
@@ -262,23 +518,45 @@ impl Render for App {
                 MouseButton::Left,
                 cx.listener(|this, event: &MouseDownEvent, _window, cx| {
                     this.mouse_button_down = true;
+                    this.last_activity = Instant::now();
+                    // A click catches a gliding image
+                    if this.pan_glide.take().is_some() {
+                        this.save_current_image_state();
+                    }
 
                     // Close menu bar when clicking on main content (Windows/Linux)
                     #[cfg(not(target_os = "macos"))]
                     this.menu_bar.update(cx, |mb, cx| mb.close_menu(cx));
 
-                    // Start Z-drag zoom if Z key is being held
-                    if this.viewer.z_drag_state.is_some() {
+                    // The eyedropper takes clicks instead of panning
+                    if this.eyedropper_active {
+                        this.handle_eyedropper_click(event.position, cx);
+                    } else if this.crop_mode {
+                        // Start a crop selection
+                        let point = (event.position.x.into(), event.position.y.into());
+                        this.viewer.crop_drag = Some(CropDrag {
+                            start: point,
+                            end: point,
+                            keep_aspect: event.modifiers.shift,
+                        });
+                        cx.notify();
+                    } else if this.viewer.z_drag_state.is_some() {
+                        // Start Z-drag zoom if Z key is being held
                         let y: f32 = event.position.y.into();
                         let x: f32 = event.position.x.into();
                         // Store: (last_x, last_y, center_x, center_y) for zoom centering
                         this.viewer.z_drag_state = Some(Some((x, y, x, y)));
                         cx.notify();
+                    } else if event.click_count == 2 && !this.last_press_panned {
+                        // Double-click toggles fit / 100% about the cursor
+                        let (x, y) = (event.position.x.into(), event.position.y.into());
+                        this.do_zoom(|v| v.reset_zoom_at_point(x, y), cx);
                     } else {
                         // Start drag-to-pan (no modifier key needed)
                         let x: f32 = event.position.x.into();
                         let y: f32 = event.position.y.into();
                         this.viewer.drag_pan_state = Some((x, y));
+                        this.pan_velocity.clear();
                         cx.notify();
                     }
                 }),
@@ -288,11 +566,22 @@ impl Render for App {
                 cx.listener(|this, _event: &MouseUpEvent, _window, cx| {
                     this.mouse_button_down = false;
 
+                    // Finish a crop selection
+                    if this.viewer.crop_drag.is_some() {
+                        this.handle_crop_release(cx);
+                    }
                     // End drag-to-pan (only save state if drag actually moved)
-                    if this.viewer.drag_pan_state.take().is_some() {
+                    else if this.viewer.drag_pan_state.take().is_some() {
+                        this.last_press_panned = this.viewer.drag_pan_moved;
                         if this.viewer.drag_pan_moved {
                             this.save_current_image_state();
                             this.viewer.drag_pan_moved = false;
+                            if this.settings.keyboard_mouse.pan_inertia {
+                                let now = Instant::now();
+                                this.pan_glide =
+                                    utils::pan_inertia::Glide::new(this.pan_velocity.velocity(now))
+                                        .map(|glide| (glide, now));
+                            }
                             cx.notify();
                         }
                     }
@@ -305,6 +594,25 @@ impl Render for App {
                 }),
             )
             .on_mouse_move(cx.listener(|this, event: &MouseMoveEvent, _window, cx| {
+                this.last_mouse_move = Instant::now();
+                this.last_activity = this.last_mouse_move;
+                if this.cursor_hidden {
+                    cx.notify();
+                }
+
+                // Follow the cursor with the eyedropper readout
+                if this.eyedropper_active && !this.mouse_button_down {
+                    let (x, y) = (event.position.x.into(), event.position.y.into());
+                    let sample = this
+                        .viewer
+                        .sample_pixel(x, y)
+                        .map(|sample| (event.position, sample));
+                    if sample != this.eyedropper_sample {
+                        this.eyedropper_sample = sample;
+                        cx.notify();
+                    }
+                }
+
                 // Check if mouse button is actually pressed (safety check for button released outside window)
                 let button_actually_pressed = event.pressed_button.is_some();
 
@@ -320,6 +628,18 @@ impl Render for App {
                     if this.viewer.z_drag_state.is_some() {
                         this.viewer.z_drag_state = Some(None);
                     }
+                    // Drop a crop selection released outside the window
+                    if this.viewer.crop_drag.take().is_some() {
+                        cx.notify();
+                    }
+                }
+
+                // Grow the crop selection
+                if let Some(drag) = this.viewer.crop_drag.as_mut() {
+                    drag.end = (event.position.x.into(), event.position.y.into());
+                    drag.keep_aspect = event.modifiers.shift;
+                    cx.notify();
+                    return;
                 }
 
                 // Handle drag-to-pan (only if mouse button is down and we have valid drag data)
@@ -335,6 +655,7 @@ impl Render for App {
                         // Apply pan directly (1:1 pixel movement)
                         this.viewer.pan(delta_x, delta_y);
                         this.viewer.drag_pan_moved = true;
+                        this.pan_velocity.record(Instant::now(), (delta_x, delta_y));
 
                         // Update last position for next delta calculation
                         this.viewer.drag_pan_state = Some((current_x, current_y));
@@ -379,6 +700,7 @@ impl Render for App {
 
                         this.viewer.image_state.pan = (new_pan_x, new_pan_y);
                         this.viewer.image_state.is_fit_to_window = false;
+                        this.viewer.image_state.view_adjusted = true;
 
                         // Update last position for next delta calculation
                         this.viewer.z_drag_state =
@@ -390,12 +712,26 @@ impl Render for App {
                 }
             }))
             .on_scroll_wheel(cx.listener(|this, event: &ScrollWheelEvent, _window, cx| {
-                // Only handle scroll if Ctrl/Cmd is held
+                this.last_activity = Instant::now();
+                // Get scroll delta in pixels (use window line height for conversion if needed)
+                let line_height = px(16.0); // Standard line height
+                let pixel_delta = event.delta.pixel_delta(line_height);
+
+                // Ctrl/Cmd+scroll zooms; plain scroll pans only if enabled
                 // Use 'platform' field which is Cmd on macOS, Ctrl on other platforms
-                if event.modifiers.platform {
-                    // Get scroll delta in pixels (use window line height for conversion if needed)
-                    let line_height = px(16.0); // Standard line height
-                    let delta_y: f32 = event.delta.pixel_delta(line_height).y.into();
+                if !event.modifiers.platform {
+                    if this.settings.keyboard_mouse.scroll_pans && !this.is_modal_open() {
+                        let (dx, dy) = utils::zoom::scroll_pan_delta(
+                            (pixel_delta.x.into(), pixel_delta.y.into()),
+                            event.modifiers.shift,
+                        );
+                        this.viewer.pan(dx, dy);
+                        this.viewer.notify_svg_zoom_pan_changed();
+                        this.save_current_image_state();
+                        cx.notify();
+                    }
+                } else {
+                    let delta_y: f32 = pixel_delta.y.into();
 
                     // Positive delta_y means scrolling down (zoom out)
                     // Negative delta_y means scrolling up (zoom in)
@@ -429,36 +765,16 @@ impl Render for App {
             }))
             .child(self.viewer.render_view(
                 active_bg,
-                self.settings.appearance.overlay_transparency,
+                overlay_transparency,
                 self.settings.appearance.font_size_scale,
                 self.show_zoom_indicator,
                 cx,
             ))
-            // Render overlays on top with proper z-order
-            .when(self.show_help, |el| el.child(self.help_overlay.clone()))
-            .when(self.show_debug, |el| {
-                let image_dimensions = self
-                    .viewer
-                    .current_image
-                    .as_ref()
-                    .map(|img| (img.width, img.height));
-                self.debug_overlay.update(cx, |overlay, _cx| {
-                    overlay.update_config(DebugOverlayConfig {
-                        current_path: self.app_state.current_image().cloned(),
-                        current_index: self.app_state.current_index,
-                        total_images: self.app_state.image_paths.len(),
-                        zoom: self.viewer.image_state.zoom,
-                        pan: self.viewer.image_state.pan,
-                        is_fit_to_window: self.viewer.image_state.is_fit_to_window,
-                        image_dimensions,
-                        viewport_size: self.viewer.viewport_size,
-                        sort_mode: self.app_state.sort_mode,
-                        overlay_transparency: self.settings.appearance.overlay_transparency,
-                        font_size_scale: self.settings.appearance.font_size_scale,
-                    });
-                });
-                el.child(self.debug_overlay.clone())
-            })
+            // The montage covers the viewer; overlays still draw on top
+            .when_some(self.montage.clone(), |el, montage| el.child(montage))
+            .children(navigator)
+            // Info overlays back to front, then settings over everything
+            .children(info_overlays)
             .when(self.show_settings, |el| {
                 el.child(self.settings_window.clone())
             })
@@ -554,6 +870,671 @@ impl Render for App {
                         ),
                 )
             })
+            // Restore-last-session offer at bottom-center
+            .when_some(
+                self.pending_session_restore
+                    .as_ref()
+                    .map(|s| s.image_paths.len()),
+                |el, count| {
+                    el.child(
+                        div()
+                            .absolute()
+                            .bottom(px(48.0))
+                            .w_full()
+                            .flex()
+                            .justify_center()
+                            .child(
+                                div()
+                                    .bg(rgba(0x1e1e1eee))
+                                    .border_1()
+                                    .border_color(rgba(0x8be9fd99))
+                                    .rounded(px(10.0))
+                                    .px(px(20.0))
+                                    .py(px(16.0))
+                                    .shadow_lg()
+                                    .max_w(px(500.0))
+                                    .flex()
+                                    .flex_col()
+                                    .items_center()
+                                    .gap(px(10.0))
+                                    .child(
+                                        div()
+                                            .text_color(rgb(0xffffff))
+                                            .text_size(px(14.0))
+                                            .font_weight(FontWeight::SEMIBOLD)
+                                            .text_align(TextAlign::Center)
+                                            .child(format!(
+                                                "Restore last session ({} images)?",
+                                                count
+                                            )),
+                                    )
+                                    .child(
+                                        div()
+                                            .id("session-restore-btn")
+                                            .cursor_pointer()
+                                            .bg(rgba(0x8be9fdff))
+                                            .hover(|s| s.bg(rgba(0x6fd8f0ff)))
+                                            .rounded(px(6.0))
+                                            .px(px(24.0))
+                                            .py(px(8.0))
+                                            .text_color(rgb(0x1e1e1e))
+                                            .font_weight(FontWeight::BOLD)
+                                            .text_size(px(13.0))
+                                            .child("Restore")
+                                            .on_mouse_down(
+                                                MouseButton::Left,
+                                                cx.listener(
+                                                    |this, _event: &MouseDownEvent, window, cx| {
+                                                        this.handle_restore_session(window, cx);
+                                                    },
+                                                ),
+                                            ),
+                                    )
+                                    .child(
+                                        div()
+                                            .text_color(rgb(0x666666))
+                                            .text_size(px(11.0))
+                                            .child("Press Esc to dismiss"),
+                                    ),
+                            ),
+                    )
+                },
+            )
+            // Exact-zoom prompt at bottom-center
+            .when_some(self.zoom_entry.clone(), |el, input| {
+                el.child(
+                    div()
+                        .absolute()
+                        .bottom(px(48.0))
+                        .w_full()
+                        .flex()
+                        .justify_center()
+                        .child(
+                            div()
+                                .bg(rgba(0x1e1e1eee))
+                                .border_1()
+                                .border_color(rgba(0x50fa7b99))
+                                .rounded(px(10.0))
+                                .px(px(20.0))
+                                .py(px(16.0))
+                                .shadow_lg()
+                                .flex()
+                                .flex_col()
+                                .items_center()
+                                .gap(px(10.0))
+                                .child(
+                                    div()
+                                        .flex()
+                                        .flex_row()
+                                        .items_center()
+                                        .gap(px(8.0))
+                                        .child(
+                                            div()
+                                                .text_color(rgb(0xffffff))
+                                                .text_size(px(14.0))
+                                                .font_weight(FontWeight::SEMIBOLD)
+                                                .child("Zoom to"),
+                                        )
+                                        .child(div().w(px(90.0)).child(input))
+                                        .child(
+                                            div()
+                                                .text_color(rgb(0xffffff))
+                                                .text_size(px(14.0))
+                                                .child("%"),
+                                        ),
+                                )
+                                .child(
+                                    div()
+                                        .text_color(rgb(0x666666))
+                                        .text_size(px(11.0))
+                                        .child("Enter to apply, Esc to cancel"),
+                                ),
+                        ),
+                )
+            })
+            // Go-to-image or go-to-frame prompt at bottom-center
+            .when_some(go_to_prompt, |el, (label, input, total)| {
+                el.child(
+                    div()
+                        .absolute()
+                        .bottom(px(48.0))
+                        .w_full()
+                        .flex()
+                        .justify_center()
+                        .child(
+                            div()
+                                .bg(rgba(0x1e1e1eee))
+                                .border_1()
+                                .border_color(rgba(0x50fa7b99))
+                                .rounded(px(10.0))
+                                .px(px(20.0))
+                                .py(px(16.0))
+                                .shadow_lg()
+                                .flex()
+                                .flex_col()
+                                .items_center()
+                                .gap(px(10.0))
+                                .child(
+                                    div()
+                                        .flex()
+                                        .flex_row()
+                                        .items_center()
+                                        .gap(px(8.0))
+                                        .child(
+                                            div()
+                                                .text_color(rgb(0xffffff))
+                                                .text_size(px(14.0))
+                                                .font_weight(FontWeight::SEMIBOLD)
+                                                .child(label),
+                                        )
+                                        .child(div().w(px(90.0)).child(input))
+                                        .child(
+                                            div()
+                                                .text_color(rgb(0xffffff))
+                                                .text_size(px(14.0))
+                                                .child(format!("/ {}", total)),
+                                        ),
+                                )
+                                .child(
+                                    div()
+                                        .text_color(rgb(0x666666))
+                                        .text_size(px(11.0))
+                                        .child("Enter to jump, Esc to cancel"),
+                                ),
+                        ),
+                )
+            })
+            // Rename prompt at bottom-center
+            .when_some(self.rename_entry.clone(), |el, input| {
+                let extension = self
+                    .app_state
+                    .current_image()
+                    .and_then(|p| p.extension())
+                    .map(|ext| format!(".{}", ext.to_string_lossy()))
+                    .unwrap_or_default();
+                el.child(
+                    div()
+                        .absolute()
+                        .bottom(px(48.0))
+                        .w_full()
+                        .flex()
+                        .justify_center()
+                        .child(
+                            div()
+                                .bg(rgba(0x1e1e1eee))
+                                .border_1()
+                                .border_color(rgba(0x50fa7b99))
+                                .rounded(px(10.0))
+                                .px(px(20.0))
+                                .py(px(16.0))
+                                .shadow_lg()
+                                .flex()
+                                .flex_col()
+                                .items_center()
+                                .gap(px(10.0))
+                                .child(
+                                    div()
+                                        .flex()
+                                        .flex_row()
+                                        .items_center()
+                                        .gap(px(8.0))
+                                        .child(
+                                            div()
+                                                .text_color(rgb(0xffffff))
+                                                .text_size(px(14.0))
+                                                .font_weight(FontWeight::SEMIBOLD)
+                                                .child("Rename to"),
+                                        )
+                                        .child(div().w(px(280.0)).child(input))
+                                        .child(
+                                            div()
+                                                .text_color(rgb(0xffffff))
+                                                .text_size(px(14.0))
+                                                .child(extension),
+                                        ),
+                                )
+                                .child(
+                                    div()
+                                        .text_color(rgb(0x666666))
+                                        .text_size(px(11.0))
+                                        .child("Enter to rename, Esc to cancel"),
+                                ),
+                        ),
+                )
+            })
+            // Unsaved-filters quit confirmation card at bottom-center
+            .when(self.pending_quit.is_some(), |el| {
+                el.child(
+                    div()
+                        .absolute()
+                        .bottom(px(48.0))
+                        .w_full()
+                        .flex()
+                        .justify_center()
+                        .child(
+                            div()
+                                .bg(rgba(0x1e1e1eee))
+                                .border_1()
+                                .border_color(rgba(0xffb86c99))
+                                .rounded(px(10.0))
+                                .px(px(20.0))
+                                .py(px(16.0))
+                                .shadow_lg()
+                                .max_w(px(500.0))
+                                .flex()
+                                .flex_col()
+                                .items_center()
+                                .gap(px(10.0))
+                                .child(
+                                    div()
+                                        .text_color(rgb(0xffffff))
+                                        .text_size(px(14.0))
+                                        .font_weight(FontWeight::SEMIBOLD)
+                                        .text_align(TextAlign::Center)
+                                        .child("You have unsaved filter changes. Quit anyway?"),
+                                )
+                                .child(
+                                    div()
+                                        .id("quit-confirm-btn")
+                                        .cursor_pointer()
+                                        .bg(rgba(0xffb86cff))
+                                        .hover(|s| s.bg(rgba(0xff9f43ff)))
+                                        .rounded(px(6.0))
+                                        .px(px(24.0))
+                                        .py(px(8.0))
+                                        .text_color(rgb(0x1e1e1e))
+                                        .font_weight(FontWeight::BOLD)
+                                        .text_size(px(13.0))
+                                        .child("Quit Anyway")
+                                        .on_mouse_down(
+                                            MouseButton::Left,
+                                            cx.listener(
+                                                |this, _event: &MouseDownEvent, window, cx| {
+                                                    this.handle_confirm_quit(window, cx);
+                                                },
+                                            ),
+                                        ),
+                                )
+                                .child(
+                                    div()
+                                        .text_color(rgb(0x666666))
+                                        .text_size(px(11.0))
+                                        .child("Press Esc to cancel"),
+                                ),
+                        ),
+                )
+            })
+            // Apply-filters-to-file confirmation card at bottom-center
+            .when_some(self.pending_bake.as_ref(), |el, path| {
+                let filename = path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or("file")
+                    .to_string();
+                el.child(
+                    div()
+                        .absolute()
+                        .bottom(px(48.0))
+                        .w_full()
+                        .flex()
+                        .justify_center()
+                        .child(
+                            div()
+                                .bg(rgba(0x1e1e1eee))
+                                .border_1()
+                                .border_color(rgba(0xff555599))
+                                .rounded(px(10.0))
+                                .px(px(20.0))
+                                .py(px(16.0))
+                                .shadow_lg()
+                                .max_w(px(500.0))
+                                .flex()
+                                .flex_col()
+                                .items_center()
+                                .gap(px(10.0))
+                                .child(
+                                    div()
+                                        .text_color(rgb(0xffffff))
+                                        .text_size(px(14.0))
+                                        .font_weight(FontWeight::SEMIBOLD)
+                                        .text_align(TextAlign::Center)
+                                        .child(format!(
+                                            "Apply the current filters to {} permanently?",
+                                            filename
+                                        )),
+                                )
+                                .child(
+                                    div()
+                                        .text_color(rgb(0x888888))
+                                        .text_size(px(11.0))
+                                        .text_align(TextAlign::Center)
+                                        .child(
+                                            "The file is overwritten and its metadata \
+                                             (EXIF, color profile) is not kept.",
+                                        ),
+                                )
+                                .child(
+                                    div()
+                                        .id("bake-confirm-btn")
+                                        .cursor_pointer()
+                                        .bg(rgba(0xff5555ff))
+                                        .hover(|s| s.bg(rgba(0xff3333ff)))
+                                        .rounded(px(6.0))
+                                        .px(px(24.0))
+                                        .py(px(8.0))
+                                        .text_color(rgb(0xffffff))
+                                        .font_weight(FontWeight::BOLD)
+                                        .text_size(px(13.0))
+                                        .child("Overwrite File")
+                                        .on_mouse_down(
+                                            MouseButton::Left,
+                                            cx.listener(
+                                                |this, _event: &MouseDownEvent, window, cx| {
+                                                    this.handle_confirm_bake_filters(window, cx);
+                                                },
+                                            ),
+                                        ),
+                                )
+                                .child(
+                                    div()
+                                        .text_color(rgb(0x666666))
+                                        .text_size(px(11.0))
+                                        .child("Press Esc to cancel"),
+                                ),
+                        ),
+                )
+            })
+            // Organize by Date dry-run preview at bottom-center
+            .when_some(
+                match self.organize {
+                    Some(OrganizeStage::Preview(ref plan)) => Some(plan),
+                    _ => None,
+                },
+                |el, plan| {
+                    const PREVIEW_LINES: usize = 5;
+                    let verb = if plan.move_files { "Move" } else { "Copy" };
+                    let count = plan.moves.len();
+                    let mut lines = plan.preview_lines(PREVIEW_LINES);
+                    if count > PREVIEW_LINES {
+                        lines.push(format!("\u{2026}and {} more", count - PREVIEW_LINES));
+                    }
+                    let mtime_count = plan.mtime_count();
+                    el.child(
+                        div()
+                            .absolute()
+                            .bottom(px(48.0))
+                            .w_full()
+                            .flex()
+                            .justify_center()
+                            .child(
+                                div()
+                                    .bg(rgba(0x1e1e1eee))
+                                    .border_1()
+                                    .border_color(rgba(0x50fa7b99))
+                                    .rounded(px(10.0))
+                                    .px(px(20.0))
+                                    .py(px(16.0))
+                                    .shadow_lg()
+                                    .max_w(px(600.0))
+                                    .flex()
+                                    .flex_col()
+                                    .items_center()
+                                    .gap(px(10.0))
+                                    .child(
+                                        div()
+                                            .text_color(rgb(0xffffff))
+                                            .text_size(px(14.0))
+                                            .font_weight(FontWeight::SEMIBOLD)
+                                            .text_align(TextAlign::Center)
+                                            .child(format!(
+                                                "{} {} files into {}?",
+                                                verb,
+                                                count,
+                                                plan.output_dir.display()
+                                            )),
+                                    )
+                                    .child(
+                                        div()
+                                            .flex()
+                                            .flex_col()
+                                            .gap(px(2.0))
+                                            .text_color(rgb(0xcccccc))
+                                            .text_size(px(12.0))
+                                            .children(lines),
+                                    )
+                                    .when(mtime_count > 0, |el| {
+                                        el.child(
+                                            div()
+                                                .text_color(rgb(0x888888))
+                                                .text_size(px(11.0))
+                                                .text_align(TextAlign::Center)
+                                                .child(format!(
+                                                    "{} without an EXIF date use the file's \
+                                                     modified time.",
+                                                    mtime_count
+                                                )),
+                                        )
+                                    })
+                                    .child(
+                                        div()
+                                            .id("organize-confirm-btn")
+                                            .cursor_pointer()
+                                            .bg(rgba(0x50fa7bff))
+                                            .hover(|s| s.bg(rgba(0x3ee06aff)))
+                                            .rounded(px(6.0))
+                                            .px(px(24.0))
+                                            .py(px(8.0))
+                                            .text_color(rgb(0x1e1e1e))
+                                            .font_weight(FontWeight::BOLD)
+                                            .text_size(px(13.0))
+                                            .child(format!("{} Files", verb))
+                                            .on_mouse_down(
+                                                MouseButton::Left,
+                                                cx.listener(
+                                                    |this, _event: &MouseDownEvent, window, cx| {
+                                                        this.handle_confirm_organize(window, cx);
+                                                    },
+                                                ),
+                                            ),
+                                    )
+                                    .child(
+                                        div()
+                                            .text_color(rgb(0x666666))
+                                            .text_size(px(11.0))
+                                            .child("Press Esc to cancel"),
+                                    ),
+                            ),
+                    )
+                },
+            )
+            // Batch progress: modal backdrop with a centered card
+            .when_some(
+                self.batch_job
+                    .as_ref()
+                    .map(|job| (job.progress.label(), job.is_cancelled())),
+                |el, (label, cancelling)| {
+                    let font_size_scale = self.settings.appearance.font_size_scale;
+                    el.child(
+                        div()
+                            .id("batch-overlay")
+                            .absolute()
+                            .inset_0()
+                            .occlude()
+                            .bg(rgba(0x00000099))
+                            .flex()
+                            .items_center()
+                            .justify_center()
+                            .child(
+                                div()
+                                    .bg(rgba(0x1e1e1eee))
+                                    .border_1()
+                                    .border_color(rgba(0x50fa7b99))
+                                    .rounded(px(10.0))
+                                    .px(px(20.0))
+                                    .py(px(16.0))
+                                    .shadow_lg()
+                                    .max_w(px(500.0))
+                                    .flex()
+                                    .flex_col()
+                                    .items_center()
+                                    .gap(px(10.0))
+                                    .child(cx.new(|_cx| {
+                                        ProcessingIndicator::new(
+                                            label,
+                                            overlay_transparency,
+                                            font_size_scale,
+                                        )
+                                        .inline()
+                                    }))
+                                    .child(if cancelling {
+                                        div()
+                                            .text_color(rgb(0x888888))
+                                            .text_size(px(11.0))
+                                            .child("Cancelling after the current file...")
+                                            .into_any_element()
+                                    } else {
+                                        div()
+                                            .id("batch-cancel-btn")
+                                            .cursor_pointer()
+                                            .bg(rgba(0x444444ff))
+                                            .hover(|s| s.bg(rgba(0x555555ff)))
+                                            .rounded(px(6.0))
+                                            .px(px(24.0))
+                                            .py(px(8.0))
+                                            .text_color(rgb(0xffffff))
+                                            .font_weight(FontWeight::BOLD)
+                                            .text_size(px(13.0))
+                                            .child("Cancel")
+                                            .on_mouse_down(
+                                                MouseButton::Left,
+                                                cx.listener(
+                                                    |this, _event: &MouseDownEvent, _window, cx| {
+                                                        this.handle_cancel_batch(cx);
+                                                    },
+                                                ),
+                                            )
+                                            .into_any_element()
+                                    }),
+                            ),
+                    )
+                },
+            )
+            // Slideshow indicator, top-right
+            .when(self.slideshow.is_some(), |el| {
+                el.child(
+                    div()
+                        .absolute()
+                        .top(px(12.0))
+                        .right(px(12.0))
+                        .bg(rgba(0x000000aa))
+                        .rounded(px(6.0))
+                        .px(px(10.0))
+                        .py(px(4.0))
+                        .text_color(rgba(0x50FA7BFF))
+                        .text_size(px(12.0))
+                        .child("\u{25B6} Slideshow"),
+                )
+            })
+            // Fullscreen filename caption, above the toast position
+            .when_some(caption, |el, caption| {
+                el.child(
+                    div()
+                        .absolute()
+                        .bottom(px(96.0))
+                        .w_full()
+                        .flex()
+                        .justify_center()
+                        .opacity(caption_opacity)
+                        .child(
+                            div()
+                                .bg(rgba(0x000000aa))
+                                .rounded(px(10.0))
+                                .px(px(24.0))
+                                .py(px(12.0))
+                                .max_w(px(1200.0))
+                                .overflow_x_hidden()
+                                .text_ellipsis()
+                                .text_color(rgb(0xffffff))
+                                .text_size(px(36.0))
+                                .font_weight(FontWeight::SEMIBOLD)
+                                .child(caption),
+                        ),
+                )
+            })
+            // Crop selection being dragged out, with its size in pixels
+            .when_some(crop_outline, |el, ((x, y, w, h), label)| {
+                el.child(
+                    div()
+                        .absolute()
+                        .left(px(x))
+                        .top(px(y))
+                        .w(px(w))
+                        .h(px(h))
+                        .border_1()
+                        .border_color(rgb(0xffffff))
+                        .bg(rgba(0xffffff1a)),
+                )
+                .child(
+                    div()
+                        .absolute()
+                        .left(px(x))
+                        .top(px(y + h) + px(6.0))
+                        .bg(rgba(0x1e1e1eee))
+                        .rounded(px(4.0))
+                        .px(px(6.0))
+                        .py(px(2.0))
+                        .text_size(px(12.0))
+                        .text_color(rgb(0xffffff))
+                        .child(label),
+                )
+            })
+            // Eyedropper readout beside the cursor
+            .when_some(
+                self.eyedropper_sample.filter(|_| self.eyedropper_active),
+                |el, (position, sample)| {
+                    let [r, g, b] = sample.rgb;
+                    el.child(
+                        div()
+                            .absolute()
+                            .left(position.x + px(18.0))
+                            .top(position.y + px(18.0))
+                            .flex()
+                            .flex_row()
+                            .items_center()
+                            .gap(px(8.0))
+                            .bg(rgba(0x1e1e1eee))
+                            .border_1()
+                            .border_color(rgba(0x444444ff))
+                            .rounded(px(6.0))
+                            .px(px(8.0))
+                            .py(px(6.0))
+                            .child(
+                                div()
+                                    .size(px(28.0))
+                                    .rounded(px(4.0))
+                                    .border_1()
+                                    .border_color(rgb(0xffffff))
+                                    .bg(rgb(((r as u32) << 16) | ((g as u32) << 8) | b as u32)),
+                            )
+                            .child(
+                                div()
+                                    .flex()
+                                    .flex_col()
+                                    .text_size(px(11.0))
+                                    .text_color(rgb(0xffffff))
+                                    .font_family("monospace")
+                                    .child(sample.hex())
+                                    .child(sample.rgb_label())
+                                    .child(
+                                        div().text_color(rgb(0xaaaaaa)).child(format!(
+                                            "{}, {}",
+                                            sample.pixel.0, sample.pixel.1
+                                        )),
+                                    ),
+                            ),
+                    )
+                },
+            )
             // Toast notification at bottom-center (near delete card position)
             .when_some(self.toast.clone(), |el, toast| {
                 let border_color = if toast.is_error {
@@ -610,17 +1591,20 @@ impl Render for App {
             .flex()
             .flex_col()
             .bg(bg_color)
-            // Add menu bar for Windows/Linux
-            .when(cfg!(not(target_os = "macos")), |el| {
-                #[cfg(not(target_os = "macos"))]
-                {
-                    el.child(self.menu_bar.clone())
-                }
-                #[cfg(target_os = "macos")]
-                {
-                    el
-                }
-            })
+            // Add menu bar for Windows/Linux, out of the way in fullscreen
+            .when(
+                cfg!(not(target_os = "macos")) && !window.is_fullscreen(),
+                |el| {
+                    #[cfg(not(target_os = "macos"))]
+                    {
+                        el.child(self.menu_bar.clone())
+                    }
+                    #[cfg(target_os = "macos")]
+                    {
+                        el
+                    }
+                },
+            )
             .child(content)
             // Key handler for Z-drag zoom mode - must be on focused element
             .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
@@ -642,6 +1626,11 @@ impl Render for App {
                 }
             }))
             .on_key_up(cx.listener(|this, event: &KeyUpEvent, _window, cx| {
+                match event.keystroke.key.as_str() {
+                    "right" => this.arrow_taps.release(true),
+                    "left" => this.arrow_taps.release(false),
+                    _ => {}
+                }
                 // Check for Z key release
                 if event.keystroke.key.as_str() == "z" {
                     // Disable Z-drag zoom mode and save state
@@ -668,11 +1657,50 @@ impl Render for App {
             .on_action(cx.listener(|this, _: &PreviousImage, window, cx| {
                 this.handle_previous_image(window, cx);
             }))
+            .on_action(cx.listener(|this, _: &GoToFrame, window, cx| {
+                this.handle_go_to_frame(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &GoToImage, window, cx| {
+                this.handle_go_to_image(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &FirstImage, window, cx| {
+                this.handle_first_image(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &LastImage, window, cx| {
+                this.handle_last_image(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &PageForward, window, cx| {
+                this.handle_page_forward(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &PageBackward, window, cx| {
+                this.handle_page_backward(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &ToggleSlideshow, window, cx| {
+                this.handle_toggle_slideshow(window, cx);
+            }))
             .on_action(
                 cx.listener(|this, _: &ToggleAnimationPlayPause, window, cx| {
                     this.handle_toggle_animation(window, cx);
                 }),
             )
+            .on_action(cx.listener(|this, _: &NextFrames, window, cx| {
+                this.handle_next_frames(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &PreviousFrames, window, cx| {
+                this.handle_previous_frames(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &FirstFrame, window, cx| {
+                this.handle_first_frame(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &LastFrame, window, cx| {
+                this.handle_last_frame(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &AnimationSpeedUp, window, cx| {
+                this.handle_animation_speed(true, window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &AnimationSpeedDown, window, cx| {
+                this.handle_animation_speed(false, window, cx);
+            }))
             .on_action(cx.listener(|this, _: &NextFrame, window, cx| {
                 this.handle_next_frame(window, cx);
             }))
@@ -685,6 +1713,9 @@ impl Render for App {
             .on_action(cx.listener(|this, _: &SortByModified, window, cx| {
                 this.handle_sort_by_modified(window, cx);
             }))
+            .on_action(cx.listener(|this, _: &ToggleSortDirection, window, cx| {
+                this.handle_toggle_sort_direction(window, cx);
+            }))
             .on_action(cx.listener(|this, _: &SortByTypeToggle, window, cx| {
                 this.handle_sort_by_type_toggle(window, cx);
             }))
@@ -706,6 +1737,33 @@ impl Render for App {
             .on_action(cx.listener(|this, _: &ZoomResetAndCenter, window, cx| {
                 this.handle_zoom_reset_and_center(window, cx);
             }))
+            .on_action(cx.listener(|this, _: &ZoomToPercent, window, cx| {
+                this.handle_zoom_to_percent(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &RotateClockwise, window, cx| {
+                this.handle_rotate_clockwise(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &RotateCounterClockwise, window, cx| {
+                this.handle_rotate_counter_clockwise(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &RenameImage, window, cx| {
+                this.handle_rename_image(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &ToggleFitLock, window, cx| {
+                this.handle_toggle_fit_lock(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &ToggleFilterLock, window, cx| {
+                this.handle_toggle_filter_lock(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &ToggleAutoExposure, window, cx| {
+                this.handle_toggle_auto_exposure(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &CopyView, window, cx| {
+                this.handle_copy_view(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &PasteView, window, cx| {
+                this.handle_paste_view(window, cx);
+            }))
             .on_action(cx.listener(|this, _: &ZoomInFast, window, cx| {
                 this.handle_zoom_in_fast(window, cx);
             }))
@@ -766,12 +1824,48 @@ impl Render for App {
             .on_action(cx.listener(|this, _: &ToggleDebug, window, cx| {
                 this.handle_toggle_debug(window, cx);
             }))
+            .on_action(cx.listener(|this, _: &CycleLoopMode, window, cx| {
+                this.handle_cycle_loop_mode(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &ToggleCropMode, window, cx| {
+                this.handle_toggle_crop_mode(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &ToggleFullscreen, window, cx| {
+                this.handle_toggle_fullscreen(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &ToggleMontagePick, window, cx| {
+                this.handle_toggle_montage_pick(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &ToggleMontage, window, cx| {
+                this.handle_toggle_montage(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &ToggleMontageLink, window, cx| {
+                this.handle_toggle_montage_link(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &ToggleGenerationInfo, window, cx| {
+                this.handle_toggle_generation_info(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &ToggleHistogram, window, cx| {
+                this.handle_toggle_histogram(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &CycleOverlays, window, cx| {
+                this.handle_cycle_overlays(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &ToggleEyedropper, window, cx| {
+                this.handle_toggle_eyedropper(window, cx);
+            }))
             .on_action(cx.listener(|this, _: &ToggleZoomIndicator, window, cx| {
                 this.handle_toggle_zoom_indicator(window, cx);
             }))
+            .on_action(cx.listener(|this, _: &ToggleNavigator, window, cx| {
+                this.handle_toggle_navigator(window, cx);
+            }))
             .on_action(cx.listener(|this, _: &ToggleBackground, window, cx| {
                 this.handle_toggle_background(window, cx);
             }))
+            .on_action(cx.listener(|this, _: &CycleBackdrop, window, cx| {
+                this.handle_cycle_backdrop(window, cx);
+            }))
             .on_action(cx.listener(|this, _: &ToggleSettings, window, cx| {
                 this.handle_toggle_settings(window, cx);
             }))
@@ -788,6 +1882,12 @@ impl Render for App {
                     this.handle_load_oversized_image_anyway(window, cx);
                 }),
             )
+            .on_action(cx.listener(|this, _: &ReloadImage, window, cx| {
+                this.handle_reload_image(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &CopyShortcutSheet, _window, cx| {
+                this.handle_copy_shortcut_sheet(cx);
+            }))
             .on_action(cx.listener(|this, _: &ToggleFilters, window, cx| {
                 this.handle_toggle_filters(window, cx);
             }))
@@ -797,6 +1897,36 @@ impl Render for App {
             .on_action(cx.listener(|this, _: &EnableFilters, window, cx| {
                 this.handle_enable_filters(window, cx);
             }))
+            .on_action(cx.listener(|this, _: &ToggleFiltersEnabled, window, cx| {
+                this.handle_toggle_filters_enabled(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &JumpToDecile1, window, cx| {
+                this.handle_jump_to_decile(1, window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &JumpToDecile2, window, cx| {
+                this.handle_jump_to_decile(2, window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &JumpToDecile3, window, cx| {
+                this.handle_jump_to_decile(3, window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &JumpToDecile4, window, cx| {
+                this.handle_jump_to_decile(4, window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &JumpToDecile5, window, cx| {
+                this.handle_jump_to_decile(5, window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &JumpToDecile6, window, cx| {
+                this.handle_jump_to_decile(6, window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &JumpToDecile7, window, cx| {
+                this.handle_jump_to_decile(7, window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &JumpToDecile8, window, cx| {
+                this.handle_jump_to_decile(8, window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &JumpToDecile9, window, cx| {
+                this.handle_jump_to_decile(9, window, cx);
+            }))
             .on_action(cx.listener(|this, _: &RecallSlot3, window, cx| {
                 this.handle_recall_slot(3, window, cx);
             }))
@@ -839,9 +1969,32 @@ impl Render for App {
             .on_action(cx.listener(|this, _: &StoreSlot9, window, cx| {
                 this.handle_store_slot(9, window, cx);
             }))
+            .on_action(cx.listener(|this, _: &NextDifferentFormat, window, cx| {
+                this.handle_next_different_format(window, cx);
+            }))
+            .on_action(
+                cx.listener(|this, _: &PreviousDifferentFormat, window, cx| {
+                    this.handle_previous_different_format(window, cx);
+                }),
+            )
+            .on_action(cx.listener(|this, _: &BakeFilters, window, cx| {
+                this.handle_request_bake_filters(window, cx);
+            }))
             .on_action(cx.listener(|this, _: &ResetFilters, window, cx| {
                 this.handle_reset_filters(window, cx);
             }))
+            .on_action(cx.listener(|this, _: &UndoFilter, window, cx| {
+                this.handle_undo_filter(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &RedoFilter, window, cx| {
+                this.handle_redo_filter(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &ToggleInvert, window, cx| {
+                this.handle_toggle_invert(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &ToggleGrayscale, window, cx| {
+                this.handle_toggle_grayscale(window, cx);
+            }))
             .on_action(cx.listener(|this, _: &BrightnessUp, window, cx| {
                 this.handle_brightness_up(window, cx);
             }))
@@ -863,12 +2016,42 @@ impl Render for App {
             .on_action(cx.listener(|this, _: &OpenFile, window, cx| {
                 this.handle_open_file(window, cx);
             }))
+            .on_action(cx.listener(|this, _: &OpenFolder, window, cx| {
+                this.handle_open_folder(window, cx);
+            }))
             .on_action(cx.listener(|this, _: &SaveFile, window, cx| {
                 this.handle_save_file(window, cx);
             }))
+            .on_action(cx.listener(|this, _: &ExportAll, window, cx| {
+                this.handle_export_all(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &OrganizeByDate, window, cx| {
+                this.handle_organize_by_date(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &StackAverage, window, cx| {
+                this.handle_stack_average(window, cx);
+            }))
             .on_action(cx.listener(|this, _: &SaveFileToDownloads, window, cx| {
                 this.handle_save_file_to_downloads(window, cx);
             }))
+            .on_action(cx.listener(|this, _: &CopyViewportImage, window, cx| {
+                this.handle_copy_viewport_image(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &CopyImageToClipboard, window, cx| {
+                this.handle_copy_image_to_clipboard(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &CopyPath, window, cx| {
+                this.handle_copy_path(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &SaveViewportImage, window, cx| {
+                this.handle_save_viewport_image(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &ExportCurrentFrame, window, cx| {
+                this.handle_export_current_frame(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &OpenParentFolder, window, cx| {
+                this.handle_open_parent_folder(window, cx);
+            }))
             .on_action(cx.listener(|this, _: &OpenInExternalViewer, window, cx| {
                 this.handle_open_in_external_viewer(window, cx);
             }))
@@ -877,6 +2060,14 @@ impl Render for App {
                     this.handle_open_in_external_viewer_and_quit(window, cx);
                 }),
             )
+            .on_action(cx.listener(|this, _: &OpenInDefaultApp, window, cx| {
+                this.handle_open_in_default_app(window, cx);
+            }))
+            .on_action(
+                cx.listener(|this, _: &OpenInDefaultAppAndQuit, window, cx| {
+                    this.handle_open_in_default_app_and_quit(window, cx);
+                }),
+            )
             .on_action(cx.listener(|this, _: &OpenInExternalEditor, window, cx| {
                 this.handle_open_in_external_editor(window, cx);
             }))
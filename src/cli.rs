@@ -1,7 +1,7 @@
 use crate::error::{AppError, AppResult};
 use crate::utils::file_scanner;
 use clap::Parser;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// rpview - A fast, keyboard-driven image viewer built with GPUI
 #[derive(Parser, Debug)]
@@ -38,6 +38,19 @@ pub struct Cli {
     /// - Mixed: `rpview img1.png /path/to/images img2.jpg`
     #[arg(value_name = "PATH")]
     pub paths: Vec<PathBuf>,
+
+    /// Also write a diagnostic log to rpview.log next to the settings file
+    ///
+    /// LEVEL is one of error, warn, info, debug or trace.  `trace` includes
+    /// per-frame render details and gets large quickly.
+    #[arg(long, value_name = "LEVEL")]
+    pub log_level: Option<log::LevelFilter>,
+
+    /// Include images in subfolders of any directory scanned
+    ///
+    /// Also turned on by the "Include subfolders" setting.
+    #[arg(short, long)]
+    pub recursive: bool,
 }
 
 /// What the command line resolved to.
@@ -57,20 +70,24 @@ pub struct CliPaths {
 }
 
 impl Cli {
-    /// Parse command-line arguments into the image list, the starting image
+    /// Resolve the path arguments into the image list, the starting image
     /// (when a single file was named), and whether any path was given at all.
-    pub fn parse_image_paths() -> AppResult<CliPaths> {
-        let cli = Cli::parse();
-
-        let paths = if cli.paths.is_empty() {
-            // No arguments: default to current directory
+    /// With no arguments the images come from `default_dir`, or the current
+    /// directory when that's None.
+    pub fn image_paths(self, default_dir: Option<&Path>) -> AppResult<CliPaths> {
+        let recursive = self.recursive;
+        let paths = if self.paths.is_empty() {
+            let dir = match default_dir {
+                Some(dir) => dir.to_path_buf(),
+                None => std::env::current_dir()?,
+            };
             return Ok(CliPaths {
-                images: Self::collect_image_paths(&[std::env::current_dir()?])?,
+                images: Self::collect_image_paths(&[dir], recursive)?,
                 start: None,
                 from_arguments: false,
             });
         } else {
-            cli.paths
+            self.paths
         };
 
         // Special case: single file specified
@@ -88,7 +105,7 @@ impl Cli {
             // Get the parent directory
             if let Some(parent_dir) = specified_file.parent() {
                 // Scan the directory for all images
-                let all_images = file_scanner::scan_directory(parent_dir)?;
+                let all_images = file_scanner::scan_directory_with(parent_dir, recursive)?;
 
                 // Even if empty, return the result - the app will display a message
                 if all_images.is_empty() {
@@ -116,14 +133,14 @@ impl Cli {
 
         // Multiple files or directories: use the existing logic
         Ok(CliPaths {
-            images: Self::collect_image_paths(&paths)?,
+            images: Self::collect_image_paths(&paths, recursive)?,
             start: None,
             from_arguments: true,
         })
     }
 
     /// Collect all image paths from the given list of files/directories
-    fn collect_image_paths(paths: &[PathBuf]) -> AppResult<Vec<PathBuf>> {
+    fn collect_image_paths(paths: &[PathBuf], recursive: bool) -> AppResult<Vec<PathBuf>> {
         let mut image_paths = Vec::new();
 
         for path in paths {
@@ -143,7 +160,7 @@ impl Cli {
                 }
             } else if path.is_dir() {
                 // Directory: scan for all supported images
-                let dir_images = file_scanner::scan_directory(path)?;
+                let dir_images = file_scanner::scan_directory_with(path, recursive)?;
                 image_paths.extend(dir_images);
             }
         }
@@ -1,3 +1,4 @@
+use crate::utils::animation::LoopMode;
 use crate::utils::style::{Colors, Spacing, scaled_text_size};
 use gpui::*;
 
@@ -6,6 +7,7 @@ pub struct AnimationIndicator {
     current_frame: usize,
     total_frames: usize,
     is_playing: bool,
+    loop_mode: LoopMode,
     /// Overlay transparency (0-255)
     overlay_transparency: u8,
     /// Font size scale multiplier
@@ -17,6 +19,7 @@ impl AnimationIndicator {
         current_frame: usize,
         total_frames: usize,
         is_playing: bool,
+        loop_mode: LoopMode,
         overlay_transparency: u8,
         font_size_scale: f32,
     ) -> Self {
@@ -24,6 +27,7 @@ impl AnimationIndicator {
             current_frame,
             total_frames,
             is_playing,
+            loop_mode,
             overlay_transparency,
             font_size_scale,
         }
@@ -34,10 +38,11 @@ impl Render for AnimationIndicator {
     fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
         let play_status = if self.is_playing { "▶" } else { "⏸" };
         let frame_text = format!(
-            "{} Frame {}/{}",
+            "{} Frame {}/{} · {}",
             play_status,
             self.current_frame + 1,
-            self.total_frames
+            self.total_frames,
+            self.loop_mode.label()
         );
 
         div()
@@ -1,4 +1,5 @@
 use crate::state::app_state::SortMode;
+use crate::utils::exif::PhotoInfo;
 use crate::utils::style::{Colors, Spacing, scaled_text_size};
 use gpui::*;
 use std::path::PathBuf;
@@ -15,6 +16,18 @@ pub struct DebugOverlayConfig {
     pub image_dimensions: Option<(u32, u32)>,
     pub viewport_size: Option<Size<Pixels>>,
     pub sort_mode: SortMode,
+    /// EXIF details of the current image (None when it has none)
+    pub photo_info: Option<PhotoInfo>,
+    /// Keyword / text pairs from PNG text chunks (empty for other formats)
+    pub png_text: Vec<(String, String)>,
+    /// Current frame (0-based) and frame count of an animation or page
+    /// stack (None for stills)
+    pub animation_frame: Option<(usize, usize)>,
+    /// Playback speed multiplier of the current animation (None for stills)
+    pub animation_speed: Option<f32>,
+    /// Neighbours waiting in the decode cache and the bytes they hold
+    /// (None when it's empty)
+    pub preloaded: Option<(usize, u64)>,
     /// Overlay transparency (0-255)
     pub overlay_transparency: u8,
     /// Font size scale multiplier
@@ -47,7 +60,7 @@ impl DebugOverlay {
                 div()
                     .min_w(px(140.0))
                     .text_size(scaled_text_size(12.0, self.config.font_size_scale))
-                    .text_color(rgb(0x888888))
+                    .text_color(Colors::muted_text())
                     .child(format!("{}:", label)),
             )
             .child(
@@ -59,6 +72,56 @@ impl DebugOverlay {
             )
     }
 
+    /// Render a section heading
+    fn render_section_header(&self, title: &'static str) -> impl IntoElement {
+        div()
+            .mt(Spacing::sm())
+            .mb(Spacing::xs())
+            .text_size(scaled_text_size(12.0, self.config.font_size_scale))
+            .text_color(Colors::muted_text())
+            .font_weight(FontWeight::BOLD)
+            .child(title)
+    }
+
+    /// Camera, lens and exposure lines, skipping tags the file lacks
+    fn render_photo_info(&self) -> Vec<AnyElement> {
+        let Some(ref info) = self.config.photo_info else {
+            return vec![
+                div()
+                    .text_size(scaled_text_size(12.0, self.config.font_size_scale))
+                    .text_color(Colors::muted_text())
+                    .child("No EXIF data.")
+                    .into_any_element(),
+            ];
+        };
+        [
+            ("Camera", info.camera.clone()),
+            ("Lens", info.lens.clone()),
+            ("ISO", info.iso.map(|iso| iso.to_string())),
+            ("Shutter", info.shutter_label()),
+            ("Aperture", info.aperture_label()),
+            ("Taken", info.date_label()),
+        ]
+        .into_iter()
+        .filter_map(|(label, value)| {
+            value.map(|value| self.render_info_line(label, value).into_any_element())
+        })
+        .collect()
+    }
+
+    /// PNG text chunks under their own heading, or nothing when there are none
+    fn render_png_text(&self) -> Vec<AnyElement> {
+        if self.config.png_text.is_empty() {
+            return Vec::new();
+        }
+        std::iter::once(self.render_section_header("PNG Text").into_any_element())
+            .chain(self.config.png_text.iter().map(|(keyword, text)| {
+                self.render_info_line_wrapping(keyword, text.clone())
+                    .into_any_element()
+            }))
+            .collect()
+    }
+
     /// Render a debug info line with word wrapping for long values
     fn render_info_line_wrapping(&self, label: &str, value: String) -> impl IntoElement {
         div()
@@ -68,7 +131,7 @@ impl DebugOverlay {
             .child(
                 div()
                     .text_size(scaled_text_size(12.0, self.config.font_size_scale))
-                    .text_color(rgb(0x888888))
+                    .text_color(Colors::muted_text())
                     .mb(px(2.0))
                     .child(format!("{}:", label)),
             )
@@ -150,8 +213,8 @@ impl Render for DebugOverlay {
             .top(Spacing::md())
             .right(Spacing::md())
             .bg(Colors::overlay_bg_alpha(self.config.overlay_transparency))
-            .border_1()
-            .border_color(rgb(0x444444))
+            .border(Colors::border_width())
+            .border_color(Colors::border())
             .rounded(px(6.0))
             .p(Spacing::md())
             .shadow_lg()
@@ -170,8 +233,8 @@ impl Render for DebugOverlay {
                             .font_weight(FontWeight::BOLD)
                             .mb(Spacing::sm())
                             .pb(Spacing::xs())
-                            .border_b_1()
-                            .border_color(rgb(0x444444))
+                            .border_b(Colors::border_width())
+                            .border_color(Colors::border())
                             .child(format!(
                                 "Debug Information — v{}",
                                 env!("CARGO_PKG_VERSION")
@@ -182,6 +245,22 @@ impl Render for DebugOverlay {
                     .child(self.render_info_line_wrapping("Image Folder", folder_str))
                     .child(self.render_info_line("Image Index", index_str))
                     .child(self.render_info_line("File Size", image_dims_str))
+                    .children(self.config.animation_frame.map(|(frame, count)| {
+                        self.render_info_line("Frame", format!("{} / {}", frame + 1, count))
+                    }))
+                    .children(self.config.animation_speed.map(|speed| {
+                        self.render_info_line("Animation Speed", format!("{}x", speed))
+                    }))
+                    .children(self.config.preloaded.map(|(count, bytes)| {
+                        self.render_info_line(
+                            "Preloaded",
+                            format!(
+                                "{} image(s), {:.1} MB",
+                                count,
+                                bytes as f64 / (1024.0 * 1024.0)
+                            ),
+                        )
+                    }))
                     .child(
                         self.render_info_line(
                             "Sort Mode",
@@ -194,38 +273,26 @@ impl Render for DebugOverlay {
                             .to_string(),
                         ),
                     )
+                    // Camera metadata
+                    .child(self.render_section_header("EXIF"))
+                    .children(self.render_photo_info())
+                    .children(self.render_png_text())
                     // Zoom & Pan info
-                    .child(
-                        div()
-                            .mt(Spacing::sm())
-                            .mb(Spacing::xs())
-                            .text_size(scaled_text_size(12.0, self.config.font_size_scale))
-                            .text_color(rgb(0x888888))
-                            .font_weight(FontWeight::BOLD)
-                            .child("Transform"),
-                    )
+                    .child(self.render_section_header("Transform"))
                     .child(self.render_info_line("Zoom", zoom_str))
                     .child(self.render_info_line("Pan (x, y)", pan_str))
                     // Viewport info
-                    .child(
-                        div()
-                            .mt(Spacing::sm())
-                            .mb(Spacing::xs())
-                            .text_size(scaled_text_size(12.0, self.config.font_size_scale))
-                            .text_color(rgb(0x888888))
-                            .font_weight(FontWeight::BOLD)
-                            .child("Viewport"),
-                    )
+                    .child(self.render_section_header("Viewport"))
                     .child(self.render_info_line("Viewport Size", viewport_str))
                     // Close instructions
                     .child(
                         div()
                             .mt(Spacing::md())
                             .pt(Spacing::sm())
-                            .border_t_1()
-                            .border_color(rgb(0x444444))
+                            .border_t(Colors::border_width())
+                            .border_color(Colors::border())
                             .text_size(scaled_text_size(12.0, self.config.font_size_scale))
-                            .text_color(rgb(0x888888))
+                            .text_color(Colors::muted_text())
                             .text_align(TextAlign::Center)
                             .child("Press F12 to close"),
                     ),
@@ -1,10 +1,14 @@
+use crate::ReloadImage;
 use crate::utils::style::{Colors, Spacing, TextSize};
+use gpui::prelude::FluentBuilder;
 use gpui::*;
 
 /// Component for displaying error messages
 pub struct ErrorDisplay {
     message: SharedString,
     text_color: Hsla,
+    /// Show a Reload button (for files that may still be arriving)
+    show_reload: bool,
 }
 
 impl ErrorDisplay {
@@ -12,9 +16,16 @@ impl ErrorDisplay {
         Self {
             message: message.into(),
             text_color: Colors::text(),
+            show_reload: false,
         }
     }
 
+    /// Offer to load the file again, e.g. once a download finishes
+    pub fn with_reload(mut self, show: bool) -> Self {
+        self.show_reload = show;
+        self
+    }
+
     /// Set custom text color (for contrast with light backgrounds)
     pub fn with_text_color(mut self, color: Hsla) -> Self {
         self.text_color = color;
@@ -43,5 +54,24 @@ impl Render for ErrorDisplay {
                     .text_color(self.text_color)
                     .child(self.message.clone()),
             )
+            .when(self.show_reload, |el| {
+                el.child(
+                    div()
+                        .mt(Spacing::md())
+                        .px(Spacing::lg())
+                        .py(Spacing::sm())
+                        .bg(Colors::info())
+                        .rounded(px(6.0))
+                        .text_size(TextSize::md())
+                        .text_color(rgb(0x1a1a1a))
+                        .font_weight(FontWeight::MEDIUM)
+                        .cursor_pointer()
+                        .hover(|style| style.bg(rgb(0x6272a4)))
+                        .on_mouse_down(MouseButton::Left, |_event, window, cx| {
+                            window.dispatch_action(ReloadImage.boxed_clone(), cx);
+                        })
+                        .child("Reload"),
+                )
+            })
     }
 }
@@ -1,6 +1,7 @@
 use crate::state::image_state::FilterSettings;
+use crate::state::settings::FilterPreset;
 use crate::utils::style::{Colors, Spacing, TextSize, scaled_text_size};
-use ccf_gpui_widgets::prelude::{Slider, SliderEvent};
+use ccf_gpui_widgets::prelude::{Checkbox, CheckboxEvent, Slider, SliderEvent, TextInput, Theme};
 use gpui::*;
 
 /// Events emitted by FilterControls
@@ -8,6 +9,10 @@ use gpui::*;
 pub enum FilterControlsEvent {
     /// Filter settings changed via slider interaction
     FiltersChanged,
+    /// "Save" pressed in the preset bar, with the name typed (may be empty)
+    SavePreset(String),
+    /// Delete button pressed on the preset at this index
+    DeletePreset(usize),
 }
 
 /// Filter controls overlay component
@@ -16,6 +21,19 @@ pub struct FilterControls {
     pub brightness_slider: Entity<Slider>,
     pub contrast_slider: Entity<Slider>,
     pub gamma_slider: Entity<Slider>,
+    pub saturation_slider: Entity<Slider>,
+    /// Color inversion on/off
+    pub invert_checkbox: Entity<Checkbox>,
+    /// Grayscale conversion on/off
+    pub grayscale_checkbox: Entity<Checkbox>,
+    /// Per-channel offsets for the Color subsection
+    pub red_offset_slider: Entity<Slider>,
+    pub green_offset_slider: Entity<Slider>,
+    pub blue_offset_slider: Entity<Slider>,
+    /// Saved presets, mirrored from `Filters::filter_presets`
+    pub presets: Vec<FilterPreset>,
+    /// Name for the next "Save current as preset"
+    pub preset_name_input: Entity<TextInput>,
 
     /// Font size scale multiplier
     pub font_size_scale: f32,
@@ -24,7 +42,12 @@ pub struct FilterControls {
 impl EventEmitter<FilterControlsEvent> for FilterControls {}
 
 impl FilterControls {
-    pub fn new(filters: FilterSettings, font_size_scale: f32, cx: &mut Context<Self>) -> Self {
+    pub fn new(
+        filters: FilterSettings,
+        presets: Vec<FilterPreset>,
+        font_size_scale: f32,
+        cx: &mut Context<Self>,
+    ) -> Self {
         // Create brightness slider (-100 to +100, current value)
         let brightness_slider = cx.new(|cx| {
             Slider::new(cx)
@@ -85,14 +108,225 @@ impl FilterControls {
         })
         .detach();
 
+        // Create saturation slider (-100 to +100, current value)
+        let saturation_slider = cx.new(|cx| {
+            Slider::new(cx)
+                .with_value(filters.saturation as f64)
+                .min(-100.0)
+                .max(100.0)
+                .step(1.0)
+                .display_precision(0)
+        });
+
+        // Subscribe to saturation slider changes
+        cx.subscribe(
+            &saturation_slider,
+            |_this, _slider, event: &SliderEvent, cx| {
+                if let SliderEvent::Change(_) = event {
+                    cx.emit(FilterControlsEvent::FiltersChanged);
+                }
+            },
+        )
+        .detach();
+
+        // Create invert checkbox
+        let invert_checkbox = cx.new(|cx| {
+            Checkbox::new(cx)
+                .with_checked(filters.invert)
+                .label("Invert colors")
+        });
+
+        // Subscribe to invert checkbox changes
+        cx.subscribe(
+            &invert_checkbox,
+            |_this, _checkbox, _event: &CheckboxEvent, cx| {
+                cx.emit(FilterControlsEvent::FiltersChanged);
+            },
+        )
+        .detach();
+
+        // Create grayscale checkbox
+        let grayscale_checkbox = cx.new(|cx| {
+            Checkbox::new(cx)
+                .with_checked(filters.grayscale)
+                .label("Grayscale")
+        });
+
+        // Subscribe to grayscale checkbox changes
+        cx.subscribe(
+            &grayscale_checkbox,
+            |_this, _checkbox, _event: &CheckboxEvent, cx| {
+                cx.emit(FilterControlsEvent::FiltersChanged);
+            },
+        )
+        .detach();
+
+        // Create channel offset sliders (-100 to +100 levels each)
+        let red_offset_slider = Self::new_offset_slider(filters.red_offset, cx);
+        let green_offset_slider = Self::new_offset_slider(filters.green_offset, cx);
+        let blue_offset_slider = Self::new_offset_slider(filters.blue_offset, cx);
+
+        let preset_name_input = cx.new(|cx| {
+            TextInput::new(cx)
+                .placeholder("Preset name")
+                .theme(Theme::dark().with_border_focus(0x50fa7b))
+        });
+
         Self {
             brightness_slider,
             contrast_slider,
             gamma_slider,
+            saturation_slider,
+            invert_checkbox,
+            grayscale_checkbox,
+            red_offset_slider,
+            green_offset_slider,
+            blue_offset_slider,
+            presets,
+            preset_name_input,
             font_size_scale,
         }
     }
 
+    /// Replace the preset list shown in the preset bar
+    pub fn set_presets(&mut self, presets: Vec<FilterPreset>, cx: &mut Context<Self>) {
+        self.presets = presets;
+        cx.notify();
+    }
+
+    /// Load the preset at `index` into the sliders and tell the viewer
+    fn apply_preset(&mut self, index: usize, cx: &mut Context<Self>) {
+        let Some(preset) = self.presets.get(index) else {
+            return;
+        };
+        let filters = preset.filters();
+        self.update_from_filters(filters, cx);
+        cx.emit(FilterControlsEvent::FiltersChanged);
+    }
+
+    /// Ask the app to save the current values under the typed name
+    fn save_preset(&mut self, cx: &mut Context<Self>) {
+        let name = self.preset_name_input.read(cx).content().to_string();
+        self.preset_name_input
+            .update(cx, |input, cx| input.set_value("", cx));
+        cx.emit(FilterControlsEvent::SavePreset(name));
+    }
+
+    /// The Presets subsection: one clickable row per preset with a delete
+    /// button, then a name field and Save button
+    fn render_preset_bar(&self, cx: &mut Context<Self>) -> Div {
+        let text_size = scaled_text_size(12.0, self.font_size_scale);
+        let rows = self.presets.iter().enumerate().map(|(index, preset)| {
+            div()
+                .flex()
+                .items_center()
+                .justify_between()
+                .child(
+                    div()
+                        .id(("apply-preset", index))
+                        .flex_1()
+                        .text_size(text_size)
+                        .text_color(Colors::text())
+                        .cursor_pointer()
+                        .hover(|s| s.text_color(Colors::info()))
+                        .on_click(cx.listener(move |this, _, _, cx| this.apply_preset(index, cx)))
+                        .child(preset.name.clone()),
+                )
+                .child(
+                    div()
+                        .id(("delete-preset", index))
+                        .px(Spacing::xs())
+                        .text_size(text_size)
+                        .text_color(rgb(0xAAAAAA))
+                        .cursor_pointer()
+                        .hover(|s| s.text_color(Colors::error()))
+                        .on_click(cx.listener(move |_, _, _, cx| {
+                            cx.emit(FilterControlsEvent::DeletePreset(index));
+                        }))
+                        .child("\u{00D7}"),
+                )
+        });
+
+        div()
+            .flex()
+            .flex_col()
+            .gap(px(4.0))
+            .child(
+                div()
+                    .text_size(text_size)
+                    .text_color(Colors::text())
+                    .font_weight(FontWeight::BOLD)
+                    .child("Presets"),
+            )
+            .children(rows)
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap(Spacing::sm())
+                    .child(div().flex_1().child(self.preset_name_input.clone()))
+                    .child(
+                        div()
+                            .id("save-preset")
+                            .px(Spacing::sm())
+                            .py(px(2.0))
+                            .rounded(px(4.0))
+                            .bg(rgba(0x44_44_44_FF))
+                            .text_size(text_size)
+                            .text_color(Colors::text())
+                            .cursor_pointer()
+                            .hover(|s| s.bg(rgba(0x55_55_55_FF)))
+                            .on_click(cx.listener(|this, _, _, cx| this.save_preset(cx)))
+                            .child("Save current as preset"),
+                    ),
+            )
+    }
+
+    /// A small -100..100 slider for one channel offset, subscribed like the
+    /// others
+    fn new_offset_slider(value: f32, cx: &mut Context<Self>) -> Entity<Slider> {
+        let slider = cx.new(|cx| {
+            Slider::new(cx)
+                .with_value(value as f64)
+                .min(-100.0)
+                .max(100.0)
+                .step(1.0)
+                .display_precision(0)
+        });
+        cx.subscribe(&slider, |_this, _slider, event: &SliderEvent, cx| {
+            if let SliderEvent::Change(_) = event {
+                cx.emit(FilterControlsEvent::FiltersChanged);
+            }
+        })
+        .detach();
+        slider
+    }
+
+    /// One labelled row of the Color subsection
+    fn render_offset_row(&self, label: &'static str, slider: &Entity<Slider>, cx: &App) -> Div {
+        let value = slider.read(cx).value();
+        div()
+            .flex()
+            .items_center()
+            .gap(Spacing::sm())
+            .child(
+                div()
+                    .w(px(14.0))
+                    .text_size(scaled_text_size(12.0, self.font_size_scale))
+                    .text_color(Colors::text())
+                    .child(label),
+            )
+            .child(div().flex_1().child(slider.clone()))
+            .child(
+                div()
+                    .w(px(32.0))
+                    .text_size(scaled_text_size(12.0, self.font_size_scale))
+                    .text_color(rgb(0xAAAAAA))
+                    .font_weight(FontWeight::BOLD)
+                    .child(format!("{:+.0}", value)),
+            )
+    }
+
     /// Update slider values from filter settings (e.g., when filters are reset)
     pub fn update_from_filters(&mut self, filters: FilterSettings, cx: &mut Context<Self>) {
         self.brightness_slider.update(cx, |slider, cx| {
@@ -104,6 +338,24 @@ impl FilterControls {
         self.gamma_slider.update(cx, |slider, cx| {
             slider.set_value(filters.gamma as f64, cx);
         });
+        self.saturation_slider.update(cx, |slider, cx| {
+            slider.set_value(filters.saturation as f64, cx);
+        });
+        self.invert_checkbox.update(cx, |checkbox, cx| {
+            checkbox.set_checked(filters.invert, cx);
+        });
+        self.grayscale_checkbox.update(cx, |checkbox, cx| {
+            checkbox.set_checked(filters.grayscale, cx);
+        });
+        self.red_offset_slider.update(cx, |slider, cx| {
+            slider.set_value(filters.red_offset as f64, cx);
+        });
+        self.green_offset_slider.update(cx, |slider, cx| {
+            slider.set_value(filters.green_offset as f64, cx);
+        });
+        self.blue_offset_slider.update(cx, |slider, cx| {
+            slider.set_value(filters.blue_offset as f64, cx);
+        });
     }
 
     /// Get current filter settings from sliders
@@ -112,6 +364,12 @@ impl FilterControls {
             brightness: self.brightness_slider.read(cx).value() as f32,
             contrast: self.contrast_slider.read(cx).value() as f32,
             gamma: self.gamma_slider.read(cx).value() as f32,
+            saturation: self.saturation_slider.read(cx).value() as f32,
+            invert: self.invert_checkbox.read(cx).is_checked(),
+            grayscale: self.grayscale_checkbox.read(cx).is_checked(),
+            red_offset: self.red_offset_slider.read(cx).value() as f32,
+            green_offset: self.green_offset_slider.read(cx).value() as f32,
+            blue_offset: self.blue_offset_slider.read(cx).value() as f32,
         }
     }
 }
@@ -121,6 +379,7 @@ impl Render for FilterControls {
         let brightness_value = self.brightness_slider.read(cx).value();
         let contrast_value = self.contrast_slider.read(cx).value();
         let gamma_value = self.gamma_slider.read(cx).value();
+        let saturation_value = self.saturation_slider.read(cx).value();
         let reset_all = crate::utils::style::format_shortcut("R", true, false);
 
         div()
@@ -194,6 +453,33 @@ impl Render for FilterControls {
                             )
                             .child(self.contrast_slider.clone()),
                     )
+                    // Saturation slider
+                    .child(
+                        div()
+                            .flex()
+                            .flex_col()
+                            .gap(px(4.0))
+                            .child(
+                                div()
+                                    .flex()
+                                    .justify_between()
+                                    .items_center()
+                                    .child(
+                                        div()
+                                            .text_size(scaled_text_size(12.0, self.font_size_scale))
+                                            .text_color(Colors::text())
+                                            .child("Saturation"),
+                                    )
+                                    .child(
+                                        div()
+                                            .text_size(scaled_text_size(12.0, self.font_size_scale))
+                                            .text_color(rgb(0xAAAAAA))
+                                            .font_weight(FontWeight::BOLD)
+                                            .child(format!("{:+.0}", saturation_value)),
+                                    ),
+                            )
+                            .child(self.saturation_slider.clone()),
+                    )
                     // Gamma slider
                     .child(
                         div()
@@ -221,6 +507,29 @@ impl Render for FilterControls {
                             )
                             .child(self.gamma_slider.clone()),
                     )
+                    // Invert checkbox
+                    .child(self.invert_checkbox.clone())
+                    // Grayscale checkbox
+                    .child(self.grayscale_checkbox.clone())
+                    // Color subsection: per-channel offsets
+                    .child(
+                        div()
+                            .flex()
+                            .flex_col()
+                            .gap(px(4.0))
+                            .child(
+                                div()
+                                    .text_size(scaled_text_size(12.0, self.font_size_scale))
+                                    .text_color(Colors::text())
+                                    .font_weight(FontWeight::BOLD)
+                                    .child("Color"),
+                            )
+                            .child(self.render_offset_row("R", &self.red_offset_slider, cx))
+                            .child(self.render_offset_row("G", &self.green_offset_slider, cx))
+                            .child(self.render_offset_row("B", &self.blue_offset_slider, cx)),
+                    )
+                    .child(div().h(px(1.0)).bg(rgba(0x44_44_44_FF)).mt(Spacing::sm()))
+                    .child(self.render_preset_bar(cx))
                     .child(div().h(px(1.0)).bg(rgba(0x44_44_44_FF)).mt(Spacing::sm()))
                     .child(
                         div()
@@ -239,6 +548,18 @@ impl Render for FilterControls {
                                     .text_color(rgb(0xAAAAAA))
                                     .child("1 / 2: Disable/Enable"),
                             )
+                            .child(
+                                div()
+                                    .text_size(TextSize::sm())
+                                    .text_color(rgb(0xAAAAAA))
+                                    .child("N: Invert colors"),
+                            )
+                            .child(
+                                div()
+                                    .text_size(TextSize::sm())
+                                    .text_color(rgb(0xAAAAAA))
+                                    .child("G: Grayscale"),
+                            )
                             .child(
                                 div()
                                     .text_size(TextSize::sm())
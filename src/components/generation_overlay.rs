@@ -0,0 +1,180 @@
+use crate::utils::generation_params::{GenerationInfo, GenerationParams};
+use crate::utils::style::{Colors, Spacing, scaled_text_size};
+use gpui::prelude::*;
+use gpui::*;
+
+/// Generation info overlay: the prompts, seed, sampler and model an AI
+/// image tool stored in the file, with buttons to copy the prompts.
+pub struct GenerationOverlay {
+    info: Option<GenerationInfo>,
+    /// The text last copied, so its button can say so
+    copied: Option<String>,
+    overlay_transparency: u8,
+    font_size_scale: f32,
+}
+
+impl GenerationOverlay {
+    pub fn new(overlay_transparency: u8, font_size_scale: f32) -> Self {
+        Self {
+            info: None,
+            copied: None,
+            overlay_transparency,
+            font_size_scale,
+        }
+    }
+
+    /// Show `info` (`None` when the image has no generation metadata)
+    pub fn update(
+        &mut self,
+        info: Option<GenerationInfo>,
+        overlay_transparency: u8,
+        font_size_scale: f32,
+    ) {
+        self.info = info;
+        self.overlay_transparency = overlay_transparency;
+        self.font_size_scale = font_size_scale;
+    }
+
+    fn render_label(&self, label: &'static str) -> Div {
+        div()
+            .text_size(scaled_text_size(12.0, self.font_size_scale))
+            .text_color(Colors::muted_text())
+            .child(label)
+    }
+
+    /// A labelled block of text with a Copy button beside the label
+    fn render_text_block(
+        &self,
+        id: &'static str,
+        label: &'static str,
+        text: &str,
+        cx: &mut Context<Self>,
+    ) -> Div {
+        let copied = self.copied.as_deref() == Some(text);
+        let text = text.to_string();
+        let copy_text = text.clone();
+        div()
+            .flex()
+            .flex_col()
+            .gap(px(2.0))
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .justify_between()
+                    .child(self.render_label(label))
+                    .child(
+                        div()
+                            .id(id)
+                            .px(Spacing::sm())
+                            .py(px(1.0))
+                            .bg(rgb(0x444444))
+                            .rounded(px(4.0))
+                            .text_size(scaled_text_size(11.0, self.font_size_scale))
+                            .text_color(Colors::text())
+                            .cursor_pointer()
+                            .on_click(cx.listener(move |this, _: &ClickEvent, _window, cx| {
+                                cx.write_to_clipboard(ClipboardItem::new_string(copy_text.clone()));
+                                this.copied = Some(copy_text.clone());
+                                cx.notify();
+                            }))
+                            .child(if copied { "Copied" } else { "Copy" }),
+                    ),
+            )
+            .child(
+                div()
+                    .text_size(scaled_text_size(12.0, self.font_size_scale))
+                    .text_color(Colors::text())
+                    .line_height(relative(1.4))
+                    .child(text),
+            )
+    }
+
+    fn render_field(&self, label: &'static str, value: String) -> Div {
+        div()
+            .flex()
+            .flex_row()
+            .gap(Spacing::sm())
+            .child(div().min_w(px(70.0)).child(self.render_label(label)))
+            .child(
+                div()
+                    .text_size(scaled_text_size(12.0, self.font_size_scale))
+                    .text_color(Colors::text())
+                    .font_family("monospace")
+                    .child(value),
+            )
+    }
+
+    fn render_params(&self, params: &GenerationParams, cx: &mut Context<Self>) -> Div {
+        let mut body = div().flex().flex_col().gap(Spacing::sm());
+        if let Some(prompt) = &params.prompt {
+            body = body.child(self.render_text_block("copy-prompt", "Prompt", prompt, cx));
+        }
+        if let Some(negative) = &params.negative_prompt {
+            body = body.child(self.render_text_block(
+                "copy-negative-prompt",
+                "Negative prompt",
+                negative,
+                cx,
+            ));
+        }
+        let fields = [
+            ("Seed", params.seed.map(|seed| seed.to_string())),
+            ("Sampler", params.sampler.clone()),
+            ("Model", params.model.clone()),
+        ];
+        body.children(
+            fields
+                .into_iter()
+                .filter_map(|(label, value)| value.map(|value| self.render_field(label, value))),
+        )
+    }
+}
+
+impl Render for GenerationOverlay {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let body = match self.info.clone() {
+            Some(GenerationInfo::Parsed(params)) => self.render_params(&params, cx),
+            Some(GenerationInfo::Raw(text)) => div()
+                .flex()
+                .flex_col()
+                .gap(Spacing::sm())
+                .child(self.render_label("Unrecognized layout, shown as stored"))
+                .child(self.render_text_block("copy-raw-parameters", "Parameters", &text, cx)),
+            None => self.render_label("No generation metadata"),
+        };
+
+        div()
+            // Bottom-left, above the animation indicator and clear of the
+            // debug and histogram overlays in the top corners
+            .absolute()
+            .bottom(px(48.0))
+            .left(Spacing::md())
+            .w(px(420.0))
+            .max_h(relative(0.7))
+            .overflow_hidden()
+            .bg(Colors::overlay_bg_alpha(self.overlay_transparency))
+            .border(Colors::border_width())
+            .border_color(Colors::border())
+            .rounded(px(6.0))
+            .p(Spacing::md())
+            .shadow_lg()
+            // Clicks on the copy buttons shouldn't start a pan underneath
+            .occlude()
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap(Spacing::sm())
+                    .child(
+                        div()
+                            .text_size(scaled_text_size(14.0, self.font_size_scale))
+                            .text_color(Colors::text())
+                            .font_weight(FontWeight::BOLD)
+                            .child("Generation Info"),
+                    )
+                    .child(body),
+            )
+    }
+}
@@ -439,7 +439,7 @@ impl GpuPipelineControls {
         }
         let preset = self.to_preset(cx);
         if let Err(e) = gpu_presets::save_preset(name, &preset) {
-            eprintln!("Failed to save GPU preset: {}", e);
+            log::error!("Failed to save GPU preset: {}", e);
             return;
         }
         self.current_preset = Some(name.to_string());
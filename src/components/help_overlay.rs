@@ -1,3 +1,4 @@
+use crate::CopyShortcutSheet;
 use crate::utils::style::{
     Colors, Spacing, format_shortcut, modifier_key, option_prefix, scaled_text_size, shift_prefix,
 };
@@ -24,6 +25,12 @@ impl HelpOverlay {
         }
     }
 
+    /// Follow appearance changes made after the overlay was created
+    pub fn set_appearance(&mut self, overlay_transparency: u8, font_size_scale: f32) {
+        self.overlay_transparency = overlay_transparency;
+        self.font_size_scale = font_size_scale;
+    }
+
     fn render_popover_header(&self) -> impl Element {
         div()
             .px(Spacing::xl())
@@ -57,7 +64,7 @@ impl HelpOverlay {
                 div()
                     .min_w(px(140.0))
                     .text_size(scaled_text_size(12.0, self.font_size_scale))
-                    .text_color(rgb(0xaaaaaa))
+                    .text_color(Colors::secondary_text())
                     .font_family("monospace")
                     .child(keys),
             )
@@ -80,6 +87,30 @@ impl HelpOverlay {
                 .into_any_element(),
             self.render_shortcut("← →".to_string(), "Previous/Next image".to_string())
                 .into_any_element(),
+            self.render_shortcut(
+                "PgUp PgDn".to_string(),
+                "Jump back/forward by several images".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut("Home End".to_string(), "First/Last image".to_string())
+                .into_any_element(),
+            self.render_shortcut(
+                format_shortcut("G", false, false),
+                "Go to image number".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut(
+                format!("{}← →", shift_prefix()),
+                "Previous/Next image of a different format".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut(
+                format!("{}1\u{2013}9", option_prefix()),
+                "Jump 10%\u{2013}90% through the list".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut("P".to_string(), "Start/stop slideshow".to_string())
+                .into_any_element(),
             self.render_shortcut(
                 format_shortcut("A", true, false),
                 "Sort alphabetically".to_string(),
@@ -95,6 +126,11 @@ impl HelpOverlay {
                 "Sort by type (toggles secondary A/M)".to_string(),
             )
             .into_any_element(),
+            self.render_shortcut(
+                format_shortcut("D", true, false),
+                "Reverse sort order".to_string(),
+            )
+            .into_any_element(),
             // Zoom section
             self.render_section_header("Zoom".to_string())
                 .into_any_element(),
@@ -102,8 +138,31 @@ impl HelpOverlay {
                 .into_any_element(),
             self.render_shortcut("-".to_string(), "Zoom out".to_string())
                 .into_any_element(),
-            self.render_shortcut("0".to_string(), "Toggle fit-to-window / 100%".to_string())
-                .into_any_element(),
+            self.render_shortcut(
+                "0".to_string(),
+                "Fit-to-window / 100% (or zoom-stop cycle)".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut(
+                format!("{}Z", shift),
+                "Type an exact zoom (e.g. 250%)".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut(
+                format!("{}F", option_prefix()),
+                "Lock fit-to-window for all images".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut(
+                format!("{}C / {}V", option_prefix(), option_prefix()),
+                "Copy / paste zoom and pan".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut(
+                "R / Shift+R".to_string(),
+                "Rotate 90° clockwise / counter-clockwise".to_string(),
+            )
+            .into_any_element(),
             self.render_shortcut(
                 format!("{}+ / {}−", shift, shift),
                 "Fast zoom (1.5x steps)".to_string(),
@@ -133,6 +192,11 @@ impl HelpOverlay {
                 "Zoom at cursor (mouse wheel)".to_string(),
             )
             .into_any_element(),
+            self.render_shortcut(
+                "Double-click".to_string(),
+                "Toggle fit / 100% at cursor".to_string(),
+            )
+            .into_any_element(),
             self.render_shortcut("Z + Drag".to_string(), "Drag to zoom (dynamic)".to_string())
                 .into_any_element(),
             // Pan section
@@ -160,8 +224,35 @@ impl HelpOverlay {
                 .into_any_element(),
             self.render_shortcut("O".to_string(), "Play/Pause animation".to_string())
                 .into_any_element(),
+            self.render_shortcut(
+                format!("{}O", shift_prefix()),
+                "Loop / play once / ping-pong".to_string(),
+            )
+            .into_any_element(),
             self.render_shortcut("[ ]".to_string(), "Previous/Next frame".to_string())
                 .into_any_element(),
+            self.render_shortcut(
+                format!("{}[ ]", shift_prefix()),
+                "Jump back/forward several frames".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut(
+                format!("{}[ ]", option_prefix()),
+                "First/Last frame".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut(
+                format!("{}G", shift_prefix()),
+                "Go to frame number".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut(
+                format_shortcut("F", false, true),
+                "Save the current frame as an image".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut(", .".to_string(), "Slower/Faster playback".to_string())
+                .into_any_element(),
             // Window section
             self.render_section_header("Window".to_string())
                 .into_any_element(),
@@ -175,6 +266,8 @@ impl HelpOverlay {
                 "Quit application".to_string(),
             )
             .into_any_element(),
+            self.render_shortcut("F11".to_string(), "Toggle fullscreen".to_string())
+                .into_any_element(),
             self.render_shortcut(
                 "Esc (3x)".to_string(),
                 "Quick close window (press 3 times within 2s)".to_string(),
@@ -192,10 +285,36 @@ impl HelpOverlay {
                 .into_any_element(),
             self.render_shortcut("2".to_string(), "Show processed image (A/B)".to_string())
                 .into_any_element(),
+            self.render_shortcut("`".to_string(), "Toggle raw/processed".to_string())
+                .into_any_element(),
+            self.render_shortcut(
+                format!("{}G", option_prefix()),
+                "Lock filters across images".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut(
+                format!("{}E", option_prefix()),
+                "Auto exposure for dark/bright images".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut("N".to_string(), "Invert colors".to_string())
+                .into_any_element(),
+            self.render_shortcut("G".to_string(), "Grayscale".to_string())
+                .into_any_element(),
             self.render_shortcut("3\u{2013}9".to_string(), "Recall saved slot".to_string())
                 .into_any_element(),
             self.render_shortcut("Ctrl+3\u{2013}9".to_string(), "Save to slot".to_string())
                 .into_any_element(),
+            self.render_shortcut(
+                format_shortcut("Z", false, false),
+                "Undo filter change".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut(
+                format_shortcut("Z", true, false),
+                "Redo filter change".to_string(),
+            )
+            .into_any_element(),
             self.render_shortcut(
                 format_shortcut("R", true, false),
                 "Reset all filters".to_string(),
@@ -219,6 +338,13 @@ impl HelpOverlay {
                 "Open image file(s)".to_string(),
             )
             .into_any_element(),
+            self.render_shortcut(format_shortcut("O", true, false), "Open folder".to_string())
+                .into_any_element(),
+            self.render_shortcut(
+                format_shortcut("↑", false, false),
+                "Open the enclosing folder".to_string(),
+            )
+            .into_any_element(),
             self.render_shortcut(
                 format_shortcut("S", false, false),
                 "Save image (current folder)".to_string(),
@@ -229,6 +355,31 @@ impl HelpOverlay {
                 "Save to Downloads folder".to_string(),
             )
             .into_any_element(),
+            self.render_shortcut(
+                format_shortcut("E", true, false),
+                "Export all images to a folder".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut(
+                format_shortcut("C", true, false),
+                "Copy the image to the clipboard".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut(
+                format_shortcut("C", true, true),
+                "Copy the file's full path".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut(
+                format_shortcut("C", false, true),
+                "Copy the visible area to the clipboard".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut(
+                format_shortcut("S", true, true),
+                "Save the visible area as an image".to_string(),
+            )
+            .into_any_element(),
             self.render_shortcut(
                 format_shortcut("R", false, false),
                 "Reveal in Finder".to_string(),
@@ -244,11 +395,23 @@ impl HelpOverlay {
                 "Open externally and quit".to_string(),
             )
             .into_any_element(),
+            self.render_shortcut(
+                format_shortcut("O", false, true),
+                "Open in the system default app".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut(
+                format_shortcut("O", true, true),
+                "Open in the default app and quit".to_string(),
+            )
+            .into_any_element(),
             self.render_shortcut(
                 format_shortcut("E", false, false),
                 "Open in external editor".to_string(),
             )
             .into_any_element(),
+            self.render_shortcut("F2".to_string(), "Rename file".to_string())
+                .into_any_element(),
             self.render_shortcut(
                 format_shortcut("Delete", false, false),
                 "Delete file (to Trash)".to_string(),
@@ -274,10 +437,57 @@ impl HelpOverlay {
             .into_any_element(),
             self.render_shortcut("F12".to_string(), "Toggle debug overlay".to_string())
                 .into_any_element(),
+            self.render_shortcut("Shift+H".to_string(), "Toggle histogram".to_string())
+                .into_any_element(),
+            self.render_shortcut(
+                "Shift+P".to_string(),
+                "Toggle AI generation info (prompt, seed, sampler, model)".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut(
+                "M".to_string(),
+                "Pick/unpick the image for the montage".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut(
+                format!("{}M", shift_prefix()),
+                "Toggle the montage of picked images".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut(
+                format!("{}M", option_prefix()),
+                "Link/unlink montage views".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut(
+                "Tab".to_string(),
+                "Bring the next overlay to the front".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut(
+                "E".to_string(),
+                "Eyedropper: show the color under the cursor, click to copy it".to_string(),
+            )
+            .into_any_element(),
+            self.render_shortcut(
+                "C".to_string(),
+                "Crop: drag a selection to save (Shift keeps proportions)".to_string(),
+            )
+            .into_any_element(),
             self.render_shortcut("T".to_string(), "Toggle zoom/size indicator".to_string())
                 .into_any_element(),
+            self.render_shortcut(
+                "V".to_string(),
+                "Toggle navigator (shown when zoomed in)".to_string(),
+            )
+            .into_any_element(),
             self.render_shortcut("B".to_string(), "Toggle light/dark background".to_string())
                 .into_any_element(),
+            self.render_shortcut(
+                "Shift+B".to_string(),
+                "Cycle backdrop (checkerboard, white, black)".to_string(),
+            )
+            .into_any_element(),
             self.render_shortcut(
                 format_shortcut(",", false, false),
                 "Open settings window".to_string(),
@@ -295,7 +505,7 @@ impl HelpOverlay {
             .flex_1()
             .min_h_0() // Critical for scrolling - allows flex item to shrink below content size
             .w(px(500.0))
-            .bg(rgb(0x313244))
+            .bg(Colors::inset_panel())
             //.rounded_lg()
             .py(px(5.0))
             // No horizontal padding here - put it inside scrollable so scrollbar is at edge
@@ -319,17 +529,34 @@ impl HelpOverlay {
             .px(Spacing::xl())
             .pb(Spacing::xl())
             .pt(Spacing::md())
-            .border_t_1()
-            .border_color(rgb(0x444444))
+            .border_t(Colors::border_width())
+            .border_color(Colors::border())
             .text_size(scaled_text_size(12.0, self.font_size_scale))
-            .text_color(rgb(0xaaaaaa))
+            .text_color(Colors::secondary_text())
             .text_align(TextAlign::Center)
             .child("Press H, ?, F1, or Esc to close this help")
+            .child(
+                div().flex().justify_center().mt(Spacing::sm()).child(
+                    div()
+                        .px(Spacing::md())
+                        .py(Spacing::xs())
+                        .rounded(px(4.0))
+                        .border(Colors::border_width())
+                        .border_color(Colors::border())
+                        .text_color(Colors::text())
+                        .cursor_pointer()
+                        .hover(|style| style.bg(Colors::inset_panel()))
+                        .on_mouse_down(MouseButton::Left, |_event, window, cx| {
+                            window.dispatch_action(CopyShortcutSheet.boxed_clone(), cx);
+                        })
+                        .child("Copy all shortcuts as Markdown"),
+                ),
+            )
             .child(
                 div()
                     .mt(Spacing::xs())
                     .text_size(scaled_text_size(10.0, self.font_size_scale))
-                    .text_color(rgb(0x666666))
+                    .text_color(Colors::muted_text())
                     .child(format!("rpview v{}", env!("CARGO_PKG_VERSION"))),
             )
     }
@@ -348,9 +575,9 @@ impl Render for HelpOverlay {
             .child(
                 // Help content box
                 div()
-                    .bg(rgb(0x1e1e1e))
-                    .border_1()
-                    .border_color(rgb(0x444444))
+                    .bg(Colors::panel())
+                    .border(Colors::border_width())
+                    .border_color(Colors::border())
                     .rounded(px(8.0))
                     .w(px(500.0))
                     .h(px(600.0))
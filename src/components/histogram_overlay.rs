@@ -0,0 +1,119 @@
+use crate::utils::histogram::Histogram;
+use crate::utils::style::{Colors, Spacing, scaled_text_size};
+use gpui::prelude::*;
+use gpui::*;
+
+/// Height of each of the four histogram plots
+const PLOT_HEIGHT: f32 = 48.0;
+
+/// Histogram overlay: luminance plus red / green / blue, one 1px bar per
+/// bucket, each plot scaled to its own tallest bucket.
+pub struct HistogramOverlay {
+    histogram: Option<Histogram>,
+    overlay_transparency: u8,
+    font_size_scale: f32,
+}
+
+impl HistogramOverlay {
+    pub fn new(overlay_transparency: u8, font_size_scale: f32) -> Self {
+        Self {
+            histogram: None,
+            overlay_transparency,
+            font_size_scale,
+        }
+    }
+
+    /// Show `histogram` (`None` while there is no image to count)
+    pub fn update(
+        &mut self,
+        histogram: Option<Histogram>,
+        overlay_transparency: u8,
+        font_size_scale: f32,
+    ) {
+        self.histogram = histogram;
+        self.overlay_transparency = overlay_transparency;
+        self.font_size_scale = font_size_scale;
+    }
+
+    fn render_plot(&self, label: &'static str, counts: &[u32; 256], color: Hsla) -> Div {
+        let tallest = counts.iter().copied().max().unwrap_or(0).max(1) as f32;
+        let bars = counts.iter().map(|&count| {
+            div()
+                .w(px(1.0))
+                .h(px(PLOT_HEIGHT * count as f32 / tallest))
+                .bg(color)
+        });
+        div()
+            .flex()
+            .flex_col()
+            .gap(px(2.0))
+            .child(
+                div()
+                    .text_size(scaled_text_size(11.0, self.font_size_scale))
+                    .text_color(Colors::muted_text())
+                    .child(label),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_end()
+                    .w(px(256.0))
+                    .h(px(PLOT_HEIGHT))
+                    .bg(rgba(0x00_00_00_66))
+                    .children(bars),
+            )
+    }
+}
+
+impl Render for HistogramOverlay {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        let body = match &self.histogram {
+            Some(histogram) => {
+                let colors = [
+                    rgb(0xDDDDDD).into(),
+                    rgb(0xFF5555).into(),
+                    rgb(0x50FA7B).into(),
+                    rgb(0x6272FF).into(),
+                ];
+                div().flex().flex_col().gap(Spacing::sm()).children(
+                    histogram
+                        .channels()
+                        .into_iter()
+                        .zip(colors)
+                        .map(|((label, counts), color)| self.render_plot(label, counts, color)),
+                )
+            }
+            None => div()
+                .text_size(scaled_text_size(12.0, self.font_size_scale))
+                .text_color(Colors::muted_text())
+                .child("No image"),
+        };
+
+        div()
+            // Top-left, clear of the debug overlay in the top-right
+            .absolute()
+            .top(Spacing::md())
+            .left(Spacing::md())
+            .bg(Colors::overlay_bg_alpha(self.overlay_transparency))
+            .border(Colors::border_width())
+            .border_color(Colors::border())
+            .rounded(px(6.0))
+            .p(Spacing::md())
+            .shadow_lg()
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap(Spacing::sm())
+                    .child(
+                        div()
+                            .text_size(scaled_text_size(14.0, self.font_size_scale))
+                            .text_color(Colors::text())
+                            .font_weight(FontWeight::BOLD)
+                            .child("Histogram"),
+                    )
+                    .child(body),
+            )
+    }
+}
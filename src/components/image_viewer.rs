@@ -5,17 +5,24 @@ use crate::components::animation_indicator::AnimationIndicator;
 use crate::components::error_display::ErrorDisplay;
 use crate::components::processing_indicator::ProcessingIndicator;
 use crate::components::zoom_indicator::ZoomIndicator;
-use crate::state::ImageState;
 use crate::state::image_state::FilterSettings;
-use crate::utils::animation::AnimationData;
-use crate::utils::debug_eprintln;
+use crate::state::{Backdrop, ImageState};
+use crate::utils::animation::FrameSequence;
+use crate::utils::crop::{self, CropDrag, SelectionRect};
+use crate::utils::decode_cache::{self, DecodeCache};
+use crate::utils::eyedropper::{self, ColorSample};
 use crate::utils::filters;
+use crate::utils::histogram::{
+    Histogram, PixelLayout, auto_exposure_brightness, compute_histogram,
+};
 use crate::utils::image_loader;
+use crate::utils::rotation::{rotate_image, rotated_size, source_point};
 use crate::utils::style::{Colors, Spacing, TextSize};
 use crate::utils::svg::SvgRerasterRegion;
 use crate::utils::zoom;
+use gpui::prelude::FluentBuilder;
 use gpui::*;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex, mpsc};
 use std::time::Instant;
 
@@ -52,13 +59,64 @@ fn rgba_to_bgra_render_image(rgba: &image::RgbaImage) -> Arc<gpui::RenderImage>
     )))
 }
 
+/// A captured display's pixels back in RGBA order
+fn snapshot_rgba(snapshot: &SavedSlot) -> Option<image::RgbaImage> {
+    let mut rgba = snapshot.render.as_bytes(0)?.to_vec();
+    for px in rgba.chunks_exact_mut(4) {
+        px.swap(0, 2);
+    }
+    image::RgbaImage::from_raw(snapshot.width, snapshot.height, rgba)
+}
+
+/// Side of one checkerboard square, in screen pixels
+const CHECKER_SIZE: f32 = 8.0;
+
+/// The transparency grid drawn behind the image for `Backdrop::Checkerboard`.
+/// Squares are fixed in screen size and only those inside the window are
+/// painted, so a deep zoom costs no more than a fitted image.
+fn checkerboard() -> Canvas<()> {
+    canvas(
+        |_, _, _| {},
+        |bounds, _, window, _| {
+            window.paint_quad(fill(bounds, rgb(0xFFFFFF)));
+            let visible = bounds.intersect(&Bounds::new(
+                point(px(0.0), px(0.0)),
+                window.viewport_size(),
+            ));
+            if visible.size.width <= px(0.0) || visible.size.height <= px(0.0) {
+                return;
+            }
+            let origin_x: f32 = bounds.origin.x.into();
+            let origin_y: f32 = bounds.origin.y.into();
+            let first_col = ((f32::from(visible.origin.x) - origin_x) / CHECKER_SIZE) as i64;
+            let first_row = ((f32::from(visible.origin.y) - origin_y) / CHECKER_SIZE) as i64;
+            let last_col = ((f32::from(visible.right()) - origin_x) / CHECKER_SIZE).ceil() as i64;
+            let last_row = ((f32::from(visible.bottom()) - origin_y) / CHECKER_SIZE).ceil() as i64;
+            for row in first_row..last_row {
+                for col in (first_col..last_col).filter(|col| (col + row) % 2 == 1) {
+                    let square = Bounds::new(
+                        point(
+                            px(origin_x + col as f32 * CHECKER_SIZE),
+                            px(origin_y + row as f32 * CHECKER_SIZE),
+                        ),
+                        size(px(CHECKER_SIZE), px(CHECKER_SIZE)),
+                    )
+                    .intersect(&bounds);
+                    window.paint_quad(fill(square, rgb(0xCCCCCC)));
+                }
+            }
+        },
+    )
+}
+
 /// Effective display dimensions for `loaded` at `frame_idx` (when animated).
 ///
 /// Selection priority:
 /// 1. **GPU pipeline enabled + render present** → that render's size
 ///    (`resize_factor` may have shrunk it).
 /// 2. **Animated** → the current frame's source dimensions.
-/// 3. **Static (or fallback)** → file dimensions.
+/// 3. **Filtered render** → file dimensions, turned by its rotation.
+/// 4. **Static (or fallback)** → file dimensions.
 fn effective_image_size(
     loaded: &LoadedImage,
     gpu_pipeline_enabled: bool,
@@ -71,11 +129,14 @@ fn effective_image_size(
         return size;
     }
     if let Some(idx) = frame_idx
-        && let Some(ref anim) = loaded.animation_data
+        && let Some(ref anim) = loaded.frame_sequence
         && let Some(frame) = anim.frames.get(idx)
     {
         return (frame.image.width(), frame.image.height());
     }
+    if loaded.filtered_render.is_some() {
+        return rotated_size((loaded.width, loaded.height), loaded.cached_rotation);
+    }
     (loaded.width, loaded.height)
 }
 
@@ -102,6 +163,18 @@ impl GpuJob {
     }
 }
 
+/// The facing page drawn beside the current image in two-page spread mode.
+/// It is shown as decoded, at the current image's height; filters, slots
+/// and the GPU pipeline apply to the current image only.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpreadPage {
+    pub path: PathBuf,
+    pub width: u32,
+    pub height: u32,
+    /// Drawn left of the current image rather than right
+    pub on_left: bool,
+}
+
 /// Loaded image data
 ///
 /// # Animation Frame Caching Strategy
@@ -144,7 +217,10 @@ pub struct LoadedImage {
     /// GPUI via `ImageSource::Render`, no temp file.
     pub filtered_render: Option<Arc<gpui::RenderImage>>,
     /// Filter settings used to produce `filtered_render` (for change detection).
+    /// `None` when it holds only a rotation.
     pub cached_filter_settings: Option<FilterSettings>,
+    /// Rotation baked into `filtered_render` (clockwise degrees)
+    pub cached_rotation: u16,
 
     // --- GPU pipeline state ---
     /// Output of the unified GPU pixel-shader pipeline (PSP3 algorithm,
@@ -172,7 +248,7 @@ pub struct LoadedImage {
 
     // --- Animation ---
     /// Animation data (if this is an animated image)
-    pub animation_data: Option<AnimationData>,
+    pub frame_sequence: Option<FrameSequence>,
     /// Cached paths for each animation frame (disk cache)
     /// Empty PathBuf means frame not yet cached (will be cached on-demand)
     pub frame_cache_paths: Vec<PathBuf>,
@@ -188,6 +264,13 @@ pub struct LoadedImage {
     pub svg_tree: Option<Arc<resvg::usvg::Tree>>,
     /// Scale factor used for the initial SVG rasterization (typically 2.0)
     pub svg_base_scale: f32,
+    /// What the SVG is rasterized onto (None = transparency); re-rasters
+    /// and saves use the same
+    pub svg_background: Option<[u8; 3]>,
+
+    /// Pixel aspect ratio (pixel width / height) from the file's metadata;
+    /// None for square pixels
+    pub pixel_aspect: Option<f32>,
 }
 
 /// Component for viewing images
@@ -199,11 +282,29 @@ pub struct ImageViewer {
     pub(crate) error_message: Option<String>,
     /// Path of the image that failed to load (for full path display)
     pub(crate) error_path: Option<PathBuf>,
+    /// Whether the error is a file cut short (the error screen offers Reload)
+    pub(crate) error_is_incomplete: bool,
     /// Path to directory with no images (for friendly notice, not an error)
     pub(crate) no_images_path: Option<PathBuf>,
     /// Oversized image warning: (path, width, height, max_dimension)
     pub(crate) oversized_image: Option<(PathBuf, u32, u32, u32)>,
 
+    // --- Histogram ---
+    /// Histogram of the image as displayed, kept by `refresh_histogram`
+    /// while the histogram overlay is showing
+    pub(crate) histogram: Option<Histogram>,
+    /// What `histogram` counted: the image path and the filter settings of
+    /// the filtered render (`None` = the unfiltered image)
+    histogram_source: Option<(PathBuf, Option<FilterSettings>)>,
+
+    // --- Two-page spread ---
+    /// Facing page shown beside the current image (spread mode only)
+    pub(crate) spread_partner: Option<SpreadPage>,
+
+    /// What is drawn behind the image, cycled with `Shift+B` for the
+    /// session; `Configured` uses the background passed to `render_view`
+    pub(crate) backdrop: Backdrop,
+
     // --- Viewport / interaction state ---
     /// Focus handle for keyboard events
     pub(crate) focus_handle: FocusHandle,
@@ -211,6 +312,32 @@ pub struct ImageViewer {
     pub(crate) image_state: ImageState,
     /// Last known viewport size (for fit-to-window calculations)
     pub(crate) viewport_size: Option<Size<Pixels>>,
+    /// What SVGs are rasterized onto when loaded (from
+    /// `appearance.svg_background`)
+    pub(crate) svg_background: Option<[u8; 3]>,
+    /// Margin in pixels left around the image on each side when fitting to
+    /// the window (from `viewer_behavior.fit_padding`)
+    pub(crate) fit_padding: f32,
+    /// Percent of the viewport kept clear at each edge when fitting (the
+    /// presentation safe area; 0 outside fullscreen and slideshows)
+    safe_area_percent: f32,
+    /// Lay out images with non-square pixels at their intended proportions
+    /// (from `viewer_behavior.honor_pixel_aspect`)
+    pub(crate) honor_pixel_aspect: bool,
+    /// Fit-lock: every image shows fit-to-window, refit on resize and on
+    /// navigation regardless of its saved zoom (toggled with `alt-f`)
+    pub(crate) fit_locked: bool,
+    /// Filter-lock: the live filters carry over to every image shown,
+    /// regardless of its saved filters (toggled with `alt-g`)
+    pub(crate) filters_locked: bool,
+    /// Auto exposure: show very dark and very bright images nudged toward
+    /// mid-tones (from `filters.auto_exposure`, toggled with `alt-e`)
+    pub(crate) auto_exposure: bool,
+    /// The auto-exposure brightness worked out for an image and its path;
+    /// added to the live brightness when rendering, never to `image_state`
+    auto_exposure_offset: Option<(PathBuf, f32)>,
+    /// Where the last `0` press landed on the zoom-stop cycle (0 = fit)
+    zoom_cycle_position: usize,
     // These fields are accessed from the binary crate (app_render.rs) but the lib crate
     // can't see that usage, so the compiler warns about dead code.
     /// Z key drag zoom state: outer Option = Z key held, inner Option = actively dragging
@@ -224,11 +351,17 @@ pub struct ImageViewer {
     /// Whether a drag-to-pan actually moved (avoids unnecessary state saves on plain clicks).
     #[allow(dead_code)]
     pub(crate) drag_pan_moved: bool,
+    /// Crop mode's selection while it's being dragged out
+    #[allow(dead_code)]
+    pub(crate) crop_drag: Option<CropDrag>,
 
     // --- Async image loading ---
     /// Paths to preload into GPU (for smooth navigation)
     /// These images are rendered invisibly to prime the GPU texture cache
     pub(crate) preload_paths: Vec<PathBuf>,
+    /// Neighbours the preloader has already been through the loader, so
+    /// stepping to one skips it
+    pub(crate) decode_cache: DecodeCache<image_loader::LoadedImageData>,
     /// Active async loading operation
     pub(crate) loading_handle: Option<image_loader::LoaderHandle>,
     /// Loading state indicator
@@ -289,6 +422,9 @@ pub struct ImageViewer {
 
     /// Timestamp of last zoom/pan change (for debouncing re-raster triggers)
     pub(crate) last_zoom_pan_change: Option<Instant>,
+
+    /// A `rotate` whose render hasn't arrived yet
+    rotation_pending: bool,
 }
 
 impl ImageViewer {
@@ -296,16 +432,35 @@ impl ImageViewer {
         Self {
             current_image: None,
             error_message: None,
+            error_is_incomplete: false,
             error_path: None,
             no_images_path: None,
             oversized_image: None,
+            histogram: None,
+            histogram_source: None,
+            spread_partner: None,
+            backdrop: Backdrop::default(),
             focus_handle,
             image_state: ImageState::new(),
             viewport_size: None,
+            svg_background: None,
+            fit_padding: 0.0,
+            safe_area_percent: 0.0,
+            honor_pixel_aspect: true,
+            fit_locked: false,
+            filters_locked: false,
+            auto_exposure: false,
+            auto_exposure_offset: None,
+            zoom_cycle_position: 0,
             z_drag_state: None,
             drag_pan_state: None,
             drag_pan_moved: false,
+            crop_drag: None,
             preload_paths: Vec::new(),
+            decode_cache: DecodeCache::new(
+                decode_cache::entries_for_radius(1),
+                crate::state::settings::Performance::PRELOAD_MEMORY_BUDGET,
+            ),
             loading_handle: None,
             is_loading: false,
             is_processing_filters: false,
@@ -325,6 +480,7 @@ impl ImageViewer {
             is_svg_rerastering: false,
             svg_reraster_cancel: None,
             last_zoom_pan_change: None,
+            rotation_pending: false,
         }
     }
 
@@ -334,10 +490,21 @@ impl ImageViewer {
     /// from a saved state captured against a 4× LC render would render
     /// the source image at 1:1 (visibly 4× too small) rather than at the
     /// 4× apparent size the user actually saw.
-    pub fn set_image_state(&mut self, state: ImageState) {
+    pub fn set_image_state(&mut self, mut state: ImageState) {
+        if self.fit_locked {
+            state.lock_to_fit();
+        }
+        if self.filters_locked {
+            state.lock_filters(self.image_state.filters, self.image_state.filters_enabled);
+        }
         let saved_eff = state.saved_effective_size;
         self.image_state = state;
-        let current_eff = self.display_dimensions();
+        // Sizes were saved as displayed, with the rotation baked in; a
+        // render still to be built for it will turn the current size too
+        let pending_turn = (360 + self.image_state.rotation - self.displayed_rotation()) % 360;
+        let current_eff = self
+            .display_dimensions()
+            .map(|size| rotated_size(size, pending_turn));
         if let (Some(saved), Some(current)) = (saved_eff, current_eff)
             && saved != current
         {
@@ -357,19 +524,33 @@ impl ImageViewer {
     /// Calculate and set fit-to-window zoom for the current image
     pub fn fit_to_window(&mut self) {
         if let (Some((eff_w, eff_h)), Some(viewport)) =
-            (self.display_dimensions(), self.viewport_size)
+            (self.layout_dimensions(), self.viewport_size)
         {
             let viewport_width: f32 = viewport.width.into();
             let viewport_height: f32 = viewport.height.into();
+            let (safe_width, safe_height) =
+                zoom::safe_area(viewport_width, viewport_height, self.safe_area_percent);
+
+            // A spread fits both pages together
+            let partner_w = self.spread_partner_width(eff_h);
+            let fit_zoom = zoom::calculate_fit_to_window_with_padding(
+                eff_w + partner_w,
+                eff_h,
+                safe_width,
+                safe_height,
+                self.fit_padding,
+            );
 
-            let fit_zoom =
-                zoom::calculate_fit_to_window(eff_w, eff_h, viewport_width, viewport_height);
-
-            // Calculate pan to center the image in the viewing area
-            let zoomed_width = eff_w as f32 * fit_zoom;
+            // Calculate pan to center the image in the viewing area; the
+            // safe area and padding are symmetric, so centering in the full
+            // viewport leaves the same margin on opposite sides
+            let zoomed_width = (eff_w + partner_w) as f32 * fit_zoom;
             let zoomed_height = eff_h as f32 * fit_zoom;
-            let pan_x = (viewport_width - zoomed_width) / 2.0;
+            let mut pan_x = (viewport_width - zoomed_width) / 2.0;
             let pan_y = (viewport_height - zoomed_height) / 2.0;
+            if self.spread_partner.as_ref().is_some_and(|p| p.on_left) {
+                pan_x += partner_w as f32 * fit_zoom;
+            }
 
             self.image_state.zoom = fit_zoom;
             self.image_state.is_fit_to_window = true;
@@ -377,6 +558,39 @@ impl ImageViewer {
         }
     }
 
+    /// Keep `percent` of the viewport clear at each edge when fitting,
+    /// refitting when the view is fitted
+    pub fn set_safe_area_percent(&mut self, percent: f32) {
+        if self.safe_area_percent != percent {
+            self.safe_area_percent = percent;
+            if self.image_state.refits_on_resize(self.fit_locked) {
+                self.fit_to_window();
+            }
+        }
+    }
+
+    /// Show `partner` beside the current image (None = a single page),
+    /// refitting when the view is fitted
+    pub fn set_spread_partner(&mut self, partner: Option<SpreadPage>) {
+        if self.spread_partner != partner {
+            self.spread_partner = partner;
+            if self.image_state.is_fit_to_window {
+                self.fit_to_window();
+            }
+        }
+    }
+
+    /// Layout width of the spread's facing page when scaled to `height`
+    /// (0 without one)
+    fn spread_partner_width(&self, height: u32) -> u32 {
+        self.spread_partner
+            .as_ref()
+            .filter(|p| p.height > 0)
+            .map_or(0, |p| {
+                (p.width as u64 * height as u64 / p.height as u64) as u32
+            })
+    }
+
     /// Update viewport size and recalculate fit-to-window if needed
     pub fn update_viewport_size(&mut self, size: Size<Pixels>) {
         let size_changed = self
@@ -391,8 +605,8 @@ impl ImageViewer {
         if size_changed {
             self.viewport_size = Some(size);
 
-            // If we're in fit-to-window mode, recalculate
-            if self.image_state.is_fit_to_window {
+            // If we're in fit-to-window mode (or fit-locked), recalculate
+            if self.image_state.refits_on_resize(self.fit_locked) {
                 self.fit_to_window();
             }
         }
@@ -405,13 +619,14 @@ impl ImageViewer {
 
         // Adjust pan to keep center of image at same screen location (if we have the data)
         if let (Some((eff_w, eff_h)), Some(viewport)) =
-            (self.display_dimensions(), self.viewport_size)
+            (self.layout_dimensions(), self.viewport_size)
         {
             self.adjust_pan_for_zoom(eff_w, eff_h, viewport, old_zoom, new_zoom);
         }
 
         self.image_state.zoom = new_zoom;
         self.image_state.is_fit_to_window = false;
+        self.image_state.view_adjusted = true;
     }
 
     /// Zoom out, keeping the center of the image at the same screen location
@@ -421,13 +636,35 @@ impl ImageViewer {
 
         // Adjust pan to keep center of image at same screen location (if we have the data)
         if let (Some((eff_w, eff_h)), Some(viewport)) =
-            (self.display_dimensions(), self.viewport_size)
+            (self.layout_dimensions(), self.viewport_size)
         {
             self.adjust_pan_for_zoom(eff_w, eff_h, viewport, old_zoom, new_zoom);
         }
 
         self.image_state.zoom = new_zoom;
         self.image_state.is_fit_to_window = false;
+        self.image_state.view_adjusted = true;
+    }
+
+    /// Jump to an exact zoom (`percent` = 250.0 for 250%), clamped to the
+    /// zoom range and keeping the viewport center in place.  Non-positive or
+    /// non-finite values are ignored.
+    pub fn set_zoom_percent(&mut self, percent: f32) {
+        if !percent.is_finite() || percent <= 0.0 || self.current_image.is_none() {
+            return;
+        }
+        let old_zoom = self.image_state.zoom;
+        let new_zoom = zoom::clamp_zoom(percent / 100.0);
+
+        if let (Some((eff_w, eff_h)), Some(viewport)) =
+            (self.layout_dimensions(), self.viewport_size)
+        {
+            self.adjust_pan_for_zoom(eff_w, eff_h, viewport, old_zoom, new_zoom);
+        }
+
+        self.image_state.zoom = new_zoom;
+        self.image_state.is_fit_to_window = false;
+        self.image_state.view_adjusted = true;
     }
 
     /// Adjust pan so the image pixel at the viewport center stays at the viewport center after zoom.
@@ -440,17 +677,12 @@ impl ImageViewer {
         old_zoom: f32,
         new_zoom: f32,
     ) {
-        let (pan_x, pan_y) = self.image_state.pan;
-        let vp_center_x: f32 = f32::from(viewport.width) / 2.0;
-        let vp_center_y: f32 = f32::from(viewport.height) / 2.0;
-
-        // Find which image pixel is currently at the viewport center
-        let pixel_x = (vp_center_x - pan_x) / old_zoom;
-        let pixel_y = (vp_center_y - pan_y) / old_zoom;
-
-        // Compute new pan so that same pixel remains at the viewport center
-        let new_pan_x = vp_center_x - pixel_x * new_zoom;
-        let new_pan_y = vp_center_y - pixel_y * new_zoom;
+        let vp_center = (
+            f32::from(viewport.width) / 2.0,
+            f32::from(viewport.height) / 2.0,
+        );
+        let (new_pan_x, new_pan_y) =
+            zoom::pan_keeping_point(self.image_state.pan, vp_center, old_zoom, new_zoom);
 
         // Apply pan constraints
         self.image_state.pan = self.constrain_pan(new_pan_x, new_pan_y);
@@ -520,19 +752,30 @@ impl ImageViewer {
     /// Toggle between fit-to-window (centered) and 100% zoom.
     /// When going to fit-to-window, the image is fully centered.
     /// When going to 100%, the viewport-center anchor point is preserved.
-    pub fn reset_zoom(&mut self) {
+    pub fn reset_zoom(&mut self, stops: &[f32]) {
         if let (Some((eff_w, eff_h)), Some(viewport)) =
-            (self.display_dimensions(), self.viewport_size)
+            (self.layout_dimensions(), self.viewport_size)
         {
-            if self.image_state.is_fit_to_window {
-                // Currently at fit-to-window → switch to 100% keeping viewport center stable
-                let old_zoom = self.image_state.zoom;
-                self.adjust_pan_for_zoom(eff_w, eff_h, viewport, old_zoom, 1.0);
-                self.image_state.zoom = 1.0;
-                self.image_state.is_fit_to_window = false;
-            } else {
-                // Any other zoom → switch to fit-to-window, fully centered
-                self.fit_to_window();
+            self.image_state.view_adjusted = true;
+            let (position, stop) = zoom::next_zoom_stop(
+                self.image_state.is_fit_to_window,
+                self.image_state.zoom,
+                self.zoom_cycle_position,
+                stops,
+            );
+            self.zoom_cycle_position = position;
+            match stop {
+                zoom::ZoomStop::Zoom(new_zoom) => {
+                    // Next stop, keeping viewport center stable
+                    let old_zoom = self.image_state.zoom;
+                    self.adjust_pan_for_zoom(eff_w, eff_h, viewport, old_zoom, new_zoom);
+                    self.image_state.zoom = new_zoom;
+                    self.image_state.is_fit_to_window = false;
+                }
+                zoom::ZoomStop::FitToWindow => {
+                    // Back round to fit-to-window, fully centered
+                    self.fit_to_window();
+                }
             }
         }
     }
@@ -540,7 +783,7 @@ impl ImageViewer {
     /// Set zoom to 100% (actual size) with image centered
     pub fn set_one_hundred_percent(&mut self) {
         if let (Some((eff_w, eff_h)), Some(viewport)) =
-            (self.display_dimensions(), self.viewport_size)
+            (self.layout_dimensions(), self.viewport_size)
         {
             let viewport_width: f32 = viewport.width.into();
             let viewport_height: f32 = viewport.height.into();
@@ -564,6 +807,26 @@ impl ImageViewer {
         } else {
             self.fit_to_window();
         }
+        self.image_state.view_adjusted = true;
+    }
+
+    /// Like `reset_zoom_and_pan`, but going to 100% keeps the image point
+    /// under the cursor in place (a double-click).  Fit stays centered.
+    pub fn reset_zoom_at_point(&mut self, cursor_x: f32, cursor_y: f32) {
+        if !self.image_state.is_fit_to_window {
+            self.fit_to_window();
+        } else if self.current_image.is_some() {
+            let (pan_x, pan_y) = zoom::pan_keeping_point(
+                self.image_state.pan,
+                (cursor_x, cursor_y),
+                self.image_state.zoom,
+                1.0,
+            );
+            self.image_state.zoom = 1.0;
+            self.image_state.pan = self.constrain_pan(pan_x, pan_y);
+            self.image_state.is_fit_to_window = false;
+        }
+        self.image_state.view_adjusted = true;
     }
 
     /// Pan the image with constraints to prevent panning completely off-screen
@@ -572,46 +835,53 @@ impl ImageViewer {
         let new_pan_x = pan_x + delta_x;
         let new_pan_y = pan_y + delta_y;
         self.image_state.pan = self.constrain_pan(new_pan_x, new_pan_y);
+        self.image_state.view_adjusted = true;
     }
 
     /// Constrain pan to prevent the image from going completely off-screen
     /// Ensures at least a small portion of the image remains visible
     fn constrain_pan(&self, pan_x: f32, pan_y: f32) -> (f32, f32) {
         if let (Some((eff_w, eff_h)), Some(viewport)) =
-            (self.display_dimensions(), self.viewport_size)
+            (self.layout_dimensions(), self.viewport_size)
         {
-            let viewport_width: f32 = viewport.width.into();
-            let viewport_height: f32 = viewport.height.into();
-
-            let zoomed_width = eff_w as f32 * self.image_state.zoom;
-            let zoomed_height = eff_h as f32 * self.image_state.zoom;
-
-            // Define minimum visible portion (e.g., 50 pixels or 10% of image, whichever is smaller)
-            let min_visible_x = (zoomed_width * 0.1).min(50.0);
-            let min_visible_y = (zoomed_height * 0.1).min(50.0);
-
-            // Calculate allowed pan range
-            // Image can be panned right until only min_visible_x pixels show on the left
-            let max_pan_x = viewport_width - min_visible_x;
-            // Image can be panned left until only min_visible_x pixels show on the right
-            let min_pan_x = -(zoomed_width - min_visible_x);
-
-            // Image can be panned down until only min_visible_y pixels show on the top
-            let max_pan_y = viewport_height - min_visible_y;
-            // Image can be panned up until only min_visible_y pixels show on the bottom
-            let min_pan_y = -(zoomed_height - min_visible_y);
-
-            // Clamp pan values to allowed range
-            let constrained_x = pan_x.max(min_pan_x).min(max_pan_x);
-            let constrained_y = pan_y.max(min_pan_y).min(max_pan_y);
-
-            (constrained_x, constrained_y)
+            let zoom = self.image_state.zoom;
+            zoom::constrain_pan(
+                (pan_x, pan_y),
+                (eff_w as f32 * zoom, eff_h as f32 * zoom),
+                (viewport.width.into(), viewport.height.into()),
+            )
         } else {
             // No image or viewport, return unconstrained values
             (pan_x, pan_y)
         }
     }
 
+    /// The current zoom/pan, for pasting onto another image
+    pub fn view_snapshot(&self) -> zoom::ViewSnapshot {
+        zoom::ViewSnapshot {
+            zoom: self.image_state.zoom,
+            pan: self.image_state.pan,
+        }
+    }
+
+    /// Show `snapshot`'s zoom/pan on the current image, clamped to its
+    /// size.  Returns false when there's no image or viewport to apply to.
+    pub fn apply_view_snapshot(&mut self, snapshot: zoom::ViewSnapshot) -> bool {
+        let (Some((eff_w, eff_h)), Some(viewport)) = (self.layout_dimensions(), self.viewport_size)
+        else {
+            return false;
+        };
+        let (zoom, pan) = snapshot.applied_to(
+            (eff_w, eff_h),
+            (viewport.width.into(), viewport.height.into()),
+        );
+        self.image_state.zoom = zoom;
+        self.image_state.pan = pan;
+        self.image_state.is_fit_to_window = false;
+        self.image_state.view_adjusted = true;
+        true
+    }
+
     /// Zoom toward a specific point (cursor position)
     /// cursor_x and cursor_y are in viewport coordinates (pixels from top-left of viewport)
     pub fn zoom_toward_point(&mut self, cursor_x: f32, cursor_y: f32, zoom_in: bool, step: f32) {
@@ -626,19 +896,19 @@ impl ImageViewer {
             zoom::zoom_out(old_zoom, step)
         };
 
-        // Calculate the cursor position in image coordinates (before zoom)
-        let (pan_x, pan_y) = self.image_state.pan;
-        let cursor_in_image_x = (cursor_x - pan_x) / old_zoom;
-        let cursor_in_image_y = (cursor_y - pan_y) / old_zoom;
-
-        // Calculate the new pan to keep the cursor at the same image location
-        let new_pan_x = cursor_x - cursor_in_image_x * new_zoom;
-        let new_pan_y = cursor_y - cursor_in_image_y * new_zoom;
+        // Keep the cursor at the same image location
+        let (new_pan_x, new_pan_y) = zoom::pan_keeping_point(
+            self.image_state.pan,
+            (cursor_x, cursor_y),
+            old_zoom,
+            new_zoom,
+        );
 
         // Update zoom first, then constrain pan
         self.image_state.zoom = new_zoom;
         self.image_state.pan = self.constrain_pan(new_pan_x, new_pan_y);
         self.image_state.is_fit_to_window = false;
+        self.image_state.view_adjusted = true;
     }
 
     /// Load an image from a path (synchronous, legacy)
@@ -661,7 +931,7 @@ impl ImageViewer {
         match image_loader::get_image_dimensions(&path) {
             Ok((width, height)) => {
                 // Try to load animation data if it's an animated image
-                let animation_data = crate::utils::animation::load_animation(&path)
+                let frame_sequence = crate::utils::animation::load_frame_sequence(&path)
                     .ok()
                     .flatten();
 
@@ -670,10 +940,9 @@ impl ImageViewer {
 
                 // Cache first 3 frames immediately for instant display, rest will load in background
                 let mut frame_cache_paths = Vec::new();
-                if let (Some(anim_data), Some(key)) = (animation_data.as_ref(), image_key.as_ref())
-                {
-                    let initial_cache_count = std::cmp::min(3, anim_data.frames.len());
-                    debug_eprintln!(
+                if let (Some(sequence), Some(key)) = (frame_sequence.as_ref(), image_key.as_ref()) {
+                    let initial_cache_count = std::cmp::min(3, sequence.frames.len());
+                    log::debug!(
                         "[LOAD] Caching first {} frames for immediate display...",
                         initial_cache_count
                     );
@@ -681,7 +950,7 @@ impl ImageViewer {
                         let dest = match crate::utils::frame_cache::raw_frame_path(key, i) {
                             Ok(p) => p,
                             Err(_e) => {
-                                debug_eprintln!(
+                                log::debug!(
                                     "[ERROR] Failed to resolve cache path for frame {}: {}",
                                     i,
                                     _e
@@ -691,43 +960,43 @@ impl ImageViewer {
                             }
                         };
                         if dest.exists() {
-                            debug_eprintln!("[LOAD] Frame {} already cached on disk", i);
+                            log::debug!("[LOAD] Frame {} already cached on disk", i);
                             frame_cache_paths.push(dest);
                             continue;
                         }
-                        match anim_data.frames[i].image.save(&dest) {
+                        match sequence.frames[i].image.save(&dest) {
                             Ok(_) => {
-                                debug_eprintln!("[LOAD] Cached frame {}", i);
+                                log::debug!("[LOAD] Cached frame {}", i);
                                 frame_cache_paths.push(dest);
                             }
                             Err(_e) => {
-                                debug_eprintln!("[ERROR] Failed to cache frame {}: {}", i, _e);
+                                log::debug!("[ERROR] Failed to cache frame {}: {}", i, _e);
                                 frame_cache_paths.push(PathBuf::new());
                             }
                         }
                     }
 
                     // Pre-allocate paths for remaining frames (will be filled on-demand)
-                    for _ in initial_cache_count..anim_data.frames.len() {
+                    for _ in initial_cache_count..sequence.frames.len() {
                         frame_cache_paths.push(PathBuf::new());
                     }
-                    debug_eprintln!(
+                    log::debug!(
                         "[LOAD] Initial caching complete: {}/{} frames ready",
                         initial_cache_count,
-                        anim_data.frames.len()
+                        sequence.frames.len()
                     );
-                } else if let Some(ref anim_data) = animation_data {
+                } else if let Some(ref sequence) = frame_sequence {
                     // No image_key (uncacheable image) — pre-allocate empty slots only.
-                    for _ in 0..anim_data.frames.len() {
+                    for _ in 0..sequence.frames.len() {
                         frame_cache_paths.push(PathBuf::new());
                     }
                 }
 
                 // Initialize animation state if we have animation data
-                if let Some(ref anim_data) = animation_data {
+                if let Some(ref sequence) = frame_sequence {
                     use crate::state::image_state::AnimationState;
                     let mut anim_state =
-                        AnimationState::new(anim_data.frame_count, anim_data.frame_durations());
+                        AnimationState::new(sequence.frame_count, sequence.frame_durations());
                     // First few frames are cached, rest will load on-demand
                     // Check if we have at least 2 frames cached (frame 0 and frame 1)
                     let cached_count = frame_cache_paths
@@ -735,12 +1004,15 @@ impl ImageViewer {
                         .filter(|p| !p.as_os_str().is_empty() && p.exists())
                         .count();
                     anim_state.next_frame_ready = cached_count >= 2;
+                    anim_state.timed = sequence.is_timed();
+                    anim_state.plays = sequence.plays;
                     self.image_state.animation = Some(anim_state);
                 } else {
                     self.image_state.animation = None;
                 }
 
-                let frame_count = animation_data.as_ref().map(|a| a.frame_count).unwrap_or(0);
+                let frame_count = frame_sequence.as_ref().map(|a| a.frame_count).unwrap_or(0);
+                let pixel_aspect = crate::utils::pixel_aspect::read_pixel_aspect(&path);
                 self.current_image = Some(LoadedImage {
                     path: path.clone(),
                     width,
@@ -748,17 +1020,20 @@ impl ImageViewer {
                     decoded_rgba8: None,
                     filtered_render: None,
                     cached_filter_settings: None,
+                    cached_rotation: 0,
                     gpu_pipeline_render: None,
                     gpu_pipeline_render_size: None,
                     cached_gpu_pipeline_params: None,
                     cached_gpu_pipeline_frame_idx: None,
                     gpu_frame_renders: vec![None; frame_count],
-                    animation_data,
+                    frame_sequence,
                     frame_cache_paths,
                     image_key,
                     rasterized_path: None,
                     svg_tree: None,
                     svg_base_scale: 2.0,
+                    svg_background: None,
+                    pixel_aspect,
                 });
                 self.error_message = None;
                 self.error_path = None;
@@ -787,19 +1062,34 @@ impl ImageViewer {
             handle.cancel();
         }
 
-        // Start new async load
-        debug_eprintln!("[ASYNC] Starting async load for: {}", path.display());
-        self.loading_handle = Some(image_loader::load_image_async(
-            path,
-            max_dimension,
-            force_load,
-        ));
+        // A preloaded copy is good while the file hasn't changed since and
+        // it's still within the size limit (and drawn on today's SVG
+        // background)
+        let svg_background = self.svg_background;
+        let preloaded = self.decode_cache.take(&path).filter(|data| {
+            data.image_key.is_some()
+                && data.svg_background == svg_background
+                && data.image_key == crate::utils::frame_cache::image_key(&path)
+                && (force_load
+                    || max_dimension.is_none_or(|max| data.width <= max && data.height <= max))
+        });
+        self.loading_handle = Some(match preloaded {
+            Some(data) => {
+                log::debug!("[ASYNC] Using preloaded: {}", path.display());
+                image_loader::LoaderHandle::ready(image_loader::LoaderMessage::Success(data))
+            }
+            None => {
+                log::debug!("[ASYNC] Starting async load for: {}", path.display());
+                image_loader::load_image_async(path, max_dimension, force_load, svg_background)
+            }
+        });
         self.is_loading = true;
 
         // Clear previous image and errors
         self.current_image = None;
         self.error_message = None;
         self.error_path = None;
+        self.error_is_incomplete = false;
         self.no_images_path = None;
 
         // Clear SVG re-raster state from previous image
@@ -817,36 +1107,38 @@ impl ImageViewer {
 
                 match msg {
                     image_loader::LoaderMessage::Success(mut data) => {
-                        debug_eprintln!("[ASYNC] Load complete: {}", data.path.display());
+                        log::debug!("[ASYNC] Load complete: {}", data.path.display());
 
                         // Prepare frame cache paths
                         let mut frame_cache_paths = std::mem::take(&mut data.initial_frame_paths);
-                        if let Some(ref anim_data) = data.animation_data {
+                        if let Some(ref sequence) = data.frame_sequence {
                             // Pre-allocate empty slots for remaining frames
-                            while frame_cache_paths.len() < anim_data.frames.len() {
+                            while frame_cache_paths.len() < sequence.frames.len() {
                                 frame_cache_paths.push(PathBuf::new());
                             }
                         }
 
                         // Initialize animation state if we have animation data
-                        if let Some(ref anim_data) = data.animation_data {
+                        if let Some(ref sequence) = data.frame_sequence {
                             use crate::state::image_state::AnimationState;
                             let mut anim_state = AnimationState::new(
-                                anim_data.frame_count,
-                                anim_data.frame_durations(),
+                                sequence.frame_count,
+                                sequence.frame_durations(),
                             );
                             let cached_count = frame_cache_paths
                                 .iter()
                                 .filter(|p| !p.as_os_str().is_empty() && p.exists())
                                 .count();
                             anim_state.next_frame_ready = cached_count >= 2;
+                            anim_state.timed = sequence.is_timed();
+                            anim_state.plays = sequence.plays;
                             self.image_state.animation = Some(anim_state);
                         } else {
                             self.image_state.animation = None;
                         }
 
                         let frame_count = data
-                            .animation_data
+                            .frame_sequence
                             .as_ref()
                             .map(|a| a.frame_count)
                             .unwrap_or(0);
@@ -857,17 +1149,20 @@ impl ImageViewer {
                             decoded_rgba8: None,
                             filtered_render: None,
                             cached_filter_settings: None,
+                            cached_rotation: 0,
                             gpu_pipeline_render: None,
                             gpu_pipeline_render_size: None,
                             cached_gpu_pipeline_params: None,
                             cached_gpu_pipeline_frame_idx: None,
                             gpu_frame_renders: vec![None; frame_count],
-                            animation_data: data.animation_data,
+                            frame_sequence: data.frame_sequence,
                             frame_cache_paths,
                             image_key: data.image_key,
                             rasterized_path: data.rasterized_path,
                             svg_tree: data.svg_tree,
                             svg_base_scale: 2.0,
+                            svg_background: data.svg_background,
+                            pixel_aspect: data.pixel_aspect,
                         });
                         self.error_message = None;
                         self.error_path = None;
@@ -880,7 +1175,7 @@ impl ImageViewer {
                         return true;
                     }
                     image_loader::LoaderMessage::Error(path, msg) => {
-                        debug_eprintln!("[ASYNC] Load failed: {}: {}", path.display(), msg);
+                        log::debug!("[ASYNC] Load failed: {}: {}", path.display(), msg);
                         self.current_image = None;
                         self.error_message = Some(msg);
                         self.error_path = Some(path);
@@ -888,8 +1183,18 @@ impl ImageViewer {
 
                         return true;
                     }
+                    image_loader::LoaderMessage::Incomplete(path, msg) => {
+                        log::debug!("[ASYNC] File incomplete: {}: {}", path.display(), msg);
+                        self.current_image = None;
+                        self.error_message = Some(msg);
+                        self.error_path = Some(path);
+                        self.error_is_incomplete = true;
+                        self.oversized_image = None;
+
+                        return true;
+                    }
                     image_loader::LoaderMessage::OversizedImage(path, width, height, max_dim) => {
-                        debug_eprintln!(
+                        log::debug!(
                             "[ASYNC] Image oversized: {}×{} exceeds max {}",
                             width,
                             height,
@@ -912,7 +1217,7 @@ impl ImageViewer {
 
     /// Update filtered image cache if needed (async)
     pub fn update_filtered_cache(&mut self) {
-        debug_eprintln!("[ImageViewer::update_filtered_cache] Called");
+        log::debug!("[ImageViewer::update_filtered_cache] Called");
 
         // Filters not supported for SVG files (image::open can't read SVGs)
         if let Some(ref loaded) = self.current_image {
@@ -923,30 +1228,25 @@ impl ImageViewer {
 
         // Cancel any previous filter processing when starting new one
         if self.is_processing_filters {
-            debug_eprintln!("[ImageViewer::update_filtered_cache] Canceling previous processing");
+            log::debug!("[ImageViewer::update_filtered_cache] Canceling previous processing");
             self.filter_processing_handle = None;
             self.is_processing_filters = false;
         }
 
+        let (filters, rotation) = self.filter_cache_key();
         let Some(loaded) = self.current_image.as_mut() else {
             return;
         };
 
-        let filters = self.image_state.filters;
-        let filters_enabled = self.image_state.filters_enabled;
+        let is_noop = filters.is_none() && rotation == 0;
 
-        let is_noop = !filters_enabled
-            || (filters.brightness.abs() < 0.001
-                && filters.contrast.abs() < 0.001
-                && (filters.gamma - 1.0).abs() < 0.001);
+        let built = loaded
+            .filtered_render
+            .is_some()
+            .then_some((loaded.cached_filter_settings, loaded.cached_rotation));
+        let needs_update = filtered_render_stale(built, (filters, rotation));
 
-        let needs_update = if is_noop {
-            loaded.filtered_render.is_some()
-        } else {
-            loaded.cached_filter_settings.as_ref() != Some(&filters)
-        };
-
-        debug_eprintln!(
+        log::debug!(
             "[ImageViewer::update_filtered_cache] noop={}, needs_update={}",
             is_noop,
             needs_update
@@ -965,43 +1265,146 @@ impl ImageViewer {
             return;
         }
 
-        // Lazily decode the source RGBA8 the first time we need to filter this image.
-        // All subsequent slider ticks reuse the same decoded buffer — no disk I/O.
+        let Some(source) = Self::decoded_source(loaded) else {
+            return;
+        };
+
+        self.is_processing_filters = true;
+        let (sender, receiver) = std::sync::mpsc::channel();
+        self.filter_processing_handle = Some(receiver);
+
+        rayon::spawn(move || {
+            log::debug!("[FILTER_THREAD] LUT pass starting");
+            let render_image = match filters {
+                Some(filters) => {
+                    let bgra = filters::apply_filters_to_bgra(&source, &filters);
+                    // Channel order doesn't matter to a rotation
+                    let bgra =
+                        rotate_image(image::DynamicImage::ImageRgba8(bgra), rotation).into_rgba8();
+                    let frame = image::Frame::new(bgra);
+                    Arc::new(gpui::RenderImage::new(smallvec::SmallVec::from_elem(
+                        frame, 1,
+                    )))
+                }
+                None => {
+                    let rgba = image::DynamicImage::ImageRgba8((*source).clone());
+                    rgba_to_bgra_render_image(&rotate_image(rgba, rotation).into_rgba8())
+                }
+            };
+            log::debug!("[FILTER_THREAD] LUT pass complete");
+            let _ = sender.send(Ok(render_image));
+        });
+    }
+
+    /// What `filtered_render` is built from: the filters, when they are on
+    /// and do something, and the image's rotation.  `(None, 0)` means the
+    /// source is shown as is.
+    fn filter_cache_key(&self) -> (Option<FilterSettings>, u16) {
+        filter_cache_key(&self.image_state, self.auto_exposure_amount())
+    }
+
+    /// The auto-exposure brightness applied to the current image (0 when
+    /// auto exposure is off or hasn't been worked out for it)
+    pub fn auto_exposure_amount(&self) -> f32 {
+        match (&self.auto_exposure_offset, &self.current_image) {
+            (Some((path, amount)), Some(loaded)) if *path == loaded.path => *amount,
+            _ => 0.0,
+        }
+    }
+
+    /// Work out auto exposure for the current image from the luminance
+    /// histogram of its decoded source (once per image) and rebuild the
+    /// render.  Animations and SVGs are left alone: neither is drawn from
+    /// the filtered render.
+    pub fn update_auto_exposure(&mut self) {
+        let applies = self.auto_exposure
+            && self.current_image.as_ref().is_some_and(|loaded| {
+                loaded.frame_sequence.is_none() && !crate::utils::file_scanner::is_svg(&loaded.path)
+            });
+        if !applies {
+            self.auto_exposure_offset = None;
+        } else if let Some(loaded) = self.current_image.as_mut()
+            && self.auto_exposure_offset.as_ref().map(|(path, _)| path) != Some(&loaded.path)
+        {
+            let amount = Self::decoded_source(loaded)
+                .map(|rgba| {
+                    auto_exposure_brightness(&compute_histogram(rgba.as_raw(), PixelLayout::Rgba))
+                })
+                .unwrap_or(0.0);
+            log::debug!(
+                "[ImageViewer::update_auto_exposure] {}: brightness {:+.1}",
+                loaded.path.display(),
+                amount
+            );
+            self.auto_exposure_offset = Some((loaded.path.clone(), amount));
+        }
+        self.update_filtered_cache();
+    }
+
+    /// Whether the current image can be rotated: rotation is baked into
+    /// the filtered render, which animations and SVGs don't use
+    pub fn supports_rotation(&self) -> bool {
+        self.current_image.as_ref().is_some_and(|loaded| {
+            loaded.frame_sequence.is_none() && !crate::utils::file_scanner::is_svg(&loaded.path)
+        })
+    }
+
+    /// Turn the current image a quarter turn and rebuild its render
+    pub fn rotate(&mut self, clockwise: bool) {
+        self.image_state.rotate(clockwise);
+        self.rotation_pending = true;
+        self.update_filtered_cache();
+    }
+
+    /// The decoded RGBA8 source, decoding it the first time it is needed.
+    /// All subsequent slider ticks reuse the same buffer — no disk I/O.
+    fn decoded_source(loaded: &mut LoadedImage) -> Option<Arc<image::RgbaImage>> {
         if loaded.decoded_rgba8.is_none() {
-            match image_loader::load_image(&loaded.path) {
+            match image_loader::load_source_image(&loaded.path, loaded.svg_background) {
                 Ok(img) => {
                     loaded.decoded_rgba8 = Some(Arc::new(img.to_rgba8()));
                 }
                 Err(_e) => {
-                    debug_eprintln!("[ImageViewer::update_filtered_cache] Decode failed: {}", _e);
-                    return;
+                    log::debug!("[ImageViewer::decoded_source] Decode failed: {}", _e);
+                    return None;
                 }
             }
         }
-        let Some(source) = loaded.decoded_rgba8.clone() else {
-            debug_eprintln!("[BUG] decoded_rgba8 is None after successful load");
+        loaded.decoded_rgba8.clone()
+    }
+
+    /// Recount `histogram` if the image or its filtered render changed
+    /// since it was last counted — a new image, or a filter result that
+    /// `update_filtered_cache` produced or dropped.  Counts the CPU-filtered
+    /// render when there is one, else the decoded source.
+    pub fn refresh_histogram(&mut self) {
+        let Some(loaded) = self.current_image.as_mut() else {
+            self.clear_histogram();
             return;
         };
+        let filtered = loaded
+            .filtered_render
+            .as_ref()
+            .and(loaded.cached_filter_settings);
+        let source = (loaded.path.clone(), filtered);
+        if self.histogram_source.as_ref() == Some(&source) {
+            return;
+        }
 
-        self.is_processing_filters = true;
-        let (sender, receiver) = std::sync::mpsc::channel();
-        self.filter_processing_handle = Some(receiver);
+        self.histogram = match (&loaded.filtered_render, filtered) {
+            (Some(render), Some(_)) => render
+                .as_bytes(0)
+                .map(|bgra| compute_histogram(bgra, PixelLayout::Bgra)),
+            _ => Self::decoded_source(loaded)
+                .map(|rgba| compute_histogram(rgba.as_raw(), PixelLayout::Rgba)),
+        };
+        self.histogram_source = Some(source);
+    }
 
-        rayon::spawn(move || {
-            debug_eprintln!("[FILTER_THREAD] LUT pass starting");
-            let bgra = filters::apply_filters_to_bgra(
-                &source,
-                filters.brightness,
-                filters.contrast,
-                filters.gamma,
-            );
-            let frame = image::Frame::new(bgra);
-            let render_image = Arc::new(gpui::RenderImage::new(smallvec::SmallVec::from_elem(
-                frame, 1,
-            )));
-            debug_eprintln!("[FILTER_THREAD] LUT pass complete");
-            let _ = sender.send(Ok(render_image));
-        });
+    /// Forget the histogram (the overlay was hidden)
+    pub fn clear_histogram(&mut self) {
+        self.histogram = None;
+        self.histogram_source = None;
     }
 
     /// Run the unified GPU pipeline (PSP3 algorithm: LC + SBC + Vibrance + Hue
@@ -1127,25 +1530,25 @@ impl ImageViewer {
 
         // Lazily decode source RGBA (shared with CPU filter / LC paths).
         if loaded.decoded_rgba8.is_none() {
-            match image_loader::load_image(&loaded.path) {
+            match image_loader::load_source_image(&loaded.path, loaded.svg_background) {
                 Ok(img) => {
                     loaded.decoded_rgba8 = Some(Arc::new(img.to_rgba8()));
                 }
                 Err(_e) => {
-                    debug_eprintln!("[GPU] Source decode failed: {}", _e);
+                    log::debug!("[GPU] Source decode failed: {}", _e);
                     return;
                 }
             }
         }
 
         // For animated images, prefer the currently-displayed frame's
-        // already-decoded RGBA (held in animation_data) over the on-disk
+        // already-decoded RGBA (held in frame_sequence) over the on-disk
         // re-decode of frame 0.
-        let (rgba, w, h): (Vec<u8>, u32, u32) = if let Some(ref anim_data) = loaded.animation_data {
+        let (rgba, w, h): (Vec<u8>, u32, u32) = if let Some(ref sequence) = loaded.frame_sequence {
             let idx = frame_idx
                 .unwrap_or(0)
-                .min(anim_data.frames.len().saturating_sub(1));
-            let frame_rgba = anim_data.frames[idx].image.to_rgba8();
+                .min(sequence.frames.len().saturating_sub(1));
+            let frame_rgba = sequence.frames[idx].image.to_rgba8();
             let dims = frame_rgba.dimensions();
             (frame_rgba.into_raw(), dims.0, dims.1)
         } else {
@@ -1184,7 +1587,7 @@ impl ImageViewer {
         });
 
         rayon::spawn(move || {
-            debug_eprintln!("[GPU_THREAD] start (frame {:?}, {}x{})", frame_idx, w, h);
+            log::debug!("[GPU_THREAD] start (frame {:?}, {}x{})", frame_idx, w, h);
             let result: RenderResult =
                 match crate::gpu::process_pipeline(&rgba, w, h, &params_for_thread) {
                     Ok((bgra_bytes, out_w, out_h)) => {
@@ -1197,7 +1600,7 @@ impl ImageViewer {
                                 Some((render, (out_w, out_h)))
                             }
                             None => {
-                                debug_eprintln!(
+                                log::debug!(
                                     "[GPU] BGRA buffer size mismatch (out {}x{} expected {} bytes)",
                                     out_w,
                                     out_h,
@@ -1208,11 +1611,11 @@ impl ImageViewer {
                         }
                     }
                     Err(_e) => {
-                        debug_eprintln!("[GPU] pipeline error: {_e}");
+                        log::debug!("[GPU] pipeline error: {_e}");
                         None
                     }
                 };
-            debug_eprintln!("[GPU_THREAD] done, produced={}", result.is_some());
+            log::debug!("[GPU_THREAD] done, produced={}", result.is_some());
             let _ = tx.send(result);
         });
     }
@@ -1327,6 +1730,35 @@ impl ImageViewer {
         self.with_size_aware_change(|s| s.active_slot = None);
     }
 
+    /// Keep the view put across a rotation: refit a fitted image, else keep
+    /// the zoom and the image's center where it was.  A restored view was
+    /// saved already turned, so only a fresh `rotate` moves the pan.
+    fn refit_after_rotation(&mut self, (old_w, old_h): (u32, u32), (new_w, new_h): (u32, u32)) {
+        if self.image_state.is_fit_to_window {
+            self.fit_to_window();
+        } else if self.rotation_pending {
+            let zoom = self.image_state.zoom;
+            let (pan_x, pan_y) = self.image_state.pan;
+            self.image_state.pan = (
+                pan_x + (old_w as f32 - new_w as f32) * zoom / 2.0,
+                pan_y + (old_h as f32 - new_h as f32) * zoom / 2.0,
+            );
+        }
+    }
+
+    /// Rotation of what the renderer is showing: the filtered render's,
+    /// or 0 while a slot or GPU pipeline output is up (both unrotated)
+    fn displayed_rotation(&self) -> u16 {
+        if self.active_slot.is_some() {
+            return 0;
+        }
+        self.current_image
+            .as_ref()
+            .filter(|img| !(self.gpu_pipeline_enabled && img.gpu_pipeline_render.is_some()))
+            .filter(|img| img.filtered_render.is_some())
+            .map_or(0, |img| img.cached_rotation)
+    }
+
     /// Effective display dimensions taking active slot and animation frame
     /// into account.
     pub(crate) fn display_dimensions(&self) -> Option<(u32, u32)> {
@@ -1340,6 +1772,26 @@ impl ImageViewer {
             .map(|img| effective_image_size(img, self.gpu_pipeline_enabled, frame_idx))
     }
 
+    /// Size the image is laid out at on screen: `display_dimensions` with
+    /// the file's pixel aspect ratio applied (when honored), so non-square
+    /// pixels appear at their intended proportions.  Fit, zoom, pan and
+    /// rendering work in these units; the stored pixel grid stays native.
+    pub(crate) fn layout_dimensions(&self) -> Option<(u32, u32)> {
+        let (width, height) = self.display_dimensions()?;
+        // Turned on its side, a pixel's width becomes its height
+        let quarter_turn = self.displayed_rotation() % 180 == 90;
+        let par = self
+            .current_image
+            .as_ref()
+            .and_then(|img| img.pixel_aspect)
+            .filter(|_| self.honor_pixel_aspect)
+            .map(|par| if quarter_turn { 1.0 / par } else { par });
+        Some(match par {
+            Some(par) => crate::utils::pixel_aspect::display_size(width, height, par),
+            None => (width, height),
+        })
+    }
+
     /// Capture the currently visible image as a `SavedSlot`.  Mirrors the
     /// display priority used by the renderer (active slot → GPU pipeline →
     /// filtered → raw) so a slot store, or `Save File`, captures exactly
@@ -1367,27 +1819,176 @@ impl ImageViewer {
             });
         }
         if let Some(ref render) = loaded.filtered_render {
+            let (width, height) =
+                rotated_size((loaded.width, loaded.height), loaded.cached_rotation);
             return Some(SavedSlot {
                 render: render.clone(),
-                width: loaded.width,
-                height: loaded.height,
+                width,
+                height,
             });
         }
         // Raw source — need to create a BGRA RenderImage on the fly.
         if loaded.decoded_rgba8.is_none() {
-            if let Ok(img) = image_loader::load_image(&loaded.path) {
+            if let Ok(img) = image_loader::load_source_image(&loaded.path, loaded.svg_background) {
                 loaded.decoded_rgba8 = Some(Arc::new(img.to_rgba8()));
             }
         }
         let rgba = loaded.decoded_rgba8.as_ref()?;
-        let render = rgba_to_bgra_render_image(rgba);
+        // A rotation whose render is still being built is captured turned
+        let rotation = self.image_state.rotation;
+        let (render, (width, height)) = if rotation == 0 {
+            (
+                rgba_to_bgra_render_image(rgba),
+                (loaded.width, loaded.height),
+            )
+        } else {
+            let rotated = rotate_image(image::DynamicImage::ImageRgba8((**rgba).clone()), rotation)
+                .into_rgba8();
+            let size = rotated.dimensions();
+            (rgba_to_bgra_render_image(&rotated), size)
+        };
         Some(SavedSlot {
             render,
-            width: loaded.width,
-            height: loaded.height,
+            width,
+            height,
         })
     }
 
+    /// The displayed color at viewport point `(x, y)`, read from the same
+    /// source `capture_current_display` would use.  None off the image, and
+    /// for animation frames shown straight from the source.
+    pub fn sample_pixel(&mut self, x: f32, y: f32) -> Option<ColorSample> {
+        let layout = self.layout_dimensions()?;
+        let (width, height) = self.display_dimensions()?;
+        let (lx, ly) =
+            zoom::screen_to_image((x, y), self.image_state.pan, self.image_state.zoom, layout)?;
+        // Undo the pixel-aspect stretch to land on a stored pixel
+        let pixel = (
+            ((lx as u64 * width as u64 / layout.0 as u64) as u32).min(width - 1),
+            ((ly as u64 * height as u64 / layout.1 as u64) as u32).min(height - 1),
+        );
+        let from_render =
+            |render: &gpui::RenderImage| eyedropper::sample_bgra(render.as_bytes(0)?, width, pixel);
+        let rgb = if let Some(slot) = self.active_slot {
+            from_render(&self.saved_slots[(slot - 3) as usize].as_ref()?.render)?
+        } else {
+            let loaded = self.current_image.as_mut()?;
+            if let Some(render) = loaded
+                .gpu_pipeline_render
+                .as_ref()
+                .filter(|_| self.gpu_pipeline_enabled)
+                .or(loaded.filtered_render.as_ref())
+            {
+                from_render(render)?
+            } else if self.image_state.animation.is_some() {
+                return None;
+            } else {
+                let source = Self::decoded_source(loaded)?;
+                let (sx, sy) = source_point(pixel, source.dimensions(), self.image_state.rotation);
+                let [r, g, b, _] = source.get_pixel_checked(sx, sy)?.0;
+                [r, g, b]
+            }
+        };
+        Some(ColorSample { pixel, rgb })
+    }
+
+    /// The displayed pixels a crop drag selects, with the image's aspect
+    /// ratio held if asked and the edges snapped to `grid`.  None when it
+    /// covers none of the image.
+    pub fn crop_selection(&self, drag: CropDrag, grid: u32) -> Option<SelectionRect> {
+        let layout = self.layout_dimensions()?;
+        let (width, height) = self.display_dimensions()?;
+        let region = crop::crop_region(
+            layout,
+            self.image_state.zoom,
+            self.image_state.pan,
+            drag.start,
+            drag.end,
+        )?;
+        // Undo the pixel-aspect stretch to land on stored pixels
+        let to_pixels = |v: u32, pixels: u32, laid_out: u32| {
+            (v as u64 * pixels as u64 / laid_out.max(1) as u64) as u32
+        };
+        let mut selection = SelectionRect {
+            x: to_pixels(region.x, width, layout.0),
+            y: to_pixels(region.y, height, layout.1),
+            width: to_pixels(region.width, width, layout.0),
+            height: to_pixels(region.height, height, layout.1),
+        };
+        if drag.keep_aspect {
+            selection = selection.lock_aspect(width as f32 / height.max(1) as f32);
+        }
+        let selection = selection.snap_to_grid(grid, (width, height));
+        (!selection.is_empty()).then_some(selection)
+    }
+
+    /// Where `selection` sits in the viewport, as (x, y, width, height)
+    pub fn selection_on_screen(&self, selection: SelectionRect) -> Option<(f32, f32, f32, f32)> {
+        let layout = self.layout_dimensions()?;
+        let (width, height) = self.display_dimensions()?;
+        let zoom = self.image_state.zoom;
+        let (pan_x, pan_y) = self.image_state.pan;
+        let sx = layout.0 as f32 / width.max(1) as f32 * zoom;
+        let sy = layout.1 as f32 / height.max(1) as f32 * zoom;
+        Some((
+            pan_x + selection.x as f32 * sx,
+            pan_y + selection.y as f32 * sy,
+            selection.width as f32 * sx,
+            selection.height as f32 * sy,
+        ))
+    }
+
+    /// The `selection` of what's displayed (from `capture_current_display`,
+    /// so rotation, filters and recalled slots are kept)
+    pub fn crop_display(&mut self, selection: SelectionRect) -> Option<image::RgbaImage> {
+        let snapshot = self.capture_current_display()?;
+        let full = snapshot_rgba(&snapshot)?;
+        Some(
+            image::imageops::crop_imm(
+                &full,
+                selection.x,
+                selection.y,
+                selection.width,
+                selection.height,
+            )
+            .to_image(),
+        )
+    }
+
+    /// Render exactly what the viewport shows: the visible part of the
+    /// displayed image (slot / GPU pipeline / filters included, via
+    /// `capture_current_display`) scaled by the current zoom, and by the
+    /// pixel aspect ratio when honored, with `resize_filter`.  Areas of the
+    /// viewport outside the image are left out.
+    pub fn capture_viewport(
+        &mut self,
+        resize_filter: crate::state::settings::ResizeFilter,
+    ) -> Option<image::RgbaImage> {
+        let viewport = self.viewport_size?;
+        let zoom = self.image_state.zoom;
+        let layout = self.layout_dimensions()?;
+        let snapshot = self.capture_current_display()?;
+        let zoom::VisiblePixels {
+            region: (x, y, w, h),
+            drawn: (out_w, out_h),
+        } = zoom::visible_pixel_region(
+            (snapshot.width, snapshot.height),
+            layout,
+            zoom,
+            self.image_state.pan,
+            (viewport.width.into(), viewport.height.into()),
+        )?;
+
+        let full = snapshot_rgba(&snapshot)?;
+        let visible = image::imageops::crop_imm(&full, x, y, w, h).to_image();
+        Some(image::imageops::resize(
+            &visible,
+            out_w,
+            out_h,
+            resize_filter.filter_type(zoom),
+        ))
+    }
+
     /// Check for completed filter processing and install the resulting in-memory image.
     /// Returns true if a new filtered image was applied this tick (caller may want to notify).
     pub fn check_filter_processing(&mut self) -> bool {
@@ -1401,14 +2002,24 @@ impl ImageViewer {
         self.filter_processing_handle = None;
         match result {
             Ok(render_image) => {
+                let (filters, rotation) = self.filter_cache_key();
+                let old_size = self.display_dimensions();
                 if let Some(loaded) = self.current_image.as_mut() {
                     loaded.filtered_render = Some(render_image);
-                    loaded.cached_filter_settings = Some(self.image_state.filters);
+                    loaded.cached_filter_settings = filters;
+                    loaded.cached_rotation = rotation;
+                }
+                // A quarter turn swaps the image's width and height
+                if let (Some(old), Some(new)) = (old_size, self.display_dimensions())
+                    && old != new
+                {
+                    self.refit_after_rotation(old, new);
                 }
+                self.rotation_pending = false;
                 true
             }
             Err(_e) => {
-                debug_eprintln!(
+                log::debug!(
                     "[ImageViewer::check_filter_processing] Filter failed: {}",
                     _e
                 );
@@ -1524,6 +2135,7 @@ impl ImageViewer {
         let total_pixels = full_w * full_h;
 
         let tree_clone = Arc::clone(tree);
+        let background = loaded.svg_background;
         let (tx, rx) = mpsc::channel();
         let cancel_flag = Arc::new(Mutex::new(false));
         let cancel_clone = cancel_flag.clone();
@@ -1535,7 +2147,8 @@ impl ImageViewer {
                 if cancel_clone.lock().map(|f| *f).unwrap_or(false) {
                     return;
                 }
-                let result = svg::rerasterize_svg_full(&tree_clone, zoom).map(|path| (path, None));
+                let result = svg::rerasterize_svg_full(&tree_clone, zoom, background)
+                    .map(|path| (path, None));
                 let _ = tx.send(result);
             });
         } else {
@@ -1566,6 +2179,7 @@ impl ImageViewer {
                     (vis_x, vis_y, vis_w, vis_h),
                     svg::VIEWPORT_PADDING_FACTOR,
                     zoom,
+                    background,
                 )
                 .map(|(path, region)| (path, Some(region)));
 
@@ -1589,10 +2203,10 @@ impl ImageViewer {
                     Ok((path, region)) => {
                         self.pending_svg_reraster_path = Some(path);
                         self.pending_svg_reraster_region = region;
-                        debug_eprintln!("[SVG] Re-raster complete, pending GPU preload");
+                        log::debug!("[SVG] Re-raster complete, pending GPU preload");
                     }
                     Err(_e) => {
-                        debug_eprintln!("[SVG] Re-raster failed: {}", _e);
+                        log::debug!("[SVG] Re-raster failed: {}", _e);
                     }
                 }
                 self.is_svg_rerastering = false;
@@ -1617,7 +2231,7 @@ impl ImageViewer {
             self.svg_reraster_scale = Some(self.image_state.zoom);
             self.pending_svg_reraster_preload_frames = 0;
 
-            debug_eprintln!(
+            log::debug!(
                 "[SVG] Applied re-raster at zoom {:.2}",
                 self.image_state.zoom
             );
@@ -1656,8 +2270,10 @@ impl ImageViewer {
     /// Clear the current image
     pub fn clear(&mut self) {
         self.current_image = None;
+        self.spread_partner = None;
         self.error_message = None;
         self.error_path = None;
+        self.error_is_incomplete = false;
         self.no_images_path = None;
     }
 
@@ -1681,7 +2297,7 @@ impl ImageViewer {
             return false;
         };
 
-        let Some(anim_data) = &loaded.animation_data else {
+        let Some(sequence) = &loaded.frame_sequence else {
             return false;
         };
 
@@ -1697,14 +2313,14 @@ impl ImageViewer {
         let Some(key) = loaded.image_key.as_ref() else {
             return false; // Uncacheable image (no path/mtime).
         };
-        if frame_index >= anim_data.frames.len() {
+        if frame_index >= sequence.frames.len() {
             return false;
         }
 
         let dest = match crate::utils::frame_cache::raw_frame_path(key, frame_index) {
             Ok(p) => p,
             Err(_e) => {
-                debug_eprintln!(
+                log::debug!(
                     "[ERROR] Failed to resolve cache path for frame {}: {}",
                     frame_index,
                     _e
@@ -1721,16 +2337,16 @@ impl ImageViewer {
             return true;
         }
 
-        match anim_data.frames[frame_index].image.save(&dest) {
+        match sequence.frames[frame_index].image.save(&dest) {
             Ok(_) => {
-                debug_eprintln!("[CACHE] Cached frame {} on-demand", frame_index);
+                log::trace!("[CACHE] Cached frame {} on-demand", frame_index);
                 if frame_index < loaded.frame_cache_paths.len() {
                     loaded.frame_cache_paths[frame_index] = dest;
                 }
                 true
             }
             Err(_e) => {
-                debug_eprintln!("[ERROR] Failed to cache frame {}: {}", frame_index, _e);
+                log::debug!("[ERROR] Failed to cache frame {}: {}", frame_index, _e);
                 false
             }
         }
@@ -1906,7 +2522,11 @@ impl ImageViewer {
             let text_color = Colors::text_for_background(background_color);
             div()
                 .size_full()
-                .child(cx.new(|_cx| ErrorDisplay::new(full_message).with_text_color(text_color)))
+                .child(cx.new(|_cx| {
+                    ErrorDisplay::new(full_message)
+                        .with_text_color(text_color)
+                        .with_reload(self.error_is_incomplete)
+                }))
                 .into_any_element()
         } else if let Some(ref loaded) = self.current_image {
             self.render_image(
@@ -1922,6 +2542,67 @@ impl ImageViewer {
         }
     }
 
+    /// The file showing for `loaded`: the current frame's cached PNG for
+    /// animations, else a full SVG re-raster, the rasterized SVG or the
+    /// original.  Errs with a message when an animation frame is missing.
+    fn display_path(&self, loaded: &LoadedImage) -> Result<PathBuf, &'static str> {
+        if let Some(ref anim_state) = self.image_state.animation {
+            let cached_path = loaded
+                .frame_cache_paths
+                .get(anim_state.current_frame)
+                .ok_or("Invalid frame index")?;
+            if cached_path.as_os_str().is_empty() || !cached_path.exists() {
+                return Err("Failed to load image frame");
+            }
+            return Ok(cached_path.clone());
+        }
+        // The in-memory filtered image is handled by `display_source`
+        let full_reraster = self
+            .svg_reraster_path
+            .as_ref()
+            .filter(|_| self.svg_reraster_region.is_none());
+        Ok(full_reraster
+            .or(loaded.rasterized_path.as_ref())
+            .unwrap_or(&loaded.path)
+            .clone())
+    }
+
+    /// What to draw for `loaded` and its element id.  Priority: saved slot
+    /// > GPU pipeline output > B/C/G filtered output > the file at `path`.
+    fn display_source(&self, loaded: &LoadedImage, path: &Path) -> (gpui::ImageSource, ElementId) {
+        let slot_candidate = self.active_slot.and_then(|s| {
+            self.saved_slots[(s - 3) as usize]
+                .as_ref()
+                .map(|slot| &slot.render)
+        });
+        let gpu_candidate = if slot_candidate.is_none() && self.gpu_pipeline_enabled {
+            loaded.gpu_pipeline_render.as_ref()
+        } else {
+            None
+        };
+        if let Some(render_image) = slot_candidate {
+            let id = ElementId::Name(format!("slot-{}", render_image.id.0).into());
+            (gpui::ImageSource::Render(render_image.clone()), id)
+        } else if let Some(render_image) = gpu_candidate {
+            let id = ElementId::Name(format!("gpu-{}", render_image.id.0).into());
+            (gpui::ImageSource::Render(render_image.clone()), id)
+        } else if let Some(ref render_image) = loaded.filtered_render {
+            let id = ElementId::Name(format!("filtered-{}", render_image.id.0).into());
+            (gpui::ImageSource::Render(render_image.clone()), id)
+        } else {
+            let id = ElementId::Name(format!("image-{}", path.display()).into());
+            (gpui::ImageSource::from(path.to_path_buf()), id)
+        }
+    }
+
+    /// What the navigator shows: the displayed image, drawn the same way
+    /// as the main view
+    pub fn navigator_source(&self) -> Option<gpui::ImageSource> {
+        let loaded = self.current_image.as_ref()?;
+        let path = self.display_path(loaded).ok()?;
+        Some(self.display_source(loaded, &path).0)
+    }
+
     fn render_image<V>(
         &self,
         loaded: &LoadedImage,
@@ -1932,44 +2613,18 @@ impl ImageViewer {
         cx: &mut Context<V>,
     ) -> AnyElement {
         let (width, height) = self
-            .display_dimensions()
+            .layout_dimensions()
             .unwrap_or((loaded.width, loaded.height));
+        let background_color = self.backdrop.fill_color(background_color);
 
-        // Get the display path (handles animation frames and filters).
-        // For animations the path points at the current frame's cached PNG.
-        let path = if let Some(ref anim_state) = self.image_state.animation {
-            let frame_index = anim_state.current_frame;
-
-            if frame_index >= loaded.frame_cache_paths.len() {
+        let path = match self.display_path(loaded) {
+            Ok(path) => path,
+            Err(message) => {
                 return div()
                     .size_full()
-                    .child(cx.new(|_cx| ErrorDisplay::new("Invalid frame index".to_string())))
+                    .child(cx.new(|_cx| ErrorDisplay::new(message.to_string())))
                     .into_any_element();
             }
-            let cached_path = &loaded.frame_cache_paths[frame_index];
-            let path_ok = !cached_path.as_os_str().is_empty() && cached_path.exists();
-
-            if path_ok {
-                cached_path.clone()
-            } else {
-                return div()
-                    .size_full()
-                    .child(
-                        cx.new(|_cx| ErrorDisplay::new("Failed to load image frame".to_string())),
-                    )
-                    .into_any_element();
-            }
-        } else {
-            // Static image priority: full SVG re-raster (no region) → rasterized → original.
-            // The in-memory filtered image is handled below via `filtered_source`.
-            let full_reraster = self
-                .svg_reraster_path
-                .as_ref()
-                .filter(|_| self.svg_reraster_region.is_none());
-            full_reraster
-                .or(loaded.rasterized_path.as_ref())
-                .unwrap_or(&loaded.path)
-                .clone()
         };
 
         // Apply zoom to image dimensions
@@ -1983,32 +2638,20 @@ impl ImageViewer {
         let zoom_level = self.image_state.zoom;
         let is_fit = self.image_state.is_fit_to_window;
 
-        // Priority: saved slot > GPU pipeline output > B/C/G filtered output
-        //           > file path.
-        let slot_candidate = self.active_slot.and_then(|s| {
-            self.saved_slots[(s - 3) as usize]
-                .as_ref()
-                .map(|slot| &slot.render)
+        let (image_source, image_id) = self.display_source(loaded, &path);
+
+        // Both pages of a spread, for the checkerboard to sit behind
+        let partner_width = self.spread_partner.as_ref().map(|p| {
+            (
+                p.on_left,
+                self.spread_partner_width(height) as f32 * zoom_level,
+            )
         });
-        let gpu_candidate = if slot_candidate.is_none() && self.gpu_pipeline_enabled {
-            loaded.gpu_pipeline_render.as_ref()
-        } else {
-            None
+        let (spread_left, spread_width) = match partner_width {
+            Some((true, w)) => (pan_x - w, zoomed_width as f32 + w),
+            Some((false, w)) => (pan_x, zoomed_width as f32 + w),
+            None => (pan_x, zoomed_width as f32),
         };
-        let (image_source, image_id): (gpui::ImageSource, ElementId) =
-            if let Some(render_image) = slot_candidate {
-                let id = ElementId::Name(format!("slot-{}", render_image.id.0).into());
-                (gpui::ImageSource::Render(render_image.clone()), id)
-            } else if let Some(render_image) = gpu_candidate {
-                let id = ElementId::Name(format!("gpu-{}", render_image.id.0).into());
-                (gpui::ImageSource::Render(render_image.clone()), id)
-            } else if let Some(ref render_image) = loaded.filtered_render {
-                let id = ElementId::Name(format!("filtered-{}", render_image.id.0).into());
-                (gpui::ImageSource::Render(render_image.clone()), id)
-            } else {
-                let id = ElementId::Name(format!("image-{}", path.display()).into());
-                (gpui::ImageSource::from(path.clone()), id)
-            };
 
         let mut container = div()
             .size_full()
@@ -2017,9 +2660,22 @@ impl ImageViewer {
                 | (background_color[2] as u32)))
             .overflow_hidden()
             .relative()
+            .when(self.backdrop == Backdrop::Checkerboard, |container| {
+                container.child(
+                    checkerboard()
+                        .absolute()
+                        .left(px(spread_left))
+                        .top(px(pan_y))
+                        .w(px(spread_width))
+                        .h(px(zoomed_height as f32)),
+                )
+            })
             .child(
                 img(image_source)
                     .id(image_id)
+                    // Fill, not the default Contain, so a pixel-aspect
+                    // stretch of the box stretches the image with it
+                    .object_fit(ObjectFit::Fill)
                     .w(px(zoomed_width as f32))
                     .h(px(zoomed_height as f32))
                     .absolute()
@@ -2027,6 +2683,27 @@ impl ImageViewer {
                     .top(px(pan_y)),
             );
 
+        // The spread's facing page, matched to the current image's height
+        if let (Some(partner), Some((_, partner_width))) = (&self.spread_partner, partner_width) {
+            let left = if partner.on_left {
+                pan_x - partner_width
+            } else {
+                pan_x + zoomed_width as f32
+            };
+            container = container.child(
+                img(partner.path.clone())
+                    .id(ElementId::Name(
+                        format!("spread-{}", partner.path.display()).into(),
+                    ))
+                    .object_fit(ObjectFit::Fill)
+                    .w(px(partner_width))
+                    .h(px(zoomed_height as f32))
+                    .absolute()
+                    .left(px(left))
+                    .top(px(pan_y)),
+            );
+        }
+
         // Overlay sharp viewport-only SVG re-raster on top of the base image
         if let (Some(rr_path), Some(region)) = (&self.svg_reraster_path, &self.svg_reraster_region)
         {
@@ -2111,6 +2788,9 @@ impl ImageViewer {
                     zoom_level,
                     is_fit,
                     Some((width, height)),
+                    self.fit_locked,
+                    self.filters_locked,
+                    !self.image_state.filters_enabled,
                     overlay_transparency,
                     font_size_scale,
                 )
@@ -2135,6 +2815,7 @@ impl ImageViewer {
                     anim_state.current_frame,
                     anim_state.frame_count,
                     anim_state.is_playing,
+                    anim_state.loop_mode,
                     overlay_transparency,
                     font_size_scale,
                 )
@@ -2156,3 +2837,87 @@ impl Render for ImageViewer {
             .into_any_element()
     }
 }
+
+/// [`ImageViewer::filter_cache_key`] for `state`, with `auto_exposure`
+/// added to the brightness while filters are on
+fn filter_cache_key(state: &ImageState, auto_exposure: f32) -> (Option<FilterSettings>, u16) {
+    let mut filters = state.filters;
+    if state.filters_enabled {
+        filters.brightness = (filters.brightness + auto_exposure).clamp(-100.0, 100.0);
+    }
+    let active = state.filters_enabled
+        && (filters.brightness.abs() >= 0.001
+            || filters.contrast.abs() >= 0.001
+            || (filters.gamma - 1.0).abs() >= 0.001
+            || filters.saturation.abs() >= 0.001
+            || filters.invert
+            || filters.grayscale
+            || filters.channel_offsets() != [0.0; 3]);
+    (active.then_some(filters), state.rotation)
+}
+
+/// Whether the filtered render has to be rebuilt, or dropped, to match
+/// `key`.  `built` is the key the current one was made from, None when
+/// there is none.
+fn filtered_render_stale(
+    built: Option<(Option<FilterSettings>, u16)>,
+    key: (Option<FilterSettings>, u16),
+) -> bool {
+    let is_noop = key == (None, 0);
+    match built {
+        None => !is_noop,
+        Some(built) => is_noop || built != key,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{filter_cache_key, filtered_render_stale};
+    use crate::state::ImageState;
+
+    #[test]
+    fn toggling_filters_invalidates_and_rebuilds_the_render() {
+        // Arrange - a render built for the filters that are on
+        let mut state = ImageState::new();
+        state.filters.brightness = 40.0;
+        let on = filter_cache_key(&state, 0.0);
+        assert!(on.0.is_some());
+        assert!(!filtered_render_stale(Some(on), on));
+
+        // Act - filters off
+        state.toggle_filters_enabled();
+        let off = filter_cache_key(&state, 0.0);
+
+        // Assert - the render is dropped, and once gone stays gone
+        assert_eq!(off, (None, 0));
+        assert!(filtered_render_stale(Some(on), off));
+        assert!(!filtered_render_stale(None, off));
+
+        // Act - back on
+        state.toggle_filters_enabled();
+        let back_on = filter_cache_key(&state, 0.0);
+
+        // Assert - rebuilt with the same values as before
+        assert_eq!(back_on, on);
+        assert!(filtered_render_stale(None, back_on));
+    }
+
+    #[test]
+    fn changed_filter_values_make_the_render_stale() {
+        let mut state = ImageState::new();
+        state.filters.contrast = 10.0;
+        let before = filter_cache_key(&state, 0.0);
+
+        state.filters.contrast = 20.0;
+
+        assert!(filtered_render_stale(
+            Some(before),
+            filter_cache_key(&state, 0.0)
+        ));
+        // Auto exposure counts as a brightness change
+        assert!(filtered_render_stale(
+            Some(before),
+            filter_cache_key(&state, 5.0)
+        ));
+    }
+}
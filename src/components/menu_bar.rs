@@ -5,7 +5,7 @@
 //!
 //! On macOS, native menus are used instead (via cx.set_menus()).
 
-use crate::utils::style::{Colors, Spacing, format_shortcut};
+use crate::utils::style::{Colors, Spacing};
 use gpui::prelude::*;
 use gpui::*;
 
@@ -29,10 +29,12 @@ impl Clone for MenuItemDef {
 }
 
 impl MenuItemDef {
-    pub fn action(label: &str, shortcut: Option<&str>, action: impl Action) -> Self {
+    /// An item for `action`; its shortcut is filled in from the key
+    /// bindings by `MenuBar::new`
+    pub fn action(label: &str, action: impl Action) -> Self {
         Self {
             label: label.to_string(),
-            shortcut: shortcut.map(|s| s.to_string()),
+            shortcut: None,
             action: Some(Box::new(action)),
             is_separator: false,
         }
@@ -48,6 +50,74 @@ impl MenuItemDef {
     }
 }
 
+/// Menu label for a keystroke, written like `format_shortcut` writes them
+/// (`⌥⇧⌘S` on macOS, `Ctrl+Shift+Alt+S` elsewhere)
+pub fn shortcut_label(keystroke: &Keystroke) -> String {
+    let key = match keystroke.key.as_str() {
+        "left" => "←".to_string(),
+        "right" => "→".to_string(),
+        "up" => "↑".to_string(),
+        "down" => "↓".to_string(),
+        "pageup" => "PgUp".to_string(),
+        "pagedown" => "PgDn".to_string(),
+        "backspace" => "Delete".to_string(),
+        "escape" => "Esc".to_string(),
+        "enter" => "Enter".to_string(),
+        "space" => "Space".to_string(),
+        "home" => "Home".to_string(),
+        "end" => "End".to_string(),
+        key => key.to_uppercase(),
+    };
+    let m = &keystroke.modifiers;
+    if cfg!(target_os = "macos") {
+        let mut s = String::new();
+        for (on, glyph) in [
+            (m.control, '⌃'),
+            (m.alt, '⌥'),
+            (m.shift, '⇧'),
+            (m.platform, '⌘'),
+        ] {
+            if on {
+                s.push(glyph);
+            }
+        }
+        s + &key
+    } else {
+        let mut parts = Vec::new();
+        for (on, name) in [
+            (m.control || m.platform, "Ctrl"),
+            (m.shift, "Shift"),
+            (m.alt, "Alt"),
+        ] {
+            if on {
+                parts.push(name);
+            }
+        }
+        parts.push(&key);
+        parts.join("+")
+    }
+}
+
+/// The shortcut a menu shows for `action`: its first binding in `bindings`.
+/// Off macOS the explicit `ctrl-` bindings win over `cmd-` ones, which
+/// don't fire there.
+pub fn shortcut_for_action(action: &dyn Action, bindings: &[KeyBinding]) -> Option<String> {
+    let mut matching = bindings
+        .iter()
+        .filter(|b| b.action().partial_eq(action))
+        .filter_map(|b| b.keystrokes().first().map(|k| k.inner().clone()));
+    let first = matching.next()?;
+    let chosen = if cfg!(target_os = "macos") || !first.modifiers.platform {
+        first
+    } else {
+        std::iter::once(first.clone())
+            .chain(matching)
+            .find(|k| !k.modifiers.platform)
+            .unwrap_or(first)
+    };
+    Some(shortcut_label(&chosen))
+}
+
 /// Menu definition (a dropdown menu with items)
 #[derive(Clone)]
 pub struct MenuDef {
@@ -66,136 +136,157 @@ pub struct MenuBar {
 }
 
 impl MenuBar {
-    pub fn new(cx: &mut Context<Self>) -> Self {
+    /// `bindings` is the app's keymap; each item shows the shortcut bound
+    /// to its action there, so the menus can't drift from the real keys
+    pub fn new(bindings: &[KeyBinding], cx: &mut Context<Self>) -> Self {
         Self {
             open_menu: None,
-            menus: Self::create_menu_definitions(),
+            menus: Self::menu_definitions(bindings),
             focus_handle: cx.focus_handle(),
         }
     }
 
+    /// The menus with their shortcuts looked up in `bindings`
+    pub fn menu_definitions(bindings: &[KeyBinding]) -> Vec<MenuDef> {
+        let mut menus = Self::create_menu_definitions();
+        for item in menus.iter_mut().flat_map(|menu| menu.items.iter_mut()) {
+            if let Some(action) = &item.action {
+                item.shortcut = shortcut_for_action(action.as_ref(), bindings);
+            }
+        }
+        menus
+    }
+
     fn create_menu_definitions() -> Vec<MenuDef> {
         use crate::{
-            CloseWindow, DisableFilters, EnableFilters, NextFrame, NextImage, OpenFile,
-            OpenInExternalEditor, OpenInExternalViewer, OpenInExternalViewerAndQuit, PreviousFrame,
-            PreviousImage, Quit, ResetFilters, RevealInFinder, SaveFile, SaveFileToDownloads,
-            SortAlphabetical, SortByModified, ToggleAnimationPlayPause, ToggleDebug, ToggleFilters,
-            ToggleHelp, ToggleSettings, ZoomIn, ZoomOut, ZoomReset,
+            AnimationSpeedDown, AnimationSpeedUp, BakeFilters, CloseWindow, CopyImageToClipboard,
+            CopyPath, CopyView, CopyViewportImage, CycleBackdrop, CycleLoopMode, CycleOverlays,
+            DisableFilters, EnableFilters, ExportAll, ExportCurrentFrame, FirstFrame, FirstImage,
+            GoToFrame, GoToImage, LastFrame, LastImage, NextDifferentFormat, NextFrame, NextFrames,
+            NextImage, OpenFile, OpenFolder, OpenInDefaultApp, OpenInDefaultAppAndQuit,
+            OpenInExternalEditor, OpenInExternalViewer, OpenInExternalViewerAndQuit,
+            OpenParentFolder, OrganizeByDate, PageBackward, PageForward, PasteView,
+            PreviousDifferentFormat, PreviousFrame, PreviousFrames, PreviousImage, Quit,
+            RedoFilter, RenameImage, ResetFilters, RevealInFinder, RotateClockwise,
+            RotateCounterClockwise, SaveFile, SaveFileToDownloads, SaveViewportImage,
+            SortAlphabetical, SortByModified, StackAverage, ToggleAnimationPlayPause,
+            ToggleAutoExposure, ToggleCropMode, ToggleDebug, ToggleEyedropper, ToggleFilterLock,
+            ToggleFilters, ToggleFiltersEnabled, ToggleFitLock, ToggleFullscreen,
+            ToggleGenerationInfo, ToggleGrayscale, ToggleHelp, ToggleHistogram, ToggleInvert,
+            ToggleMontage, ToggleMontageLink, ToggleMontagePick, ToggleNavigator, ToggleSettings,
+            ToggleSlideshow, ToggleSortDirection, UndoFilter, ZoomIn, ZoomOut, ZoomReset,
+            ZoomToPercent,
         };
 
         vec![
             MenuDef {
                 name: "File".to_string(),
                 items: vec![
-                    MenuItemDef::action(
-                        "Open File...",
-                        Some(&format_shortcut("O", false, false)),
-                        OpenFile,
-                    ),
-                    MenuItemDef::action(
-                        "Save File...",
-                        Some(&format_shortcut("S", false, false)),
-                        SaveFile,
-                    ),
-                    MenuItemDef::action(
-                        "Save to Downloads...",
-                        Some(&format_shortcut("S", false, true)),
-                        SaveFileToDownloads,
-                    ),
+                    MenuItemDef::action("Open File...", OpenFile),
+                    MenuItemDef::action("Open Folder...", OpenFolder),
+                    MenuItemDef::action("Open Enclosing Folder", OpenParentFolder),
+                    MenuItemDef::action("Save File...", SaveFile),
+                    MenuItemDef::action("Save to Downloads...", SaveFileToDownloads),
+                    MenuItemDef::action("Export All...", ExportAll),
+                    MenuItemDef::action("Stack Average...", StackAverage),
+                    MenuItemDef::action("Organize by Date...", OrganizeByDate),
+                    MenuItemDef::action("Copy Image", CopyImageToClipboard),
+                    MenuItemDef::action("Copy Path", CopyPath),
+                    MenuItemDef::action("Copy Visible Area", CopyViewportImage),
+                    MenuItemDef::action("Save Visible Area...", SaveViewportImage),
                     MenuItemDef::separator(),
-                    MenuItemDef::action(
-                        "Reveal in Explorer",
-                        Some(&format_shortcut("R", false, false)),
-                        RevealInFinder,
-                    ),
-                    MenuItemDef::action(
-                        "Open in External Viewer",
-                        Some(&format_shortcut("V", false, true)),
-                        OpenInExternalViewer,
-                    ),
-                    MenuItemDef::action(
-                        "Open in Viewer and Quit",
-                        Some(&format_shortcut("V", true, true)),
-                        OpenInExternalViewerAndQuit,
-                    ),
-                    MenuItemDef::action(
-                        "Open in External Editor",
-                        Some(&format_shortcut("E", false, false)),
-                        OpenInExternalEditor,
-                    ),
+                    MenuItemDef::action("Reveal in Explorer", RevealInFinder),
+                    MenuItemDef::action("Open in External Viewer", OpenInExternalViewer),
+                    MenuItemDef::action("Open in Viewer and Quit", OpenInExternalViewerAndQuit),
+                    MenuItemDef::action("Open in Default App", OpenInDefaultApp),
+                    MenuItemDef::action("Open in Default App and Quit", OpenInDefaultAppAndQuit),
+                    MenuItemDef::action("Open in External Editor", OpenInExternalEditor),
+                    MenuItemDef::action("Rename...", RenameImage),
                     MenuItemDef::separator(),
-                    MenuItemDef::action(
-                        "Close Window",
-                        Some(&format_shortcut("W", false, false)),
-                        CloseWindow,
-                    ),
-                    MenuItemDef::action("Quit", Some(&format_shortcut("Q", false, false)), Quit),
+                    MenuItemDef::action("Close Window", CloseWindow),
+                    MenuItemDef::action("Quit", Quit),
                 ],
             },
             MenuDef {
                 name: "Edit".to_string(),
-                items: vec![MenuItemDef::action(
-                    "Settings...",
-                    Some(&format_shortcut(",", false, false)),
-                    ToggleSettings,
-                )],
+                items: vec![MenuItemDef::action("Settings...", ToggleSettings)],
             },
             MenuDef {
                 name: "View".to_string(),
                 items: vec![
-                    MenuItemDef::action("Zoom In", Some("+"), ZoomIn),
-                    MenuItemDef::action("Zoom Out", Some("-"), ZoomOut),
-                    MenuItemDef::action("Reset Zoom", Some("0"), ZoomReset),
+                    MenuItemDef::action("Zoom In", ZoomIn),
+                    MenuItemDef::action("Zoom Out", ZoomOut),
+                    MenuItemDef::action("Reset Zoom", ZoomReset),
+                    MenuItemDef::action("Zoom To...", ZoomToPercent),
+                    MenuItemDef::action("Lock Fit to Window", ToggleFitLock),
+                    MenuItemDef::action("Copy View", CopyView),
+                    MenuItemDef::action("Paste View", PasteView),
+                    MenuItemDef::action("Rotate Clockwise", RotateClockwise),
+                    MenuItemDef::action("Rotate Counter-Clockwise", RotateCounterClockwise),
+                    MenuItemDef::separator(),
+                    MenuItemDef::action("Toggle Filters", ToggleFilters),
+                    MenuItemDef::action("Disable Filters", DisableFilters),
+                    MenuItemDef::action("Enable Filters", EnableFilters),
+                    MenuItemDef::action("Filters On/Off", ToggleFiltersEnabled),
+                    MenuItemDef::action("Lock Filters Across Images", ToggleFilterLock),
+                    MenuItemDef::action("Auto Exposure", ToggleAutoExposure),
+                    MenuItemDef::action("Invert Colors", ToggleInvert),
+                    MenuItemDef::action("Grayscale", ToggleGrayscale),
+                    MenuItemDef::action("Undo Filter Change", UndoFilter),
+                    MenuItemDef::action("Redo Filter Change", RedoFilter),
+                    MenuItemDef::action("Reset Filters", ResetFilters),
+                    MenuItemDef::action("Apply Filters to File...", BakeFilters),
                     MenuItemDef::separator(),
-                    MenuItemDef::action(
-                        "Toggle Filters",
-                        Some(&format_shortcut("F", false, false)),
-                        ToggleFilters,
-                    ),
-                    MenuItemDef::action(
-                        "Disable Filters",
-                        Some(&format_shortcut("1", false, false)),
-                        DisableFilters,
-                    ),
-                    MenuItemDef::action(
-                        "Enable Filters",
-                        Some(&format_shortcut("2", false, false)),
-                        EnableFilters,
-                    ),
-                    MenuItemDef::action(
-                        "Reset Filters",
-                        Some(&format_shortcut("R", true, false)),
-                        ResetFilters,
-                    ),
+                    MenuItemDef::action("Toggle Help", ToggleHelp),
+                    MenuItemDef::action("Toggle Debug", ToggleDebug),
+                    MenuItemDef::action("Toggle Histogram", ToggleHistogram),
+                    MenuItemDef::action("Toggle Generation Info", ToggleGenerationInfo),
+                    MenuItemDef::action("Pick for Montage", ToggleMontagePick),
+                    MenuItemDef::action("Toggle Montage", ToggleMontage),
+                    MenuItemDef::action("Link Montage Views", ToggleMontageLink),
+                    MenuItemDef::action("Cycle Overlays", CycleOverlays),
+                    MenuItemDef::action("Eyedropper", ToggleEyedropper),
+                    MenuItemDef::action("Crop to Selection", ToggleCropMode),
+                    MenuItemDef::action("Toggle Navigator", ToggleNavigator),
+                    MenuItemDef::action("Cycle Backdrop", CycleBackdrop),
                     MenuItemDef::separator(),
-                    MenuItemDef::action("Toggle Help", Some("H"), ToggleHelp),
-                    MenuItemDef::action("Toggle Debug", Some("F12"), ToggleDebug),
+                    MenuItemDef::action("Toggle Full Screen", ToggleFullscreen),
                 ],
             },
             MenuDef {
                 name: "Navigate".to_string(),
                 items: vec![
-                    MenuItemDef::action("Next Image", Some("→"), NextImage),
-                    MenuItemDef::action("Previous Image", Some("←"), PreviousImage),
+                    MenuItemDef::action("Next Image", NextImage),
+                    MenuItemDef::action("Previous Image", PreviousImage),
+                    MenuItemDef::action("Jump Forward", PageForward),
+                    MenuItemDef::action("Jump Backward", PageBackward),
+                    MenuItemDef::action("First Image", FirstImage),
+                    MenuItemDef::action("Last Image", LastImage),
+                    MenuItemDef::action("Go to Image...", GoToImage),
+                    MenuItemDef::action("Next Different Format", NextDifferentFormat),
+                    MenuItemDef::action("Previous Different Format", PreviousDifferentFormat),
+                    MenuItemDef::action("Slideshow", ToggleSlideshow),
                     MenuItemDef::separator(),
-                    MenuItemDef::action(
-                        "Sort Alphabetically",
-                        Some(&format_shortcut("A", true, false)),
-                        SortAlphabetical,
-                    ),
-                    MenuItemDef::action(
-                        "Sort by Modified Date",
-                        Some(&format_shortcut("M", true, false)),
-                        SortByModified,
-                    ),
+                    MenuItemDef::action("Sort Alphabetically", SortAlphabetical),
+                    MenuItemDef::action("Sort by Modified Date", SortByModified),
+                    MenuItemDef::action("Reverse Sort Order", ToggleSortDirection),
                 ],
             },
             MenuDef {
                 name: "Animation".to_string(),
                 items: vec![
-                    MenuItemDef::action("Play/Pause", Some("O"), ToggleAnimationPlayPause),
-                    MenuItemDef::action("Next Frame", Some("]"), NextFrame),
-                    MenuItemDef::action("Previous Frame", Some("["), PreviousFrame),
+                    MenuItemDef::action("Play/Pause", ToggleAnimationPlayPause),
+                    MenuItemDef::action("Cycle Loop Mode", CycleLoopMode),
+                    MenuItemDef::action("Next Frame", NextFrame),
+                    MenuItemDef::action("Previous Frame", PreviousFrame),
+                    MenuItemDef::action("Jump Frames Forward", NextFrames),
+                    MenuItemDef::action("Jump Frames Backward", PreviousFrames),
+                    MenuItemDef::action("First Frame", FirstFrame),
+                    MenuItemDef::action("Last Frame", LastFrame),
+                    MenuItemDef::action("Go to Frame...", GoToFrame),
+                    MenuItemDef::action("Export Frame...", ExportCurrentFrame),
+                    MenuItemDef::action("Faster Playback", AnimationSpeedUp),
+                    MenuItemDef::action("Slower Playback", AnimationSpeedDown),
                 ],
             },
         ]
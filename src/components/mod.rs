@@ -10,13 +10,17 @@ pub type EscapeCallback = Box<dyn Fn(&mut gpui::Window, &mut gpui::App) + 'stati
 pub mod error_display;
 pub mod filter_controls;
 pub mod filter_window;
+pub mod generation_overlay;
 pub mod gpu_pipeline_controls;
 pub mod gpu_pipeline_window;
 pub mod help_overlay;
+pub mod histogram_overlay;
 pub mod image_viewer;
 pub mod loading_indicator;
 #[cfg(not(target_os = "macos"))]
 pub mod menu_bar;
+pub mod montage_view;
+pub mod navigator;
 pub mod processing_indicator;
 pub mod settings_window;
 pub mod zoom_indicator;
@@ -24,10 +28,14 @@ pub mod zoom_indicator;
 pub use debug_overlay::{DebugOverlay, DebugOverlayConfig};
 pub use filter_controls::{FilterControls, FilterControlsEvent};
 pub use filter_window::FilterWindowView;
+pub use generation_overlay::GenerationOverlay;
 pub use gpu_pipeline_controls::{GpuPipelineControls, GpuPipelineControlsEvent};
 pub use gpu_pipeline_window::GpuPipelineWindowView;
 pub use help_overlay::HelpOverlay;
-pub use image_viewer::ImageViewer;
+pub use histogram_overlay::HistogramOverlay;
+pub use image_viewer::{ImageViewer, SpreadPage};
 #[cfg(not(target_os = "macos"))]
 pub use menu_bar::MenuBar;
-pub use settings_window::SettingsWindow;
+pub use montage_view::MontageView;
+pub use navigator::{Navigator, NavigatorEvent};
+pub use settings_window::{SettingsWindow, SettingsWindowEvent};
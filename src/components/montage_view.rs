@@ -0,0 +1,277 @@
+use crate::utils::image_loader;
+use crate::utils::montage::{self, PaneView};
+use crate::utils::style::{Colors, Spacing, option_prefix, scaled_text_size};
+use gpui::prelude::*;
+use gpui::*;
+use std::path::PathBuf;
+
+/// One image of the montage
+struct MontagePane {
+    path: PathBuf,
+    /// None when the file couldn't be read
+    dimensions: Option<(u32, u32)>,
+    view: PaneView,
+}
+
+/// Montage: the picked images side by side, each zoomed with the scroll
+/// wheel and panned by dragging.  With linked views every pane follows
+/// the one being moved.
+pub struct MontageView {
+    panes: Vec<MontagePane>,
+    linked: bool,
+    /// The pane being dragged and where the cursor was last
+    drag: Option<(usize, Point<Pixels>)>,
+    viewport: Option<Size<Pixels>>,
+    background: [u8; 3],
+    zoom_step: f32,
+    scroll_pans: bool,
+    overlay_transparency: u8,
+    font_size_scale: f32,
+}
+
+impl MontageView {
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        let panes = paths
+            .into_iter()
+            .take(montage::MAX_PANES)
+            .map(|path| MontagePane {
+                dimensions: image_loader::get_image_dimensions(&path).ok(),
+                path,
+                view: PaneView::default(),
+            })
+            .collect();
+        Self {
+            panes,
+            linked: false,
+            drag: None,
+            viewport: None,
+            background: [0, 0, 0],
+            zoom_step: crate::utils::zoom::ZOOM_STEP_WHEEL,
+            scroll_pans: false,
+            overlay_transparency: 204,
+            font_size_scale: 1.0,
+        }
+    }
+
+    /// Lay out for a `viewport`-sized area and take up the current settings
+    pub fn update(
+        &mut self,
+        viewport: Option<Size<Pixels>>,
+        background: [u8; 3],
+        zoom_step: f32,
+        scroll_pans: bool,
+        overlay_transparency: u8,
+        font_size_scale: f32,
+    ) {
+        self.viewport = viewport;
+        self.background = background;
+        self.zoom_step = zoom_step;
+        self.scroll_pans = scroll_pans;
+        self.overlay_transparency = overlay_transparency;
+        self.font_size_scale = font_size_scale;
+    }
+
+    pub fn linked(&self) -> bool {
+        self.linked
+    }
+
+    /// Link or unlink the views.  Linking snaps every pane to the first
+    /// one's view so they start out matched.
+    pub fn set_linked(&mut self, linked: bool) {
+        self.linked = linked;
+        let first = self.panes.first().map(|pane| pane.view);
+        if let (true, Some(first)) = (linked, first) {
+            self.panes.iter_mut().for_each(|pane| pane.view = first);
+        }
+    }
+
+    /// Change pane `index`'s view, and every other pane's with it when
+    /// the views are linked
+    fn change_view(&mut self, index: usize, change: impl FnOnce(&mut PaneView)) {
+        let Some(pane) = self.panes.get_mut(index) else {
+            return;
+        };
+        change(&mut pane.view);
+        let view = pane.view;
+        if self.linked {
+            self.panes.iter_mut().for_each(|pane| pane.view = view);
+        }
+    }
+
+    fn render_pane(
+        &self,
+        index: usize,
+        rect: montage::Rect,
+        cx: &mut Context<Self>,
+    ) -> Stateful<Div> {
+        let pane = &self.panes[index];
+        let name = pane
+            .path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let image = match pane.dimensions {
+            Some(size) => {
+                let at = pane.view.image_rect(size, rect);
+                img(pane.path.clone())
+                    .id(ElementId::Name(
+                        format!("montage-{}-{}", index, pane.path.display()).into(),
+                    ))
+                    .object_fit(ObjectFit::Fill)
+                    .absolute()
+                    .left(px(at.x))
+                    .top(px(at.y))
+                    .w(px(at.w))
+                    .h(px(at.h))
+                    .into_any_element()
+            }
+            None => div()
+                .size_full()
+                .flex()
+                .items_center()
+                .justify_center()
+                .text_color(Colors::error())
+                .child("Couldn't read this image")
+                .into_any_element(),
+        };
+
+        div()
+            .id(("montage-pane", index))
+            .absolute()
+            .left(px(rect.x))
+            .top(px(rect.y))
+            .w(px(rect.w))
+            .h(px(rect.h))
+            .overflow_hidden()
+            .child(image)
+            .child(
+                div()
+                    .absolute()
+                    .left(Spacing::sm())
+                    .bottom(Spacing::sm())
+                    .px(Spacing::sm())
+                    .py(px(2.0))
+                    .bg(Colors::overlay_bg_alpha(self.overlay_transparency))
+                    .rounded(px(4.0))
+                    .text_size(scaled_text_size(12.0, self.font_size_scale))
+                    .text_color(Colors::text())
+                    .child(name),
+            )
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(move |this, event: &MouseDownEvent, _window, cx| {
+                    if event.click_count == 2 {
+                        // Double-click puts the pane back to fit
+                        this.change_view(index, |view| *view = PaneView::default());
+                        this.drag = None;
+                    } else {
+                        this.drag = Some((index, event.position));
+                    }
+                    cx.notify();
+                }),
+            )
+            .on_scroll_wheel(
+                cx.listener(move |this, event: &ScrollWheelEvent, _window, cx| {
+                    let delta = event.delta.pixel_delta(px(16.0));
+                    // Same as the main view: Ctrl/Cmd+scroll zooms, plain
+                    // scroll pans only if enabled
+                    if event.modifiers.platform {
+                        let zoom_in = f32::from(delta.y) < 0.0;
+                        let step = this.zoom_step;
+                        this.change_view(index, |view| view.zoom_by(zoom_in, step));
+                    } else if this.scroll_pans {
+                        let (dx, dy) = crate::utils::zoom::scroll_pan_delta(
+                            (delta.x.into(), delta.y.into()),
+                            event.modifiers.shift,
+                        );
+                        this.change_view(index, |view| {
+                            view.pan = (view.pan.0 + dx, view.pan.1 + dy)
+                        });
+                    } else {
+                        return;
+                    }
+                    cx.notify();
+                }),
+            )
+    }
+
+    fn render_link_badge(&self, cx: &mut Context<Self>) -> Stateful<Div> {
+        let label = if self.linked {
+            "Views linked"
+        } else {
+            "Views independent"
+        };
+        div()
+            .id("montage-link")
+            .absolute()
+            .top(Spacing::sm())
+            .right(Spacing::sm())
+            .px(Spacing::md())
+            .py(Spacing::xs())
+            .bg(Colors::overlay_bg_alpha(self.overlay_transparency))
+            .rounded(px(4.0))
+            .text_size(scaled_text_size(12.0, self.font_size_scale))
+            .text_color(Colors::text())
+            .cursor_pointer()
+            .on_click(cx.listener(|this, _: &ClickEvent, _window, cx| {
+                this.set_linked(!this.linked);
+                cx.notify();
+            }))
+            .child(format!("{} ({}M)", label, option_prefix()))
+    }
+}
+
+impl Render for MontageView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let (width, height) = self
+            .viewport
+            .map(|size| (size.width.into(), size.height.into()))
+            .unwrap_or((0.0, 0.0));
+        let rects = montage::pane_rects(self.panes.len(), width, height, montage::PANE_GAP);
+        let panes: Vec<_> = rects
+            .into_iter()
+            .enumerate()
+            .map(|(index, rect)| self.render_pane(index, rect, cx))
+            .collect();
+        let [r, g, b] = self.background;
+
+        div()
+            .absolute()
+            .top_0()
+            .left_0()
+            .size_full()
+            // The gaps between panes show as thin dark lines
+            .bg(rgb(0x000000))
+            .children(
+                panes
+                    .into_iter()
+                    .map(|pane| pane.bg(rgb(((r as u32) << 16) | ((g as u32) << 8) | (b as u32)))),
+            )
+            .child(self.render_link_badge(cx))
+            // Panning and zooming here shouldn't reach the viewer underneath
+            .occlude()
+            .on_mouse_move(cx.listener(|this, event: &MouseMoveEvent, _window, cx| {
+                let Some((index, last)) = this.drag else {
+                    return;
+                };
+                // The button came up somewhere the montage didn't see
+                if event.pressed_button != Some(MouseButton::Left) {
+                    this.drag = None;
+                    return;
+                }
+                let dx: f32 = (event.position.x - last.x).into();
+                let dy: f32 = (event.position.y - last.y).into();
+                this.drag = Some((index, event.position));
+                this.change_view(index, |view| view.pan = (view.pan.0 + dx, view.pan.1 + dy));
+                cx.notify();
+            }))
+            .on_mouse_up(
+                MouseButton::Left,
+                cx.listener(|this, _: &MouseUpEvent, _window, cx| {
+                    if this.drag.take().is_some() {
+                        cx.notify();
+                    }
+                }),
+            )
+    }
+}
@@ -0,0 +1,165 @@
+use crate::utils::navigator::ThumbRect;
+use crate::utils::style::{Colors, Spacing};
+use gpui::prelude::*;
+use gpui::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Events emitted by the Navigator
+#[derive(Clone, Debug)]
+pub enum NavigatorEvent {
+    /// Center the view on this point of the thumbnail, in thumbnail pixels
+    CenterOn(f32, f32),
+    /// The click or drag that moved the view is over
+    Released,
+}
+
+/// Navigator: the whole image shrunk into a corner, with the part on
+/// screen outlined.  Clicking or dragging centers the view on that spot.
+pub struct Navigator {
+    source: Option<ImageSource>,
+    thumb_size: (f32, f32),
+    visible: Option<ThumbRect>,
+    overlay_transparency: u8,
+    dragging: bool,
+    /// Where the thumbnail was last painted, to turn clicks into its pixels
+    bounds: Rc<Cell<Option<Bounds<Pixels>>>>,
+}
+
+impl EventEmitter<NavigatorEvent> for Navigator {}
+
+impl Navigator {
+    pub fn new() -> Self {
+        Self {
+            source: None,
+            thumb_size: (0.0, 0.0),
+            visible: None,
+            overlay_transparency: 204,
+            dragging: false,
+            bounds: Rc::new(Cell::new(None)),
+        }
+    }
+
+    /// Show `source` at `thumb_size` with `visible` outlined
+    pub fn update(
+        &mut self,
+        source: Option<ImageSource>,
+        thumb_size: (f32, f32),
+        visible: Option<ThumbRect>,
+        overlay_transparency: u8,
+    ) {
+        self.source = source;
+        self.thumb_size = thumb_size;
+        self.visible = visible;
+        self.overlay_transparency = overlay_transparency;
+    }
+
+    fn center_on(&self, position: Point<Pixels>, cx: &mut Context<Self>) {
+        let Some(bounds) = self.bounds.get() else {
+            return;
+        };
+        let x: f32 = (position.x - bounds.origin.x).into();
+        let y: f32 = (position.y - bounds.origin.y).into();
+        cx.emit(NavigatorEvent::CenterOn(x, y));
+    }
+
+    fn release(&mut self, cx: &mut Context<Self>) {
+        if self.dragging {
+            self.dragging = false;
+            cx.emit(NavigatorEvent::Released);
+        }
+    }
+}
+
+impl Default for Navigator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Render for Navigator {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let (w, h) = self.thumb_size;
+        let bounds = self.bounds.clone();
+        let thumbnail = self.source.clone().map(|source| {
+            img(source)
+                .object_fit(ObjectFit::Fill)
+                .absolute()
+                .top_0()
+                .left_0()
+                .w(px(w))
+                .h(px(h))
+        });
+        let outline = self.visible.map(|rect| {
+            div()
+                .absolute()
+                .left(px(rect.x))
+                .top(px(rect.y))
+                .w(px(rect.w))
+                .h(px(rect.h))
+                .border_2()
+                .border_color(rgb(0xFF5555))
+        });
+
+        div()
+            // Bottom-right, above the zoom indicator
+            .absolute()
+            .bottom(px(56.0))
+            .right(Spacing::lg())
+            .p(Spacing::xs())
+            .bg(Colors::overlay_bg_alpha(self.overlay_transparency))
+            .border(Colors::border_width())
+            .border_color(Colors::border())
+            .rounded(px(4.0))
+            .shadow_lg()
+            .child(
+                div()
+                    .id("navigator")
+                    .relative()
+                    .w(px(w))
+                    .h(px(h))
+                    .overflow_hidden()
+                    .cursor_pointer()
+                    .children(thumbnail)
+                    .children(outline)
+                    .child(
+                        canvas(
+                            move |element_bounds, _, _| bounds.set(Some(element_bounds)),
+                            |_, _, _, _| {},
+                        )
+                        .absolute()
+                        .size_full(),
+                    )
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(|this, event: &MouseDownEvent, _window, cx| {
+                            cx.stop_propagation();
+                            this.dragging = true;
+                            this.center_on(event.position, cx);
+                        }),
+                    )
+                    .on_mouse_move(cx.listener(|this, event: &MouseMoveEvent, _window, cx| {
+                        if !this.dragging {
+                            return;
+                        }
+                        cx.stop_propagation();
+                        // The button came up somewhere the navigator didn't see
+                        if event.pressed_button != Some(MouseButton::Left) {
+                            this.release(cx);
+                            return;
+                        }
+                        this.center_on(event.position, cx);
+                    }))
+                    .on_mouse_up(
+                        MouseButton::Left,
+                        cx.listener(|this, _: &MouseUpEvent, _window, cx| this.release(cx)),
+                    )
+                    .on_mouse_up_out(
+                        MouseButton::Left,
+                        cx.listener(|this, _: &MouseUpEvent, _window, cx| this.release(cx)),
+                    ),
+            )
+            // Clicks here shouldn't reach the viewer underneath
+            .occlude()
+    }
+}
@@ -1,8 +1,10 @@
 use crate::utils::style::{Colors, Spacing, scaled_text_size};
+use gpui::prelude::FluentBuilder;
 use gpui::*;
 
 /// Processing indicator component
-/// Displays in the upper left corner while filters are being processed
+/// Displays in the upper left corner while filters are being processed,
+/// or inline inside another card (the batch progress overlay)
 #[derive(Clone)]
 pub struct ProcessingIndicator {
     pub message: String,
@@ -10,6 +12,8 @@ pub struct ProcessingIndicator {
     pub overlay_transparency: u8,
     /// Font size scale multiplier
     pub font_size_scale: f32,
+    /// Whether to float in the upper left corner (false = lay out inline)
+    pub floating: bool,
 }
 
 impl ProcessingIndicator {
@@ -18,21 +22,28 @@ impl ProcessingIndicator {
             message: message.into(),
             overlay_transparency,
             font_size_scale,
+            floating: true,
         }
     }
+
+    /// Lay out in the parent's flow instead of floating in the corner
+    pub fn inline(mut self) -> Self {
+        self.floating = false;
+        self
+    }
 }
 
 impl Render for ProcessingIndicator {
     fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
         div()
-            .absolute()
-            .top(Spacing::lg())
-            .left(Spacing::lg())
+            .when(self.floating, |d| {
+                d.absolute().top(Spacing::lg()).left(Spacing::lg())
+            })
             .p(Spacing::md())
             .bg(Colors::overlay_bg_alpha(self.overlay_transparency))
             .rounded(px(6.0))
-            .border_1()
-            .border_color(rgba(0x444444FF))
+            .border(Colors::border_width())
+            .border_color(Colors::border())
             .flex()
             .flex_row()
             .items_center()
@@ -14,7 +14,7 @@
 //!   - Pan speeds (normal, fast, slow)
 //!   - Zoom sensitivities (scroll wheel, Z-drag)
 //!   - Cache sizes and thread counts
-//!   - Filter defaults (brightness, contrast, gamma)
+//!   - Filter defaults (brightness, contrast, gamma, saturation)
 //!   - Appearance settings (transparency, font scale, RGB color picker)
 //! - ✅ **Text input**: Window title format is editable with proper focus handling
 //! - ✅ **Range validation**: All numeric values are clamped to valid ranges
@@ -39,10 +39,10 @@
 //! The component maintains settings in `working_settings` which are immediately
 //! visible in the UI and saved to disk when the settings window is closed.
 
-use crate::state::app_state::SortMode;
+use crate::state::app_state::{EndOfList, SortMode};
 use crate::state::settings::*;
-use crate::utils::settings_io;
 use crate::utils::style::{Colors, Spacing, TextSize};
+use crate::utils::{reveal, settings_io};
 use crate::{CloseSettings, NextImage, PreviousImage, ResetSettingsToDefaults};
 use ccf_gpui_widgets::prelude::{
     ColorSwatch, ColorSwatchEvent, DirectoryPicker, DirectoryPickerEvent, NumberStepper,
@@ -94,6 +94,48 @@ impl SettingsSection {
             Self::SettingsFile,
         ]
     }
+
+    /// Reset the settings shown in this section to their defaults, leaving
+    /// every other section untouched.  The filter/GPU window geometry
+    /// stored under `appearance` is not shown in the Appearance section, so
+    /// it is kept, as is the last-used sort mode.
+    pub fn reset_settings(&self, settings: &mut AppSettings) {
+        let defaults = AppSettings::default();
+        match self {
+            Self::ViewerBehavior => {
+                settings.viewer_behavior = defaults.viewer_behavior;
+                settings.filters.panel_enables_filters = defaults.filters.panel_enables_filters;
+                settings.filters.auto_exposure = defaults.filters.auto_exposure;
+            }
+            Self::Performance => settings.performance = defaults.performance,
+            Self::KeyboardMouse => settings.keyboard_mouse = defaults.keyboard_mouse,
+            Self::FileOperations => settings.file_operations = defaults.file_operations,
+            Self::Appearance => {
+                let previous = std::mem::replace(&mut settings.appearance, defaults.appearance);
+                settings.appearance.filter_window_bounds = previous.filter_window_bounds;
+                settings.appearance.filter_window_open = previous.filter_window_open;
+                settings.appearance.gpu_pipeline_window_bounds =
+                    previous.gpu_pipeline_window_bounds;
+                settings.appearance.gpu_pipeline_window_open = previous.gpu_pipeline_window_open;
+            }
+            Self::SortNavigation => {
+                let last_sort_mode = settings.sort_navigation.last_sort_mode;
+                let sort_descending = settings.sort_navigation.sort_descending;
+                settings.sort_navigation = defaults.sort_navigation;
+                settings.sort_navigation.last_sort_mode = last_sort_mode;
+                settings.sort_navigation.sort_descending = sort_descending;
+            }
+            Self::ExternalTools => settings.external_tools = defaults.external_tools,
+            Self::SettingsFile => {}
+        }
+    }
+
+    /// Whether resetting this section would change nothing
+    pub fn is_at_defaults(&self, settings: &AppSettings) -> bool {
+        let mut reset = settings.clone();
+        self.reset_settings(&mut reset);
+        reset == *settings
+    }
 }
 
 impl SelectionItem for SettingsSection {
@@ -186,10 +228,61 @@ macro_rules! create_toggle {
     }};
 }
 
+/// The appearance values the app previews live behind the settings overlay
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AppearancePreview {
+    pub background_color_dark: [u8; 3],
+    pub background_color_light: [u8; 3],
+    pub overlay_transparency: u8,
+    pub font_size_scale: f32,
+    pub high_contrast: bool,
+}
+
+impl AppearancePreview {
+    pub fn from_settings(appearance: &Appearance) -> Self {
+        Self {
+            background_color_dark: appearance.background_color_dark,
+            background_color_light: appearance.background_color_light,
+            overlay_transparency: appearance.overlay_transparency,
+            font_size_scale: appearance.font_size_scale,
+            high_contrast: appearance.high_contrast,
+        }
+    }
+
+    /// Copy the previewed values into `appearance`
+    pub fn apply_to(&self, appearance: &mut Appearance) {
+        appearance.background_color_dark = self.background_color_dark;
+        appearance.background_color_light = self.background_color_light;
+        appearance.overlay_transparency = self.overlay_transparency;
+        appearance.font_size_scale = self.font_size_scale;
+        appearance.high_contrast = self.high_contrast;
+    }
+}
+
+/// Events emitted by SettingsWindow
+#[derive(Clone, Debug)]
+pub enum SettingsWindowEvent {
+    /// An appearance setting was edited; the app applies it immediately
+    /// rather than waiting for the settings window to close
+    AppearanceChanged(AppearancePreview),
+}
+
+/// The event to send for `settings`, or None if its appearance matches
+/// what was last sent
+pub fn appearance_change(
+    previous: &AppearancePreview,
+    settings: &AppSettings,
+) -> Option<SettingsWindowEvent> {
+    let current = AppearancePreview::from_settings(&settings.appearance);
+    (current != *previous).then_some(SettingsWindowEvent::AppearanceChanged(current))
+}
+
 /// Settings window component
 pub struct SettingsWindow {
     /// Working copy of settings (being edited)
     pub working_settings: AppSettings,
+    /// Appearance last sent to the app for live preview
+    previewed_appearance: AppearancePreview,
     /// Currently selected section
     pub current_section: SettingsSection,
     /// Focus handle for the settings window
@@ -198,27 +291,42 @@ pub struct SettingsWindow {
     sidebar_nav: Entity<SidebarNav<SettingsSection>>,
     /// Text input for window title format
     window_title_input: Entity<TextInput>,
+    organize_pattern_input: Entity<TextInput>,
 
     // Number steppers for numeric settings
     state_cache_size_stepper: Entity<NumberStepper>,
+    fit_padding_stepper: Entity<NumberStepper>,
+    safe_area_stepper: Entity<NumberStepper>,
+    cursor_hide_delay_stepper: Entity<NumberStepper>,
+    slideshow_interval_stepper: Entity<NumberStepper>,
+    idle_slideshow_stepper: Entity<NumberStepper>,
+    frame_step_stepper: Entity<NumberStepper>,
     filter_processing_threads_stepper: Entity<NumberStepper>,
     max_image_dimension_stepper: Entity<NumberStepper>,
+    preload_radius_stepper: Entity<NumberStepper>,
     pan_speed_normal_stepper: Entity<NumberStepper>,
     pan_speed_fast_stepper: Entity<NumberStepper>,
     pan_speed_slow_stepper: Entity<NumberStepper>,
     scroll_wheel_sensitivity_stepper: Entity<NumberStepper>,
     z_drag_sensitivity_stepper: Entity<NumberStepper>,
+    crop_grid_stepper: Entity<NumberStepper>,
     overlay_transparency_stepper: Entity<NumberStepper>,
     font_size_scale_stepper: Entity<NumberStepper>,
     default_brightness_stepper: Entity<NumberStepper>,
     default_contrast_stepper: Entity<NumberStepper>,
     default_gamma_stepper: Entity<NumberStepper>,
+    default_saturation_stepper: Entity<NumberStepper>,
+    page_stride_stepper: Entity<NumberStepper>,
+    double_tap_secs_stepper: Entity<NumberStepper>,
 
     // Segmented controls
     zoom_mode_control: Entity<SegmentedControl>,
     pan_direction_mode_control: Entity<SegmentedControl>,
     sort_mode_control: Entity<SegmentedControl>,
+    end_of_list_control: Entity<SegmentedControl>,
+    svg_background_control: Entity<SegmentedControl>,
     save_format_control: Entity<SegmentedControl>,
+    resize_filter_control: Entity<SegmentedControl>,
     save_location_mode_control: Entity<SegmentedControl>,
 
     // Color pickers for background colors
@@ -227,24 +335,48 @@ pub struct SettingsWindow {
 
     // Directory picker for default save directory
     default_save_directory_picker: Entity<DirectoryPicker>,
+    // Directory picker for the temp-file scratch directory
+    scratch_directory_picker: Entity<DirectoryPicker>,
 
     // Toggle switches for boolean settings
     remember_per_image_state_toggle: Entity<ToggleSwitch>,
     animation_auto_play_toggle: Entity<ToggleSwitch>,
+    confirm_quit_toggle: Entity<ToggleSwitch>,
+    panel_enables_filters_toggle: Entity<ToggleSwitch>,
+    auto_exposure_toggle: Entity<ToggleSwitch>,
+    auto_hide_cursor_toggle: Entity<ToggleSwitch>,
+    slideshow_animations_toggle: Entity<ToggleSwitch>,
+    idle_slideshow_toggle: Entity<ToggleSwitch>,
+    honor_pixel_aspect_toggle: Entity<ToggleSwitch>,
+    presentation_caption_toggle: Entity<ToggleSwitch>,
+    zoom_cycle_toggle: Entity<ToggleSwitch>,
+    high_contrast_toggle: Entity<ToggleSwitch>,
     preload_adjacent_images_toggle: Entity<ToggleSwitch>,
     spacebar_pan_accelerated_toggle: Entity<ToggleSwitch>,
+    scroll_pans_toggle: Entity<ToggleSwitch>,
+    pan_inertia_toggle: Entity<ToggleSwitch>,
     auto_save_filtered_cache_toggle: Entity<ToggleSwitch>,
     remember_last_directory_toggle: Entity<ToggleSwitch>,
+    organize_moves_files_toggle: Entity<ToggleSwitch>,
     remember_filter_state_toggle: Entity<ToggleSwitch>,
     wrap_navigation_toggle: Entity<ToggleSwitch>,
     show_image_counter_toggle: Entity<ToggleSwitch>,
+    right_to_left_toggle: Entity<ToggleSwitch>,
+    two_page_spread_toggle: Entity<ToggleSwitch>,
+    spread_cover_alone_toggle: Entity<ToggleSwitch>,
+    recursive_scan_toggle: Entity<ToggleSwitch>,
+    double_tap_to_end_toggle: Entity<ToggleSwitch>,
     file_manager_integration_toggle: Entity<ToggleSwitch>,
 
     /// Inline feedback for the "Clear All Cached Frames" button (Performance
     /// section). Empty until the user clicks it.
     cache_status: String,
+    /// Inline feedback for settings import/export, and whether it's an error
+    settings_file_status: Option<(String, bool)>,
 }
 
+impl EventEmitter<SettingsWindowEvent> for SettingsWindow {}
+
 impl SettingsWindow {
     /// Create a new settings window with the given settings
     pub fn new(settings: AppSettings, cx: &mut Context<Self>) -> Self {
@@ -271,6 +403,79 @@ impl SettingsWindow {
             |this: &mut Self, v: f64| this.working_settings.viewer_behavior.state_cache_size =
                 v as usize
         );
+        let fit_padding_stepper = create_stepper!(
+            cx,
+            app_theme,
+            settings.viewer_behavior.fit_padding as f64,
+            0.0,
+            500.0,
+            8.0,
+            1.0,
+            0,
+            |this: &mut Self, v: f64| this.working_settings.viewer_behavior.fit_padding = v as f32
+        );
+        let safe_area_stepper = create_stepper!(
+            cx,
+            app_theme,
+            settings.viewer_behavior.safe_area_percent as f64,
+            0.0,
+            20.0,
+            1.0,
+            0.5,
+            1,
+            |this: &mut Self, v: f64| this.working_settings.viewer_behavior.safe_area_percent =
+                v as f32
+        );
+        let cursor_hide_delay_stepper = create_stepper!(
+            cx,
+            app_theme,
+            settings.viewer_behavior.cursor_hide_delay_secs as f64,
+            0.5,
+            60.0,
+            1.0,
+            0.5,
+            1,
+            |this: &mut Self, v: f64| this
+                .working_settings
+                .viewer_behavior
+                .cursor_hide_delay_secs = v as f32
+        );
+        let slideshow_interval_stepper = create_stepper!(
+            cx,
+            app_theme,
+            settings.viewer_behavior.slideshow_interval_secs as f64,
+            0.5,
+            600.0,
+            1.0,
+            0.5,
+            1,
+            |this: &mut Self, v: f64| this
+                .working_settings
+                .viewer_behavior
+                .slideshow_interval_secs = v as f32
+        );
+        let idle_slideshow_stepper = create_stepper!(
+            cx,
+            app_theme,
+            settings.viewer_behavior.idle_slideshow_secs as f64,
+            10.0,
+            3600.0,
+            10.0,
+            1.0,
+            0,
+            |this: &mut Self, v: f64| this.working_settings.viewer_behavior.idle_slideshow_secs =
+                v as f32
+        );
+        let frame_step_stepper = create_stepper!(
+            cx,
+            app_theme,
+            settings.viewer_behavior.frame_step as f64,
+            2.0,
+            1000.0,
+            1.0,
+            0,
+            |this: &mut Self, v: f64| this.working_settings.viewer_behavior.frame_step = v as usize
+        );
         let filter_processing_threads_stepper = create_stepper!(
             cx,
             app_theme,
@@ -282,6 +487,16 @@ impl SettingsWindow {
             |this: &mut Self, v: f64| this.working_settings.performance.filter_processing_threads =
                 v as usize
         );
+        let preload_radius_stepper = create_stepper!(
+            cx,
+            app_theme,
+            settings.performance.preload_radius as f64,
+            1.0,
+            Performance::MAX_PRELOAD_RADIUS as f64,
+            1.0,
+            0,
+            |this: &mut Self, v: f64| this.working_settings.performance.preload_radius = v as usize
+        );
         let max_image_dimension_stepper = create_stepper!(
             cx,
             app_theme,
@@ -379,6 +594,16 @@ impl SettingsWindow {
             |this: &mut Self, v: f64| this.working_settings.keyboard_mouse.z_drag_sensitivity =
                 v as f32
         );
+        let crop_grid_stepper = create_stepper!(
+            cx,
+            app_theme,
+            settings.keyboard_mouse.crop_grid as f64,
+            0.0,
+            256.0,
+            1.0,
+            0,
+            |this: &mut Self, v: f64| this.working_settings.keyboard_mouse.crop_grid = v as u32
+        );
         let overlay_transparency_stepper = create_stepper!(
             cx,
             app_theme,
@@ -433,6 +658,39 @@ impl SettingsWindow {
             2,
             |this: &mut Self, v: f64| this.working_settings.filters.default_gamma = v as f32
         );
+        let default_saturation_stepper = create_stepper!(
+            cx,
+            app_theme,
+            settings.filters.default_saturation.into(),
+            -100.0,
+            100.0,
+            5.0,
+            0.2,
+            0,
+            |this: &mut Self, v: f64| this.working_settings.filters.default_saturation = v as f32
+        );
+        let page_stride_stepper = create_stepper!(
+            cx,
+            app_theme,
+            settings.sort_navigation.page_stride as f64,
+            1.0,
+            1000.0,
+            1.0,
+            0,
+            |this: &mut Self, v: f64| this.working_settings.sort_navigation.page_stride =
+                v as usize
+        );
+        let double_tap_secs_stepper = create_stepper!(
+            cx,
+            app_theme,
+            settings.sort_navigation.double_tap_secs as f64,
+            0.1,
+            1.0,
+            0.05,
+            2,
+            |this: &mut Self, v: f64| this.working_settings.sort_navigation.double_tap_secs =
+                v as f32
+        );
 
         // Segmented control for zoom mode
         let initial_zoom = match settings.viewer_behavior.default_zoom_mode {
@@ -498,6 +756,66 @@ impl SettingsWindow {
         )
         .detach();
 
+        // Segmented control for what stepping past either end of the list does
+        let initial_end_of_list = match settings.sort_navigation.end_of_list {
+            EndOfList::Stop => "stop",
+            EndOfList::Notify => "notify",
+            EndOfList::AdjacentFolder => "folder",
+        };
+        let end_of_list_control = cx.new(|cx| {
+            SegmentedControl::new(cx)
+                .options(vec![
+                    ("stop", "Stop"),
+                    ("notify", "Show a notice"),
+                    ("folder", "Next folder"),
+                ])
+                .with_selected_value(initial_end_of_list)
+                .theme(app_theme)
+        });
+        cx.subscribe(
+            &end_of_list_control,
+            |this, _control, event: &SegmentedControlEvent<SegmentOption>, cx| {
+                let SegmentedControlEvent::Change(option) = event;
+                this.working_settings.sort_navigation.end_of_list = match option.value.as_str() {
+                    "notify" => EndOfList::Notify,
+                    "folder" => EndOfList::AdjacentFolder,
+                    _ => EndOfList::Stop,
+                };
+                cx.notify();
+            },
+        )
+        .detach();
+
+        // Segmented control for what SVGs are rasterized onto
+        let initial_svg_background = match settings.appearance.svg_background {
+            SvgBackground::Transparent => "transparent",
+            SvgBackground::White => "white",
+            SvgBackground::Black => "black",
+        };
+        let svg_background_control = cx.new(|cx| {
+            SegmentedControl::new(cx)
+                .options(vec![
+                    ("transparent", "Transparent"),
+                    ("white", "White"),
+                    ("black", "Black"),
+                ])
+                .with_selected_value(initial_svg_background)
+                .theme(app_theme)
+        });
+        cx.subscribe(
+            &svg_background_control,
+            |this, _control, event: &SegmentedControlEvent<SegmentOption>, cx| {
+                let SegmentedControlEvent::Change(option) = event;
+                this.working_settings.appearance.svg_background = match option.value.as_str() {
+                    "white" => SvgBackground::White,
+                    "black" => SvgBackground::Black,
+                    _ => SvgBackground::Transparent,
+                };
+                cx.notify();
+            },
+        )
+        .detach();
+
         // Segmented control for save format
         let initial_format = match settings.file_operations.default_save_format {
             SaveFormat::SameAsLoaded => "same",
@@ -539,6 +857,42 @@ impl SettingsWindow {
         )
         .detach();
 
+        // Segmented control for the resampling filter used when saving scaled
+        let initial_resize_filter = match settings.file_operations.resize_filter {
+            ResizeFilter::Auto => "auto",
+            ResizeFilter::Nearest => "nearest",
+            ResizeFilter::Triangle => "triangle",
+            ResizeFilter::CatmullRom => "catmull-rom",
+            ResizeFilter::Lanczos3 => "lanczos3",
+        };
+        let resize_filter_control = cx.new(|cx| {
+            SegmentedControl::new(cx)
+                .options(vec![
+                    ("auto", "Auto"),
+                    ("nearest", "Nearest"),
+                    ("triangle", "Bilinear"),
+                    ("catmull-rom", "Bicubic"),
+                    ("lanczos3", "Lanczos3"),
+                ])
+                .with_selected_value(initial_resize_filter)
+                .theme(app_theme)
+        });
+        cx.subscribe(
+            &resize_filter_control,
+            |this, _control, event: &SegmentedControlEvent<SegmentOption>, cx| {
+                let SegmentedControlEvent::Change(option) = event;
+                this.working_settings.file_operations.resize_filter = match option.value.as_str() {
+                    "nearest" => ResizeFilter::Nearest,
+                    "triangle" => ResizeFilter::Triangle,
+                    "catmull-rom" => ResizeFilter::CatmullRom,
+                    "lanczos3" => ResizeFilter::Lanczos3,
+                    _ => ResizeFilter::Auto,
+                };
+                cx.notify();
+            },
+        )
+        .detach();
+
         // Segmented control for save location mode
         let initial_save_location = if settings.file_operations.default_save_directory.is_some() {
             "custom"
@@ -609,6 +963,73 @@ impl SettingsWindow {
             |this: &mut Self, on: bool| this.working_settings.viewer_behavior.animation_auto_play =
                 on
         );
+        let confirm_quit_toggle = create_toggle!(
+            cx,
+            toggle_theme,
+            settings.viewer_behavior.confirm_quit_with_unsaved_filters,
+            "Confirm quit with unsaved filters",
+            |this: &mut Self, on: bool| this
+                .working_settings
+                .viewer_behavior
+                .confirm_quit_with_unsaved_filters = on
+        );
+        let auto_hide_cursor_toggle = create_toggle!(
+            cx,
+            toggle_theme,
+            settings.viewer_behavior.auto_hide_cursor,
+            "Auto-hide cursor when presenting",
+            |this: &mut Self, on: bool| this.working_settings.viewer_behavior.auto_hide_cursor = on
+        );
+        let slideshow_animations_toggle = create_toggle!(
+            cx,
+            toggle_theme,
+            settings.viewer_behavior.slideshow_let_animations_finish,
+            "Let animations finish in slideshow",
+            |this: &mut Self, on: bool| this
+                .working_settings
+                .viewer_behavior
+                .slideshow_let_animations_finish = on
+        );
+        let idle_slideshow_toggle = create_toggle!(
+            cx,
+            toggle_theme,
+            settings.viewer_behavior.idle_slideshow,
+            "Start slideshow when idle",
+            |this: &mut Self, on: bool| this.working_settings.viewer_behavior.idle_slideshow = on
+        );
+        let honor_pixel_aspect_toggle = create_toggle!(
+            cx,
+            toggle_theme,
+            settings.viewer_behavior.honor_pixel_aspect,
+            "Honor pixel aspect ratio",
+            |this: &mut Self, on: bool| this.working_settings.viewer_behavior.honor_pixel_aspect =
+                on
+        );
+        let presentation_caption_toggle = create_toggle!(
+            cx,
+            toggle_theme,
+            settings.viewer_behavior.show_presentation_caption,
+            "Show filename caption when presenting",
+            |this: &mut Self, on: bool| this
+                .working_settings
+                .viewer_behavior
+                .show_presentation_caption = on
+        );
+        let high_contrast_toggle = create_toggle!(
+            cx,
+            toggle_theme,
+            settings.appearance.high_contrast,
+            "High-contrast overlays",
+            |this: &mut Self, on: bool| this.working_settings.appearance.high_contrast = on
+        );
+        let zoom_cycle_toggle = create_toggle!(
+            cx,
+            toggle_theme,
+            settings.viewer_behavior.zoom_cycle_enabled,
+            "0 key cycles zoom stops",
+            |this: &mut Self, on: bool| this.working_settings.viewer_behavior.zoom_cycle_enabled =
+                on
+        );
         let preload_adjacent_images_toggle = create_toggle!(
             cx,
             toggle_theme,
@@ -627,6 +1048,20 @@ impl SettingsWindow {
                 .keyboard_mouse
                 .spacebar_pan_accelerated = on
         );
+        let scroll_pans_toggle = create_toggle!(
+            cx,
+            toggle_theme,
+            settings.keyboard_mouse.scroll_pans,
+            "Scroll to pan",
+            |this: &mut Self, on: bool| this.working_settings.keyboard_mouse.scroll_pans = on
+        );
+        let pan_inertia_toggle = create_toggle!(
+            cx,
+            toggle_theme,
+            settings.keyboard_mouse.pan_inertia,
+            "Pan inertia",
+            |this: &mut Self, on: bool| this.working_settings.keyboard_mouse.pan_inertia = on
+        );
         let auto_save_filtered_cache_toggle = create_toggle!(
             cx,
             toggle_theme,
@@ -647,6 +1082,30 @@ impl SettingsWindow {
                 .file_operations
                 .remember_last_directory = on
         );
+        let organize_moves_files_toggle = create_toggle!(
+            cx,
+            toggle_theme,
+            settings.file_operations.organize_moves_files,
+            "Organize by Date moves files",
+            |this: &mut Self, on: bool| this
+                .working_settings
+                .file_operations
+                .organize_moves_files = on
+        );
+        let panel_enables_filters_toggle = create_toggle!(
+            cx,
+            toggle_theme,
+            settings.filters.panel_enables_filters,
+            "Opening filter panel enables filters",
+            |this: &mut Self, on: bool| this.working_settings.filters.panel_enables_filters = on
+        );
+        let auto_exposure_toggle = create_toggle!(
+            cx,
+            toggle_theme,
+            settings.filters.auto_exposure,
+            "Auto exposure",
+            |this: &mut Self, on: bool| this.working_settings.filters.auto_exposure = on
+        );
         let remember_filter_state_toggle = create_toggle!(
             cx,
             toggle_theme,
@@ -669,6 +1128,43 @@ impl SettingsWindow {
             |this: &mut Self, on: bool| this.working_settings.sort_navigation.show_image_counter =
                 on
         );
+        let right_to_left_toggle = create_toggle!(
+            cx,
+            toggle_theme,
+            settings.sort_navigation.right_to_left,
+            "Right-to-left reading",
+            |this: &mut Self, on: bool| this.working_settings.sort_navigation.right_to_left = on
+        );
+        let two_page_spread_toggle = create_toggle!(
+            cx,
+            toggle_theme,
+            settings.sort_navigation.two_page_spread,
+            "Two-page spreads",
+            |this: &mut Self, on: bool| this.working_settings.sort_navigation.two_page_spread = on
+        );
+        let spread_cover_alone_toggle = create_toggle!(
+            cx,
+            toggle_theme,
+            settings.sort_navigation.spread_cover_alone,
+            "Show cover alone",
+            |this: &mut Self, on: bool| this.working_settings.sort_navigation.spread_cover_alone =
+                on
+        );
+        let recursive_scan_toggle = create_toggle!(
+            cx,
+            toggle_theme,
+            settings.sort_navigation.recursive_scan,
+            "Include subfolders",
+            |this: &mut Self, on: bool| this.working_settings.sort_navigation.recursive_scan = on
+        );
+        let double_tap_to_end_toggle = create_toggle!(
+            cx,
+            toggle_theme,
+            settings.sort_navigation.double_tap_to_end,
+            "Double-tap arrows for ends",
+            |this: &mut Self, on: bool| this.working_settings.sort_navigation.double_tap_to_end =
+                on
+        );
         let file_manager_integration_toggle = create_toggle!(
             cx,
             toggle_theme,
@@ -753,6 +1249,25 @@ impl SettingsWindow {
         )
         .detach();
 
+        // Text input for the Organize by Date pattern
+        let organize_pattern_input = cx.new(|cx| {
+            TextInput::new(cx)
+                .with_value(&settings.file_operations.organize_pattern)
+                .placeholder(crate::utils::organize::DEFAULT_PATTERN)
+                .theme(app_theme)
+        });
+        cx.subscribe(
+            &organize_pattern_input,
+            |this, input, event: &TextInputEvent, cx| {
+                if let TextInputEvent::Change = event {
+                    let value = input.read(cx).content().to_string();
+                    this.working_settings.file_operations.organize_pattern = value;
+                    cx.notify();
+                }
+            },
+        )
+        .detach();
+
         // Create directory picker for default save directory
         let has_custom_dir = settings.file_operations.default_save_directory.is_some();
         let initial_dir = settings
@@ -784,6 +1299,34 @@ impl SettingsWindow {
         )
         .detach();
 
+        // Create directory picker for the scratch directory (empty = OS temp dir)
+        let initial_scratch_dir = settings
+            .file_operations
+            .scratch_directory
+            .as_ref()
+            .map(|p| p.display().to_string())
+            .unwrap_or_default();
+        let scratch_directory_picker = cx.new(|cx| {
+            DirectoryPicker::new(cx)
+                .with_value(initial_scratch_dir)
+                .placeholder("System temp directory")
+                .browse_shortcut(false)
+                .theme(app_theme)
+        });
+        cx.subscribe(
+            &scratch_directory_picker,
+            |this, _picker, event: &DirectoryPickerEvent, cx| {
+                let DirectoryPickerEvent::Change(path) = event;
+                this.working_settings.file_operations.scratch_directory = if path.is_empty() {
+                    None
+                } else {
+                    Some(std::path::PathBuf::from(path))
+                };
+                cx.notify();
+            },
+        )
+        .detach();
+
         // Create sidebar navigation widget
         let sidebar_nav = cx.new(|cx| {
             SidebarNav::new(SettingsSection::all(), SettingsSection::ViewerBehavior, cx)
@@ -800,199 +1343,460 @@ impl SettingsWindow {
         )
         .detach();
 
+        // Every control change notifies; forward appearance edits to the app
+        cx.observe_self(|this, cx| this.emit_appearance_if_changed(cx))
+            .detach();
+
         Self {
+            previewed_appearance: AppearancePreview::from_settings(&settings.appearance),
             window_title_input,
+            organize_pattern_input,
             working_settings: settings,
             current_section: SettingsSection::ViewerBehavior,
             focus_handle: cx.focus_handle(),
             sidebar_nav,
             state_cache_size_stepper,
+            fit_padding_stepper,
+            safe_area_stepper,
+            cursor_hide_delay_stepper,
+            slideshow_interval_stepper,
+            idle_slideshow_stepper,
+            frame_step_stepper,
             filter_processing_threads_stepper,
             max_image_dimension_stepper,
+            preload_radius_stepper,
             pan_speed_normal_stepper,
             pan_speed_fast_stepper,
             pan_speed_slow_stepper,
             scroll_wheel_sensitivity_stepper,
             z_drag_sensitivity_stepper,
+            crop_grid_stepper,
             overlay_transparency_stepper,
             font_size_scale_stepper,
             default_brightness_stepper,
             default_contrast_stepper,
             default_gamma_stepper,
+            default_saturation_stepper,
+            page_stride_stepper,
+            double_tap_secs_stepper,
             zoom_mode_control,
             pan_direction_mode_control,
             sort_mode_control,
+            end_of_list_control,
+            svg_background_control,
             save_format_control,
+            resize_filter_control,
             save_location_mode_control,
             bg_color_dark_swatch,
             bg_color_light_swatch,
             default_save_directory_picker,
+            scratch_directory_picker,
             remember_per_image_state_toggle,
             animation_auto_play_toggle,
+            confirm_quit_toggle,
+            panel_enables_filters_toggle,
+            auto_exposure_toggle,
+            auto_hide_cursor_toggle,
+            slideshow_animations_toggle,
+            idle_slideshow_toggle,
+            honor_pixel_aspect_toggle,
+            presentation_caption_toggle,
+            zoom_cycle_toggle,
+            high_contrast_toggle,
             preload_adjacent_images_toggle,
             spacebar_pan_accelerated_toggle,
+            scroll_pans_toggle,
+            pan_inertia_toggle,
             auto_save_filtered_cache_toggle,
             remember_last_directory_toggle,
+            organize_moves_files_toggle,
             remember_filter_state_toggle,
             wrap_navigation_toggle,
             show_image_counter_toggle,
+            right_to_left_toggle,
+            two_page_spread_toggle,
+            spread_cover_alone_toggle,
+            recursive_scan_toggle,
+            double_tap_to_end_toggle,
             file_manager_integration_toggle,
             cache_status: String::new(),
+            settings_file_status: None,
         }
     }
 
     /// Reset all settings to defaults
     pub fn reset_to_defaults(&mut self, cx: &mut Context<Self>) {
-        let defaults = AppSettings::default();
-        self.working_settings = defaults.clone();
+        self.replace_working_settings(AppSettings::default(), cx);
+    }
 
-        // Reset window title input
-        self.window_title_input.update(cx, |input, cx| {
-            input.set_value(&defaults.appearance.window_title_format, cx);
-        });
+    /// Replace every working setting with `settings` and point all the
+    /// widgets at the new values
+    fn replace_working_settings(&mut self, settings: AppSettings, cx: &mut Context<Self>) {
+        self.working_settings = settings.clone();
 
-        // Reset all stepper values
-        self.state_cache_size_stepper.update(cx, |stepper, cx| {
-            stepper.set_value(defaults.viewer_behavior.state_cache_size as f64, cx);
-        });
-        self.filter_processing_threads_stepper
-            .update(cx, |stepper, cx| {
-                stepper.set_value(defaults.performance.filter_processing_threads as f64, cx);
-            });
-        self.max_image_dimension_stepper.update(cx, |stepper, cx| {
-            stepper.set_value(defaults.performance.max_image_dimension as f64, cx);
-        });
-        self.pan_speed_normal_stepper.update(cx, |stepper, cx| {
-            stepper.set_value(defaults.keyboard_mouse.pan_speed_normal.into(), cx);
-        });
-        self.pan_speed_fast_stepper.update(cx, |stepper, cx| {
-            stepper.set_value(defaults.keyboard_mouse.pan_speed_fast.into(), cx);
-        });
-        self.pan_speed_slow_stepper.update(cx, |stepper, cx| {
-            stepper.set_value(defaults.keyboard_mouse.pan_speed_slow.into(), cx);
-        });
-        self.scroll_wheel_sensitivity_stepper
-            .update(cx, |stepper, cx| {
-                stepper.set_value(defaults.keyboard_mouse.scroll_wheel_sensitivity.into(), cx);
-            });
-        self.z_drag_sensitivity_stepper.update(cx, |stepper, cx| {
-            stepper.set_value(defaults.keyboard_mouse.z_drag_sensitivity.into(), cx);
-        });
-        self.overlay_transparency_stepper.update(cx, |stepper, cx| {
-            stepper.set_value(defaults.appearance.overlay_transparency as f64, cx);
-        });
-        self.font_size_scale_stepper.update(cx, |stepper, cx| {
-            stepper.set_value(defaults.appearance.font_size_scale.into(), cx);
-        });
+        for section in SettingsSection::all() {
+            self.reset_section_controls(section, &settings, cx);
+        }
+
+        // Filter settings have no section of their own
         self.default_brightness_stepper.update(cx, |stepper, cx| {
-            stepper.set_value(defaults.filters.default_brightness.into(), cx);
+            stepper.set_value(settings.filters.default_brightness.into(), cx);
         });
         self.default_contrast_stepper.update(cx, |stepper, cx| {
-            stepper.set_value(defaults.filters.default_contrast.into(), cx);
+            stepper.set_value(settings.filters.default_contrast.into(), cx);
         });
         self.default_gamma_stepper.update(cx, |stepper, cx| {
-            stepper.set_value(defaults.filters.default_gamma.into(), cx);
+            stepper.set_value(settings.filters.default_gamma.into(), cx);
         });
-
-        // Reset segmented controls
-        let zoom_value = match defaults.viewer_behavior.default_zoom_mode {
-            ZoomMode::FitToWindow => "fit",
-            ZoomMode::OneHundredPercent => "100",
-        };
-        self.zoom_mode_control.update(cx, |control, cx| {
-            control.set_selected_value(zoom_value, cx);
+        self.default_saturation_stepper.update(cx, |stepper, cx| {
+            stepper.set_value(settings.filters.default_saturation.into(), cx);
         });
-
-        let pan_direction_value = match defaults.keyboard_mouse.pan_direction_mode {
-            PanDirectionMode::MoveImage => "image",
-            PanDirectionMode::MoveViewport => "viewport",
-        };
-        self.pan_direction_mode_control.update(cx, |control, cx| {
-            control.set_selected_value(pan_direction_value, cx);
+        self.remember_filter_state_toggle.update(cx, |toggle, cx| {
+            toggle.set_on(settings.filters.remember_filter_state, cx);
         });
+    }
 
-        let sort_value = match defaults.sort_navigation.default_sort_mode {
-            SortMode::Alphabetical => "alpha",
-            SortMode::ModifiedDate => "date",
-            SortMode::TypeAlpha => "type-alpha",
-            SortMode::TypeModified => "type-date",
+    /// Write the working settings to a file the user picks, for use on
+    /// another machine
+    fn export_settings(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("JSON", &["json"])
+            .set_file_name("rpview-settings.json")
+            .set_title("Export Settings")
+            .save_file()
+        else {
+            return;
         };
-        self.sort_mode_control.update(cx, |control, cx| {
-            control.set_selected_value(sort_value, cx);
-        });
+        self.settings_file_status = Some(
+            match settings_io::save_settings_to_path(&self.working_settings, &path) {
+                Ok(()) => (format!("Exported to {}", path.display()), false),
+                Err(e) => (e, true),
+            },
+        );
+    }
 
-        let format_value = match defaults.file_operations.default_save_format {
-            SaveFormat::SameAsLoaded => "same",
-            SaveFormat::Png => "png",
-            SaveFormat::Jpeg => "jpeg",
-            SaveFormat::Bmp => "bmp",
-            SaveFormat::Tiff => "tiff",
-            SaveFormat::Webp => "webp",
+    /// Replace the working settings with ones from a file the user picks.
+    /// A file that isn't valid settings leaves them as they were.
+    fn import_settings(&mut self, cx: &mut Context<Self>) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("JSON", &["json"])
+            .set_title("Import Settings")
+            .pick_file()
+        else {
+            return;
         };
-        self.save_format_control.update(cx, |control, cx| {
-            control.set_selected_value(format_value, cx);
+        self.settings_file_status = Some(match settings_io::import_settings_from_path(&path) {
+            Ok(settings) => {
+                self.replace_working_settings(settings, cx);
+                (format!("Imported {}", path.display()), false)
+            }
+            Err(e) => {
+                log::warn!("Settings import failed: {}", e);
+                (e, true)
+            }
         });
+    }
 
-        // Reset save location mode (defaults to "same")
-        self.save_location_mode_control.update(cx, |control, cx| {
-            control.set_selected_value("same", cx);
-        });
+    /// Reset only the settings shown in `section`
+    pub fn reset_section(&mut self, section: SettingsSection, cx: &mut Context<Self>) {
+        section.reset_settings(&mut self.working_settings);
+        self.reset_section_controls(section, &AppSettings::default(), cx);
+    }
 
-        // Reset color swatches
-        let bg_dark = &defaults.appearance.background_color_dark;
-        let dark_hex = format!("#{:02x}{:02x}{:02x}", bg_dark[0], bg_dark[1], bg_dark[2]);
-        self.bg_color_dark_swatch.update(cx, |swatch, cx| {
-            swatch.set_value(&dark_hex, cx);
-        });
-        let bg_light = &defaults.appearance.background_color_light;
-        let light_hex = format!("#{:02x}{:02x}{:02x}", bg_light[0], bg_light[1], bg_light[2]);
-        self.bg_color_light_swatch.update(cx, |swatch, cx| {
-            swatch.set_value(&light_hex, cx);
-        });
+    /// Point the widgets shown in `section` at the values in `defaults` (the
+    /// defaults when resetting, imported settings otherwise)
+    fn reset_section_controls(
+        &mut self,
+        section: SettingsSection,
+        defaults: &AppSettings,
+        cx: &mut Context<Self>,
+    ) {
+        match section {
+            SettingsSection::ViewerBehavior => {
+                let zoom_value = match defaults.viewer_behavior.default_zoom_mode {
+                    ZoomMode::FitToWindow => "fit",
+                    ZoomMode::OneHundredPercent => "100",
+                };
+                self.zoom_mode_control.update(cx, |control, cx| {
+                    control.set_selected_value(zoom_value, cx);
+                });
+                self.state_cache_size_stepper.update(cx, |stepper, cx| {
+                    stepper.set_value(defaults.viewer_behavior.state_cache_size as f64, cx);
+                });
+                self.fit_padding_stepper.update(cx, |stepper, cx| {
+                    stepper.set_value(defaults.viewer_behavior.fit_padding as f64, cx);
+                });
+                self.safe_area_stepper.update(cx, |stepper, cx| {
+                    stepper.set_value(defaults.viewer_behavior.safe_area_percent as f64, cx);
+                });
+                self.cursor_hide_delay_stepper.update(cx, |stepper, cx| {
+                    stepper.set_value(defaults.viewer_behavior.cursor_hide_delay_secs as f64, cx);
+                });
+                self.slideshow_interval_stepper.update(cx, |stepper, cx| {
+                    stepper.set_value(defaults.viewer_behavior.slideshow_interval_secs as f64, cx);
+                });
+                self.idle_slideshow_stepper.update(cx, |stepper, cx| {
+                    stepper.set_value(defaults.viewer_behavior.idle_slideshow_secs as f64, cx);
+                });
+                self.frame_step_stepper.update(cx, |stepper, cx| {
+                    stepper.set_value(defaults.viewer_behavior.frame_step as f64, cx);
+                });
+                self.remember_per_image_state_toggle
+                    .update(cx, |toggle, cx| {
+                        toggle.set_on(defaults.viewer_behavior.remember_per_image_state, cx);
+                    });
+                self.animation_auto_play_toggle.update(cx, |toggle, cx| {
+                    toggle.set_on(defaults.viewer_behavior.animation_auto_play, cx);
+                });
+                self.confirm_quit_toggle.update(cx, |toggle, cx| {
+                    toggle.set_on(
+                        defaults.viewer_behavior.confirm_quit_with_unsaved_filters,
+                        cx,
+                    );
+                });
+                self.panel_enables_filters_toggle.update(cx, |toggle, cx| {
+                    toggle.set_on(defaults.filters.panel_enables_filters, cx);
+                });
+                self.auto_exposure_toggle.update(cx, |toggle, cx| {
+                    toggle.set_on(defaults.filters.auto_exposure, cx);
+                });
+                self.auto_hide_cursor_toggle.update(cx, |toggle, cx| {
+                    toggle.set_on(defaults.viewer_behavior.auto_hide_cursor, cx);
+                });
+                self.slideshow_animations_toggle.update(cx, |toggle, cx| {
+                    toggle.set_on(defaults.viewer_behavior.slideshow_let_animations_finish, cx);
+                });
+                self.idle_slideshow_toggle.update(cx, |toggle, cx| {
+                    toggle.set_on(defaults.viewer_behavior.idle_slideshow, cx);
+                });
+                self.honor_pixel_aspect_toggle.update(cx, |toggle, cx| {
+                    toggle.set_on(defaults.viewer_behavior.honor_pixel_aspect, cx);
+                });
+                self.presentation_caption_toggle.update(cx, |toggle, cx| {
+                    toggle.set_on(defaults.viewer_behavior.show_presentation_caption, cx);
+                });
+                self.zoom_cycle_toggle.update(cx, |toggle, cx| {
+                    toggle.set_on(defaults.viewer_behavior.zoom_cycle_enabled, cx);
+                });
+            }
+            SettingsSection::Performance => {
+                self.filter_processing_threads_stepper
+                    .update(cx, |stepper, cx| {
+                        stepper
+                            .set_value(defaults.performance.filter_processing_threads as f64, cx);
+                    });
+                self.max_image_dimension_stepper.update(cx, |stepper, cx| {
+                    stepper.set_value(defaults.performance.max_image_dimension as f64, cx);
+                });
+                self.preload_radius_stepper.update(cx, |stepper, cx| {
+                    stepper.set_value(defaults.performance.preload_radius as f64, cx);
+                });
+                self.preload_adjacent_images_toggle
+                    .update(cx, |toggle, cx| {
+                        toggle.set_on(defaults.performance.preload_adjacent_images, cx);
+                    });
+            }
+            SettingsSection::KeyboardMouse => {
+                self.pan_speed_normal_stepper.update(cx, |stepper, cx| {
+                    stepper.set_value(defaults.keyboard_mouse.pan_speed_normal.into(), cx);
+                });
+                self.pan_speed_fast_stepper.update(cx, |stepper, cx| {
+                    stepper.set_value(defaults.keyboard_mouse.pan_speed_fast.into(), cx);
+                });
+                self.pan_speed_slow_stepper.update(cx, |stepper, cx| {
+                    stepper.set_value(defaults.keyboard_mouse.pan_speed_slow.into(), cx);
+                });
+                self.scroll_wheel_sensitivity_stepper
+                    .update(cx, |stepper, cx| {
+                        stepper
+                            .set_value(defaults.keyboard_mouse.scroll_wheel_sensitivity.into(), cx);
+                    });
+                self.z_drag_sensitivity_stepper.update(cx, |stepper, cx| {
+                    stepper.set_value(defaults.keyboard_mouse.z_drag_sensitivity.into(), cx);
+                });
+                self.crop_grid_stepper.update(cx, |stepper, cx| {
+                    stepper.set_value(defaults.keyboard_mouse.crop_grid as f64, cx);
+                });
+                let pan_direction_value = match defaults.keyboard_mouse.pan_direction_mode {
+                    PanDirectionMode::MoveImage => "image",
+                    PanDirectionMode::MoveViewport => "viewport",
+                };
+                self.pan_direction_mode_control.update(cx, |control, cx| {
+                    control.set_selected_value(pan_direction_value, cx);
+                });
+                self.spacebar_pan_accelerated_toggle
+                    .update(cx, |toggle, cx| {
+                        toggle.set_on(defaults.keyboard_mouse.spacebar_pan_accelerated, cx);
+                    });
+                self.scroll_pans_toggle.update(cx, |toggle, cx| {
+                    toggle.set_on(defaults.keyboard_mouse.scroll_pans, cx);
+                });
+                self.pan_inertia_toggle.update(cx, |toggle, cx| {
+                    toggle.set_on(defaults.keyboard_mouse.pan_inertia, cx);
+                });
+            }
+            SettingsSection::FileOperations => {
+                let format_value = match defaults.file_operations.default_save_format {
+                    SaveFormat::SameAsLoaded => "same",
+                    SaveFormat::Png => "png",
+                    SaveFormat::Jpeg => "jpeg",
+                    SaveFormat::Bmp => "bmp",
+                    SaveFormat::Tiff => "tiff",
+                    SaveFormat::Webp => "webp",
+                };
+                self.save_format_control.update(cx, |control, cx| {
+                    control.set_selected_value(format_value, cx);
+                });
+                let resize_filter_value = match defaults.file_operations.resize_filter {
+                    ResizeFilter::Auto => "auto",
+                    ResizeFilter::Nearest => "nearest",
+                    ResizeFilter::Triangle => "triangle",
+                    ResizeFilter::CatmullRom => "catmull-rom",
+                    ResizeFilter::Lanczos3 => "lanczos3",
+                };
+                self.resize_filter_control.update(cx, |control, cx| {
+                    control.set_selected_value(resize_filter_value, cx);
+                });
 
-        // Reset directory picker (disabled since default is "same as current image")
-        self.default_save_directory_picker.update(cx, |picker, cx| {
-            picker.set_value("", cx);
-            picker.set_enabled(false, cx);
-        });
+                // Save location mode and directory picker (the picker is
+                // disabled for "Same as current image")
+                let save_dir = defaults
+                    .file_operations
+                    .default_save_directory
+                    .as_ref()
+                    .map(|p| p.display().to_string());
+                self.save_location_mode_control.update(cx, |control, cx| {
+                    let mode = if save_dir.is_some() { "custom" } else { "same" };
+                    control.set_selected_value(mode, cx);
+                });
+                self.default_save_directory_picker.update(cx, |picker, cx| {
+                    picker.set_value(save_dir.as_deref().unwrap_or(""), cx);
+                    picker.set_enabled(save_dir.is_some(), cx);
+                });
+                let scratch_dir = defaults
+                    .file_operations
+                    .scratch_directory
+                    .as_ref()
+                    .map(|p| p.display().to_string())
+                    .unwrap_or_default();
+                self.scratch_directory_picker.update(cx, |picker, cx| {
+                    picker.set_value(&scratch_dir, cx);
+                });
 
-        // Reset toggle switches
-        self.remember_per_image_state_toggle
-            .update(cx, |toggle, cx| {
-                toggle.set_on(defaults.viewer_behavior.remember_per_image_state, cx);
-            });
-        self.animation_auto_play_toggle.update(cx, |toggle, cx| {
-            toggle.set_on(defaults.viewer_behavior.animation_auto_play, cx);
-        });
-        self.preload_adjacent_images_toggle
-            .update(cx, |toggle, cx| {
-                toggle.set_on(defaults.performance.preload_adjacent_images, cx);
-            });
-        self.spacebar_pan_accelerated_toggle
-            .update(cx, |toggle, cx| {
-                toggle.set_on(defaults.keyboard_mouse.spacebar_pan_accelerated, cx);
-            });
-        self.auto_save_filtered_cache_toggle
-            .update(cx, |toggle, cx| {
-                toggle.set_on(defaults.file_operations.auto_save_filtered_cache, cx);
-            });
-        self.remember_last_directory_toggle
-            .update(cx, |toggle, cx| {
-                toggle.set_on(defaults.file_operations.remember_last_directory, cx);
-            });
-        self.remember_filter_state_toggle.update(cx, |toggle, cx| {
-            toggle.set_on(defaults.filters.remember_filter_state, cx);
-        });
-        self.wrap_navigation_toggle.update(cx, |toggle, cx| {
-            toggle.set_on(defaults.sort_navigation.wrap_navigation, cx);
-        });
-        self.show_image_counter_toggle.update(cx, |toggle, cx| {
-            toggle.set_on(defaults.sort_navigation.show_image_counter, cx);
-        });
-        self.file_manager_integration_toggle
-            .update(cx, |toggle, cx| {
-                toggle.set_on(defaults.external_tools.enable_file_manager_integration, cx);
-            });
+                self.auto_save_filtered_cache_toggle
+                    .update(cx, |toggle, cx| {
+                        toggle.set_on(defaults.file_operations.auto_save_filtered_cache, cx);
+                    });
+                self.remember_last_directory_toggle
+                    .update(cx, |toggle, cx| {
+                        toggle.set_on(defaults.file_operations.remember_last_directory, cx);
+                    });
+                self.organize_pattern_input.update(cx, |input, cx| {
+                    input.set_value(&defaults.file_operations.organize_pattern, cx);
+                });
+                self.organize_moves_files_toggle.update(cx, |toggle, cx| {
+                    toggle.set_on(defaults.file_operations.organize_moves_files, cx);
+                });
+            }
+            SettingsSection::Appearance => {
+                self.window_title_input.update(cx, |input, cx| {
+                    input.set_value(&defaults.appearance.window_title_format, cx);
+                });
+                self.overlay_transparency_stepper.update(cx, |stepper, cx| {
+                    stepper.set_value(defaults.appearance.overlay_transparency as f64, cx);
+                });
+                self.font_size_scale_stepper.update(cx, |stepper, cx| {
+                    stepper.set_value(defaults.appearance.font_size_scale.into(), cx);
+                });
+                self.high_contrast_toggle.update(cx, |toggle, cx| {
+                    toggle.set_on(defaults.appearance.high_contrast, cx);
+                });
+                let svg_value = match defaults.appearance.svg_background {
+                    SvgBackground::Transparent => "transparent",
+                    SvgBackground::White => "white",
+                    SvgBackground::Black => "black",
+                };
+                self.svg_background_control.update(cx, |control, cx| {
+                    control.set_selected_value(svg_value, cx);
+                });
+
+                // Reset color swatches
+                let bg_dark = &defaults.appearance.background_color_dark;
+                let dark_hex = format!("#{:02x}{:02x}{:02x}", bg_dark[0], bg_dark[1], bg_dark[2]);
+                self.bg_color_dark_swatch.update(cx, |swatch, cx| {
+                    swatch.set_value(&dark_hex, cx);
+                });
+                let bg_light = &defaults.appearance.background_color_light;
+                let light_hex =
+                    format!("#{:02x}{:02x}{:02x}", bg_light[0], bg_light[1], bg_light[2]);
+                self.bg_color_light_swatch.update(cx, |swatch, cx| {
+                    swatch.set_value(&light_hex, cx);
+                });
+            }
+            SettingsSection::SortNavigation => {
+                let sort_value = match defaults.sort_navigation.default_sort_mode {
+                    SortMode::Alphabetical => "alpha",
+                    SortMode::ModifiedDate => "date",
+                    SortMode::TypeAlpha => "type-alpha",
+                    SortMode::TypeModified => "type-date",
+                };
+                self.sort_mode_control.update(cx, |control, cx| {
+                    control.set_selected_value(sort_value, cx);
+                });
+                let end_value = match defaults.sort_navigation.end_of_list {
+                    EndOfList::Stop => "stop",
+                    EndOfList::Notify => "notify",
+                    EndOfList::AdjacentFolder => "folder",
+                };
+                self.end_of_list_control.update(cx, |control, cx| {
+                    control.set_selected_value(end_value, cx);
+                });
+                self.page_stride_stepper.update(cx, |stepper, cx| {
+                    stepper.set_value(defaults.sort_navigation.page_stride as f64, cx);
+                });
+                self.wrap_navigation_toggle.update(cx, |toggle, cx| {
+                    toggle.set_on(defaults.sort_navigation.wrap_navigation, cx);
+                });
+                self.show_image_counter_toggle.update(cx, |toggle, cx| {
+                    toggle.set_on(defaults.sort_navigation.show_image_counter, cx);
+                });
+                self.right_to_left_toggle.update(cx, |toggle, cx| {
+                    toggle.set_on(defaults.sort_navigation.right_to_left, cx);
+                });
+                self.two_page_spread_toggle.update(cx, |toggle, cx| {
+                    toggle.set_on(defaults.sort_navigation.two_page_spread, cx);
+                });
+                self.spread_cover_alone_toggle.update(cx, |toggle, cx| {
+                    toggle.set_on(defaults.sort_navigation.spread_cover_alone, cx);
+                });
+                self.recursive_scan_toggle.update(cx, |toggle, cx| {
+                    toggle.set_on(defaults.sort_navigation.recursive_scan, cx);
+                });
+                self.double_tap_to_end_toggle.update(cx, |toggle, cx| {
+                    toggle.set_on(defaults.sort_navigation.double_tap_to_end, cx);
+                });
+                self.double_tap_secs_stepper.update(cx, |stepper, cx| {
+                    stepper.set_value(defaults.sort_navigation.double_tap_secs as f64, cx);
+                });
+            }
+            SettingsSection::ExternalTools => {
+                self.file_manager_integration_toggle
+                    .update(cx, |toggle, cx| {
+                        toggle.set_on(defaults.external_tools.enable_file_manager_integration, cx);
+                    });
+            }
+            SettingsSection::SettingsFile => {}
+        }
+    }
+
+    /// Emit `AppearanceChanged` if an appearance value changed since the
+    /// last call
+    fn emit_appearance_if_changed(&mut self, cx: &mut Context<Self>) {
+        if let Some(event) = appearance_change(&self.previewed_appearance, &self.working_settings) {
+            let SettingsWindowEvent::AppearanceChanged(preview) = event;
+            self.previewed_appearance = preview;
+            cx.emit(event);
+        }
     }
 
     /// Get the final settings (for apply)
@@ -1029,14 +1833,54 @@ impl SettingsWindow {
             )
     }
 
-    /// Render a section header within the content area
-    fn render_section_header(&self, title: String) -> impl IntoElement {
+    /// Render a section header within the content area, with a "Reset this
+    /// section" button that is dimmed while the section is at its defaults
+    fn render_section_header(
+        &self,
+        section: SettingsSection,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let is_default = section.is_at_defaults(&self.working_settings);
+        let reset_button = div()
+            .id("reset-section")
+            .px(Spacing::sm())
+            .py(Spacing::xs())
+            .rounded(px(4.0))
+            .border_1()
+            .text_size(TextSize::sm())
+            .font_weight(FontWeight::NORMAL);
+        let reset_button = if is_default {
+            reset_button
+                .border_color(rgb(0x444444))
+                .text_color(rgb(0x555555))
+        } else {
+            reset_button
+                .border_color(rgb(0x666666))
+                .text_color(rgb(0xaaaaaa))
+                .cursor_pointer()
+                .hover(|s| s.text_color(Colors::info()))
+                .on_mouse_down(
+                    MouseButton::Left,
+                    cx.listener(move |this, _event: &MouseDownEvent, _window, cx| {
+                        this.reset_section(section, cx);
+                        cx.notify();
+                    }),
+                )
+        };
+
         div()
+            .flex()
+            .flex_row()
+            .items_center()
+            .justify_between()
             .text_size(TextSize::lg())
             .text_color(Colors::text())
             .font_weight(FontWeight::BOLD)
             .mb(Spacing::md())
-            .child(title)
+            .child(section.name())
+            .when(section != SettingsSection::SettingsFile, |el| {
+                el.child(reset_button.child("Reset this section"))
+            })
     }
 
     /// Render a label for a setting
@@ -1203,6 +2047,34 @@ impl SettingsWindow {
             cx,
         );
 
+        let fit_padding_reset = Self::render_reset_button(
+            "reset-fit-padding",
+            self.working_settings.viewer_behavior.fit_padding
+                == defaults.viewer_behavior.fit_padding,
+            |this, _, _, cx| {
+                let d = AppSettings::default().viewer_behavior.fit_padding;
+                this.working_settings.viewer_behavior.fit_padding = d;
+                this.fit_padding_stepper
+                    .update(cx, |s, cx| s.set_value(d as f64, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
+        let safe_area_reset = Self::render_reset_button(
+            "reset-safe-area",
+            self.working_settings.viewer_behavior.safe_area_percent
+                == defaults.viewer_behavior.safe_area_percent,
+            |this, _, _, cx| {
+                let d = AppSettings::default().viewer_behavior.safe_area_percent;
+                this.working_settings.viewer_behavior.safe_area_percent = d;
+                this.safe_area_stepper
+                    .update(cx, |s, cx| s.set_value(d as f64, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
         let auto_play_reset = Self::render_reset_button(
             "reset-animation-auto-play",
             self.working_settings.viewer_behavior.animation_auto_play
@@ -1217,10 +2089,221 @@ impl SettingsWindow {
             cx,
         );
 
+        let panel_enables_filters_reset = Self::render_reset_button(
+            "reset-panel-enables-filters",
+            self.working_settings.filters.panel_enables_filters
+                == defaults.filters.panel_enables_filters,
+            |this, _, _, cx| {
+                let d = AppSettings::default().filters.panel_enables_filters;
+                this.working_settings.filters.panel_enables_filters = d;
+                this.panel_enables_filters_toggle
+                    .update(cx, |t, cx| t.set_on(d, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
+        let auto_exposure_reset = Self::render_reset_button(
+            "reset-auto-exposure",
+            self.working_settings.filters.auto_exposure == defaults.filters.auto_exposure,
+            |this, _, _, cx| {
+                let d = AppSettings::default().filters.auto_exposure;
+                this.working_settings.filters.auto_exposure = d;
+                this.auto_exposure_toggle
+                    .update(cx, |t, cx| t.set_on(d, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
+        let confirm_quit_reset = Self::render_reset_button(
+            "reset-confirm-quit",
+            self.working_settings
+                .viewer_behavior
+                .confirm_quit_with_unsaved_filters
+                == defaults.viewer_behavior.confirm_quit_with_unsaved_filters,
+            |this, _, _, cx| {
+                let d = AppSettings::default()
+                    .viewer_behavior
+                    .confirm_quit_with_unsaved_filters;
+                this.working_settings
+                    .viewer_behavior
+                    .confirm_quit_with_unsaved_filters = d;
+                this.confirm_quit_toggle.update(cx, |t, cx| t.set_on(d, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
+        let auto_hide_cursor_reset = Self::render_reset_button(
+            "reset-auto-hide-cursor",
+            self.working_settings.viewer_behavior.auto_hide_cursor
+                == defaults.viewer_behavior.auto_hide_cursor,
+            |this, _, _, cx| {
+                let d = AppSettings::default().viewer_behavior.auto_hide_cursor;
+                this.working_settings.viewer_behavior.auto_hide_cursor = d;
+                this.auto_hide_cursor_toggle
+                    .update(cx, |t, cx| t.set_on(d, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
+        let pixel_aspect_reset = Self::render_reset_button(
+            "reset-honor-pixel-aspect",
+            self.working_settings.viewer_behavior.honor_pixel_aspect
+                == defaults.viewer_behavior.honor_pixel_aspect,
+            |this, _, _, cx| {
+                let d = AppSettings::default().viewer_behavior.honor_pixel_aspect;
+                this.working_settings.viewer_behavior.honor_pixel_aspect = d;
+                this.honor_pixel_aspect_toggle
+                    .update(cx, |t, cx| t.set_on(d, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
+        let presentation_caption_reset = Self::render_reset_button(
+            "reset-presentation-caption",
+            self.working_settings
+                .viewer_behavior
+                .show_presentation_caption
+                == defaults.viewer_behavior.show_presentation_caption,
+            |this, _, _, cx| {
+                let d = AppSettings::default()
+                    .viewer_behavior
+                    .show_presentation_caption;
+                this.working_settings
+                    .viewer_behavior
+                    .show_presentation_caption = d;
+                this.presentation_caption_toggle
+                    .update(cx, |t, cx| t.set_on(d, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
+        let zoom_cycle_reset = Self::render_reset_button(
+            "reset-zoom-cycle",
+            self.working_settings.viewer_behavior.zoom_cycle_enabled
+                == defaults.viewer_behavior.zoom_cycle_enabled,
+            |this, _, _, cx| {
+                let d = AppSettings::default().viewer_behavior.zoom_cycle_enabled;
+                this.working_settings.viewer_behavior.zoom_cycle_enabled = d;
+                this.zoom_cycle_toggle.update(cx, |t, cx| t.set_on(d, cx));
+                cx.notify();
+            },
+            cx,
+        );
+        let zoom_cycle_description = format!(
+            "Press 0 to step fit \u{2192} {} \u{2192} fit instead of toggling fit / 100% \
+             (stops are set in settings.json)",
+            self.working_settings
+                .viewer_behavior
+                .zoom_cycle_stops
+                .iter()
+                .map(|stop| crate::utils::zoom::format_zoom_percentage(*stop))
+                .collect::<Vec<_>>()
+                .join(" \u{2192} ")
+        );
+
+        let cursor_delay_reset = Self::render_reset_button(
+            "reset-cursor-hide-delay",
+            self.working_settings.viewer_behavior.cursor_hide_delay_secs
+                == defaults.viewer_behavior.cursor_hide_delay_secs,
+            |this, _, _, cx| {
+                let d = AppSettings::default()
+                    .viewer_behavior
+                    .cursor_hide_delay_secs;
+                this.working_settings.viewer_behavior.cursor_hide_delay_secs = d;
+                this.cursor_hide_delay_stepper
+                    .update(cx, |s, cx| s.set_value(d as f64, cx));
+                cx.notify();
+            },
+            cx,
+        );
+        let slideshow_interval_reset = Self::render_reset_button(
+            "reset-slideshow-interval",
+            self.working_settings
+                .viewer_behavior
+                .slideshow_interval_secs
+                == defaults.viewer_behavior.slideshow_interval_secs,
+            |this, _, _, cx| {
+                let d = AppSettings::default()
+                    .viewer_behavior
+                    .slideshow_interval_secs;
+                this.working_settings
+                    .viewer_behavior
+                    .slideshow_interval_secs = d;
+                this.slideshow_interval_stepper
+                    .update(cx, |s, cx| s.set_value(d as f64, cx));
+                cx.notify();
+            },
+            cx,
+        );
+        let slideshow_animations_reset = Self::render_reset_button(
+            "reset-slideshow-animations",
+            self.working_settings
+                .viewer_behavior
+                .slideshow_let_animations_finish
+                == defaults.viewer_behavior.slideshow_let_animations_finish,
+            |this, _, _, cx| {
+                let d = AppSettings::default()
+                    .viewer_behavior
+                    .slideshow_let_animations_finish;
+                this.working_settings
+                    .viewer_behavior
+                    .slideshow_let_animations_finish = d;
+                this.slideshow_animations_toggle
+                    .update(cx, |t, cx| t.set_on(d, cx));
+                cx.notify();
+            },
+            cx,
+        );
+        let idle_slideshow_reset = Self::render_reset_button(
+            "reset-idle-slideshow",
+            self.working_settings.viewer_behavior.idle_slideshow
+                == defaults.viewer_behavior.idle_slideshow,
+            |this, _, _, cx| {
+                let d = AppSettings::default().viewer_behavior.idle_slideshow;
+                this.working_settings.viewer_behavior.idle_slideshow = d;
+                this.idle_slideshow_toggle
+                    .update(cx, |t, cx| t.set_on(d, cx));
+                cx.notify();
+            },
+            cx,
+        );
+        let idle_slideshow_delay_reset = Self::render_reset_button(
+            "reset-idle-slideshow-delay",
+            self.working_settings.viewer_behavior.idle_slideshow_secs
+                == defaults.viewer_behavior.idle_slideshow_secs,
+            |this, _, _, cx| {
+                let d = AppSettings::default().viewer_behavior.idle_slideshow_secs;
+                this.working_settings.viewer_behavior.idle_slideshow_secs = d;
+                this.idle_slideshow_stepper
+                    .update(cx, |s, cx| s.set_value(d as f64, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
+        let frame_step_reset = Self::render_reset_button(
+            "reset-frame-step",
+            self.working_settings.viewer_behavior.frame_step == defaults.viewer_behavior.frame_step,
+            |this, _, _, cx| {
+                let d = AppSettings::default().viewer_behavior.frame_step;
+                this.working_settings.viewer_behavior.frame_step = d;
+                this.frame_step_stepper
+                    .update(cx, |s, cx| s.set_value(d as f64, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
         div()
             .flex()
             .flex_col()
-            .child(self.render_section_header("Viewer Behavior".to_string()))
+            .child(self.render_section_header(SettingsSection::ViewerBehavior, cx))
             .child(
                 div()
                     .flex()
@@ -1240,6 +2323,24 @@ impl SettingsWindow {
                             .child(self.zoom_mode_control.clone()),
                     ),
             )
+            .child(self.render_stepper_row(
+                "Fit padding".to_string(),
+                Some("Margin in pixels around the image when fit to window".to_string()),
+                &self.fit_padding_stepper,
+                fit_padding_reset,
+            ))
+            .child(
+                self.render_stepper_row(
+                    "Safe area (%)".to_string(),
+                    Some(
+                        "Inset from each screen edge when fit in fullscreen or a slideshow, \
+                     for projectors that overscan"
+                            .to_string(),
+                    ),
+                    &self.safe_area_stepper,
+                    safe_area_reset,
+                ),
+            )
             .child(self.render_toggle_row(
                 Some("Remember zoom, pan, and filters for each image".to_string()),
                 &self.remember_per_image_state_toggle,
@@ -1256,6 +2357,92 @@ impl SettingsWindow {
                 &self.animation_auto_play_toggle,
                 auto_play_reset,
             ))
+            .child(self.render_toggle_row(
+                Some("Ask before quitting while filter edits are unsaved".to_string()),
+                &self.confirm_quit_toggle,
+                confirm_quit_reset,
+            ))
+            .child(self.render_toggle_row(
+                Some("Turn filters back on when showing the filter panel (cmd-f)".to_string()),
+                &self.panel_enables_filters_toggle,
+                panel_enables_filters_reset,
+            ))
+            .child(
+                self.render_toggle_row(
+                    Some(
+                        "Brighten very dark and darken very bright images for viewing (alt-e)"
+                            .to_string(),
+                    ),
+                    &self.auto_exposure_toggle,
+                    auto_exposure_reset,
+                ),
+            )
+            .child(self.render_toggle_row(
+                Some("Hide the mouse cursor in fullscreen and slideshows when it stops moving".to_string()),
+                &self.auto_hide_cursor_toggle,
+                auto_hide_cursor_reset,
+            ))
+            .child(self.render_stepper_row(
+                "Cursor hide delay".to_string(),
+                Some("Seconds without mouse movement before the cursor hides".to_string()),
+                &self.cursor_hide_delay_stepper,
+                cursor_delay_reset,
+            ))
+            .child(
+                self.render_toggle_row(
+                    Some(
+                        "Stretch images whose metadata records non-square pixels to their \
+                     intended shape"
+                            .to_string(),
+                    ),
+                    &self.honor_pixel_aspect_toggle,
+                    pixel_aspect_reset,
+                ),
+            )
+            .child(
+                self.render_toggle_row(
+                    Some(
+                        "Briefly show the filename in large text when each image appears \
+                     in fullscreen"
+                            .to_string(),
+                    ),
+                    &self.presentation_caption_toggle,
+                    presentation_caption_reset,
+                ),
+            )
+            .child(self.render_toggle_row(
+                Some(zoom_cycle_description),
+                &self.zoom_cycle_toggle,
+                zoom_cycle_reset,
+            ))
+            .child(self.render_stepper_row(
+                "Slideshow interval".to_string(),
+                Some("Seconds each image stays up while the slideshow (P) runs".to_string()),
+                &self.slideshow_interval_stepper,
+                slideshow_interval_reset,
+            ))
+            .child(self.render_toggle_row(
+                Some("Stay on an animation until it has played as many times as the file asks; ones that loop forever still move on at the interval".to_string()),
+                &self.slideshow_animations_toggle,
+                slideshow_animations_reset,
+            ))
+            .child(self.render_toggle_row(
+                Some("Run the slideshow by itself after a while without input, like a photo frame; any input stops it".to_string()),
+                &self.idle_slideshow_toggle,
+                idle_slideshow_reset,
+            ))
+            .child(self.render_stepper_row(
+                "Idle slideshow delay".to_string(),
+                Some("Seconds without keyboard or mouse input before the slideshow starts".to_string()),
+                &self.idle_slideshow_stepper,
+                idle_slideshow_delay_reset,
+            ))
+            .child(self.render_stepper_row(
+                "Frame jump size".to_string(),
+                Some("Frames or pages skipped by Shift+[ / Shift+]".to_string()),
+                &self.frame_step_stepper,
+                frame_step_reset,
+            ))
     }
 
     /// Render performance section
@@ -1276,6 +2463,19 @@ impl SettingsWindow {
             cx,
         );
 
+        let preload_radius_reset = Self::render_reset_button(
+            "reset-preload-radius",
+            self.working_settings.performance.preload_radius == defaults.performance.preload_radius,
+            |this, _, _, cx| {
+                let d = AppSettings::default().performance.preload_radius;
+                this.working_settings.performance.preload_radius = d;
+                this.preload_radius_stepper
+                    .update(cx, |s, cx| s.set_value(d as f64, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
         let threads_reset = Self::render_reset_button(
             "reset-threads",
             self.working_settings.performance.filter_processing_threads
@@ -1307,12 +2507,24 @@ impl SettingsWindow {
         div()
             .flex()
             .flex_col()
-            .child(self.render_section_header("Performance".to_string()))
+            .child(self.render_section_header(SettingsSection::Performance, cx))
             .child(self.render_toggle_row(
                 Some("Load next/previous images in background for faster navigation".to_string()),
                 &self.preload_adjacent_images_toggle,
                 preload_reset,
             ))
+            .child(
+                self.render_stepper_row(
+                    "Preload radius".to_string(),
+                    Some(
+                        "Images to preload on each side; more is snappier but uses more \
+                     memory (reduced automatically for very large images)"
+                            .to_string(),
+                    ),
+                    &self.preload_radius_stepper,
+                    preload_radius_reset,
+                ),
+            )
             .child(self.render_stepper_row(
                 "Filter processing threads".to_string(),
                 Some("Number of CPU threads for filter processing".to_string()),
@@ -1480,6 +2692,19 @@ impl SettingsWindow {
             cx,
         );
 
+        let crop_grid_reset = Self::render_reset_button(
+            "reset-crop-grid",
+            self.working_settings.keyboard_mouse.crop_grid == defaults.keyboard_mouse.crop_grid,
+            |this, _, _, cx| {
+                let d = AppSettings::default().keyboard_mouse.crop_grid;
+                this.working_settings.keyboard_mouse.crop_grid = d;
+                this.crop_grid_stepper
+                    .update(cx, |s, cx| s.set_value(d as f64, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
         let spacebar_reset = Self::render_reset_button(
             "reset-spacebar-accel",
             self.working_settings
@@ -1500,10 +2725,34 @@ impl SettingsWindow {
             cx,
         );
 
+        let scroll_pans_reset = Self::render_reset_button(
+            "reset-scroll-pans",
+            self.working_settings.keyboard_mouse.scroll_pans == defaults.keyboard_mouse.scroll_pans,
+            |this, _, _, cx| {
+                let d = AppSettings::default().keyboard_mouse.scroll_pans;
+                this.working_settings.keyboard_mouse.scroll_pans = d;
+                this.scroll_pans_toggle.update(cx, |t, cx| t.set_on(d, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
+        let pan_inertia_reset = Self::render_reset_button(
+            "reset-pan-inertia",
+            self.working_settings.keyboard_mouse.pan_inertia == defaults.keyboard_mouse.pan_inertia,
+            |this, _, _, cx| {
+                let d = AppSettings::default().keyboard_mouse.pan_inertia;
+                this.working_settings.keyboard_mouse.pan_inertia = d;
+                this.pan_inertia_toggle.update(cx, |t, cx| t.set_on(d, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
         div()
             .flex()
             .flex_col()
-            .child(self.render_section_header("Keyboard & Mouse".to_string()))
+            .child(self.render_section_header(SettingsSection::KeyboardMouse, cx))
             .child(
                 div()
                     .flex()
@@ -1553,11 +2802,33 @@ impl SettingsWindow {
                 &self.z_drag_sensitivity_stepper,
                 zdrag_reset,
             ))
+            .child(self.render_stepper_row(
+                "Crop grid".to_string(),
+                Some("Crop selection edges snap to multiples of this many pixels (0 = off)".to_string()),
+                &self.crop_grid_stepper,
+                crop_grid_reset,
+            ))
             .child(self.render_toggle_row(
                 Some("Enable acceleration for spacebar+mouse panning".to_string()),
                 &self.spacebar_pan_accelerated_toggle,
                 spacebar_reset,
             ))
+            .child(self.render_toggle_row(
+                Some(
+                    "Scroll pans the image up/down (Shift+scroll: sideways); \
+                     Cmd/Ctrl+scroll still zooms"
+                        .to_string(),
+                ),
+                &self.scroll_pans_toggle,
+                scroll_pans_reset,
+            ))
+            .child(self.render_toggle_row(
+                Some(
+                    "A drag-pan let go while moving keeps gliding and slows to a stop".to_string(),
+                ),
+                &self.pan_inertia_toggle,
+                pan_inertia_reset,
+            ))
     }
 
     /// Render file operations section
@@ -1618,6 +2889,27 @@ impl SettingsWindow {
             cx,
         );
 
+        let resize_filter_reset = Self::render_reset_button(
+            "reset-resize-filter",
+            self.working_settings.file_operations.resize_filter
+                == defaults.file_operations.resize_filter,
+            |this, _, _, cx| {
+                let d = AppSettings::default().file_operations.resize_filter;
+                this.working_settings.file_operations.resize_filter = d;
+                let v = match d {
+                    ResizeFilter::Auto => "auto",
+                    ResizeFilter::Nearest => "nearest",
+                    ResizeFilter::Triangle => "triangle",
+                    ResizeFilter::CatmullRom => "catmull-rom",
+                    ResizeFilter::Lanczos3 => "lanczos3",
+                };
+                this.resize_filter_control
+                    .update(cx, |c, cx| c.set_selected_value(v, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
         let auto_save_reset = Self::render_reset_button(
             "reset-auto-save-cache",
             self.working_settings
@@ -1638,6 +2930,34 @@ impl SettingsWindow {
             cx,
         );
 
+        let scratch_dir_reset = Self::render_reset_button(
+            "reset-scratch-directory",
+            self.working_settings.file_operations.scratch_directory
+                == defaults.file_operations.scratch_directory,
+            |this, _, _, cx| {
+                this.working_settings.file_operations.scratch_directory = None;
+                this.scratch_directory_picker
+                    .update(cx, |p, cx| p.set_value("", cx));
+                cx.notify();
+            },
+            cx,
+        );
+
+        // Describe where temp files will actually go, flagging an unusable choice
+        let scratch_text = match self.working_settings.file_operations.scratch_directory {
+            Some(ref dir) if crate::utils::scratch::is_writable_dir(dir) => {
+                format!("Temporary files go to \"{}\"", dir.display())
+            }
+            Some(_) => format!(
+                "Not a writable directory \u{2014} using \"{}\" instead",
+                std::env::temp_dir().display()
+            ),
+            None => format!(
+                "Temporary files go to \"{}\"",
+                std::env::temp_dir().display()
+            ),
+        };
+
         let remember_dir_reset = Self::render_reset_button(
             "reset-remember-dir",
             self.working_settings
@@ -1658,10 +2978,38 @@ impl SettingsWindow {
             cx,
         );
 
+        let organize_pattern_reset = Self::render_reset_button(
+            "reset-organize-pattern",
+            self.working_settings.file_operations.organize_pattern
+                == defaults.file_operations.organize_pattern,
+            |this, _, _, cx| {
+                let d = AppSettings::default().file_operations.organize_pattern;
+                this.working_settings.file_operations.organize_pattern = d.clone();
+                this.organize_pattern_input
+                    .update(cx, |i, cx| i.set_value(&d, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
+        let organize_moves_reset = Self::render_reset_button(
+            "reset-organize-moves",
+            self.working_settings.file_operations.organize_moves_files
+                == defaults.file_operations.organize_moves_files,
+            |this, _, _, cx| {
+                let d = AppSettings::default().file_operations.organize_moves_files;
+                this.working_settings.file_operations.organize_moves_files = d;
+                this.organize_moves_files_toggle
+                    .update(cx, |t, cx| t.set_on(d, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
         div()
             .flex()
             .flex_col()
-            .child(self.render_section_header("File Operations".to_string()))
+            .child(self.render_section_header(SettingsSection::FileOperations, cx))
             .child(
                 div()
                     .flex()
@@ -1716,16 +3064,97 @@ impl SettingsWindow {
                             .child(self.save_format_control.clone()),
                     ),
             )
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_start()
+                    .gap(Spacing::sm())
+                    .mb(Spacing::md())
+                    .child(resize_filter_reset)
+                    .child(
+                        div()
+                            .flex()
+                            .flex_col()
+                            .child(self.render_label(
+                                "Resize filter".to_string(),
+                                Some(
+                                    "Resampling for Save / Copy Visible Area: Nearest for pixel art, Lanczos3 for photos"
+                                        .to_string(),
+                                ),
+                            ))
+                            .child(self.resize_filter_control.clone()),
+                    ),
+            )
             .child(self.render_toggle_row(
                 Some("Permanently save filtered image cache to disk".to_string()),
                 &self.auto_save_filtered_cache_toggle,
                 auto_save_reset,
             ))
             .child(self.render_toggle_row(
-                Some("Remember last used directory in file dialogs".to_string()),
+                Some("Reopen the last browsed folder when started without a path".to_string()),
                 &self.remember_last_directory_toggle,
                 remember_dir_reset,
             ))
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_start()
+                    .gap(Spacing::sm())
+                    .mb(Spacing::md())
+                    .child(organize_pattern_reset)
+                    .child(
+                        div()
+                            .flex()
+                            .flex_col()
+                            .child(self.render_label(
+                                "Organize by Date pattern".to_string(),
+                                Some(
+                                    "Template: {year}, {month}, {day}, {hour}, {minute}, {second}, {filename}, {stem}, {ext}"
+                                        .to_string(),
+                                ),
+                            ))
+                            .child(self.organize_pattern_input.clone()),
+                    ),
+            )
+            .child(self.render_toggle_row(
+                Some("Move files instead of copying them when organizing".to_string()),
+                &self.organize_moves_files_toggle,
+                organize_moves_reset,
+            ))
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_start()
+                    .gap(Spacing::sm())
+                    .mb(Spacing::md())
+                    .child(scratch_dir_reset)
+                    .child(
+                        div()
+                            .flex()
+                            .flex_col()
+                            .child(
+                                self.render_label(
+                                    "Scratch directory".to_string(),
+                                    Some(
+                                        "Where temporary files (SVG rasterizations) are written"
+                                            .to_string(),
+                                    ),
+                                ),
+                            )
+                            .child(self.scratch_directory_picker.clone())
+                            .child(
+                                div()
+                                    .text_size(TextSize::sm())
+                                    .text_color(rgb(0x888888))
+                                    .italic()
+                                    .mt(Spacing::sm())
+                                    .child(scratch_text),
+                            ),
+                    ),
+            )
     }
 
     /// Render appearance section
@@ -1789,6 +3218,37 @@ impl SettingsWindow {
             cx,
         );
 
+        let high_contrast_reset = Self::render_reset_button(
+            "reset-high-contrast",
+            self.working_settings.appearance.high_contrast == defaults.appearance.high_contrast,
+            |this, _, _, cx| {
+                let d = AppSettings::default().appearance.high_contrast;
+                this.working_settings.appearance.high_contrast = d;
+                this.high_contrast_toggle
+                    .update(cx, |t, cx| t.set_on(d, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
+        let svg_background_reset = Self::render_reset_button(
+            "reset-svg-background",
+            self.working_settings.appearance.svg_background == defaults.appearance.svg_background,
+            |this, _, _, cx| {
+                let d = AppSettings::default().appearance.svg_background;
+                this.working_settings.appearance.svg_background = d;
+                let v = match d {
+                    SvgBackground::Transparent => "transparent",
+                    SvgBackground::White => "white",
+                    SvgBackground::Black => "black",
+                };
+                this.svg_background_control
+                    .update(cx, |c, cx| c.set_selected_value(v, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
         let title_reset = Self::render_reset_button(
             "reset-window-title",
             self.working_settings.appearance.window_title_format
@@ -1806,7 +3266,7 @@ impl SettingsWindow {
         div()
             .flex()
             .flex_col()
-            .child(self.render_section_header("Appearance".to_string()))
+            .child(self.render_section_header(SettingsSection::Appearance, cx))
             .child(
                 div()
                     .flex()
@@ -1862,6 +3322,36 @@ impl SettingsWindow {
                 &self.font_size_scale_stepper,
                 font_scale_reset,
             ))
+            .child(self.render_toggle_row(
+                Some(
+                    "White-on-black overlays and help screen with bold borders, for readability"
+                        .to_string(),
+                ),
+                &self.high_contrast_toggle,
+                high_contrast_reset,
+            ))
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_start()
+                    .gap(Spacing::sm())
+                    .mb(Spacing::md())
+                    .child(svg_background_reset)
+                    .child(
+                        div()
+                            .flex()
+                            .flex_col()
+                            .child(self.render_label(
+                                "SVG background".to_string(),
+                                Some(
+                                    "What SVGs are drawn onto, on screen and when saved"
+                                        .to_string(),
+                                ),
+                            ))
+                            .child(self.svg_background_control.clone()),
+                    ),
+            )
             .child(
                 div()
                     .flex()
@@ -1878,7 +3368,7 @@ impl SettingsWindow {
                                 self.render_label(
                                     "Window title format".to_string(),
                                     Some(
-                                        "Template: {filename}, {index}, {total}, {sm}, {sortmode}"
+                                        "Template: {filename}, {index}, {total}, {sm}, {sortmode}, {zoom}, {dimensions}"
                                             .to_string(),
                                     ),
                                 ),
@@ -1926,6 +3416,25 @@ impl SettingsWindow {
             cx,
         );
 
+        let end_of_list_reset = Self::render_reset_button(
+            "reset-end-of-list",
+            self.working_settings.sort_navigation.end_of_list
+                == defaults.sort_navigation.end_of_list,
+            |this, _, _, cx| {
+                let d = AppSettings::default().sort_navigation.end_of_list;
+                this.working_settings.sort_navigation.end_of_list = d;
+                let v = match d {
+                    EndOfList::Stop => "stop",
+                    EndOfList::Notify => "notify",
+                    EndOfList::AdjacentFolder => "folder",
+                };
+                this.end_of_list_control
+                    .update(cx, |c, cx| c.set_selected_value(v, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
         let counter_reset = Self::render_reset_button(
             "reset-show-counter",
             self.working_settings.sort_navigation.show_image_counter
@@ -1940,10 +3449,108 @@ impl SettingsWindow {
             cx,
         );
 
+        let right_to_left_reset = Self::render_reset_button(
+            "reset-right-to-left",
+            self.working_settings.sort_navigation.right_to_left
+                == defaults.sort_navigation.right_to_left,
+            |this, _, _, cx| {
+                let d = AppSettings::default().sort_navigation.right_to_left;
+                this.working_settings.sort_navigation.right_to_left = d;
+                this.right_to_left_toggle
+                    .update(cx, |t, cx| t.set_on(d, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
+        let two_page_spread_reset = Self::render_reset_button(
+            "reset-two-page-spread",
+            self.working_settings.sort_navigation.two_page_spread
+                == defaults.sort_navigation.two_page_spread,
+            |this, _, _, cx| {
+                let d = AppSettings::default().sort_navigation.two_page_spread;
+                this.working_settings.sort_navigation.two_page_spread = d;
+                this.two_page_spread_toggle
+                    .update(cx, |t, cx| t.set_on(d, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
+        let cover_alone_reset = Self::render_reset_button(
+            "reset-spread-cover-alone",
+            self.working_settings.sort_navigation.spread_cover_alone
+                == defaults.sort_navigation.spread_cover_alone,
+            |this, _, _, cx| {
+                let d = AppSettings::default().sort_navigation.spread_cover_alone;
+                this.working_settings.sort_navigation.spread_cover_alone = d;
+                this.spread_cover_alone_toggle
+                    .update(cx, |t, cx| t.set_on(d, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
+        let recursive_scan_reset = Self::render_reset_button(
+            "reset-recursive-scan",
+            self.working_settings.sort_navigation.recursive_scan
+                == defaults.sort_navigation.recursive_scan,
+            |this, _, _, cx| {
+                let d = AppSettings::default().sort_navigation.recursive_scan;
+                this.working_settings.sort_navigation.recursive_scan = d;
+                this.recursive_scan_toggle
+                    .update(cx, |t, cx| t.set_on(d, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
+        let double_tap_reset = Self::render_reset_button(
+            "reset-double-tap-to-end",
+            self.working_settings.sort_navigation.double_tap_to_end
+                == defaults.sort_navigation.double_tap_to_end,
+            |this, _, _, cx| {
+                let d = AppSettings::default().sort_navigation.double_tap_to_end;
+                this.working_settings.sort_navigation.double_tap_to_end = d;
+                this.double_tap_to_end_toggle
+                    .update(cx, |t, cx| t.set_on(d, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
+        let double_tap_secs_reset = Self::render_reset_button(
+            "reset-double-tap-secs",
+            self.working_settings.sort_navigation.double_tap_secs
+                == defaults.sort_navigation.double_tap_secs,
+            |this, _, _, cx| {
+                let d = AppSettings::default().sort_navigation.double_tap_secs;
+                this.working_settings.sort_navigation.double_tap_secs = d;
+                this.double_tap_secs_stepper
+                    .update(cx, |s, cx| s.set_value(d as f64, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
+        let page_stride_reset = Self::render_reset_button(
+            "reset-page-stride",
+            self.working_settings.sort_navigation.page_stride
+                == defaults.sort_navigation.page_stride,
+            |this, _, _, cx| {
+                let d = AppSettings::default().sort_navigation.page_stride;
+                this.working_settings.sort_navigation.page_stride = d;
+                this.page_stride_stepper
+                    .update(cx, |s, cx| s.set_value(d as f64, cx));
+                cx.notify();
+            },
+            cx,
+        );
+
         div()
             .flex()
             .flex_col()
-            .child(self.render_section_header("Sort & Navigation".to_string()))
+            .child(self.render_section_header(SettingsSection::SortNavigation, cx))
             .child(
                 div()
                     .flex()
@@ -1968,11 +3575,70 @@ impl SettingsWindow {
                 &self.wrap_navigation_toggle,
                 wrap_nav_reset,
             ))
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_start()
+                    .gap(Spacing::sm())
+                    .mb(Spacing::md())
+                    .child(end_of_list_reset)
+                    .child(
+                        div()
+                            .flex()
+                            .flex_col()
+                            .child(self.render_label(
+                                "At the end of the list".to_string(),
+                                Some(
+                                    "When not wrapping: stay put, say so, or open the next folder beside this one"
+                                        .to_string(),
+                                ),
+                            ))
+                            .child(self.end_of_list_control.clone()),
+                    ),
+            )
             .child(self.render_toggle_row(
                 Some("Display image position in window title".to_string()),
                 &self.show_image_counter_toggle,
                 counter_reset,
             ))
+            .child(self.render_stepper_row(
+                "Page jump size".to_string(),
+                Some("Images skipped by Page Up / Page Down".to_string()),
+                &self.page_stride_stepper,
+                page_stride_reset,
+            ))
+            .child(self.render_toggle_row(
+                Some("Tap an arrow twice quickly to jump to the first or last image".to_string()),
+                &self.double_tap_to_end_toggle,
+                double_tap_reset,
+            ))
+            .child(self.render_stepper_row(
+                "Double-tap window (seconds)".to_string(),
+                Some("Most time between the two taps".to_string()),
+                &self.double_tap_secs_stepper,
+                double_tap_secs_reset,
+            ))
+            .child(self.render_toggle_row(
+                Some("Left arrow advances, for manga".to_string()),
+                &self.right_to_left_toggle,
+                right_to_left_reset,
+            ))
+            .child(self.render_toggle_row(
+                Some("Show facing pages side by side".to_string()),
+                &self.two_page_spread_toggle,
+                two_page_spread_reset,
+            ))
+            .child(self.render_toggle_row(
+                Some("Pair the pages after the first, as printed".to_string()),
+                &self.spread_cover_alone_toggle,
+                cover_alone_reset,
+            ))
+            .child(self.render_toggle_row(
+                Some("Opening a folder also shows the images in its subfolders".to_string()),
+                &self.recursive_scan_toggle,
+                recursive_scan_reset,
+            ))
     }
 
     /// Render external tools section
@@ -2008,7 +3674,7 @@ impl SettingsWindow {
         div()
             .flex()
             .flex_col()
-            .child(self.render_section_header("External Tools".to_string()))
+            .child(self.render_section_header(SettingsSection::ExternalTools, cx))
             .child(
                 div()
                     .flex()
@@ -2146,7 +3812,7 @@ impl SettingsWindow {
             .flex()
             .flex_col()
             .max_w_full() // Ensure we don't exceed parent width
-            .child(self.render_section_header("Settings File".to_string()))
+            .child(self.render_section_header(SettingsSection::SettingsFile, cx))
             .child(
                 div()
                     .flex()
@@ -2225,31 +3891,8 @@ impl SettingsWindow {
                                 cx.listener(move |_this, _event: &MouseDownEvent, _window, _cx| {
                                     // Reveal settings file in file manager
                                     let path = settings_io::get_settings_path();
-                                    #[cfg(target_os = "macos")]
-                                    {
-                                        std::process::Command::new("open")
-                                            .arg("-R")
-                                            .arg(&path)
-                                            .spawn()
-                                            .ok();
-                                    }
-                                    #[cfg(target_os = "windows")]
-                                    {
-                                        std::process::Command::new("explorer")
-                                            .arg("/select,")
-                                            .arg(&path)
-                                            .spawn()
-                                            .ok();
-                                    }
-                                    #[cfg(target_os = "linux")]
-                                    {
-                                        // Try to get the parent directory
-                                        if let Some(parent) = path.parent() {
-                                            std::process::Command::new("xdg-open")
-                                                .arg(parent)
-                                                .spawn()
-                                                .ok();
-                                        }
+                                    if let Err(e) = reveal::reveal_in_file_manager(&path) {
+                                        log::error!("Failed to reveal settings file: {}", e);
                                     }
                                 }),
                             )
@@ -2291,6 +3934,58 @@ impl SettingsWindow {
                             .child("Reset all settings to Defaults"),
                     ),
             )
+            .child(
+                div()
+                    .mb(Spacing::md())
+                    .flex()
+                    .flex_row()
+                    .gap(Spacing::md())
+                    .children(
+                        [("Export settings…", false), ("Import settings…", true)].map(
+                            |(label, import)| {
+                                div()
+                                    .px(Spacing::md())
+                                    .py(Spacing::sm())
+                                    .bg(rgb(0x444444))
+                                    .rounded(px(4.0))
+                                    .text_size(TextSize::sm())
+                                    .text_color(Colors::text())
+                                    .font_weight(FontWeight::BOLD)
+                                    .cursor_pointer()
+                                    .on_mouse_down(
+                                        MouseButton::Left,
+                                        cx.listener(
+                                            move |this, _event: &MouseDownEvent, _window, cx| {
+                                                if import {
+                                                    this.import_settings(cx);
+                                                } else {
+                                                    this.export_settings();
+                                                }
+                                                cx.notify();
+                                            },
+                                        ),
+                                    )
+                                    .child(label)
+                            },
+                        ),
+                    ),
+            )
+            .when_some(
+                self.settings_file_status.clone(),
+                |el, (status, is_error)| {
+                    el.child(
+                        div()
+                            .mb(Spacing::md())
+                            .text_size(TextSize::sm())
+                            .text_color(if is_error {
+                                Colors::error()
+                            } else {
+                                rgb(0xAA_AA_AA).into()
+                            })
+                            .child(status),
+                    )
+                },
+            )
             .child(
                 div()
                     .max_w_full()
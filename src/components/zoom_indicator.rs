@@ -11,6 +11,12 @@ pub struct ZoomIndicator {
     pub is_fit_to_window: bool,
     /// Image dimensions (width, height)
     pub image_dimensions: Option<(u32, u32)>,
+    /// Whether fit-lock is holding every image at fit-to-window
+    pub fit_locked: bool,
+    /// Whether filter-lock is carrying the filters across images
+    pub filters_locked: bool,
+    /// Whether filters are switched off (raw image showing)
+    pub filters_disabled: bool,
     /// Overlay transparency (0-255)
     pub overlay_transparency: u8,
     /// Font size scale multiplier
@@ -18,10 +24,14 @@ pub struct ZoomIndicator {
 }
 
 impl ZoomIndicator {
+    #[allow(clippy::too_many_arguments)] // One per field.
     pub fn new(
         zoom: f32,
         is_fit_to_window: bool,
         image_dimensions: Option<(u32, u32)>,
+        fit_locked: bool,
+        filters_locked: bool,
+        filters_disabled: bool,
         overlay_transparency: u8,
         font_size_scale: f32,
     ) -> Self {
@@ -29,6 +39,9 @@ impl ZoomIndicator {
             zoom,
             is_fit_to_window,
             image_dimensions,
+            fit_locked,
+            filters_locked,
+            filters_disabled,
             overlay_transparency,
             font_size_scale,
         }
@@ -37,11 +50,7 @@ impl ZoomIndicator {
 
 impl Render for ZoomIndicator {
     fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
-        let zoom_text = if self.is_fit_to_window {
-            format!("Fit ({})", zoom::format_zoom_percentage(self.zoom))
-        } else {
-            zoom::format_zoom_percentage(self.zoom)
-        };
+        let zoom_text = zoom::format_zoom_label(self.zoom, self.is_fit_to_window);
 
         let mut container = div()
             .absolute()
@@ -50,8 +59,8 @@ impl Render for ZoomIndicator {
             .p(Spacing::md())
             .bg(Colors::overlay_bg_alpha(self.overlay_transparency))
             .rounded(px(6.0))
-            .border_1()
-            .border_color(rgba(0x444444FF))
+            .border(Colors::border_width())
+            .border_color(Colors::border())
             .flex()
             .flex_col()
             .items_center() // Center all children horizontally
@@ -68,11 +77,38 @@ impl Render for ZoomIndicator {
             container = container.child(
                 div()
                     .text_size(scaled_text_size(11.0, self.font_size_scale))
-                    .text_color(rgba(0xAAAAAAFF))
+                    .text_color(Colors::secondary_text())
                     .child(format!("{}×{}", width, height)),
             );
         }
 
+        if self.fit_locked {
+            container = container.child(
+                div()
+                    .text_size(scaled_text_size(11.0, self.font_size_scale))
+                    .text_color(rgba(0x8BE9FDFF))
+                    .child("Fit-locked"),
+            );
+        }
+
+        if self.filters_locked {
+            container = container.child(
+                div()
+                    .text_size(scaled_text_size(11.0, self.font_size_scale))
+                    .text_color(rgba(0x8BE9FDFF))
+                    .child("Filters locked"),
+            );
+        }
+
+        if self.filters_disabled {
+            container = container.child(
+                div()
+                    .text_size(scaled_text_size(11.0, self.font_size_scale))
+                    .text_color(rgba(0xFFB86CFF))
+                    .child("Filters off"),
+            );
+        }
+
         container
     }
 }
@@ -16,6 +16,8 @@ pub enum AppError {
     PermissionDenied(PathBuf),
     #[error("Failed to load image {}: {}", .0.display(), .1)]
     ImageLoadError(PathBuf, String),
+    #[error("File appears incomplete or corrupt: {} ({})", .0.display(), .1)]
+    IncompleteFile(PathBuf, String),
     #[error("{0}")]
     Generic(String),
 }
@@ -74,6 +76,14 @@ mod tests {
         assert!(msg.contains("corrupt header"), "got: {msg}");
     }
 
+    #[test]
+    fn display_incomplete_file() {
+        let err = AppError::IncompleteFile(PathBuf::from("part.jpg"), "cut off".into());
+        let msg = err.to_string();
+        assert!(msg.starts_with("File appears incomplete"), "got: {msg}");
+        assert!(msg.contains("part.jpg"), "got: {msg}");
+    }
+
     #[test]
     fn display_generic() {
         let err = AppError::Generic("something went wrong".into());
@@ -98,6 +108,7 @@ mod tests {
             AppError::NoImagesFound(PathBuf::from("x")),
             AppError::PermissionDenied(PathBuf::from("x")),
             AppError::ImageLoadError(PathBuf::from("x"), "y".into()),
+            AppError::IncompleteFile(PathBuf::from("x"), "y".into()),
             AppError::Generic("z".into()),
         ];
         for v in &variants {
@@ -41,7 +41,7 @@ pub fn get_context() -> Option<&'static GpuContext> {
         .get_or_init(|| match try_init() {
             Ok(ctx) => Some(ctx),
             Err(e) => {
-                eprintln!("[gpu] init failed: {e}; GPU filters disabled");
+                log::warn!("[gpu] init failed: {e}; GPU filters disabled");
                 None
             }
         })
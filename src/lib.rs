@@ -26,18 +26,43 @@ actions!(
         EscapePressed,
         NextImage,
         PreviousImage,
+        FirstImage,
+        LastImage,
+        GoToImage,
+        GoToFrame,
+        ExportCurrentFrame,
+        OpenParentFolder,
+        PageForward,
+        PageBackward,
+        ToggleSlideshow,
         ToggleAnimationPlayPause,
+        CycleLoopMode,
         NextFrame,
         PreviousFrame,
+        NextFrames,
+        PreviousFrames,
+        AnimationSpeedUp,
+        AnimationSpeedDown,
+        FirstFrame,
+        LastFrame,
         SortAlphabetical,
         SortByModified,
         SortByTypeToggle,
+        ToggleSortDirection,
         ToggleGpuPipeline,
         ResetGpuPipeline,
         ZoomIn,
         ZoomOut,
         ZoomReset,
         ZoomResetAndCenter,
+        ZoomToPercent,
+        ToggleFitLock,
+        ToggleFilterLock,
+        ToggleAutoExposure,
+        CopyView,
+        PasteView,
+        RotateClockwise,
+        RotateCounterClockwise,
         ZoomInFast,
         ZoomOutFast,
         ZoomInSlow,
@@ -58,11 +83,28 @@ actions!(
         PanRightSlow,
         ToggleHelp,
         ToggleDebug,
+        ToggleFullscreen,
+        ToggleHistogram,
+        ToggleGenerationInfo,
+        ToggleMontagePick,
+        ToggleMontage,
+        ToggleMontageLink,
+        CycleOverlays,
+        ToggleEyedropper,
+        ToggleCropMode,
         ToggleSettings,
         ToggleFilters,
         DisableFilters,
         EnableFilters,
+        ToggleFiltersEnabled,
+        ToggleInvert,
+        ToggleGrayscale,
         ResetFilters,
+        UndoFilter,
+        RedoFilter,
+        BakeFilters,
+        NextDifferentFormat,
+        PreviousDifferentFormat,
         BrightnessUp,
         BrightnessDown,
         ContrastUp,
@@ -70,17 +112,32 @@ actions!(
         GammaUp,
         GammaDown,
         OpenFile,
+        OpenFolder,
         SaveFile,
+        ExportAll,
+        StackAverage,
+        OrganizeByDate,
+        CopyViewportImage,
+        CopyImageToClipboard,
+        CopyPath,
+        SaveViewportImage,
         SaveFileToDownloads,
         OpenInExternalViewer,
         OpenInExternalViewerAndQuit,
+        OpenInDefaultApp,
+        OpenInDefaultAppAndQuit,
         OpenInExternalEditor,
         RevealInFinder,
+        RenameImage,
         CloseSettings,
         ResetSettingsToDefaults,
         LoadOversizedImageAnyway,
+        ReloadImage,
+        CopyShortcutSheet,
         ToggleZoomIndicator,
+        ToggleNavigator,
         ToggleBackground,
+        CycleBackdrop,
         RequestDelete,
         RequestPermanentDelete,
         ConfirmDelete,
@@ -98,5 +155,14 @@ actions!(
         StoreSlot7,
         StoreSlot8,
         StoreSlot9,
+        JumpToDecile1,
+        JumpToDecile2,
+        JumpToDecile3,
+        JumpToDecile4,
+        JumpToDecile5,
+        JumpToDecile6,
+        JumpToDecile7,
+        JumpToDecile8,
+        JumpToDecile9,
     ]
 );
@@ -38,7 +38,7 @@ fn store_paths(paths: Vec<PathBuf>) {
     }
 
     for _path in &paths {
-        crate::debug_eprintln!("[macos_open_handler] openFiles: {}", _path.display());
+        log::debug!("[macos_open_handler] openFiles: {}", _path.display());
     }
 
     if let Ok(mut pending) = OPEN_FILES_PATHS.lock() {
@@ -101,7 +101,7 @@ pub fn register_open_files_handler() {
             // builds so a future GPUI rename of `GPUIApplicationDelegate`
             // produces a visible signal rather than silently breaking
             // "Open With".
-            crate::debug_eprintln!(
+            log::debug!(
                 "[macos_open_handler] GPUIApplicationDelegate class not found; \
                  application:openFiles: handler NOT installed. \
                  Has GPUI's delegate class been renamed?"
@@ -133,7 +133,7 @@ pub fn register_open_files_handler() {
             // application:openFiles: handler, our override is silently dropped
             // and "Open With" from Finder breaks with no diagnostic — surface
             // it here so the cause is visible in dev builds.
-            crate::debug_eprintln!(
+            log::debug!(
                 "[macos_open_handler] class_addMethod for application:openFiles: \
                  returned false; GPUIApplicationDelegate already implements this \
                  selector. \"Open With\" handler NOT installed."
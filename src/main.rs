@@ -70,31 +70,45 @@ mod state;
 mod utils;
 mod window_title;
 
+use ccf_gpui_widgets::prelude::{TextInput, TextInputEvent};
+use clap::Parser;
 use cli::Cli;
 use components::{
     DebugOverlay, DebugOverlayConfig, FilterControls, FilterControlsEvent, FilterWindowView,
-    GpuPipelineControls, GpuPipelineControlsEvent, GpuPipelineWindowView, HelpOverlay, ImageViewer,
-    SettingsWindow,
+    GenerationOverlay, GpuPipelineControls, GpuPipelineControlsEvent, GpuPipelineWindowView,
+    HelpOverlay, HistogramOverlay, ImageViewer, MontageView, Navigator, NavigatorEvent,
+    SettingsWindow, SettingsWindowEvent, SpreadPage,
 };
-use state::{AppSettings, AppState};
-use utils::debug_eprintln;
+use state::{AppSettings, AppState, DoubleTap, Overlay, OverlayCycle, Overlays};
 use utils::settings_io;
 
 // Import all actions from lib.rs (they're defined there to avoid duplication)
 use rpview::{
-    BrightnessDown, BrightnessUp, CloseSettings, CloseWindow, ConfirmDelete, ContrastDown,
-    ContrastUp, DisableFilters, EnableFilters, EscapePressed, GammaDown, GammaUp, NextFrame,
-    NextImage, OpenFile, OpenInExternalEditor, OpenInExternalViewer, OpenInExternalViewerAndQuit,
-    PanDown, PanDownFast, PanDownSlow, PanLeft, PanLeftFast, PanLeftSlow, PanRight, PanRightFast,
-    PanRightSlow, PanUp, PanUpFast, PanUpSlow, PreviousFrame, PreviousImage, Quit, RecallSlot3,
-    RecallSlot4, RecallSlot5, RecallSlot6, RecallSlot7, RecallSlot8, RecallSlot9, RequestDelete,
-    RequestPermanentDelete, ResetFilters, ResetGpuPipeline, ResetSettingsToDefaults,
-    RevealInFinder, SaveFile, SaveFileToDownloads, SortAlphabetical, SortByModified,
-    SortByTypeToggle, StoreSlot3, StoreSlot4, StoreSlot5, StoreSlot6, StoreSlot7, StoreSlot8,
-    StoreSlot9, ToggleAnimationPlayPause, ToggleBackground, ToggleDebug, ToggleFilters,
-    ToggleGpuPipeline, ToggleHelp, ToggleSettings, ToggleZoomIndicator, ZoomIn, ZoomInFast,
-    ZoomInIncremental, ZoomInSlow, ZoomOut, ZoomOutFast, ZoomOutIncremental, ZoomOutSlow,
-    ZoomReset, ZoomResetAndCenter,
+    AnimationSpeedDown, AnimationSpeedUp, BakeFilters, BrightnessDown, BrightnessUp, CloseSettings,
+    CloseWindow, ConfirmDelete, ContrastDown, ContrastUp, CopyImageToClipboard, CopyPath,
+    CopyShortcutSheet, CopyView, CopyViewportImage, CycleBackdrop, CycleLoopMode, CycleOverlays,
+    DisableFilters, EnableFilters, EscapePressed, ExportAll, ExportCurrentFrame, FirstFrame,
+    FirstImage, GammaDown, GammaUp, GoToFrame, GoToImage, JumpToDecile1, JumpToDecile2,
+    JumpToDecile3, JumpToDecile4, JumpToDecile5, JumpToDecile6, JumpToDecile7, JumpToDecile8,
+    JumpToDecile9, LastFrame, LastImage, NextDifferentFormat, NextFrame, NextFrames, NextImage,
+    OpenFile, OpenFolder, OpenInDefaultApp, OpenInDefaultAppAndQuit, OpenInExternalEditor,
+    OpenInExternalViewer, OpenInExternalViewerAndQuit, OpenParentFolder, OrganizeByDate,
+    PageBackward, PageForward, PanDown, PanDownFast, PanDownSlow, PanLeft, PanLeftFast,
+    PanLeftSlow, PanRight, PanRightFast, PanRightSlow, PanUp, PanUpFast, PanUpSlow, PasteView,
+    PreviousDifferentFormat, PreviousFrame, PreviousFrames, PreviousImage, Quit, RecallSlot3,
+    RecallSlot4, RecallSlot5, RecallSlot6, RecallSlot7, RecallSlot8, RecallSlot9, RedoFilter,
+    ReloadImage, RenameImage, RequestDelete, RequestPermanentDelete, ResetFilters,
+    ResetGpuPipeline, ResetSettingsToDefaults, RevealInFinder, RotateClockwise,
+    RotateCounterClockwise, SaveFile, SaveFileToDownloads, SaveViewportImage, SortAlphabetical,
+    SortByModified, SortByTypeToggle, StackAverage, StoreSlot3, StoreSlot4, StoreSlot5, StoreSlot6,
+    StoreSlot7, StoreSlot8, StoreSlot9, ToggleAnimationPlayPause, ToggleAutoExposure,
+    ToggleBackground, ToggleCropMode, ToggleDebug, ToggleEyedropper, ToggleFilterLock,
+    ToggleFilters, ToggleFiltersEnabled, ToggleFitLock, ToggleFullscreen, ToggleGenerationInfo,
+    ToggleGpuPipeline, ToggleGrayscale, ToggleHelp, ToggleHistogram, ToggleInvert, ToggleMontage,
+    ToggleMontageLink, ToggleMontagePick, ToggleNavigator, ToggleSettings, ToggleSlideshow,
+    ToggleSortDirection, ToggleZoomIndicator, UndoFilter, ZoomIn, ZoomInFast, ZoomInIncremental,
+    ZoomInSlow, ZoomOut, ZoomOutFast, ZoomOutIncremental, ZoomOutSlow, ZoomReset,
+    ZoomResetAndCenter, ZoomToPercent,
 };
 
 /// What kind of delete is pending
@@ -104,6 +118,25 @@ enum DeleteMode {
     Permanent,
 }
 
+/// What a confirmed quit prompt will close
+#[derive(Clone, Copy, PartialEq)]
+enum QuitScope {
+    /// Cmd+Q — quit the whole application
+    App,
+    /// 3x ESC — close this window (quits if it is the last one)
+    Window,
+}
+
+/// Where an Organize by Date run is
+enum OrganizeStage {
+    /// Batch job reading capture dates; the plan fills in as it goes
+    Planning(std::sync::Arc<std::sync::Mutex<utils::organize::OrganizePlan>>),
+    /// Dry-run preview card is visible, waiting for confirmation
+    Preview(utils::organize::OrganizePlan),
+    /// Batch job copying or moving the files
+    Applying(utils::organize::OrganizePlan),
+}
+
 /// State for a toast notification
 #[derive(Clone)]
 struct ToastState {
@@ -122,6 +155,9 @@ pub(crate) struct App {
     /// floating panel, which arrives through a weak entity handle.
     window_handle: AnyWindowHandle,
     escape_presses: Vec<Instant>,
+    /// Arrow taps for the double-tap jump to either end, keyed by whether
+    /// the tap was on the Right arrow
+    arrow_taps: DoubleTap<bool>,
     /// Tracks if Z key is currently held down (for Z+drag zoom mode)
     z_key_held: bool,
     /// Tracks if left mouse button is currently pressed
@@ -129,10 +165,9 @@ pub(crate) struct App {
     mouse_button_down: bool,
     /// Whether zoom indicator is visible
     show_zoom_indicator: bool,
-    /// Whether help overlay is visible
-    show_help: bool,
-    /// Whether debug overlay is visible
-    show_debug: bool,
+    /// Which of the help, debug and histogram overlays are visible, and
+    /// which is in front
+    overlays: Overlays,
     /// Whether settings window is visible
     show_settings: bool,
     /// Open floating filter window handle (None = closed)
@@ -151,17 +186,120 @@ pub(crate) struct App {
     help_overlay: Entity<HelpOverlay>,
     /// Debug overlay component
     debug_overlay: Entity<DebugOverlay>,
+    /// EXIF details shown in the debug overlay, read the first time it
+    /// shows each file
+    photo_info_cache: std::collections::HashMap<PathBuf, Option<utils::exif::PhotoInfo>>,
+    /// PNG text chunks shown in the debug overlay, read alongside the EXIF
+    png_text_cache: std::collections::HashMap<PathBuf, Vec<(String, String)>>,
+    /// Histogram overlay component
+    histogram_overlay: Entity<HistogramOverlay>,
+    /// Generation info overlay component
+    generation_overlay: Entity<GenerationOverlay>,
+    /// Images picked with `M` for the montage, in the order picked
+    montage_picks: Vec<PathBuf>,
+    /// The montage, while it's up
+    montage: Option<Entity<MontageView>>,
+    /// Whether the navigator shows while zoomed in past fit
+    show_navigator: bool,
+    /// Navigator thumbnail, shown while part of the image is off screen
+    navigator: Entity<Navigator>,
     /// Menu bar component (Windows/Linux only)
     #[cfg(not(target_os = "macos"))]
     menu_bar: Entity<components::MenuBar>,
     /// Last time animation frame was updated (for animation playback)
     last_frame_update: Instant,
+    /// Running slideshow (Some = auto-advancing, indicator is visible)
+    slideshow: Option<utils::slideshow::Slideshow>,
+    /// When the slideshow last started by itself after a spell without
+    /// input (None once it's been started or stopped by hand)
+    idle_slideshow_started: Option<Instant>,
+    /// Wakes render when the running slideshow's interval is up
+    slideshow_wakeup: Option<Task<()>>,
+    /// Wakes render when the idle slideshow would be due, since nothing
+    /// else redraws a window no one is touching
+    idle_slideshow_wakeup: Option<Task<()>>,
+    /// Background loads of the current image's neighbours into the
+    /// viewer's decode cache; replaced on every step
+    preload_tasks: Vec<Task<()>>,
+    /// Window bounds from before `ToggleFullscreen` went fullscreen; their
+    /// size is put back on leaving it
+    windowed_bounds: Option<Bounds<Pixels>>,
+    /// Whether the window was fullscreen at the last render, to notice it
+    /// going in or out (by shortcut or by the window's own controls)
+    was_fullscreen: bool,
+    /// Overlays open when fullscreen began, reopened when it ends
+    overlays_before_fullscreen: Option<Overlays>,
     /// Whether files are being dragged over the window
     drag_over: bool,
+    /// Eyedropper mode: the cursor reads out the color under it and a
+    /// click copies it
+    eyedropper_active: bool,
+    /// Where the cursor is and the color beneath it (None = off the image)
+    eyedropper_sample: Option<(Point<Pixels>, utils::eyedropper::ColorSample)>,
+    /// Crop mode: dragging selects a region, saved through a Save dialog
+    /// on release
+    crop_mode: bool,
+    /// Filter values before each adjustment to the current image, for
+    /// `UndoFilter` / `RedoFilter`
+    filter_history: utils::undo::UndoStack<state::image_state::FilterSettings>,
+    /// The title last given to the window, so it's only set on a change
+    window_title: String,
+    /// Whether the last press dragged the image, so a quick second press
+    /// near it isn't taken for a double-click
+    last_press_panned: bool,
+    /// Recent drag-pan movements, for the speed when the drag is let go
+    pan_velocity: utils::pan_inertia::VelocityTracker,
+    /// A drag-pan still gliding after release, and when it last moved
+    pan_glide: Option<(utils::pan_inertia::Glide, Instant)>,
+    /// Last mouse movement over the window (drives cursor auto-hide)
+    last_mouse_move: Instant,
+    /// Last keyboard or mouse input of any kind (drives the idle slideshow)
+    last_activity: Instant,
+    /// Whether the cursor is currently auto-hidden
+    cursor_hidden: bool,
+    /// Wakes render when the still cursor is due to hide
+    cursor_hide_wakeup: Option<Task<()>>,
+    /// Zoom/pan copied with `alt-c`, for pasting onto another image
+    copied_view: Option<utils::zoom::ViewSnapshot>,
+    /// When the current image finished loading (drives the fullscreen
+    /// filename caption's fade)
+    caption_shown_at: Option<Instant>,
     /// Pending delete mode (Some = confirmation bar is visible)
     pending_delete: Option<DeleteMode>,
+    /// Pending quit with unsaved filter edits (Some = confirmation card is visible)
+    pending_quit: Option<QuitScope>,
+    /// File waiting to have its filters written in (Some = confirmation
+    /// card is visible)
+    pending_bake: Option<PathBuf>,
+    /// File a running batch job is writing the filters into
+    baking: Option<PathBuf>,
+    /// Last session's image list, offered for restore on a no-argument
+    /// launch (Some = restore card is visible)
+    pending_session_restore: Option<settings_io::SessionState>,
+    /// Field for typing an exact zoom level (Some = zoom prompt is visible)
+    zoom_entry: Option<Entity<TextInput>>,
+    /// Field for typing an image number to jump to (Some = prompt is visible)
+    go_to_entry: Option<Entity<TextInput>>,
+    /// Field for typing an animation frame to jump to (Some = prompt is
+    /// visible)
+    go_to_frame_entry: Option<Entity<TextInput>>,
+    /// Field for typing a new file name (Some = rename prompt is visible)
+    rename_entry: Option<Entity<TextInput>>,
+    /// Running batch export (Some = progress overlay is visible)
+    batch_job: Option<utils::batch::BatchHandle>,
+    /// Stack being built by the running batch job and where to save it
+    /// (Some = the job is a stack, not an export)
+    pending_stack: Option<(
+        std::sync::Arc<std::sync::Mutex<utils::stack::StackAccumulator>>,
+        PathBuf,
+    )>,
+    /// Organize by Date run in progress (see `OrganizeStage`)
+    organize: Option<OrganizeStage>,
     /// Toast notification (auto-dismisses after ~2.5 seconds)
     toast: Option<ToastState>,
+    /// Unpacked ZIP/CBZ being browsed; its temp files are deleted when this
+    /// is replaced or the window closes
+    archive: Option<utils::archive::ExtractedArchive>,
     /// Application settings (loaded on startup)
     settings: AppSettings,
 }
@@ -175,7 +313,26 @@ fn main() {
     // Parse command-line arguments first.  This makes clap short-circuit on
     // `--help` / `--version` before any settings I/O, so the help output
     // isn't preceded by debug logs in dev builds.
-    let cli_paths = match Cli::parse_image_paths() {
+    let mut cli = Cli::parse();
+    if let Some(path) = utils::logging::init(cli.log_level) {
+        log::info!(
+            "rpview {} logging to {}",
+            env!("CARGO_PKG_VERSION"),
+            path.display()
+        );
+    }
+
+    // Load settings from disk (or use defaults if file doesn't exist)
+    let settings = settings_io::load_settings();
+    log::debug!(
+        "Settings loaded from: {}",
+        settings_io::get_settings_path().display()
+    );
+
+    cli.recursive |= settings.sort_navigation.recursive_scan;
+    // Without a path, reopen the folder browsed last time if so set
+    let startup_dir = settings.file_operations.startup_directory();
+    let cli_paths = match cli.image_paths(startup_dir) {
         Ok(result) => result,
         Err(e) => {
             eprintln!("Error: {}", e);
@@ -183,13 +340,8 @@ fn main() {
         }
     };
     let (image_paths, start_path) = (cli_paths.images, cli_paths.start);
-
-    // Load settings from disk (or use defaults if file doesn't exist)
-    let settings = settings_io::load_settings();
-    debug_eprintln!(
-        "Settings loaded from: {}",
-        settings_io::get_settings_path().display()
-    );
+    utils::scratch::set_scratch_dir(settings.file_operations.scratch_directory.as_deref());
+    utils::style::Colors::set_high_contrast(settings.appearance.high_contrast);
 
     // Determine the search directory for error messages
     let search_dir = if image_paths.is_empty() {
@@ -204,6 +356,8 @@ fn main() {
             } else {
                 std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."))
             }
+        } else if let Some(dir) = startup_dir {
+            dir.to_path_buf()
         } else {
             std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."))
         }
@@ -212,7 +366,7 @@ fn main() {
     };
 
     // Print startup info
-    debug_eprintln!("rpview starting...");
+    log::debug!("rpview starting...");
 
     let application = Application::new();
 
@@ -248,6 +402,31 @@ fn main() {
         app_keybindings::setup_menus(cx);
 
         cx.on_action(|_: &Quit, cx| {
+            // With unsaved filter edits (and the confirm setting on), show the
+            // prompt in the first window that has them instead of quitting.
+            // A second Cmd+Q while the prompt is up counts as confirmation.
+            let windows = app_windows(cx);
+            let prompting = windows.iter().any(|handle| {
+                handle
+                    .read(cx)
+                    .is_ok_and(|app| app.pending_quit == Some(QuitScope::App))
+            });
+            if !prompting {
+                let dirty = windows
+                    .into_iter()
+                    .find(|handle| handle.read(cx).is_ok_and(|app| app.should_confirm_quit()));
+                if let Some(handle) = dirty {
+                    let _ = handle.update(cx, |app, window, cx| {
+                        app.pending_quit = Some(QuitScope::App);
+                        window.activate_window();
+                        cx.notify();
+                    });
+                    return;
+                }
+            }
+            if let Some(handle) = active_app_window(cx) {
+                let _ = handle.read_with(cx, |app, _| app.save_session());
+            }
             cx.quit();
         });
 
@@ -274,6 +453,7 @@ fn main() {
             discard_pending_open_paths();
         }
 
+        let offer_session_restore = !cli_paths.from_arguments;
         let reopen_filter_window = settings.appearance.filter_window_open;
         let reopen_gpu_pipeline_window = settings.appearance.gpu_pipeline_window_open;
         let Some(main_window) =
@@ -300,6 +480,17 @@ fn main() {
         })
         .detach();
 
+        // Launched with no arguments: offer the last session's list if it
+        // differs from what we're showing (the current directory).
+        if offer_session_restore && let Some(session) = settings_io::load_session() {
+            let _ = main_window.update(cx, |app, _window, app_cx| {
+                if session.image_paths != app.app_state.image_paths {
+                    app.pending_session_restore = Some(session);
+                    app_cx.notify();
+                }
+            });
+        }
+
         // Reopen the floating filter window if it was open when the app last quit.
         if reopen_filter_window {
             cx.defer(move |cx| {
@@ -355,19 +546,45 @@ fn main() {
         // Navigation
         forward!(NextImage, handle_next_image);
         forward!(PreviousImage, handle_previous_image);
+        forward!(FirstImage, handle_first_image);
+        forward!(LastImage, handle_last_image);
+        forward!(GoToImage, handle_go_to_image);
+        forward!(GoToFrame, handle_go_to_frame);
+        forward!(ExportCurrentFrame, handle_export_current_frame);
+        forward!(OpenParentFolder, handle_open_parent_folder);
+        forward!(PageForward, handle_page_forward);
+        forward!(PageBackward, handle_page_backward);
         // Animation
+        forward!(ToggleSlideshow, handle_toggle_slideshow);
         forward!(ToggleAnimationPlayPause, handle_toggle_animation);
+        forward!(CycleLoopMode, handle_cycle_loop_mode);
         forward!(NextFrame, handle_next_frame);
         forward!(PreviousFrame, handle_previous_frame);
+        forward!(NextFrames, handle_next_frames);
+        forward!(PreviousFrames, handle_previous_frames);
+        forward_slot!(AnimationSpeedUp, handle_animation_speed, true);
+        forward_slot!(AnimationSpeedDown, handle_animation_speed, false);
+        forward!(FirstFrame, handle_first_frame);
+        forward!(LastFrame, handle_last_frame);
         // Sort
         forward!(SortAlphabetical, handle_sort_alphabetical);
         forward!(SortByModified, handle_sort_by_modified);
         forward!(SortByTypeToggle, handle_sort_by_type_toggle);
+        forward!(ToggleSortDirection, handle_toggle_sort_direction);
         // Zoom
         forward!(ZoomIn, handle_zoom_in);
         forward!(ZoomOut, handle_zoom_out);
         forward!(ZoomReset, handle_zoom_reset);
+        forward!(RotateClockwise, handle_rotate_clockwise);
+        forward!(RotateCounterClockwise, handle_rotate_counter_clockwise);
         forward!(ZoomResetAndCenter, handle_zoom_reset_and_center);
+        forward!(ZoomToPercent, handle_zoom_to_percent);
+        forward!(ToggleFitLock, handle_toggle_fit_lock);
+        forward!(ToggleAutoExposure, handle_toggle_auto_exposure);
+        forward!(RenameImage, handle_rename_image);
+        forward!(ToggleFilterLock, handle_toggle_filter_lock);
+        forward!(CopyView, handle_copy_view);
+        forward!(PasteView, handle_paste_view);
         forward!(ZoomInFast, handle_zoom_in_fast);
         forward!(ZoomOutFast, handle_zoom_out_fast);
         forward!(ZoomInSlow, handle_zoom_in_slow);
@@ -390,14 +607,33 @@ fn main() {
         // View toggles
         forward!(ToggleHelp, handle_toggle_help);
         forward!(ToggleDebug, handle_toggle_debug);
+        forward!(ToggleFullscreen, handle_toggle_fullscreen);
+        forward!(ToggleHistogram, handle_toggle_histogram);
+        forward!(ToggleGenerationInfo, handle_toggle_generation_info);
+        forward!(ToggleMontagePick, handle_toggle_montage_pick);
+        forward!(ToggleMontage, handle_toggle_montage);
+        forward!(ToggleMontageLink, handle_toggle_montage_link);
+        forward!(ToggleEyedropper, handle_toggle_eyedropper);
+        forward!(ToggleCropMode, handle_toggle_crop_mode);
+        forward!(CycleOverlays, handle_cycle_overlays);
         forward!(ToggleZoomIndicator, handle_toggle_zoom_indicator);
+        forward!(ToggleNavigator, handle_toggle_navigator);
         forward!(ToggleBackground, handle_toggle_background);
+        forward!(CycleBackdrop, handle_cycle_backdrop);
         forward!(ToggleSettings, handle_toggle_settings);
         // Filters
         forward!(ToggleFilters, handle_toggle_filters);
         forward!(DisableFilters, handle_disable_filters);
         forward!(EnableFilters, handle_enable_filters);
+        forward!(ToggleFiltersEnabled, handle_toggle_filters_enabled);
+        forward!(ToggleInvert, handle_toggle_invert);
+        forward!(ToggleGrayscale, handle_toggle_grayscale);
         forward!(ResetFilters, handle_reset_filters);
+        forward!(UndoFilter, handle_undo_filter);
+        forward!(RedoFilter, handle_redo_filter);
+        forward!(BakeFilters, handle_request_bake_filters);
+        forward!(NextDifferentFormat, handle_next_different_format);
+        forward!(PreviousDifferentFormat, handle_previous_different_format);
         forward!(BrightnessUp, handle_brightness_up);
         forward!(BrightnessDown, handle_brightness_down);
         forward!(ContrastUp, handle_contrast_up);
@@ -419,15 +655,34 @@ fn main() {
         forward_slot!(StoreSlot7, handle_store_slot, 7);
         forward_slot!(StoreSlot8, handle_store_slot, 8);
         forward_slot!(StoreSlot9, handle_store_slot, 9);
+        forward_slot!(JumpToDecile1, handle_jump_to_decile, 1);
+        forward_slot!(JumpToDecile2, handle_jump_to_decile, 2);
+        forward_slot!(JumpToDecile3, handle_jump_to_decile, 3);
+        forward_slot!(JumpToDecile4, handle_jump_to_decile, 4);
+        forward_slot!(JumpToDecile5, handle_jump_to_decile, 5);
+        forward_slot!(JumpToDecile6, handle_jump_to_decile, 6);
+        forward_slot!(JumpToDecile7, handle_jump_to_decile, 7);
+        forward_slot!(JumpToDecile8, handle_jump_to_decile, 8);
+        forward_slot!(JumpToDecile9, handle_jump_to_decile, 9);
         // File operations
         forward!(OpenFile, handle_open_file);
+        forward!(OpenFolder, handle_open_folder);
         forward!(SaveFile, handle_save_file);
+        forward!(ExportAll, handle_export_all);
+        forward!(StackAverage, handle_stack_average);
+        forward!(OrganizeByDate, handle_organize_by_date);
         forward!(SaveFileToDownloads, handle_save_file_to_downloads);
+        forward!(CopyViewportImage, handle_copy_viewport_image);
+        forward!(CopyImageToClipboard, handle_copy_image_to_clipboard);
+        forward!(CopyPath, handle_copy_path);
+        forward!(SaveViewportImage, handle_save_viewport_image);
         forward!(OpenInExternalViewer, handle_open_in_external_viewer);
         forward!(
             OpenInExternalViewerAndQuit,
             handle_open_in_external_viewer_and_quit
         );
+        forward!(OpenInDefaultApp, handle_open_in_default_app);
+        forward!(OpenInDefaultAppAndQuit, handle_open_in_default_app_and_quit);
         forward!(OpenInExternalEditor, handle_open_in_external_editor);
         forward!(RevealInFinder, handle_reveal_in_finder);
         forward!(RequestDelete, handle_request_delete);
@@ -471,14 +726,14 @@ fn main() {
 fn discard_pending_open_paths() {
     if let Ok(mut pending) = PENDING_OPEN_PATHS.lock() {
         for _path in pending.iter() {
-            debug_eprintln!("Discarding launch-echo open request: {}", _path.display());
+            log::debug!("Discarding launch-echo open request: {}", _path.display());
         }
         pending.clear();
     }
 
     #[cfg(target_os = "macos")]
     for _path in macos_open_handler::take_pending_paths() {
-        debug_eprintln!("Discarding launch-echo open request: {}", _path.display());
+        log::debug!("Discarding launch-echo open request: {}", _path.display());
     }
 }
 
@@ -596,19 +851,20 @@ fn open_image_window(
         return None;
     }
 
-    let app_state = AppState::new_with_settings(
+    let mut app_state = AppState::new_with_settings(
         image_paths,
         start_path,
-        settings.sort_navigation.default_sort_mode,
+        settings.sort_navigation.startup_sort_mode(),
         settings.viewer_behavior.state_cache_size,
     );
+    app_state.set_sort_descending(settings.sort_navigation.sort_descending);
 
-    debug_eprintln!(
+    log::debug!(
         "Opening window with {} image(s)",
         app_state.image_paths.len()
     );
     if let Some(_first_image) = app_state.current_image() {
-        debug_eprintln!("Current image: {}", _first_image.display());
+        log::debug!("Current image: {}", _first_image.display());
     }
 
     let first_image_path = app_state.current_image().cloned();
@@ -626,8 +882,13 @@ fn open_image_window(
                 // Closing an image window closes the floating panels it owns.
                 // Without this they outlive their owner as orphans alongside
                 // whichever image windows remain.
+                //
+                // It also records the window's list as the session to offer
+                // next launch, so with several windows the one closed last
+                // wins.
                 inner_cx
                     .on_release(|app, cx| {
+                        app.save_session();
                         if let Some(handle) = app.filter_window.take() {
                             let _ = handle.update(cx, |_, window, _| window.remove_window());
                         }
@@ -639,6 +900,10 @@ fn open_image_window(
 
                 // Create the viewer and load the first image if available
                 let mut viewer = ImageViewer::new(inner_cx.focus_handle());
+                viewer.fit_padding = settings.viewer_behavior.fit_padding;
+                viewer.svg_background = settings.appearance.svg_background.fill();
+                viewer.honor_pixel_aspect = settings.viewer_behavior.honor_pixel_aspect;
+                viewer.auto_exposure = settings.filters.auto_exposure;
 
                 if let Some(ref path) = first_image_path {
                     let max_dim = Some(settings.performance.max_image_dimension);
@@ -651,7 +916,10 @@ fn open_image_window(
                     viewer.no_images_path = Some(canonical_dir);
                 }
 
-                build_app(app_state, viewer, focus_handle, settings, window, inner_cx)
+                let mut app =
+                    build_app(app_state, viewer, focus_handle, settings, window, inner_cx);
+                app.remember_directory();
+                app
             })
         },
     );
@@ -659,7 +927,7 @@ fn open_image_window(
     match result {
         Ok(handle) => Some(handle),
         Err(e) => {
-            eprintln!("Failed to open window: {:?}", e);
+            log::error!("Failed to open window: {:?}", e);
             None
         }
     }
@@ -694,7 +962,7 @@ fn check_and_process_pending_paths(cx: &mut gpui::App) {
     // fresh window.
     if paths.len() == 1 {
         if let Some(existing) = window_showing(&paths[0], cx) {
-            debug_eprintln!(
+            log::debug!(
                 "Open With: {} is already open; activating its window",
                 paths[0].display()
             );
@@ -714,8 +982,10 @@ fn check_and_process_pending_paths(cx: &mut gpui::App) {
         return;
     }
 
-    let Some((images, target_index)) = App::resolve_import_paths(&paths) else {
-        debug_eprintln!(
+    let settings = current_settings(cx);
+    let recursive = settings.sort_navigation.recursive_scan;
+    let Some((images, target_index)) = App::resolve_import_paths(&paths, recursive) else {
+        log::debug!(
             "Open With: no supported images among {} path(s)",
             paths.len()
         );
@@ -723,7 +993,6 @@ fn check_and_process_pending_paths(cx: &mut gpui::App) {
     };
 
     let start_path = images.get(target_index).cloned();
-    let settings = current_settings(cx);
     if open_image_window(cx, images, start_path, &settings, None).is_some() {
         cx.activate(true);
     }
@@ -745,6 +1014,10 @@ fn build_app(
         app_state.current_index,
         app_state.image_paths.len(),
         app_state.sort_mode,
+        window_title::TitleView {
+            zoom: viewer.image_state.zoom,
+            dimensions: viewer.display_dimensions(),
+        },
         &settings,
     );
     window.set_window_title(&title);
@@ -753,6 +1026,7 @@ fn build_app(
     let filter_controls = cx.new(|cx| {
         FilterControls::new(
             viewer.image_state.filters,
+            settings.filters.filter_presets.clone(),
             settings.appearance.font_size_scale,
             cx,
         )
@@ -761,13 +1035,23 @@ fn build_app(
     // Subscribe to filter control changes (event-based, not polling)
     cx.subscribe(
         &filter_controls,
-        |this, _fc, _event: &FilterControlsEvent, cx| {
-            // Update viewer with new filter values
-            let current_filters = this.filter_controls.read(cx).get_filters(cx);
-            this.viewer.image_state.filters = current_filters;
-            this.viewer.update_filtered_cache();
-            this.save_current_image_state();
-            cx.notify();
+        |this, _fc, event: &FilterControlsEvent, cx| match event {
+            FilterControlsEvent::FiltersChanged => {
+                // Update viewer with new filter values
+                let current_filters = this.filter_controls.read(cx).get_filters(cx);
+                // The sliders also report values the app set itself;
+                // only real changes go into the undo history
+                let previous = this.viewer.image_state.filters;
+                if current_filters != previous {
+                    this.filter_history.record_merging(previous, Instant::now());
+                }
+                this.viewer.image_state.filters = current_filters;
+                this.viewer.update_filtered_cache();
+                this.save_current_image_state();
+                cx.notify();
+            }
+            FilterControlsEvent::SavePreset(name) => this.save_filter_preset(name, cx),
+            FilterControlsEvent::DeletePreset(index) => this.delete_filter_preset(*index, cx),
         },
     )
     .detach();
@@ -794,6 +1078,17 @@ fn build_app(
 
     // Create settings window
     let settings_window = cx.new(|cx| SettingsWindow::new(settings.clone(), cx));
+    // Preview appearance edits live behind the semi-transparent overlay
+    cx.subscribe(
+        &settings_window,
+        |this, _sw, event: &SettingsWindowEvent, cx| {
+            let SettingsWindowEvent::AppearanceChanged(preview) = event;
+            preview.apply_to(&mut this.settings.appearance);
+            utils::style::Colors::set_high_contrast(this.settings.appearance.high_contrast);
+            cx.notify();
+        },
+    )
+    .detach();
 
     // Create help overlay
     let help_overlay = cx.new(|_cx| {
@@ -815,14 +1110,53 @@ fn build_app(
             image_dimensions: None,
             viewport_size: None,
             sort_mode: app_state.sort_mode,
+            photo_info: None,
+            png_text: Vec::new(),
+            animation_frame: None,
+            animation_speed: None,
+            preloaded: None,
             overlay_transparency: settings.appearance.overlay_transparency,
             font_size_scale: settings.appearance.font_size_scale,
         })
     });
 
+    // Create histogram overlay
+    let histogram_overlay = cx.new(|_cx| {
+        HistogramOverlay::new(
+            settings.appearance.overlay_transparency,
+            settings.appearance.font_size_scale,
+        )
+    });
+
+    // Create generation info overlay
+    let generation_overlay = cx.new(|_cx| {
+        GenerationOverlay::new(
+            settings.appearance.overlay_transparency,
+            settings.appearance.font_size_scale,
+        )
+    });
+
+    // Clicks and drags in the navigator move the main view
+    let navigator = cx.new(|_cx| Navigator::new());
+    cx.subscribe(
+        &navigator,
+        |this, _navigator, event: &NavigatorEvent, cx| match event {
+            NavigatorEvent::CenterOn(x, y) => this.center_view_on_navigator_point((*x, *y), cx),
+            NavigatorEvent::Released => this.save_current_image_state(),
+        },
+    )
+    .detach();
+
+    // Any key typed into this window is activity for the idle slideshow,
+    // bound shortcuts included
+    utils::slideshow::observe_window_keystrokes(window, cx, |this: &mut App| {
+        this.last_activity = Instant::now();
+    })
+    .detach();
+
     // Create menu bar for Windows/Linux
     #[cfg(not(target_os = "macos"))]
-    let menu_bar = cx.new(|cx| components::MenuBar::new(cx));
+    let menu_bar = cx.new(|cx| components::MenuBar::new(&app_keybindings::key_bindings(), cx));
 
     App {
         app_state,
@@ -830,11 +1164,11 @@ fn build_app(
         focus_handle,
         window_handle: window.window_handle(),
         escape_presses: Vec::new(),
+        arrow_taps: DoubleTap::default(),
         z_key_held: false,
         mouse_button_down: false,
         show_zoom_indicator: true,
-        show_help: false,
-        show_debug: false,
+        overlays: Overlays::default(),
         show_settings: false,
         filter_window: None,
         filter_controls,
@@ -843,12 +1177,54 @@ fn build_app(
         settings_window,
         help_overlay,
         debug_overlay,
+        photo_info_cache: Default::default(),
+        png_text_cache: Default::default(),
+        histogram_overlay,
+        generation_overlay,
+        montage_picks: Vec::new(),
+        montage: None,
+        show_navigator: true,
+        navigator,
         #[cfg(not(target_os = "macos"))]
         menu_bar,
         last_frame_update: Instant::now(),
+        slideshow: None,
+        idle_slideshow_started: None,
+        slideshow_wakeup: None,
+        idle_slideshow_wakeup: None,
+        preload_tasks: Vec::new(),
+        windowed_bounds: None,
+        was_fullscreen: false,
+        overlays_before_fullscreen: None,
         drag_over: false,
+        eyedropper_active: false,
+        eyedropper_sample: None,
+        crop_mode: false,
+        filter_history: utils::undo::UndoStack::new(utils::undo::MAX_UNDO_DEPTH),
+        window_title: title,
+        last_press_panned: false,
+        pan_velocity: Default::default(),
+        pan_glide: None,
+        last_mouse_move: Instant::now(),
+        last_activity: Instant::now(),
+        caption_shown_at: None,
+        copied_view: None,
+        cursor_hidden: false,
+        cursor_hide_wakeup: None,
         pending_delete: None,
+        pending_quit: None,
+        pending_bake: None,
+        baking: None,
+        pending_session_restore: None,
+        zoom_entry: None,
+        go_to_entry: None,
+        go_to_frame_entry: None,
+        rename_entry: None,
+        batch_job: None,
+        pending_stack: None,
+        organize: None,
         toast: None,
+        archive: None,
         settings,
     }
 }
@@ -1,4 +1,5 @@
 use super::image_state::{FilterSettings, ImageState};
+use crate::utils::file_scanner::{adjacent_image_folder, natural_path_cmp};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::time::Instant;
@@ -6,14 +7,15 @@ use std::time::Instant;
 /// Sort mode for image list
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
 pub enum SortMode {
-    /// Alphabetical (case-insensitive)
+    /// Alphabetical (case-insensitive, with numbers in names compared by
+    /// value so "img2" comes before "img10")
     #[default]
     Alphabetical,
 
     /// Modified date (newest first)
     ModifiedDate,
 
-    /// Group by image type, then alphabetical within each type
+    /// Group by image type, then alphabetical (as above) within each type
     TypeAlpha,
 
     /// Group by image type, then modified date (newest first) within each type
@@ -42,6 +44,34 @@ impl SortMode {
     }
 }
 
+/// What stepping past either end of the list does when navigation
+/// doesn't wrap
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub enum EndOfList {
+    /// Stay on the first or last image
+    #[default]
+    Stop,
+
+    /// Stay put and say the end has been reached
+    Notify,
+
+    /// Open the next (or previous) folder beside this one that has images
+    AdjacentFolder,
+}
+
+/// The outcome of `AppState::step_past_end`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EndOfListStep {
+    /// Nothing changed
+    Stayed,
+    /// Nothing changed, and the user should be told
+    Notify,
+    /// The list now holds this folder's images
+    OpenedFolder(PathBuf),
+    /// There's no folder with images further on; nothing changed
+    NoMoreFolders,
+}
+
 /// Image type grouping used as the primary key for composite sort modes.
 /// Variants are ordered alphabetically by extension, with JPG/JPEG merged
 /// into `Jpeg` and TIF/TIFF merged into `Tiff` so synonym extensions group
@@ -90,6 +120,9 @@ pub struct AppState {
     /// Current sort mode
     pub sort_mode: SortMode,
 
+    /// Whether the sort mode's order is reversed
+    pub sort_descending: bool,
+
     /// Cache of per-image states.  Capped at `max_cache_size` entries; on
     /// overflow, the entry with the oldest `last_accessed` timestamp is
     /// dropped.  Eviction is O(n) per insert (linear scan over the map),
@@ -120,6 +153,7 @@ impl AppState {
             image_paths,
             current_index,
             sort_mode: SortMode::default(),
+            sort_descending: false,
             image_states: HashMap::new(),
             max_cache_size: 1000,
         }
@@ -141,6 +175,7 @@ impl AppState {
             image_paths,
             current_index: 0,
             sort_mode: default_sort_mode,
+            sort_descending: false,
             image_states: HashMap::new(),
             max_cache_size: cache_size,
         };
@@ -163,7 +198,8 @@ impl AppState {
         self.image_paths.get(self.current_index)
     }
 
-    /// Get the next image path (for preloading)
+    /// Get the next image path
+    #[allow(dead_code)]
     pub fn next_image_path(&self) -> Option<&PathBuf> {
         if self.image_paths.is_empty() {
             return None;
@@ -172,7 +208,8 @@ impl AppState {
         self.image_paths.get(next_index)
     }
 
-    /// Get the previous image path (for preloading)
+    /// Get the previous image path
+    #[allow(dead_code)]
     pub fn previous_image_path(&self) -> Option<&PathBuf> {
         if self.image_paths.is_empty() {
             return None;
@@ -185,6 +222,37 @@ impl AppState {
         self.image_paths.get(prev_index)
     }
 
+    /// Indices to preload around the current image, nearest first and
+    /// alternating forward/back (+1, -1, +2, -2, ...).  With `wrap` the list
+    /// continues past either end; without it, it stops there.  Never
+    /// includes the current index or repeats one.
+    pub fn preload_indices(&self, radius: usize, wrap: bool) -> Vec<usize> {
+        let len = self.image_paths.len();
+        let mut indices = Vec::new();
+        if len == 0 {
+            return indices;
+        }
+        let current = self.current_index.min(len - 1);
+        for distance in 1..=radius {
+            let forward = current + distance;
+            let backward = current.checked_sub(distance);
+            let candidates = if wrap {
+                [
+                    Some(forward % len),
+                    Some((current + len - distance % len) % len),
+                ]
+            } else {
+                [(forward < len).then_some(forward), backward]
+            };
+            for index in candidates.into_iter().flatten() {
+                if index != current && !indices.contains(&index) {
+                    indices.push(index);
+                }
+            }
+        }
+        indices
+    }
+
     /// Navigate to the next image
     #[allow(dead_code)]
     pub fn next_image(&mut self) {
@@ -221,6 +289,166 @@ impl AppState {
         }
     }
 
+    /// Navigate to the first image
+    pub fn first_image(&mut self) {
+        if !self.image_paths.is_empty() {
+            self.current_index = 0;
+        }
+    }
+
+    /// Navigate to the last image
+    pub fn last_image(&mut self) {
+        if !self.image_paths.is_empty() {
+            self.current_index = self.image_paths.len() - 1;
+        }
+    }
+
+    /// Jump forward `n` images.  Past the end this wraps around when `wrap`
+    /// is set, otherwise it stops at the last image.
+    pub fn advance_by(&mut self, n: usize, wrap: bool) {
+        let len = self.image_paths.len();
+        if len == 0 {
+            return;
+        }
+        let target = self.current_index + n;
+        self.current_index = if wrap {
+            target % len
+        } else {
+            target.min(len - 1)
+        };
+    }
+
+    /// Jump back `n` images.  Before the start this wraps around when `wrap`
+    /// is set, otherwise it stops at the first image.
+    pub fn retreat_by(&mut self, n: usize, wrap: bool) {
+        let len = self.image_paths.len();
+        if len == 0 {
+            return;
+        }
+        self.current_index = if wrap {
+            (self.current_index + len - n % len) % len
+        } else {
+            self.current_index.saturating_sub(n)
+        };
+    }
+
+    /// First page of the two-page spread holding `index`.  Pages pair up
+    /// (0, 1), (2, 3), ...; with `cover_alone` page 0 stands by itself and
+    /// the pairs shift to (1, 2), (3, 4), ...
+    pub fn spread_start(index: usize, cover_alone: bool) -> usize {
+        match (cover_alone, index) {
+            (true, 0) => 0,
+            (true, i) => i - (i - 1) % 2,
+            (false, i) => i - i % 2,
+        }
+    }
+
+    /// Index of the other page in the current image's spread, or None for
+    /// a lone cover or an unpaired last page
+    pub fn spread_partner(&self, cover_alone: bool) -> Option<usize> {
+        let current = self.current_index;
+        let start = Self::spread_start(current, cover_alone);
+        let partner = if current == start { start + 1 } else { start };
+        let lone_cover = cover_alone && start == 0;
+        (!lone_cover && partner < self.image_paths.len()).then_some(partner)
+    }
+
+    /// Step to the next spread (the previous one when `forward` is false),
+    /// landing on its first page.  Past either end this wraps round when
+    /// `wrap` is set, otherwise it stays put.
+    pub fn step_spread(&mut self, forward: bool, wrap: bool, cover_alone: bool) {
+        let len = self.image_paths.len();
+        if len == 0 {
+            return;
+        }
+        let start = Self::spread_start(self.current_index, cover_alone);
+        let last = Self::spread_start(len - 1, cover_alone);
+        self.current_index = if forward {
+            let width = if cover_alone && start == 0 { 1 } else { 2 };
+            match (start + width < len, wrap) {
+                (true, _) => start + width,
+                (false, true) => 0,
+                (false, false) => start,
+            }
+        } else {
+            match (start, wrap) {
+                (0, true) => last,
+                (0, false) => 0,
+                (s, _) => Self::spread_start(s - 1, cover_alone),
+            }
+        };
+    }
+
+    /// Index of the nearest image after the current one (before it when
+    /// `forward` is false) whose path satisfies `matches`.  With `wrap` the
+    /// scan continues round the other end of the list, stopping short of
+    /// the current image.  None when nothing matches.
+    pub fn find_neighbor(
+        &self,
+        forward: bool,
+        wrap: bool,
+        matches: impl Fn(&Path) -> bool,
+    ) -> Option<usize> {
+        let len = self.image_paths.len();
+        if len == 0 {
+            return None;
+        }
+        let current = self.current_index;
+        let steps = if wrap {
+            len - 1
+        } else if forward {
+            len - 1 - current
+        } else {
+            current
+        };
+        (1..=steps)
+            .map(|step| {
+                if forward {
+                    (current + step) % len
+                } else {
+                    (current + len - step) % len
+                }
+            })
+            .find(|&i| matches(&self.image_paths[i]))
+    }
+
+    /// Index of the nearest image in the given direction whose type differs
+    /// from the current image's (JPG and JPEG count as one type), for
+    /// skipping through folders where formats are interleaved.
+    pub fn different_format_neighbor(&self, forward: bool, wrap: bool) -> Option<usize> {
+        let current = image_type_group(self.current_image()?);
+        self.find_neighbor(forward, wrap, |p| image_type_group(p) != current)
+    }
+
+    /// Index `fraction` of the way through a list of `len` images
+    /// (0.0 = first image; anything at or past 1.0 = last image).
+    pub fn fraction_to_index(fraction: f32, len: usize) -> usize {
+        if len == 0 {
+            return 0;
+        }
+        let index = (fraction.clamp(0.0, 1.0) * len as f32).floor() as usize;
+        index.min(len - 1)
+    }
+
+    /// Jump `fraction` of the way through the image list (0.5 = halfway).
+    pub fn jump_to_fraction(&mut self, fraction: f32) {
+        if !self.image_paths.is_empty() {
+            self.current_index = Self::fraction_to_index(fraction, self.image_paths.len());
+        }
+    }
+
+    /// Show the image at `index`, clamped to the list.  Returns whether the
+    /// current image changed (never for an empty list).
+    pub fn go_to(&mut self, index: usize) -> bool {
+        let Some(last) = self.image_paths.len().checked_sub(1) else {
+            return false;
+        };
+        let target = index.min(last);
+        let moved = target != self.current_index;
+        self.current_index = target;
+        moved
+    }
+
     /// Get the state for the current image, creating a default if it doesn't exist
     pub fn get_current_state(&mut self, default_filters: FilterSettings) -> ImageState {
         if let Some(path) = self.current_image() {
@@ -235,6 +463,41 @@ impl AppState {
         }
     }
 
+    /// Whether the current image has a cached view (zoom/pan) the user set
+    /// by hand.  Never-touched images have no view worth restoring, even
+    /// when other state (filters, animation frame) is cached for them.
+    pub fn has_adjusted_view(&self) -> bool {
+        self.current_image()
+            .and_then(|path| self.image_states.get(path))
+            .is_some_and(|state| state.view_adjusted)
+    }
+
+    /// The filters the current image opens with when it has no remembered
+    /// state (seen for the first time, or per-image state is off):
+    /// `defaults`, or under filter-lock the `locked` live filters.
+    pub fn opening_filters(
+        defaults: FilterSettings,
+        locked: Option<FilterSettings>,
+    ) -> FilterSettings {
+        locked.unwrap_or(defaults)
+    }
+
+    /// Save `shown`, the state on screen, for the current image.  Under
+    /// filter-lock its filters are the live ones rather than the image's
+    /// own, so the image's saved filters (or `defaults`) are kept instead.
+    pub fn save_shown_state(
+        &mut self,
+        mut shown: ImageState,
+        filters_locked: bool,
+        defaults: FilterSettings,
+    ) {
+        if filters_locked {
+            let saved = self.get_current_state(defaults);
+            shown.lock_filters(saved.filters, saved.filters_enabled);
+        }
+        self.save_current_state(shown);
+    }
+
     /// Save the state for the current image
     pub fn save_current_state(&mut self, mut state: ImageState) {
         if let Some(path) = self.current_image().cloned() {
@@ -276,6 +539,82 @@ impl AppState {
         Some(removed)
     }
 
+    /// Point files that moved on disk at their new paths, keeping their
+    /// place in the list and their cached view state
+    pub fn rename_paths(&mut self, renames: &HashMap<PathBuf, PathBuf>) {
+        for path in &mut self.image_paths {
+            if let Some(new_path) = renames.get(path) {
+                if let Some(state) = self.image_states.remove(path) {
+                    self.image_states.insert(new_path.clone(), state);
+                }
+                *path = new_path.clone();
+            }
+        }
+    }
+
+    /// Whether any cached per-image state holds unsaved filter edits
+    /// (see `ImageState::has_unsaved_filter_changes`).
+    pub fn has_unsaved_filter_changes(&self, defaults: FilterSettings) -> bool {
+        self.image_states
+            .values()
+            .any(|state| state.has_unsaved_filter_changes(defaults))
+    }
+
+    /// Replace the image list with the contents of a newly opened folder,
+    /// ordered by the active sort mode and starting at its first image.
+    pub fn set_images_from_folder(&mut self, images: Vec<PathBuf>) {
+        self.image_paths = images;
+        self.sort_images();
+        self.current_index = 0;
+    }
+
+    /// Handle a step off the end of the list (`forward` past the last
+    /// image, otherwise before the first) according to `end`.  Opening an
+    /// adjacent folder lands on its first image going forward and on its
+    /// last going back.
+    pub fn step_past_end(
+        &mut self,
+        forward: bool,
+        end: EndOfList,
+        recursive: bool,
+    ) -> EndOfListStep {
+        match end {
+            EndOfList::Stop => EndOfListStep::Stayed,
+            EndOfList::Notify => EndOfListStep::Notify,
+            EndOfList::AdjacentFolder => {
+                let Some(dir) = self
+                    .image_paths
+                    .get(self.current_index)
+                    .and_then(|path| path.parent())
+                else {
+                    return EndOfListStep::Stayed;
+                };
+                let Some((folder, images)) = adjacent_image_folder(dir, forward, recursive) else {
+                    return EndOfListStep::NoMoreFolders;
+                };
+                self.set_images_from_folder(images);
+                if !forward {
+                    self.current_index = self.image_paths.len() - 1;
+                }
+                EndOfListStep::OpenedFolder(folder)
+            }
+        }
+    }
+
+    /// Replace the image list with the images of the folder above
+    /// `came_from`, starting at the first one inside `came_from` when the
+    /// list reaches into it (a recursive scan) and at the top otherwise.
+    pub fn set_images_from_parent(&mut self, images: Vec<PathBuf>, came_from: &Path) {
+        self.set_images_from_folder(images);
+        if let Some(index) = self
+            .image_paths
+            .iter()
+            .position(|path| path.starts_with(came_from))
+        {
+            self.current_index = index;
+        }
+    }
+
     /// Set the sort mode and re-sort the image list
     pub fn set_sort_mode(&mut self, mode: SortMode) {
         if self.sort_mode != mode {
@@ -284,15 +623,22 @@ impl AppState {
         }
     }
 
-    /// Sort the image list according to the current sort mode,
-    /// preserving the currently-viewed image across the reorder.
+    /// Reverse (or restore) the sort mode's order and re-sort the image list
+    pub fn set_sort_descending(&mut self, descending: bool) {
+        if self.sort_descending != descending {
+            self.sort_descending = descending;
+            self.sort_images();
+        }
+    }
+
+    /// Sort the image list according to the current sort mode and
+    /// direction, preserving the currently-viewed image across the reorder.
     pub(crate) fn sort_images(&mut self) {
         let current_path = self.image_paths.get(self.current_index).cloned();
 
         match self.sort_mode {
             SortMode::Alphabetical => {
-                self.image_paths
-                    .sort_by_cached_key(|p| p.to_string_lossy().to_lowercase());
+                self.image_paths.sort_by(|a, b| natural_path_cmp(a, b));
             }
             SortMode::ModifiedDate => {
                 use std::cmp::Reverse;
@@ -301,8 +647,10 @@ impl AppState {
                 });
             }
             SortMode::TypeAlpha => {
-                self.image_paths.sort_by_cached_key(|p| {
-                    (image_type_group(p), p.to_string_lossy().to_lowercase())
+                self.image_paths.sort_by(|a, b| {
+                    image_type_group(a)
+                        .cmp(&image_type_group(b))
+                        .then_with(|| natural_path_cmp(a, b))
                 });
             }
             SortMode::TypeModified => {
@@ -315,6 +663,9 @@ impl AppState {
                 });
             }
         }
+        if self.sort_descending {
+            self.image_paths.reverse();
+        }
 
         // Restore current_index to point at the same image after reordering
         if let Some(path) = current_path
@@ -339,6 +690,125 @@ mod tests {
     const DEFAULT_CACHE_SIZE: usize = 1000;
     const SMALL_CACHE_SIZE: usize = 2;
 
+    fn state_with(count: usize, current_index: usize) -> AppState {
+        let paths = (0..count)
+            .map(|i| PathBuf::from(format!("img{i}.png")))
+            .collect();
+        let mut state = AppState::new(paths);
+        state.current_index = current_index;
+        state
+    }
+
+    #[test]
+    fn test_step_past_end_stop_and_notify_stay_put() {
+        for (end, expected) in [
+            (EndOfList::Stop, EndOfListStep::Stayed),
+            (EndOfList::Notify, EndOfListStep::Notify),
+        ] {
+            let mut state = state_with(3, 2);
+            assert_eq!(state.step_past_end(true, end, false), expected);
+            assert_eq!(state.current_index, 2);
+            assert_eq!(state.image_paths.len(), 3);
+        }
+    }
+
+    #[test]
+    fn test_step_past_end_opens_adjacent_folder() {
+        // Arrange - a/ then b/, each with two images
+        let dir = tempfile::tempdir().unwrap();
+        for folder in ["a", "b"] {
+            std::fs::create_dir(dir.path().join(folder)).unwrap();
+            for file in ["1.png", "2.png"] {
+                std::fs::write(dir.path().join(folder).join(file), b"fake").unwrap();
+            }
+        }
+        let mut state = AppState::new(vec![dir.path().join("a/1.png"), dir.path().join("a/2.png")]);
+        state.current_index = 1;
+
+        // Act - off the end of a/
+        let step = state.step_past_end(true, EndOfList::AdjacentFolder, false);
+
+        // Assert - at the start of b/
+        assert_eq!(step, EndOfListStep::OpenedFolder(dir.path().join("b")));
+        assert_eq!(state.current_image(), Some(&dir.path().join("b/1.png")));
+
+        // Act - back off the start of b/ lands on a/'s last image
+        let step = state.step_past_end(false, EndOfList::AdjacentFolder, false);
+
+        // Assert
+        assert_eq!(step, EndOfListStep::OpenedFolder(dir.path().join("a")));
+        assert_eq!(state.current_image(), Some(&dir.path().join("a/2.png")));
+
+        // Act / Assert - nothing before a/
+        assert_eq!(
+            state.step_past_end(false, EndOfList::AdjacentFolder, false),
+            EndOfListStep::NoMoreFolders
+        );
+        assert_eq!(state.current_image(), Some(&dir.path().join("a/2.png")));
+    }
+
+    #[test]
+    fn test_set_images_from_parent_keeps_focus_on_the_folder_left() {
+        // Arrange - top/a.png, top/z.png and top/sub/{b,c}.png
+        let dir = tempfile::tempdir().unwrap();
+        let top = dir.path();
+        let sub = top.join("sub");
+        std::fs::create_dir(&sub).unwrap();
+        for file in ["a.png", "z.png", "sub/b.png", "sub/c.png"] {
+            std::fs::write(top.join(file), b"fake").unwrap();
+        }
+        let mut state = AppState::new(vec![sub.join("b.png"), sub.join("c.png")]);
+        state.current_index = 1;
+
+        // Act - a recursive scan of the parent reaches back into sub/
+        let images = crate::utils::file_scanner::scan_directory_with(top, true).unwrap();
+        state.set_images_from_parent(images, &sub);
+
+        // Assert
+        assert_eq!(state.image_paths.len(), 4);
+        assert_eq!(state.current_image(), Some(&sub.join("b.png")));
+
+        // Act - a flat scan leaves sub/ out, so the list starts at the top
+        let images = crate::utils::file_scanner::scan_directory_with(top, false).unwrap();
+        state.set_images_from_parent(images, &sub);
+
+        // Assert
+        assert_eq!(
+            state.image_paths,
+            vec![top.join("a.png"), top.join("z.png")]
+        );
+        assert_eq!(state.current_index, 0);
+    }
+
+    #[test]
+    fn test_preload_indices_match_radius_with_wrap() {
+        // Arrange - at the start of a 10-image list
+        let state = state_with(10, 0);
+
+        // Act
+        let indices = state.preload_indices(3, true);
+
+        // Assert - nearest first, wrapping round to the end
+        assert_eq!(indices, vec![1, 9, 2, 8, 3, 7]);
+    }
+
+    #[test]
+    fn test_preload_indices_stop_at_ends_without_wrap() {
+        assert_eq!(state_with(10, 0).preload_indices(3, false), vec![1, 2, 3]);
+        assert_eq!(
+            state_with(10, 8).preload_indices(3, false),
+            vec![9, 7, 6, 5]
+        );
+    }
+
+    #[test]
+    fn test_preload_indices_small_list_no_repeats() {
+        // Radius wider than the list: each other image once, never current
+        assert_eq!(state_with(3, 1).preload_indices(3, true), vec![2, 0]);
+        assert!(state_with(1, 0).preload_indices(2, true).is_empty());
+        assert!(state_with(0, 0).preload_indices(2, true).is_empty());
+    }
+
     #[test]
     fn test_image_type_group_jpg_jpeg_merge() {
         assert_eq!(
@@ -393,6 +863,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_alphabetical_sort_is_natural() {
+        // Arrange
+        let paths = ["img10.png", "img2.png", "IMG100.png", "img1.png"]
+            .map(PathBuf::from)
+            .to_vec();
+        let mut state = AppState::new(paths);
+        state.current_index = 0;
+
+        // Act
+        state.sort_images();
+
+        // Assert
+        assert_eq!(
+            state.image_paths,
+            ["img1.png", "img2.png", "img10.png", "IMG100.png"].map(PathBuf::from)
+        );
+        assert_eq!(state.current_index, 2);
+    }
+
+    #[test]
+    fn test_sort_descending_reverses_and_keeps_current_image() {
+        // Arrange
+        let paths = ["c.png", "a.png", "d.png", "b.png"]
+            .map(PathBuf::from)
+            .to_vec();
+        let mut state = AppState::new(paths);
+        state.sort_images();
+        let ascending = state.image_paths.clone();
+        state.current_index = 1;
+
+        // Act
+        state.set_sort_descending(true);
+
+        // Assert
+        let mut reversed = ascending.clone();
+        reversed.reverse();
+        assert_eq!(
+            ascending,
+            ["a.png", "b.png", "c.png", "d.png"].map(PathBuf::from)
+        );
+        assert_eq!(state.image_paths, reversed);
+        assert_eq!(state.current_image(), Some(&PathBuf::from("b.png")));
+        assert_eq!(state.current_index, 2);
+
+        // Act / Assert - the direction survives a change of sort mode
+        state.set_sort_mode(SortMode::TypeAlpha);
+        assert_eq!(state.image_paths, reversed);
+        state.set_sort_descending(false);
+        assert_eq!(state.image_paths, ascending);
+        assert_eq!(state.current_image(), Some(&PathBuf::from("b.png")));
+    }
+
     #[test]
     fn test_sort_mode_default() {
         // Arrange & Act
@@ -618,6 +1141,308 @@ mod tests {
         assert_eq!(state.current_index, 0);
     }
 
+    #[test]
+    fn test_first_and_last_image() {
+        // Arrange
+        let paths = vec![
+            PathBuf::from("a.png"),
+            PathBuf::from("b.png"),
+            PathBuf::from("c.png"),
+        ];
+        let mut state = AppState::new(paths);
+        state.current_index = 1;
+
+        // Act / Assert
+        state.last_image();
+        assert_eq!(state.current_index, 2);
+        state.first_image();
+        assert_eq!(state.current_index, 0);
+    }
+
+    #[test]
+    fn test_first_and_last_image_empty_list() {
+        // Arrange
+        let mut state = AppState::new(Vec::new());
+
+        // Act
+        state.last_image();
+        state.first_image();
+
+        // Assert
+        assert_eq!(state.current_index, 0);
+        assert_eq!(state.current_image(), None);
+    }
+
+    fn ten_images() -> AppState {
+        AppState::new((0..10).map(|i| PathBuf::from(format!("{i}.png"))).collect())
+    }
+
+    #[test]
+    fn test_fraction_to_index_across_list_sizes() {
+        // (fraction, len, expected index)
+        let cases = [
+            (0.5, 10, 5),
+            (0.1, 10, 1),
+            (0.9, 10, 9),
+            (0.5, 100, 50),
+            (0.3, 7, 2),
+            (0.9, 3, 2),
+            (0.5, 1, 0),
+            (1.0, 10, 9),
+            (0.0, 10, 0),
+            (0.5, 0, 0),
+        ];
+        for (fraction, len, expected) in cases {
+            assert_eq!(
+                AppState::fraction_to_index(fraction, len),
+                expected,
+                "fraction {fraction} of {len}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_jump_to_fraction_moves_current_index() {
+        // Arrange
+        let mut state = ten_images();
+
+        // Act
+        state.jump_to_fraction(0.5);
+
+        // Assert
+        assert_eq!(state.current_index, 5);
+    }
+
+    #[test]
+    fn test_jump_to_fraction_empty_list_is_noop() {
+        // Arrange
+        let mut state = AppState::new(Vec::new());
+
+        // Act
+        state.jump_to_fraction(0.5);
+
+        // Assert
+        assert_eq!(state.current_index, 0);
+    }
+
+    fn mixed_formats() -> AppState {
+        AppState::new(
+            ["a.png", "b.png", "c.jpg", "d.JPEG", "e.png", "f.gif"]
+                .iter()
+                .map(PathBuf::from)
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_different_format_neighbor_forward() {
+        // Arrange
+        let mut state = mixed_formats();
+
+        // Act / Assert - skips the second PNG
+        assert_eq!(state.different_format_neighbor(true, false), Some(2));
+
+        // JPG and JPEG are one format, so from c.jpg the next is e.png
+        state.current_index = 2;
+        assert_eq!(state.different_format_neighbor(true, false), Some(4));
+    }
+
+    #[test]
+    fn test_different_format_neighbor_backward() {
+        // Arrange
+        let mut state = mixed_formats();
+        state.current_index = 3;
+
+        // Act
+        let result = state.different_format_neighbor(false, false);
+
+        // Assert - c.jpg matches d.JPEG, so b.png is the nearest
+        assert_eq!(result, Some(1));
+    }
+
+    #[test]
+    fn test_different_format_neighbor_respects_wrap() {
+        // Arrange - at the GIF, nothing after it
+        let mut state = mixed_formats();
+        state.current_index = 5;
+
+        // Act / Assert
+        assert_eq!(state.different_format_neighbor(true, false), None);
+        assert_eq!(state.different_format_neighbor(true, true), Some(0));
+
+        // Backward from the first image only finds something by wrapping
+        state.current_index = 0;
+        assert_eq!(state.different_format_neighbor(false, false), None);
+        assert_eq!(state.different_format_neighbor(false, true), Some(5));
+    }
+
+    #[test]
+    fn test_different_format_neighbor_single_format_is_none() {
+        // Arrange
+        let state = ten_images();
+
+        // Act / Assert
+        assert_eq!(state.different_format_neighbor(true, true), None);
+        assert_eq!(state.different_format_neighbor(false, true), None);
+        assert_eq!(
+            AppState::new(Vec::new()).different_format_neighbor(true, true),
+            None
+        );
+    }
+
+    #[test]
+    fn test_go_to_moves_to_index() {
+        // Arrange
+        let mut state = ten_images();
+
+        // Act
+        let moved = state.go_to(6);
+
+        // Assert
+        assert!(moved);
+        assert_eq!(state.current_index, 6);
+        assert!(!state.go_to(6));
+    }
+
+    #[test]
+    fn test_go_to_clamps_out_of_range() {
+        // Arrange
+        let mut state = ten_images();
+
+        // Act
+        let moved = state.go_to(1450);
+
+        // Assert - lands on the last image
+        assert!(moved);
+        assert_eq!(state.current_index, 9);
+        assert!(!state.go_to(usize::MAX));
+    }
+
+    #[test]
+    fn test_go_to_empty_list() {
+        let mut state = AppState::new(Vec::new());
+        assert!(!state.go_to(0));
+        assert!(!state.go_to(5));
+        assert_eq!(state.current_index, 0);
+    }
+
+    #[test]
+    fn test_advance_by_within_bounds() {
+        // Arrange
+        let mut state = ten_images();
+        state.current_index = 2;
+
+        // Act
+        state.advance_by(5, false);
+
+        // Assert
+        assert_eq!(state.current_index, 7);
+    }
+
+    #[test]
+    fn test_advance_by_past_end_without_wrap_clamps() {
+        // Arrange
+        let mut state = ten_images();
+        state.current_index = 7;
+
+        // Act
+        state.advance_by(5, false);
+
+        // Assert - stops at the last image
+        assert_eq!(state.current_index, 9);
+    }
+
+    #[test]
+    fn test_advance_by_past_end_with_wrap() {
+        // Arrange
+        let mut state = ten_images();
+        state.current_index = 7;
+
+        // Act
+        state.advance_by(5, true);
+
+        // Assert - 7 + 5 = 12 wraps to 2
+        assert_eq!(state.current_index, 2);
+    }
+
+    #[test]
+    fn test_advance_by_stride_larger_than_list_with_wrap() {
+        // Arrange
+        let mut state = ten_images();
+        state.current_index = 3;
+
+        // Act
+        state.advance_by(25, true);
+
+        // Assert - 3 + 25 = 28 wraps to 8
+        assert_eq!(state.current_index, 8);
+    }
+
+    #[test]
+    fn test_retreat_by_within_bounds() {
+        // Arrange
+        let mut state = ten_images();
+        state.current_index = 8;
+
+        // Act
+        state.retreat_by(5, false);
+
+        // Assert
+        assert_eq!(state.current_index, 3);
+    }
+
+    #[test]
+    fn test_retreat_by_past_start_without_wrap_clamps() {
+        // Arrange
+        let mut state = ten_images();
+        state.current_index = 3;
+
+        // Act
+        state.retreat_by(5, false);
+
+        // Assert - stops at the first image
+        assert_eq!(state.current_index, 0);
+    }
+
+    #[test]
+    fn test_retreat_by_past_start_with_wrap() {
+        // Arrange
+        let mut state = ten_images();
+        state.current_index = 3;
+
+        // Act
+        state.retreat_by(5, true);
+
+        // Assert - 3 - 5 wraps to 8
+        assert_eq!(state.current_index, 8);
+    }
+
+    #[test]
+    fn test_retreat_by_stride_larger_than_list_with_wrap() {
+        // Arrange
+        let mut state = ten_images();
+        state.current_index = 3;
+
+        // Act
+        state.retreat_by(25, true);
+
+        // Assert - 3 - 25 = -22 wraps to 8
+        assert_eq!(state.current_index, 8);
+    }
+
+    #[test]
+    fn test_stride_navigation_empty_list_no_panic() {
+        // Arrange
+        let mut state = AppState::new(Vec::new());
+
+        // Act
+        state.advance_by(10, true);
+        state.retreat_by(10, true);
+
+        // Assert
+        assert_eq!(state.current_index, 0);
+    }
+
     #[test]
     fn test_get_current_state_creates_new_with_defaults() {
         // Arrange
@@ -627,6 +1452,12 @@ mod tests {
             brightness: 10.0,
             contrast: 20.0,
             gamma: 1.5,
+            saturation: 0.0,
+            invert: false,
+            grayscale: false,
+            red_offset: 0.0,
+            green_offset: 0.0,
+            blue_offset: 0.0,
         };
 
         // Act
@@ -656,6 +1487,121 @@ mod tests {
         assert_eq!(image_state.zoom, 2.5);
     }
 
+    #[test]
+    fn test_has_adjusted_view_never_touched_image() {
+        // Arrange - state cached on navigating away, but no zoom/pan by hand
+        let paths = vec![PathBuf::from("a.png"), PathBuf::from("b.png")];
+        let mut state = AppState::new(paths);
+        let mut untouched = ImageState::new();
+        untouched.filters.brightness = 20.0;
+        state.save_current_state(untouched);
+
+        // Act & Assert - cached, but the view isn't restorable
+        assert!(state.image_states.contains_key(&PathBuf::from("a.png")));
+        assert!(!state.has_adjusted_view());
+    }
+
+    #[test]
+    fn test_has_adjusted_view_manually_zoomed_image() {
+        // Arrange
+        let paths = vec![PathBuf::from("a.png"), PathBuf::from("b.png")];
+        let mut state = AppState::new(paths);
+        let mut zoomed = ImageState::new();
+        zoomed.zoom = 3.0;
+        zoomed.is_fit_to_window = false;
+        zoomed.view_adjusted = true;
+        state.save_current_state(zoomed);
+
+        // Act - navigate away and back
+        state.next_image();
+        let elsewhere = state.has_adjusted_view();
+        state.previous_image();
+
+        // Assert
+        assert!(!elsewhere);
+        assert!(state.has_adjusted_view());
+        assert_eq!(state.get_current_state(FilterSettings::default()).zoom, 3.0);
+    }
+
+    #[test]
+    fn test_fit_lock_ignores_saved_view_on_navigation() {
+        // Arrange - a.png was zoomed by hand before navigating away
+        let paths = vec![PathBuf::from("a.png"), PathBuf::from("b.png")];
+        let mut state = AppState::new(paths);
+        let mut zoomed = ImageState::new();
+        zoomed.zoom = 3.0;
+        zoomed.is_fit_to_window = false;
+        zoomed.view_adjusted = true;
+        state.save_current_state(zoomed);
+        state.next_image();
+
+        // Act - navigate back under fit-lock
+        state.previous_image();
+        let mut restored = state.get_current_state(FilterSettings::default());
+        restored.lock_to_fit();
+
+        // Assert - back to fit, and not treated as adjusted once cached again
+        assert!(restored.is_fit_to_window);
+        state.save_current_state(restored);
+        assert!(!state.has_adjusted_view());
+    }
+
+    #[test]
+    fn test_filter_lock_survives_a_never_visited_image() {
+        // Arrange - a.png is on screen with locked filters; b.png has never
+        // been shown, so it loads without remembered state
+        let paths = vec![PathBuf::from("a.png"), PathBuf::from("b.png")];
+        let mut state = AppState::new(paths);
+        let defaults = FilterSettings {
+            contrast: 10.0,
+            ..Default::default()
+        };
+        let mut shown = ImageState::new_with_filter_defaults(defaults);
+        shown.filters.brightness = 40.0;
+
+        // Act - step to b.png and open it as the load completion does
+        state.next_image();
+        assert!(!state.image_states.contains_key(&PathBuf::from("b.png")));
+        shown.filters = AppState::opening_filters(defaults, Some(shown.filters));
+        state.save_shown_state(shown.clone(), true, defaults);
+
+        // Assert - b.png shows the locked filters, but caches the defaults
+        // as its own
+        assert_eq!(shown.filters.brightness, 40.0);
+        let saved = state.get_current_state(defaults);
+        assert_eq!(saved.filters, defaults);
+
+        // Act / Assert - without the lock a new image gets the defaults
+        assert_eq!(AppState::opening_filters(defaults, None), defaults);
+    }
+
+    #[test]
+    fn test_filter_lock_keeps_a_visited_images_own_filters() {
+        // Arrange - b.png has its own saved filters
+        let paths = vec![PathBuf::from("a.png"), PathBuf::from("b.png")];
+        let mut state = AppState::new(paths);
+        state.next_image();
+        let mut own = ImageState::new();
+        own.filters.contrast = -20.0;
+        state.save_current_state(own);
+        let mut shown = state.get_current_state(FilterSettings::default());
+        shown.filters.brightness = 40.0;
+
+        // Act - cache it while the locked filters are on screen
+        state.save_shown_state(shown, true, FilterSettings::default());
+
+        // Assert - b.png's own filters survive
+        let restored = state.get_current_state(FilterSettings::default());
+        assert_eq!(restored.filters.brightness, 0.0);
+        assert_eq!(restored.filters.contrast, -20.0);
+    }
+
+    #[test]
+    fn test_has_adjusted_view_no_cached_state() {
+        let state = AppState::new(vec![PathBuf::from("a.png")]);
+        assert!(!state.has_adjusted_view());
+    }
+
     #[test]
     fn test_get_current_state_empty_list() {
         // Arrange
@@ -664,6 +1610,12 @@ mod tests {
             brightness: 5.0,
             contrast: 5.0,
             gamma: 1.2,
+            saturation: 0.0,
+            invert: false,
+            grayscale: false,
+            red_offset: 0.0,
+            green_offset: 0.0,
+            blue_offset: 0.0,
         };
 
         // Act
@@ -892,6 +1844,70 @@ mod tests {
         assert!(!state.image_states.contains_key(&PathBuf::from("a.png")));
     }
 
+    #[test]
+    fn test_has_unsaved_filter_changes_across_cached_states() {
+        // Arrange — two images, both cached at default filters
+        let paths = vec![PathBuf::from("a.png"), PathBuf::from("b.png")];
+        let mut state = AppState::new(paths);
+        state.save_current_state(ImageState::new());
+        state.current_index = 1;
+        state.save_current_state(ImageState::new());
+        assert!(!state.has_unsaved_filter_changes(FilterSettings::default()));
+
+        // Act — edit the filters of the image we are no longer viewing
+        state
+            .image_states
+            .get_mut(&PathBuf::from("a.png"))
+            .unwrap()
+            .filters
+            .brightness = 30.0;
+
+        // Assert
+        assert!(state.has_unsaved_filter_changes(FilterSettings::default()));
+    }
+
+    #[test]
+    fn test_set_images_from_folder_sorts_and_starts_at_first() {
+        use crate::utils::file_scanner::scan_directory;
+
+        // Arrange — a folder with images (and a non-image) in scrambled order,
+        // while viewing the last image of a different list
+        let dir = tempfile::tempdir().unwrap();
+        for name in ["zebra.png", "Apple.jpg", "mango.gif", "notes.txt"] {
+            std::fs::write(dir.path().join(name), b"fake").unwrap();
+        }
+        let mut state = AppState::new(vec![PathBuf::from("old1.png"), PathBuf::from("old2.png")]);
+        state.current_index = 1;
+
+        // Act
+        state.set_images_from_folder(scan_directory(dir.path()).unwrap());
+
+        // Assert — alphabetical (active mode), non-images excluded, first selected
+        let names: Vec<_> = state
+            .image_paths
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(names, vec!["Apple.jpg", "mango.gif", "zebra.png"]);
+        assert_eq!(state.current_index, 0);
+    }
+
+    #[test]
+    fn test_set_images_from_folder_empty() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let mut state = AppState::new(vec![PathBuf::from("old.png")]);
+
+        // Act
+        state.set_images_from_folder(
+            crate::utils::file_scanner::scan_directory(dir.path()).unwrap(),
+        );
+
+        // Assert — empty list (the no-images state), no current image
+        assert!(state.image_paths.is_empty());
+        assert!(state.current_image().is_none());
+    }
+
     // -- SortMode label tests -------------------------------------------------
 
     #[test]
@@ -973,4 +1989,131 @@ mod tests {
         // Assert — should default to index 0
         assert_eq!(state.current_index, 0);
     }
+
+    #[test]
+    fn test_rotation_is_restored_on_return() {
+        // Arrange - rotate the first image, then move on
+        let mut state = ten_images();
+        let mut image_state = state.get_current_state(FilterSettings::default());
+        image_state.rotate(true);
+        state.save_current_state(image_state);
+        state.next_image_with_wrap(false);
+
+        // Act
+        state.previous_image_with_wrap(false);
+        let restored = state.get_current_state(FilterSettings::default());
+
+        // Assert
+        assert_eq!(restored.rotation, 90);
+    }
+
+    #[test]
+    fn test_rename_paths_keeps_order_and_state() {
+        // Arrange
+        let mut state = ten_images();
+        state.current_index = 3;
+        let mut image_state = state.get_current_state(FilterSettings::default());
+        image_state.rotate(true);
+        state.save_current_state(image_state);
+        let old = state.image_paths[3].clone();
+        let new = PathBuf::from("2023/05/14/moved.png");
+
+        // Act
+        state.rename_paths(&HashMap::from([(old.clone(), new.clone())]));
+
+        // Assert
+        assert_eq!(state.image_paths.len(), 10);
+        assert_eq!(state.current_image(), Some(&new));
+        assert!(!state.image_states.contains_key(&old));
+        assert_eq!(
+            state.get_current_state(FilterSettings::default()).rotation,
+            90
+        );
+    }
+
+    // -- two-page spreads -----------------------------------------------------
+
+    #[test]
+    fn test_spread_start_pairs_even_and_odd_offsets() {
+        // (index, cover_alone, expected spread start)
+        let cases = [
+            (0, false, 0),
+            (1, false, 0),
+            (2, false, 2),
+            (5, false, 4),
+            (0, true, 0),
+            (1, true, 1),
+            (2, true, 1),
+            (3, true, 3),
+            (6, true, 5),
+        ];
+        for (index, cover_alone, expected) in cases {
+            assert_eq!(
+                AppState::spread_start(index, cover_alone),
+                expected,
+                "index {index}, cover_alone {cover_alone}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_spread_partner() {
+        // Arrange
+        let mut state = ten_images();
+
+        // Act & Assert - pairs are (0, 1), (2, 3), ...
+        state.current_index = 2;
+        assert_eq!(state.spread_partner(false), Some(3));
+        state.current_index = 3;
+        assert_eq!(state.spread_partner(false), Some(2));
+
+        // Act & Assert - a lone cover, then (1, 2), ..., (9) unpaired at the end
+        state.current_index = 0;
+        assert_eq!(state.spread_partner(true), None);
+        state.current_index = 1;
+        assert_eq!(state.spread_partner(true), Some(2));
+        state.current_index = 9;
+        assert_eq!(state.spread_partner(true), None);
+    }
+
+    #[test]
+    fn test_step_spread_forward_and_back() {
+        // Arrange
+        let mut state = ten_images();
+
+        // Act & Assert - cover alone: 0 -> 1 -> 3, and back 3 -> 1 -> 0
+        state.step_spread(true, false, true);
+        assert_eq!(state.current_index, 1);
+        state.step_spread(true, false, true);
+        assert_eq!(state.current_index, 3);
+        state.step_spread(false, false, true);
+        assert_eq!(state.current_index, 1);
+        state.step_spread(false, false, true);
+        assert_eq!(state.current_index, 0);
+
+        // Act & Assert - from the second page of a spread, forward skips its partner
+        state.current_index = 3;
+        state.step_spread(true, false, false);
+        assert_eq!(state.current_index, 4);
+    }
+
+    #[test]
+    fn test_step_spread_at_the_ends() {
+        // Arrange
+        let mut state = ten_images();
+        state.current_index = 9;
+
+        // Act & Assert - the last spread (8, 9): stays without wrap, else wraps
+        state.step_spread(true, false, false);
+        assert_eq!(state.current_index, 8);
+        state.step_spread(true, true, false);
+        assert_eq!(state.current_index, 0);
+
+        // Act & Assert - back from the first spread wraps to the last
+        state.step_spread(false, true, true);
+        assert_eq!(state.current_index, 9);
+        state.current_index = 0;
+        state.step_spread(false, true, false);
+        assert_eq!(state.current_index, 8);
+    }
 }
@@ -0,0 +1,85 @@
+//! Session-only backdrop override for judging transparent images.
+//!
+//! `Shift+B` cycles what is drawn behind the image without touching the
+//! saved background settings; a new window starts back at `Configured`.
+
+/// What the viewer draws behind the image
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Backdrop {
+    /// The background color from settings (dark or light)
+    #[default]
+    Configured,
+    /// Light/gray checkerboard behind the image, the usual transparency grid
+    Checkerboard,
+    White,
+    Black,
+}
+
+impl Backdrop {
+    /// The backdrop after this one in the `Shift+B` cycle
+    pub fn next(self) -> Self {
+        match self {
+            Self::Configured => Self::Checkerboard,
+            Self::Checkerboard => Self::White,
+            Self::White => Self::Black,
+            Self::Black => Self::Configured,
+        }
+    }
+
+    /// Name shown in the toast when cycling
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Configured => "Background color",
+            Self::Checkerboard => "Checkerboard",
+            Self::White => "White",
+            Self::Black => "Black",
+        }
+    }
+
+    /// Solid color to fill the viewer with: the checkerboard sits on the
+    /// configured color, which shows around the image
+    pub fn fill_color(self, configured: [u8; 3]) -> [u8; 3] {
+        match self {
+            Self::Configured | Self::Checkerboard => configured,
+            Self::White => [0xFF; 3],
+            Self::Black => [0x00; 3],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycle_visits_every_backdrop_and_returns() {
+        // Arrange
+        let start = Backdrop::default();
+
+        // Act
+        let cycle: Vec<Backdrop> = std::iter::successors(Some(start), |b| Some(b.next()))
+            .take(5)
+            .collect();
+
+        // Assert
+        assert_eq!(
+            cycle,
+            [
+                Backdrop::Configured,
+                Backdrop::Checkerboard,
+                Backdrop::White,
+                Backdrop::Black,
+                Backdrop::Configured,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fill_color() {
+        let configured = [0x1E, 0x1E, 0x1E];
+        assert_eq!(Backdrop::Configured.fill_color(configured), configured);
+        assert_eq!(Backdrop::Checkerboard.fill_color(configured), configured);
+        assert_eq!(Backdrop::White.fill_color(configured), [0xFF; 3]);
+        assert_eq!(Backdrop::Black.fill_color(configured), [0x00; 3]);
+    }
+}
@@ -0,0 +1,130 @@
+//! Spotting a key pressed twice in quick succession.
+//!
+//! A tap only counts once its key has been let go, so holding a key down
+//! (and the auto-repeat that comes with it) never reads as a double tap.
+
+use std::time::{Duration, Instant};
+
+/// The last tap of a key that might be doubled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Tap<K> {
+    key: K,
+    at: Instant,
+    released: bool,
+    /// Auto-repeated while held, so it can't be the first of a pair
+    held: bool,
+}
+
+/// Tracks presses and releases of keys of type `K`
+#[derive(Debug, Clone, Default)]
+pub struct DoubleTap<K> {
+    last: Option<Tap<K>>,
+}
+
+impl<K: Copy + PartialEq> DoubleTap<K> {
+    /// Record a press of `key` at `now`, returning whether it completes a
+    /// double tap: the same key, tapped (not held) and released since, and
+    /// pressed again within `window`.  A completed double starts over, so a third
+    /// tap is a first tap again.
+    pub fn press(&mut self, key: K, now: Instant, window: Duration) -> bool {
+        match self.last {
+            Some(ref mut tap) if tap.key == key && !tap.released => {
+                tap.held = true;
+                false
+            }
+            Some(tap) if tap.key == key && !tap.held && now.duration_since(tap.at) <= window => {
+                self.last = None;
+                true
+            }
+            _ => {
+                self.last = Some(Tap {
+                    key,
+                    at: now,
+                    released: false,
+                    held: false,
+                });
+                false
+            }
+        }
+    }
+
+    /// Record that `key` was let go
+    pub fn release(&mut self, key: K) {
+        if let Some(tap) = self.last.as_mut().filter(|tap| tap.key == key) {
+            tap.released = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: Duration = Duration::from_millis(300);
+
+    fn tap(taps: &mut DoubleTap<char>, key: char, at: Instant) -> bool {
+        let doubled = taps.press(key, at, WINDOW);
+        taps.release(key);
+        doubled
+    }
+
+    #[test]
+    fn test_double_tap_within_window() {
+        // Arrange
+        let mut taps = DoubleTap::default();
+        let start = Instant::now();
+
+        // Act
+        let first = tap(&mut taps, 'r', start);
+        let second = tap(&mut taps, 'r', start + Duration::from_millis(250));
+
+        // Assert
+        assert!(!first);
+        assert!(second);
+    }
+
+    #[test]
+    fn test_slow_second_tap_is_a_new_first_tap() {
+        // Arrange
+        let mut taps = DoubleTap::default();
+        let start = Instant::now();
+
+        // Act
+        tap(&mut taps, 'r', start);
+        let late = tap(&mut taps, 'r', start + Duration::from_millis(301));
+        let quick = tap(&mut taps, 'r', start + Duration::from_millis(400));
+
+        // Assert - the late tap restarted the window for the quick one
+        assert!(!late);
+        assert!(quick);
+    }
+
+    #[test]
+    fn test_a_third_tap_starts_over() {
+        let mut taps = DoubleTap::default();
+        let start = Instant::now();
+        tap(&mut taps, 'r', start);
+        assert!(tap(&mut taps, 'r', start + Duration::from_millis(100)));
+        assert!(!tap(&mut taps, 'r', start + Duration::from_millis(200)));
+    }
+
+    #[test]
+    fn test_other_keys_and_held_keys_do_not_double() {
+        // Arrange
+        let mut taps = DoubleTap::default();
+        let start = Instant::now();
+
+        // Act / Assert - a different key in between breaks the pair
+        tap(&mut taps, 'l', start);
+        assert!(!tap(&mut taps, 'r', start + Duration::from_millis(50)));
+        assert!(tap(&mut taps, 'r', start + Duration::from_millis(100)));
+
+        // Act / Assert - auto-repeat presses without a release
+        let held = start + Duration::from_secs(1);
+        assert!(!taps.press('r', held, WINDOW));
+        assert!(!taps.press('r', held + Duration::from_millis(30), WINDOW));
+        assert!(!taps.press('r', held + Duration::from_millis(60), WINDOW));
+        taps.release('r');
+        assert!(!tap(&mut taps, 'r', held + Duration::from_millis(100)));
+    }
+}
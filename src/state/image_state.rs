@@ -1,3 +1,4 @@
+use crate::utils::animation::LoopMode;
 use std::time::Instant;
 
 /// Per-image state that is cached and persisted
@@ -12,6 +13,11 @@ pub struct ImageState {
     /// Whether the image is at fit-to-window size
     pub is_fit_to_window: bool,
 
+    /// Whether the user has zoomed or panned this image by hand.  Only an
+    /// adjusted view is restored on return; an untouched image reopens in
+    /// the default zoom mode even though its other state is cached.
+    pub view_adjusted: bool,
+
     /// Effective display dimensions (width, height) at the time `zoom`/`pan`
     /// were last persisted. Used by `load_current_image_state` to rescale
     /// zoom when the displayed pixel grid differs at restore time — e.g.
@@ -29,11 +35,18 @@ pub struct ImageState {
     /// Whether filters are currently enabled
     pub filters_enabled: bool,
 
+    /// Filter values as of the last successful save of this image
+    /// (None = never saved).  Baseline for `has_unsaved_filter_changes`.
+    pub saved_filters: Option<FilterSettings>,
+
     /// Animation state (if applicable)
     pub animation: Option<AnimationState>,
 
     /// Whether user has chosen to override the size limit for this image
     pub override_size_limit: bool,
+
+    /// Clockwise rotation in degrees: 0, 90, 180 or 270
+    pub rotation: u16,
 }
 
 impl ImageState {
@@ -48,14 +61,70 @@ impl ImageState {
             zoom: 1.0,
             pan: (0.0, 0.0),
             is_fit_to_window: true,
+            view_adjusted: false,
             saved_effective_size: None,
             last_accessed: Instant::now(),
             filters: default_filters,
             filters_enabled: true,
+            saved_filters: None,
             animation: None,
             override_size_limit: false,
+            rotation: 0,
         }
     }
+
+    /// Turn a quarter turn clockwise, or counter-clockwise when
+    /// `clockwise` is false
+    pub fn rotate(&mut self, clockwise: bool) {
+        self.rotation = (self.rotation + if clockwise { 90 } else { 270 }) % 360;
+    }
+
+    /// Whether the active filters hold edits that would be lost on quit:
+    /// they differ from the last saved values or, if this image was never
+    /// saved, from `defaults`.  Disabled filters are not shown, so they never
+    /// count as dirty.
+    pub fn has_unsaved_filter_changes(&self, defaults: FilterSettings) -> bool {
+        self.filters_enabled && self.filters != self.saved_filters.unwrap_or(defaults)
+    }
+
+    /// Whether a window resize should recompute fit-to-window: when the
+    /// view is already fit, or always while fit-lock holds every image at
+    /// fit.
+    pub fn refits_on_resize(&self, fit_locked: bool) -> bool {
+        fit_locked || self.is_fit_to_window
+    }
+
+    /// Drop a hand-adjusted zoom/pan in favour of fit-to-window (the viewer
+    /// recomputes the fit zoom for the current window).  Fit-lock applies
+    /// this to every image it shows, ignoring the saved view.
+    pub fn lock_to_fit(&mut self) {
+        self.is_fit_to_window = true;
+        self.view_adjusted = false;
+    }
+
+    /// Replace the filters with ones held from elsewhere.  Filter-lock
+    /// applies the live filters to every image it shows, and puts each
+    /// image's own filters back before it is cached.
+    pub fn lock_filters(&mut self, filters: FilterSettings, enabled: bool) {
+        self.filters = filters;
+        self.filters_enabled = enabled;
+    }
+
+    /// Flip the filters on/off switch, returning the new state.  The caller
+    /// refreshes the filtered render to match.
+    pub fn toggle_filters_enabled(&mut self) -> bool {
+        self.filters_enabled = !self.filters_enabled;
+        self.filters_enabled
+    }
+
+    /// The current filters are part of the image itself now (the file was
+    /// re-encoded with them): reset the live filters to `defaults` so they
+    /// aren't applied a second time on top of the rewritten pixels.  Leaves
+    /// nothing unsaved.
+    pub fn filters_baked(&mut self, defaults: FilterSettings) {
+        self.filters = defaults;
+        self.saved_filters = None;
+    }
 }
 
 impl Default for ImageState {
@@ -75,6 +144,27 @@ pub struct FilterSettings {
 
     /// Gamma correction (0.1 to 10.0)
     pub gamma: f32,
+
+    /// Saturation adjustment (-100.0 = grayscale to +100.0)
+    pub saturation: f32,
+
+    /// Invert colors (for viewing scanned negatives)
+    pub invert: bool,
+
+    /// Convert to grayscale (luminance) before the other adjustments
+    pub grayscale: bool,
+
+    /// Per-channel shifts for color-cast correction (-100.0 to +100.0 levels)
+    pub red_offset: f32,
+    pub green_offset: f32,
+    pub blue_offset: f32,
+}
+
+impl FilterSettings {
+    /// The red, green and blue offsets, in channel order
+    pub fn channel_offsets(&self) -> [f32; 3] {
+        [self.red_offset, self.green_offset, self.blue_offset]
+    }
 }
 
 impl Default for FilterSettings {
@@ -83,6 +173,12 @@ impl Default for FilterSettings {
             brightness: 0.0,
             contrast: 0.0,
             gamma: 1.0,
+            saturation: 0.0,
+            invert: false,
+            grayscale: false,
+            red_offset: 0.0,
+            green_offset: 0.0,
+            blue_offset: 0.0,
         }
     }
 }
@@ -104,9 +200,34 @@ pub struct AnimationState {
 
     /// Whether the next frame has been preloaded and is ready to display
     pub next_frame_ready: bool,
+
+    /// Whether the frames carry their own timing.  Untimed sequences
+    /// (multi-page TIFF) don't auto-play; `[`/`]` still steps through them.
+    pub timed: bool,
+
+    /// Playback speed multiplier (1.0 = the file's own timing)
+    pub speed: f32,
+
+    /// What playback does at the end (cycled with `shift-o`)
+    pub loop_mode: LoopMode,
+
+    /// Ping-pong playback is on its way back to the first frame
+    pub reversed: bool,
+
+    /// How many times the file asks to be played through; None loops
+    /// forever
+    pub plays: Option<u32>,
+
+    /// Passes playback has completed (returns to the first frame) since
+    /// the image came up
+    pub passes_played: u32,
 }
 
 impl AnimationState {
+    /// Slowest and fastest playback `change_speed` steps to
+    pub const MIN_SPEED: f32 = 0.25;
+    pub const MAX_SPEED: f32 = 4.0;
+
     pub fn new(frame_count: usize, frame_durations: Vec<u32>) -> Self {
         Self {
             current_frame: 0,
@@ -114,14 +235,77 @@ impl AnimationState {
             frame_count,
             frame_durations,
             next_frame_ready: false,
+            timed: true,
+            speed: 1.0,
+            loop_mode: LoopMode::default(),
+            reversed: false,
+            plays: None,
+            passes_played: 0,
         }
     }
+
+    /// Whether playback is in the middle of a finite run, which the
+    /// slideshow can wait out
+    pub fn finite_run_pending(&self) -> bool {
+        let plays = match self.loop_mode {
+            LoopMode::PlayOnce => Some(1),
+            LoopMode::Loop | LoopMode::PingPong => self.plays,
+        };
+        crate::utils::slideshow::animation_pending(
+            plays,
+            self.passes_played,
+            self.is_playing && self.timed,
+        )
+    }
+
+    /// How long the current frame stays up at the current speed, in
+    /// milliseconds (100 when the file doesn't say)
+    pub fn frame_duration_ms(&self) -> u32 {
+        let duration = self
+            .frame_durations
+            .get(self.current_frame)
+            .copied()
+            .unwrap_or(100);
+        if self.speed == 1.0 {
+            duration
+        } else {
+            (duration as f32 / self.speed).round() as u32
+        }
+    }
+
+    /// Double (`faster`) or halve the playback speed, within
+    /// `MIN_SPEED`..=`MAX_SPEED`.  Returns whether it changed.
+    pub fn change_speed(&mut self, faster: bool) -> bool {
+        let factor = if faster { 2.0 } else { 0.5 };
+        let speed = (self.speed * factor).clamp(Self::MIN_SPEED, Self::MAX_SPEED);
+        let changed = speed != self.speed;
+        self.speed = speed;
+        changed
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_rotate_steps_quarter_turns_and_wraps() {
+        // Arrange
+        let mut state = ImageState::new();
+
+        // Act & Assert - clockwise round to 270, then wrap to 0
+        for expected in [90, 180, 270, 0] {
+            state.rotate(true);
+            assert_eq!(state.rotation, expected);
+        }
+
+        // Act & Assert - counter-clockwise from 0 goes to 270
+        state.rotate(false);
+        assert_eq!(state.rotation, 270);
+        state.rotate(false);
+        assert_eq!(state.rotation, 180);
+    }
+
     // -- ImageState defaults --------------------------------------------------
 
     #[test]
@@ -130,6 +314,7 @@ mod tests {
         assert_eq!(state.zoom, 1.0);
         assert_eq!(state.pan, (0.0, 0.0));
         assert!(state.is_fit_to_window);
+        assert!(!state.view_adjusted);
         assert!(state.filters_enabled);
         assert!(state.animation.is_none());
         assert!(!state.override_size_limit);
@@ -152,6 +337,12 @@ mod tests {
             brightness: 25.0,
             contrast: -10.0,
             gamma: 2.2,
+            saturation: 0.0,
+            invert: false,
+            grayscale: false,
+            red_offset: 0.0,
+            green_offset: 0.0,
+            blue_offset: 0.0,
         };
         let state = ImageState::new_with_filter_defaults(custom);
         assert_eq!(state.filters.brightness, 25.0);
@@ -162,6 +353,108 @@ mod tests {
         assert!(state.is_fit_to_window);
     }
 
+    // -- Unsaved filter detection ---------------------------------------------
+
+    #[test]
+    fn unsaved_filters_clean_at_defaults() {
+        let state = ImageState::new();
+        assert!(!state.has_unsaved_filter_changes(FilterSettings::default()));
+    }
+
+    #[test]
+    fn unsaved_filters_dirty_after_edit() {
+        let mut state = ImageState::new();
+        state.filters.brightness = 15.0;
+        assert!(state.has_unsaved_filter_changes(FilterSettings::default()));
+    }
+
+    #[test]
+    fn unsaved_filters_clean_after_save() {
+        let mut state = ImageState::new();
+        state.filters.gamma = 1.8;
+        state.saved_filters = Some(state.filters);
+        assert!(!state.has_unsaved_filter_changes(FilterSettings::default()));
+
+        // Editing again after the save makes it dirty once more
+        state.filters.contrast = -20.0;
+        assert!(state.has_unsaved_filter_changes(FilterSettings::default()));
+    }
+
+    #[test]
+    fn unsaved_filters_ignored_when_disabled() {
+        let mut state = ImageState::new();
+        state.filters.brightness = 40.0;
+        state.filters_enabled = false;
+        assert!(!state.has_unsaved_filter_changes(FilterSettings::default()));
+    }
+
+    #[test]
+    fn fit_lock_refits_zoomed_view_on_resize() {
+        // Arrange - zoomed by hand, so normally a resize leaves it alone
+        let mut state = ImageState::new();
+        state.zoom = 3.0;
+        state.is_fit_to_window = false;
+        state.view_adjusted = true;
+
+        // Act / Assert
+        assert!(!state.refits_on_resize(false));
+        assert!(state.refits_on_resize(true));
+        assert!(ImageState::new().refits_on_resize(false));
+    }
+
+    #[test]
+    fn toggle_filters_enabled_flips_and_reports_state() {
+        // Arrange
+        let mut state = ImageState::new();
+        state.filters.brightness = 40.0;
+
+        // Act / Assert - off, then back on, filters themselves untouched
+        assert!(!state.toggle_filters_enabled());
+        assert!(!state.filters_enabled);
+        assert!(state.toggle_filters_enabled());
+        assert!(state.filters_enabled);
+        assert_eq!(state.filters.brightness, 40.0);
+    }
+
+    // -- Baking filters into the file -----------------------------------------
+
+    #[test]
+    fn filters_baked_resets_to_defaults() {
+        let mut state = ImageState::new();
+        state.filters.brightness = 30.0;
+        state.saved_filters = Some(FilterSettings {
+            brightness: 10.0,
+            ..FilterSettings::default()
+        });
+        let defaults = FilterSettings {
+            contrast: 5.0,
+            ..FilterSettings::default()
+        };
+
+        state.filters_baked(defaults);
+
+        assert_eq!(state.filters, defaults);
+        assert!(!state.has_unsaved_filter_changes(defaults));
+    }
+
+    #[test]
+    fn unsaved_filters_compare_against_custom_defaults() {
+        let defaults = FilterSettings {
+            brightness: 10.0,
+            contrast: 0.0,
+            gamma: 1.0,
+            saturation: 0.0,
+            invert: false,
+            grayscale: false,
+            red_offset: 0.0,
+            green_offset: 0.0,
+            blue_offset: 0.0,
+        };
+        let state = ImageState::new_with_filter_defaults(defaults);
+        assert!(!state.has_unsaved_filter_changes(defaults));
+        assert!(state.has_unsaved_filter_changes(FilterSettings::default()));
+    }
+
     // -- FilterSettings defaults ----------------------------------------------
 
     #[test]
@@ -170,6 +463,10 @@ mod tests {
         assert_eq!(f.brightness, 0.0);
         assert_eq!(f.contrast, 0.0);
         assert_eq!(f.gamma, 1.0);
+        assert_eq!(f.saturation, 0.0);
+        assert!(!f.invert);
+        assert!(!f.grayscale);
+        assert_eq!(f.channel_offsets(), [0.0; 3]);
     }
 
     #[test]
@@ -178,16 +475,34 @@ mod tests {
             brightness: 1.0,
             contrast: 2.0,
             gamma: 3.0,
+            saturation: 0.0,
+            invert: false,
+            grayscale: false,
+            red_offset: 0.0,
+            green_offset: 0.0,
+            blue_offset: 0.0,
         };
         let b = FilterSettings {
             brightness: 1.0,
             contrast: 2.0,
             gamma: 3.0,
+            saturation: 0.0,
+            invert: false,
+            grayscale: false,
+            red_offset: 0.0,
+            green_offset: 0.0,
+            blue_offset: 0.0,
         };
         let c = FilterSettings {
             brightness: 1.0,
             contrast: 2.0,
             gamma: 3.1,
+            saturation: 0.0,
+            invert: false,
+            grayscale: false,
+            red_offset: 0.0,
+            green_offset: 0.0,
+            blue_offset: 0.0,
         };
         assert_eq!(a, b);
         assert_ne!(a, c);
@@ -204,6 +519,51 @@ mod tests {
         assert_eq!(anim.frame_count, 3);
         assert_eq!(anim.frame_durations, durations);
         assert!(!anim.next_frame_ready);
+        assert_eq!(anim.speed, 1.0);
+        assert_eq!(anim.loop_mode, LoopMode::Loop);
+        assert!(!anim.reversed);
+    }
+
+    #[test]
+    fn animation_frame_duration_follows_speed() {
+        // Arrange
+        let mut anim = AnimationState::new(2, vec![70, 33]);
+
+        // Act & Assert - normal speed is the file's timing, unrounded
+        assert_eq!(anim.frame_duration_ms(), 70);
+        anim.current_frame = 1;
+        assert_eq!(anim.frame_duration_ms(), 33);
+
+        // Act & Assert - faster shortens frames, slower lengthens them
+        anim.speed = 2.0;
+        assert_eq!(anim.frame_duration_ms(), 17);
+        anim.speed = 0.25;
+        assert_eq!(anim.frame_duration_ms(), 132);
+
+        // Act & Assert - frames without a duration get 100ms
+        anim.speed = 1.0;
+        anim.current_frame = 5;
+        assert_eq!(anim.frame_duration_ms(), 100);
+    }
+
+    #[test]
+    fn animation_change_speed_steps_within_limits() {
+        // Arrange
+        let mut anim = AnimationState::new(2, vec![100, 100]);
+
+        // Act & Assert - doubles up to 4x, then stays
+        assert!(anim.change_speed(true));
+        assert!(anim.change_speed(true));
+        assert_eq!(anim.speed, 4.0);
+        assert!(!anim.change_speed(true));
+        assert_eq!(anim.speed, 4.0);
+
+        // Act & Assert - halves down to 0.25x, then stays
+        for _ in 0..4 {
+            assert!(anim.change_speed(false));
+        }
+        assert_eq!(anim.speed, 0.25);
+        assert!(!anim.change_speed(false));
     }
 
     #[test]
@@ -1,7 +1,13 @@
 pub mod app_state;
+pub mod backdrop;
+pub mod double_tap;
 pub mod image_state;
+pub mod overlays;
 pub mod settings;
 
-pub use app_state::{AppState, SortMode};
+pub use app_state::{AppState, EndOfList, EndOfListStep, SortMode};
+pub use backdrop::Backdrop;
+pub use double_tap::DoubleTap;
 pub use image_state::ImageState;
+pub use overlays::{Overlay, OverlayCycle, Overlays};
 pub use settings::AppSettings;
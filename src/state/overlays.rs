@@ -0,0 +1,187 @@
+//! The info overlays (help, debug, histogram, generation info) that are
+//! showing, and which one is in front.
+//!
+//! Any combination may be open at once: help dims the whole window while
+//! debug and histogram sit in the top corners and generation info in the
+//! bottom-left, so the one shown last wins where they overlap.  `Tab` brings the next one forward, and `Esc` closes
+//! whichever is in front.
+
+/// One of the info overlays
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overlay {
+    Help,
+    Debug,
+    Histogram,
+    Generation,
+}
+
+/// What `Overlays::cycle` did
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverlayCycle {
+    /// Nothing is open
+    None,
+    /// This overlay came to the front
+    Raised(Overlay),
+    /// The only open overlay was closed
+    Closed(Overlay),
+}
+
+/// The open overlays, back to front
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Overlays {
+    open: Vec<Overlay>,
+}
+
+impl Overlays {
+    pub fn is_open(&self, overlay: Overlay) -> bool {
+        self.open.contains(&overlay)
+    }
+
+    pub fn any_open(&self) -> bool {
+        !self.open.is_empty()
+    }
+
+    /// The open overlays in drawing order (the front one last)
+    pub fn back_to_front(&self) -> &[Overlay] {
+        &self.open
+    }
+
+    /// Close `overlay` if it is open; otherwise open it in front
+    pub fn toggle(&mut self, overlay: Overlay) {
+        if self.is_open(overlay) {
+            self.close(overlay);
+        } else {
+            self.open.push(overlay);
+        }
+    }
+
+    pub fn close(&mut self, overlay: Overlay) {
+        self.open.retain(|&open| open != overlay);
+    }
+
+    /// Close the front overlay, returning it
+    pub fn close_front(&mut self) -> Option<Overlay> {
+        self.open.pop()
+    }
+
+    /// Close every overlay, handing back what was open so `restore` can
+    /// bring it back
+    pub fn hide_all(&mut self) -> Overlays {
+        std::mem::take(self)
+    }
+
+    /// Reopen overlays put away by `hide_all`, behind any opened since
+    pub fn restore(&mut self, hidden: Overlays) {
+        let opened = std::mem::replace(&mut self.open, hidden.open);
+        for overlay in opened {
+            if !self.is_open(overlay) {
+                self.open.push(overlay);
+            }
+        }
+    }
+
+    /// With several open, bring the one at the back to the front, so
+    /// repeated cycling visits each in turn.  A lone overlay is closed
+    /// instead, so the key always does something visible.
+    pub fn cycle(&mut self) -> OverlayCycle {
+        match self.open.len() {
+            0 => OverlayCycle::None,
+            1 => OverlayCycle::Closed(self.open.remove(0)),
+            _ => {
+                self.open.rotate_left(1);
+                OverlayCycle::Raised(self.open[self.open.len() - 1])
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn open(overlays: &[Overlay]) -> Overlays {
+        let mut open = Overlays::default();
+        for &overlay in overlays {
+            open.toggle(overlay);
+        }
+        open
+    }
+
+    #[test]
+    fn test_toggle_opens_in_front_and_closes() {
+        // Arrange
+        let mut overlays = open(&[Overlay::Debug, Overlay::Help]);
+
+        // Act
+        overlays.toggle(Overlay::Debug);
+
+        // Assert
+        assert_eq!(overlays.back_to_front(), &[Overlay::Help]);
+        overlays.toggle(Overlay::Debug);
+        assert_eq!(overlays.back_to_front().last(), Some(&Overlay::Debug));
+        assert!(overlays.is_open(Overlay::Help));
+        assert!(!overlays.is_open(Overlay::Histogram));
+    }
+
+    #[test]
+    fn test_cycle_visits_each_open_overlay() {
+        // Arrange
+        let mut overlays = open(&[Overlay::Help, Overlay::Debug, Overlay::Histogram]);
+
+        // Act
+        let raised: Vec<OverlayCycle> = (0..3).map(|_| overlays.cycle()).collect();
+
+        // Assert - each comes forward once, then the original order is back
+        assert_eq!(
+            raised,
+            vec![
+                OverlayCycle::Raised(Overlay::Help),
+                OverlayCycle::Raised(Overlay::Debug),
+                OverlayCycle::Raised(Overlay::Histogram),
+            ]
+        );
+        assert_eq!(
+            overlays.back_to_front(),
+            &[Overlay::Help, Overlay::Debug, Overlay::Histogram]
+        );
+    }
+
+    #[test]
+    fn test_cycle_closes_a_lone_overlay() {
+        let mut overlays = open(&[Overlay::Histogram]);
+        assert_eq!(overlays.cycle(), OverlayCycle::Closed(Overlay::Histogram));
+        assert!(!overlays.any_open());
+        assert_eq!(overlays.cycle(), OverlayCycle::None);
+    }
+
+    #[test]
+    fn test_restore_puts_hidden_overlays_behind_new_ones() {
+        // Arrange
+        let mut overlays = open(&[Overlay::Debug, Overlay::Histogram]);
+        let hidden = overlays.hide_all();
+        assert!(!overlays.any_open());
+
+        // Act - help and histogram opened while hidden
+        overlays.toggle(Overlay::Help);
+        overlays.toggle(Overlay::Histogram);
+        overlays.restore(hidden);
+
+        // Assert
+        assert_eq!(
+            overlays.back_to_front(),
+            &[Overlay::Debug, Overlay::Histogram, Overlay::Help]
+        );
+    }
+
+    #[test]
+    fn test_close_front_takes_the_top_one() {
+        // Arrange - help opened last; cycling brings debug to the front
+        let mut overlays = open(&[Overlay::Debug, Overlay::Help]);
+        overlays.cycle();
+
+        // Act / Assert
+        assert_eq!(overlays.close_front(), Some(Overlay::Debug));
+        assert_eq!(overlays.close_front(), Some(Overlay::Help));
+        assert_eq!(overlays.close_front(), None);
+    }
+}
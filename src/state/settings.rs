@@ -3,9 +3,10 @@
 //! This module defines all user-configurable settings for the application.
 //! Settings are serialized to JSON and saved in the platform-appropriate config directory.
 
-use super::app_state::SortMode;
+use super::app_state::{EndOfList, SortMode};
+use super::image_state::FilterSettings;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Main application settings container
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
@@ -31,6 +32,101 @@ pub struct ViewerBehavior {
     pub state_cache_size: usize,
     /// Whether animated images auto-play when loaded
     pub animation_auto_play: bool,
+    /// Ask before quitting (Cmd+Q or 3x ESC) while filter edits are unsaved
+    #[serde(default)]
+    pub confirm_quit_with_unsaved_filters: bool,
+    /// Margin in pixels left on each side of the image in fit-to-window
+    /// mode (0 = touch the window edges)
+    #[serde(default)]
+    pub fit_padding: f32,
+    /// Percent of the screen's width and height kept clear at each edge
+    /// when fitting in fullscreen or a slideshow, so overscanning
+    /// projectors don't cut the image off (0 = off)
+    #[serde(default)]
+    pub safe_area_percent: f32,
+    /// Hide the mouse cursor in fullscreen and slideshows after it has been
+    /// still for `cursor_hide_delay_secs`
+    #[serde(default)]
+    pub auto_hide_cursor: bool,
+    /// Seconds of no mouse movement before the cursor hides
+    #[serde(default = "ViewerBehavior::default_cursor_hide_delay_secs")]
+    pub cursor_hide_delay_secs: f32,
+    /// Stretch images whose metadata records non-square pixels to their
+    /// intended proportions
+    #[serde(default = "ViewerBehavior::default_honor_pixel_aspect")]
+    pub honor_pixel_aspect: bool,
+    /// Briefly show the filename in large text at the bottom when each
+    /// image appears in fullscreen or a slideshow
+    #[serde(default)]
+    pub show_presentation_caption: bool,
+    /// Make `0` cycle fit → each of `zoom_cycle_stops` → fit instead of
+    /// toggling fit ↔ 100%
+    #[serde(default)]
+    pub zoom_cycle_enabled: bool,
+    /// Zoom levels (1.0 = 100%) the `0` key steps through after fit when
+    /// `zoom_cycle_enabled` is on
+    #[serde(default = "ViewerBehavior::default_zoom_cycle_stops")]
+    pub zoom_cycle_stops: Vec<f32>,
+    /// Seconds each image stays up while the slideshow (`P`) runs
+    #[serde(default = "ViewerBehavior::default_slideshow_interval_secs")]
+    pub slideshow_interval_secs: f32,
+    /// Hold the slideshow on an animation until it has played through as
+    /// many times as the file asks (once when set to play once); ones
+    /// that loop forever still move on at the interval
+    #[serde(default)]
+    pub slideshow_let_animations_finish: bool,
+    /// Start the slideshow by itself after `idle_slideshow_secs` without
+    /// keyboard or mouse input; any input stops it again
+    #[serde(default)]
+    pub idle_slideshow: bool,
+    /// Seconds without input before `idle_slideshow` kicks in
+    #[serde(default = "ViewerBehavior::default_idle_slideshow_secs")]
+    pub idle_slideshow_secs: f32,
+    /// Frames (or pages) `Shift+[` / `Shift+]` step by
+    #[serde(default = "ViewerBehavior::default_frame_step")]
+    pub frame_step: usize,
+}
+
+impl ViewerBehavior {
+    fn default_cursor_hide_delay_secs() -> f32 {
+        3.0
+    }
+
+    fn default_honor_pixel_aspect() -> bool {
+        true
+    }
+
+    fn default_zoom_cycle_stops() -> Vec<f32> {
+        vec![1.0, 2.0]
+    }
+
+    fn default_slideshow_interval_secs() -> f32 {
+        5.0
+    }
+
+    fn default_idle_slideshow_secs() -> f32 {
+        300.0
+    }
+
+    fn default_frame_step() -> usize {
+        10
+    }
+
+    /// The stops the `0` key cycles through after fit: just 100% unless
+    /// cycling is enabled with at least one usable stop
+    pub fn zero_key_zoom_stops(&self) -> Vec<f32> {
+        let stops: Vec<f32> = self
+            .zoom_cycle_stops
+            .iter()
+            .copied()
+            .filter(|stop| stop.is_finite() && *stop > 0.0)
+            .collect();
+        if self.zoom_cycle_enabled && !stops.is_empty() {
+            stops
+        } else {
+            vec![1.0]
+        }
+    }
 }
 
 impl Default for ViewerBehavior {
@@ -40,6 +136,20 @@ impl Default for ViewerBehavior {
             remember_per_image_state: true,
             state_cache_size: 1000,
             animation_auto_play: true,
+            confirm_quit_with_unsaved_filters: false,
+            fit_padding: 0.0,
+            safe_area_percent: 0.0,
+            auto_hide_cursor: false,
+            cursor_hide_delay_secs: Self::default_cursor_hide_delay_secs(),
+            honor_pixel_aspect: Self::default_honor_pixel_aspect(),
+            show_presentation_caption: false,
+            zoom_cycle_enabled: false,
+            zoom_cycle_stops: Self::default_zoom_cycle_stops(),
+            slideshow_interval_secs: Self::default_slideshow_interval_secs(),
+            slideshow_let_animations_finish: false,
+            idle_slideshow: false,
+            idle_slideshow_secs: Self::default_idle_slideshow_secs(),
+            frame_step: Self::default_frame_step(),
         }
     }
 }
@@ -62,6 +172,33 @@ pub struct Performance {
     pub filter_processing_threads: usize,
     /// Maximum image dimension to load (neither width nor height can exceed this)
     pub max_image_dimension: u32,
+    /// How many images either side of the current one to preload
+    #[serde(default = "Performance::default_preload_radius")]
+    pub preload_radius: usize,
+}
+
+impl Performance {
+    /// Largest preload radius offered in settings
+    pub const MAX_PRELOAD_RADIUS: usize = 5;
+    /// Decoded bytes the preloaded neighbours may take up together
+    pub const PRELOAD_MEMORY_BUDGET: u64 = 1024 * 1024 * 1024;
+
+    fn default_preload_radius() -> usize {
+        1
+    }
+
+    /// Preload radius to actually use, given the decoded size of the current
+    /// image as an estimate for its neighbours: the configured radius,
+    /// shrunk until both sides fit `PRELOAD_MEMORY_BUDGET` (but never below
+    /// 1), or 0 when preloading is off.
+    pub fn effective_preload_radius(&self, image_bytes: u64) -> usize {
+        if !self.preload_adjacent_images {
+            return 0;
+        }
+        let radius = self.preload_radius.clamp(1, Self::MAX_PRELOAD_RADIUS);
+        let affordable = Self::PRELOAD_MEMORY_BUDGET / image_bytes.max(1) / 2;
+        radius.min(affordable as usize).max(1)
+    }
 }
 
 impl Default for Performance {
@@ -70,6 +207,28 @@ impl Default for Performance {
             preload_adjacent_images: true,
             filter_processing_threads: 4,
             max_image_dimension: 17000,
+            preload_radius: Self::default_preload_radius(),
+        }
+    }
+}
+
+/// What SVGs are drawn onto when rasterized, on screen and when saved
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum SvgBackground {
+    /// Leave the areas the SVG doesn't paint transparent
+    #[default]
+    Transparent,
+    White,
+    Black,
+}
+
+impl SvgBackground {
+    /// The solid color to fill the raster with first, if any
+    pub fn fill(self) -> Option<[u8; 3]> {
+        match self {
+            Self::Transparent => None,
+            Self::White => Some([0xff, 0xff, 0xff]),
+            Self::Black => Some([0x00, 0x00, 0x00]),
         }
     }
 }
@@ -102,6 +261,17 @@ pub struct KeyboardMouse {
     pub z_drag_sensitivity: f32,
     /// Whether spacebar+drag panning uses acceleration
     pub spacebar_pan_accelerated: bool,
+    /// Whether plain scrolling pans (Shift+scroll sideways); Cmd/Ctrl+scroll
+    /// zooms either way
+    #[serde(default)]
+    pub scroll_pans: bool,
+    /// Whether a drag-pan let go while moving keeps gliding to a stop
+    #[serde(default)]
+    pub pan_inertia: bool,
+    /// Crop selections snap their edges to multiples of this many pixels
+    /// (0 = off)
+    #[serde(default)]
+    pub crop_grid: u32,
 }
 
 impl Default for KeyboardMouse {
@@ -114,6 +284,9 @@ impl Default for KeyboardMouse {
             scroll_wheel_sensitivity: 1.1,
             z_drag_sensitivity: 0.01,
             spacebar_pan_accelerated: false,
+            scroll_pans: false,
+            pan_inertia: false,
+            crop_grid: 0,
         }
     }
 }
@@ -127,8 +300,41 @@ pub struct FileOperations {
     pub default_save_format: SaveFormat,
     /// Whether to permanently save filtered image cache
     pub auto_save_filtered_cache: bool,
-    /// Whether to remember last used directory in file dialogs
+    /// Whether a launch without a path reopens `last_directory` instead of
+    /// the current directory
     pub remember_last_directory: bool,
+    /// Folder last browsed, kept up to date as images are opened.  Not
+    /// shown in the settings window.
+    #[serde(default)]
+    pub last_directory: Option<PathBuf>,
+    /// Scratch directory for temporary files such as SVG rasterizations
+    /// (None = OS temp directory)
+    #[serde(default)]
+    pub scratch_directory: Option<PathBuf>,
+    /// Resampling filter for images scaled on save (Save / Copy Visible Area)
+    #[serde(default)]
+    pub resize_filter: ResizeFilter,
+    /// Organize by Date destination pattern, relative to the chosen folder
+    #[serde(default = "FileOperations::default_organize_pattern")]
+    pub organize_pattern: String,
+    /// Whether Organize by Date moves files (true) or copies them
+    #[serde(default)]
+    pub organize_moves_files: bool,
+}
+
+impl FileOperations {
+    fn default_organize_pattern() -> String {
+        crate::utils::organize::DEFAULT_PATTERN.to_string()
+    }
+
+    /// The folder a launch without a path opens in place of the current
+    /// directory: the last one browsed, if remembering is on and it's
+    /// still there
+    pub fn startup_directory(&self) -> Option<&Path> {
+        self.last_directory
+            .as_deref()
+            .filter(|dir| self.remember_last_directory && dir.is_dir())
+    }
 }
 
 impl Default for FileOperations {
@@ -138,6 +344,40 @@ impl Default for FileOperations {
             default_save_format: SaveFormat::SameAsLoaded,
             auto_save_filtered_cache: false,
             remember_last_directory: true,
+            last_directory: None,
+            scratch_directory: None,
+            resize_filter: ResizeFilter::Auto,
+            organize_pattern: Self::default_organize_pattern(),
+            organize_moves_files: false,
+        }
+    }
+}
+
+/// Resampling filter used when an image is scaled for saving
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum ResizeFilter {
+    /// Nearest when enlarging (crisp pixels), Triangle when shrinking
+    #[default]
+    Auto,
+    /// Exact source pixels, no blending (pixel art, palettes)
+    Nearest,
+    Triangle,
+    CatmullRom,
+    /// Sharpest for photos, at the cost of some ringing
+    Lanczos3,
+}
+
+impl ResizeFilter {
+    /// The `image` filter to scale by `scale` with
+    pub fn filter_type(self, scale: f32) -> image::imageops::FilterType {
+        use image::imageops::FilterType;
+        match self {
+            Self::Auto if scale >= 1.0 => FilterType::Nearest,
+            Self::Auto => FilterType::Triangle,
+            Self::Nearest => FilterType::Nearest,
+            Self::Triangle => FilterType::Triangle,
+            Self::CatmullRom => FilterType::CatmullRom,
+            Self::Lanczos3 => FilterType::Lanczos3,
         }
     }
 }
@@ -182,6 +422,9 @@ impl SaveFormat {
     }
 }
 
+/// Window title template used when none is set
+pub const DEFAULT_WINDOW_TITLE_FORMAT: &str = "{filename} ({sm}, {index}/{total})";
+
 /// Appearance settings
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Appearance {
@@ -198,6 +441,12 @@ pub struct Appearance {
     pub overlay_transparency: u8,
     /// Font size multiplier for overlays (0.5 - 8.0)
     pub font_size_scale: f32,
+    /// Draw overlays and the help screen white-on-black with bold borders
+    #[serde(default)]
+    pub high_contrast: bool,
+    /// What SVGs are rasterized onto
+    #[serde(default)]
+    pub svg_background: SvgBackground,
     /// Window title format template
     pub window_title_format: String,
     /// Last-known bounds of the floating Filter window (None = use centered default)
@@ -268,7 +517,9 @@ impl Default for Appearance {
             use_light_background: false,
             overlay_transparency: 204, // ~80% opacity
             font_size_scale: 1.0,
-            window_title_format: "{filename} ({sm}, {index}/{total})".to_string(),
+            high_contrast: false,
+            svg_background: SvgBackground::default(),
+            window_title_format: DEFAULT_WINDOW_TITLE_FORMAT.to_string(),
             filter_window_bounds: None,
             filter_window_open: false,
             gpu_pipeline_window_bounds: None,
@@ -286,10 +537,64 @@ pub struct Filters {
     pub default_contrast: f32,
     /// Default gamma value when resetting
     pub default_gamma: f32,
+    /// Default saturation value when resetting
+    #[serde(default)]
+    pub default_saturation: f32,
+    /// Default red / green / blue channel offsets when resetting
+    #[serde(default)]
+    pub default_red_offset: f32,
+    #[serde(default)]
+    pub default_green_offset: f32,
+    #[serde(default)]
+    pub default_blue_offset: f32,
     /// Whether to remember filter state per-image
     pub remember_filter_state: bool,
     /// Saved filter presets
     pub filter_presets: Vec<FilterPreset>,
+    /// Whether opening the filter panel also turns filters on
+    #[serde(default = "Filters::default_panel_enables_filters")]
+    pub panel_enables_filters: bool,
+    /// Brighten very dark images and darken very bright ones a little on
+    /// display, from their mean luminance.  Display only: never saved with
+    /// an image's filters.
+    #[serde(default)]
+    pub auto_exposure: bool,
+}
+
+impl Filters {
+    fn default_panel_enables_filters() -> bool {
+        true
+    }
+
+    /// Save `filters` as a new preset called `name` (or "Preset" if
+    /// blank).  A name already in use gets a numeric suffix ("Warm 2",
+    /// "Warm 3", ...); the name actually used is returned.
+    pub fn add_preset(&mut self, name: &str, filters: &FilterSettings) -> String {
+        let base = match name.trim() {
+            "" => "Preset",
+            trimmed => trimmed,
+        };
+        let taken = |candidate: &str| self.filter_presets.iter().any(|p| p.name == candidate);
+        let mut unique = base.to_string();
+        let mut suffix = 2;
+        while taken(&unique) {
+            unique = format!("{} {}", base, suffix);
+            suffix += 1;
+        }
+        self.filter_presets.push(FilterPreset {
+            name: unique.clone(),
+            brightness: filters.brightness,
+            contrast: filters.contrast,
+            gamma: filters.gamma,
+            saturation: filters.saturation,
+            invert: filters.invert,
+            grayscale: filters.grayscale,
+            red_offset: filters.red_offset,
+            green_offset: filters.green_offset,
+            blue_offset: filters.blue_offset,
+        });
+        unique
+    }
 }
 
 impl Default for Filters {
@@ -298,8 +603,14 @@ impl Default for Filters {
             default_brightness: 0.0,
             default_contrast: 0.0,
             default_gamma: 1.0,
+            default_saturation: 0.0,
+            default_red_offset: 0.0,
+            default_green_offset: 0.0,
+            default_blue_offset: 0.0,
             remember_filter_state: true,
             filter_presets: Vec::new(),
+            panel_enables_filters: true,
+            auto_exposure: false,
         }
     }
 }
@@ -311,6 +622,36 @@ pub struct FilterPreset {
     pub brightness: f32,
     pub contrast: f32,
     pub gamma: f32,
+    #[serde(default)]
+    pub saturation: f32,
+    #[serde(default)]
+    pub invert: bool,
+    #[serde(default)]
+    pub grayscale: bool,
+    #[serde(default)]
+    pub red_offset: f32,
+    #[serde(default)]
+    pub green_offset: f32,
+    #[serde(default)]
+    pub blue_offset: f32,
+}
+
+impl FilterPreset {
+    /// The filter values this preset saved.  Presets from before the
+    /// toggles and channel offsets were recorded restore those as off.
+    pub fn filters(&self) -> FilterSettings {
+        FilterSettings {
+            brightness: self.brightness,
+            contrast: self.contrast,
+            gamma: self.gamma,
+            saturation: self.saturation,
+            invert: self.invert,
+            grayscale: self.grayscale,
+            red_offset: self.red_offset,
+            green_offset: self.green_offset,
+            blue_offset: self.blue_offset,
+        }
+    }
 }
 
 /// Sort and navigation settings
@@ -320,8 +661,68 @@ pub struct SortNavigation {
     pub default_sort_mode: SortMode,
     /// Whether navigation wraps around (last -> first)
     pub wrap_navigation: bool,
+    /// What stepping past the first or last image does when navigation
+    /// doesn't wrap
+    #[serde(default)]
+    pub end_of_list: EndOfList,
     /// Whether to show image counter in window title
     pub show_image_counter: bool,
+    /// Number of images Page Up / Page Down jump by
+    #[serde(default = "SortNavigation::default_page_stride")]
+    pub page_stride: usize,
+    /// Sort mode last picked from the Sort menu, restored on startup in
+    /// place of `default_sort_mode`.  Not shown in the settings window.
+    #[serde(default)]
+    pub last_sort_mode: Option<SortMode>,
+    /// Whether the list runs in reverse of the sort mode's order, toggled
+    /// from the Sort menu.  Not shown in the settings window.
+    #[serde(default)]
+    pub sort_descending: bool,
+    /// Right-to-left (manga) reading: the Left arrow advances and a
+    /// spread's following page sits to the left
+    #[serde(default)]
+    pub right_to_left: bool,
+    /// Show pages in facing pairs; the arrows step a spread at a time
+    #[serde(default)]
+    pub two_page_spread: bool,
+    /// In spread mode the first page (the cover) stands alone, so the
+    /// pages after it pair up as printed
+    #[serde(default = "SortNavigation::default_spread_cover_alone")]
+    pub spread_cover_alone: bool,
+    /// Include images in subfolders when opening a folder
+    #[serde(default)]
+    pub recursive_scan: bool,
+    /// Tapping an arrow twice quickly jumps to that end of the list
+    #[serde(default)]
+    pub double_tap_to_end: bool,
+    /// Most seconds between the two taps of a double tap
+    #[serde(default = "SortNavigation::default_double_tap_secs")]
+    pub double_tap_secs: f32,
+}
+
+impl SortNavigation {
+    fn default_page_stride() -> usize {
+        10
+    }
+
+    fn default_double_tap_secs() -> f32 {
+        0.3
+    }
+
+    fn default_spread_cover_alone() -> bool {
+        true
+    }
+
+    /// Whether an arrow press moves forward through the list: Right does
+    /// left-to-right, Left does right-to-left
+    pub fn arrow_moves_forward(&self, right_arrow: bool) -> bool {
+        right_arrow != self.right_to_left
+    }
+
+    /// The sort mode a new window starts in
+    pub fn startup_sort_mode(&self) -> SortMode {
+        self.last_sort_mode.unwrap_or(self.default_sort_mode)
+    }
 }
 
 impl Default for SortNavigation {
@@ -329,7 +730,17 @@ impl Default for SortNavigation {
         Self {
             default_sort_mode: SortMode::Alphabetical,
             wrap_navigation: true,
+            end_of_list: EndOfList::Stop,
             show_image_counter: true,
+            page_stride: Self::default_page_stride(),
+            last_sort_mode: None,
+            sort_descending: false,
+            right_to_left: false,
+            two_page_spread: false,
+            spread_cover_alone: Self::default_spread_cover_alone(),
+            recursive_scan: false,
+            double_tap_to_end: false,
+            double_tap_secs: Self::default_double_tap_secs(),
         }
     }
 }
@@ -518,6 +929,37 @@ mod tests {
         assert!(behavior.remember_per_image_state);
         assert_eq!(behavior.state_cache_size, DEFAULT_STATE_CACHE_SIZE);
         assert!(behavior.animation_auto_play);
+        assert!(!behavior.confirm_quit_with_unsaved_filters);
+        assert_eq!(behavior.fit_padding, 0.0);
+        assert_eq!(behavior.safe_area_percent, 0.0);
+        assert!(behavior.honor_pixel_aspect);
+        assert!(!behavior.auto_hide_cursor);
+        assert_eq!(behavior.cursor_hide_delay_secs, 3.0);
+        assert!(!behavior.show_presentation_caption);
+        assert!(!behavior.zoom_cycle_enabled);
+        assert_eq!(behavior.zoom_cycle_stops, vec![1.0, 2.0]);
+        assert_eq!(behavior.slideshow_interval_secs, 5.0);
+        assert!(!behavior.slideshow_let_animations_finish);
+        assert!(!behavior.idle_slideshow);
+        assert_eq!(behavior.idle_slideshow_secs, 300.0);
+        assert_eq!(behavior.frame_step, 10);
+    }
+
+    #[test]
+    fn test_zero_key_zoom_stops() {
+        // Arrange
+        let mut behavior = ViewerBehavior::default();
+
+        // Act & Assert - toggling by default, configured stops once enabled
+        assert_eq!(behavior.zero_key_zoom_stops(), vec![1.0]);
+        behavior.zoom_cycle_enabled = true;
+        assert_eq!(behavior.zero_key_zoom_stops(), vec![1.0, 2.0]);
+
+        // Unusable stops are dropped; none left falls back to the toggle
+        behavior.zoom_cycle_stops = vec![0.5, -1.0, f32::NAN, 4.0];
+        assert_eq!(behavior.zero_key_zoom_stops(), vec![0.5, 4.0]);
+        behavior.zoom_cycle_stops = vec![0.0];
+        assert_eq!(behavior.zero_key_zoom_stops(), vec![1.0]);
     }
 
     #[test]
@@ -529,6 +971,43 @@ mod tests {
         assert!(performance.preload_adjacent_images);
         assert_eq!(performance.filter_processing_threads, 4);
         assert_eq!(performance.max_image_dimension, DEFAULT_MAX_IMAGE_DIMENSION);
+        assert_eq!(performance.preload_radius, 1);
+    }
+
+    #[test]
+    fn test_effective_preload_radius_respects_budget() {
+        // Arrange
+        let performance = Performance {
+            preload_radius: 3,
+            ..Performance::default()
+        };
+        let small = 4 * 1000 * 1000; // 1 MP
+        let huge = Performance::PRELOAD_MEMORY_BUDGET / 3; // one per side fits
+
+        // Act & Assert
+        assert_eq!(performance.effective_preload_radius(small), 3);
+        assert_eq!(performance.effective_preload_radius(huge), 1);
+        assert_eq!(
+            performance.effective_preload_radius(Performance::PRELOAD_MEMORY_BUDGET * 4),
+            1
+        );
+    }
+
+    #[test]
+    fn test_effective_preload_radius_off_and_clamped() {
+        let off = Performance {
+            preload_adjacent_images: false,
+            ..Performance::default()
+        };
+        let too_far = Performance {
+            preload_radius: 50,
+            ..Performance::default()
+        };
+        assert_eq!(off.effective_preload_radius(1), 0);
+        assert_eq!(
+            too_far.effective_preload_radius(1),
+            Performance::MAX_PRELOAD_RADIUS
+        );
     }
 
     #[test]
@@ -544,6 +1023,9 @@ mod tests {
         assert_eq!(km.scroll_wheel_sensitivity, 1.1);
         assert_eq!(km.z_drag_sensitivity, 0.01);
         assert!(!km.spacebar_pan_accelerated);
+        assert!(!km.scroll_pans);
+        assert!(!km.pan_inertia);
+        assert_eq!(km.crop_grid, 0);
     }
 
     #[test]
@@ -556,6 +1038,67 @@ mod tests {
         assert_eq!(file_ops.default_save_format, SaveFormat::SameAsLoaded);
         assert!(!file_ops.auto_save_filtered_cache);
         assert!(file_ops.remember_last_directory);
+        assert!(file_ops.last_directory.is_none());
+        assert!(file_ops.scratch_directory.is_none());
+        assert_eq!(file_ops.resize_filter, ResizeFilter::Auto);
+        assert_eq!(file_ops.organize_pattern, "{year}/{month}/{day}/{filename}");
+        assert!(!file_ops.organize_moves_files);
+    }
+
+    #[test]
+    fn test_startup_directory_needs_setting_and_folder() {
+        // Arrange
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut file_ops = FileOperations {
+            last_directory: Some(dir.path().to_path_buf()),
+            ..FileOperations::default()
+        };
+
+        // Act / Assert
+        assert_eq!(file_ops.startup_directory(), Some(dir.path()));
+        file_ops.remember_last_directory = false;
+        assert_eq!(file_ops.startup_directory(), None);
+
+        // Act / Assert - a folder that's gone falls back
+        file_ops.remember_last_directory = true;
+        file_ops.last_directory = Some(dir.path().join("gone"));
+        assert_eq!(file_ops.startup_directory(), None);
+    }
+
+    #[test]
+    fn test_resize_filter_nearest_keeps_palette_colors() {
+        // Arrange — a 2x2 image with four flat palette colors
+        let palette = [
+            [255, 0, 0, 255],
+            [0, 255, 0, 255],
+            [0, 0, 255, 255],
+            [0, 0, 0, 255],
+        ];
+        let source =
+            image::RgbaImage::from_fn(2, 2, |x, y| image::Rgba(palette[(y * 2 + x) as usize]));
+        let resize =
+            |filter: ResizeFilter| image::imageops::resize(&source, 7, 7, filter.filter_type(3.5));
+
+        // Act
+        let nearest = resize(ResizeFilter::Nearest);
+        let lanczos = resize(ResizeFilter::Lanczos3);
+
+        // Assert — Nearest only ever repeats source pixels; Lanczos3 blends
+        // across the color boundaries
+        assert!(nearest.pixels().all(|p| palette.contains(&p.0)));
+        assert!(lanczos.pixels().any(|p| !palette.contains(&p.0)));
+    }
+
+    #[test]
+    fn test_resize_filter_auto_follows_scale() {
+        use image::imageops::FilterType;
+        assert_eq!(ResizeFilter::Auto.filter_type(2.0), FilterType::Nearest);
+        assert_eq!(ResizeFilter::Auto.filter_type(1.0), FilterType::Nearest);
+        assert_eq!(ResizeFilter::Auto.filter_type(0.5), FilterType::Triangle);
+        assert_eq!(
+            ResizeFilter::Lanczos3.filter_type(0.5),
+            FilterType::Lanczos3
+        );
     }
 
     #[test]
@@ -573,6 +1116,9 @@ mod tests {
             DEFAULT_OVERLAY_TRANSPARENCY
         );
         assert_eq!(appearance.font_size_scale, DEFAULT_FONT_SIZE_SCALE);
+        assert!(!appearance.high_contrast);
+        assert_eq!(appearance.svg_background, SvgBackground::Transparent);
+        assert_eq!(appearance.svg_background.fill(), None);
         assert_eq!(
             appearance.window_title_format,
             "{filename} ({sm}, {index}/{total})"
@@ -588,8 +1134,14 @@ mod tests {
         assert_eq!(filters.default_brightness, DEFAULT_BRIGHTNESS);
         assert_eq!(filters.default_contrast, DEFAULT_CONTRAST);
         assert_eq!(filters.default_gamma, DEFAULT_GAMMA);
+        assert_eq!(filters.default_saturation, 0.0);
+        assert_eq!(filters.default_red_offset, 0.0);
+        assert_eq!(filters.default_green_offset, 0.0);
+        assert_eq!(filters.default_blue_offset, 0.0);
         assert!(filters.remember_filter_state);
         assert!(filters.filter_presets.is_empty());
+        assert!(filters.panel_enables_filters);
+        assert!(!filters.auto_exposure);
     }
 
     #[test]
@@ -600,7 +1152,33 @@ mod tests {
         // Assert
         assert_eq!(sort_nav.default_sort_mode, SortMode::Alphabetical);
         assert!(sort_nav.wrap_navigation);
+        assert_eq!(sort_nav.end_of_list, EndOfList::Stop);
         assert!(sort_nav.show_image_counter);
+        assert_eq!(sort_nav.page_stride, 10);
+        assert!(sort_nav.last_sort_mode.is_none());
+        assert!(!sort_nav.sort_descending);
+        assert_eq!(sort_nav.startup_sort_mode(), SortMode::Alphabetical);
+        assert!(!sort_nav.right_to_left);
+        assert!(!sort_nav.two_page_spread);
+        assert!(sort_nav.spread_cover_alone);
+        assert!(!sort_nav.recursive_scan);
+        assert!(!sort_nav.double_tap_to_end);
+        assert_eq!(sort_nav.double_tap_secs, 0.3);
+    }
+
+    #[test]
+    fn test_arrow_moves_forward_swaps_for_right_to_left() {
+        // Arrange
+        let mut sort_nav = SortNavigation::default();
+
+        // Act & Assert - left-to-right: Right advances, Left goes back
+        assert!(sort_nav.arrow_moves_forward(true));
+        assert!(!sort_nav.arrow_moves_forward(false));
+
+        // Act & Assert - right-to-left: the arrows swap
+        sort_nav.right_to_left = true;
+        assert!(!sort_nav.arrow_moves_forward(true));
+        assert!(sort_nav.arrow_moves_forward(false));
     }
 
     #[test]
@@ -653,6 +1231,12 @@ mod tests {
             brightness: 10.0,
             contrast: 50.0,
             gamma: 1.2,
+            saturation: 0.0,
+            invert: false,
+            grayscale: false,
+            red_offset: 0.0,
+            green_offset: 0.0,
+            blue_offset: 0.0,
         };
 
         // Assert
@@ -662,6 +1246,68 @@ mod tests {
         assert_eq!(preset.gamma, 1.2);
     }
 
+    #[test]
+    fn test_add_preset_suffixes_duplicate_names() {
+        // Arrange
+        let mut filters = Filters::default();
+        let current = FilterSettings {
+            brightness: 15.0,
+            gamma: 1.4,
+            ..FilterSettings::default()
+        };
+
+        // Act
+        let first = filters.add_preset("Warm", &current);
+        let second = filters.add_preset("Warm", &current);
+        let third = filters.add_preset(" Warm ", &current);
+        let unnamed = filters.add_preset("", &current);
+
+        // Assert
+        assert_eq!(
+            [first, second, third, unnamed],
+            ["Warm", "Warm 2", "Warm 3", "Preset"]
+        );
+        assert_eq!(filters.filter_presets.len(), 4);
+        assert_eq!(filters.filter_presets[1].brightness, 15.0);
+        assert_eq!(filters.filter_presets[1].gamma, 1.4);
+    }
+
+    #[test]
+    fn test_preset_restores_every_filter() {
+        // Arrange
+        let mut filters = Filters::default();
+        let saved = FilterSettings {
+            contrast: 40.0,
+            saturation: 30.0,
+            invert: true,
+            grayscale: true,
+            red_offset: 10.0,
+            blue_offset: -5.0,
+            ..FilterSettings::default()
+        };
+
+        // Act
+        filters.add_preset("Punchy", &saved);
+
+        // Assert
+        assert_eq!(filters.filter_presets[0].filters(), saved);
+    }
+
+    #[test]
+    fn test_old_preset_loads_without_toggles_or_offsets() {
+        // Arrange - saved before presets recorded toggles and offsets
+        let json = r#"{"name":"Old","brightness":5.0,"contrast":0.0,"gamma":1.0}"#;
+
+        // Act
+        let preset: FilterPreset = serde_json::from_str(json).unwrap();
+
+        // Assert
+        let filters = preset.filters();
+        assert_eq!(filters.brightness, 5.0);
+        assert!(!filters.invert);
+        assert_eq!(filters.red_offset, 0.0);
+    }
+
     #[test]
     fn test_viewer_config_creation() {
         // Arrange & Act
@@ -1,33 +1,151 @@
+//! Multi-frame images as one `FrameSequence`.
+//!
+//! Animated GIF, WEBP and APNG files and multi-page TIFFs all load into the
+//! same structure, so `[`/`]` frame stepping and play/pause work the same
+//! for every container.  Timed formats carry a duration per frame; TIFF
+//! pages have none and play back at `UNTIMED_FRAME_MS` per page.
+
 use crate::error::AppError;
 use image::codecs::gif::GifDecoder;
+use image::codecs::png::PngDecoder;
 use image::codecs::webp::WebPDecoder;
-use image::{AnimationDecoder, DynamicImage};
+use image::{AnimationDecoder, DynamicImage, RgbaImage};
 use std::fs::File;
 use std::io::BufReader;
 use std::path::Path;
 
-/// Represents a single animation frame with its timing
+/// How long playback shows each frame of a sequence without timing
+/// (multi-page TIFF)
+pub const UNTIMED_FRAME_MS: u32 = 1000;
+
+/// A single frame (or page) of a sequence
 #[derive(Clone)]
-pub struct AnimationFrame {
+pub struct SequenceFrame {
     /// The image data for this frame
     pub image: DynamicImage,
-    /// Duration in milliseconds
-    pub duration_ms: u32,
+    /// Duration in milliseconds; None for pages that have no timing
+    pub duration_ms: Option<u32>,
 }
 
-/// Animation data extracted from an animated image
+/// All frames of a multi-frame image
 #[derive(Clone)]
-pub struct AnimationData {
-    /// All frames in the animation
-    pub frames: Vec<AnimationFrame>,
+pub struct FrameSequence {
+    /// All frames, in display order
+    pub frames: Vec<SequenceFrame>,
     /// Total number of frames
     pub frame_count: usize,
+    /// How many times the file asks to be played through; None when it
+    /// loops forever (or, for pages, doesn't say)
+    pub plays: Option<u32>,
 }
 
-impl AnimationData {
-    /// Get frame durations as a vector of milliseconds
+impl FrameSequence {
+    pub fn new(frames: Vec<SequenceFrame>) -> Self {
+        let frame_count = frames.len();
+        Self {
+            frames,
+            frame_count,
+            plays: None,
+        }
+    }
+
+    /// Frame durations in milliseconds for playback; untimed frames get
+    /// `UNTIMED_FRAME_MS`
     pub fn frame_durations(&self) -> Vec<u32> {
-        self.frames.iter().map(|f| f.duration_ms).collect()
+        self.frames
+            .iter()
+            .map(|f| f.duration_ms.unwrap_or(UNTIMED_FRAME_MS))
+            .collect()
+    }
+
+    /// Whether the frames carry their own timing (an animation rather than
+    /// a stack of pages)
+    pub fn is_timed(&self) -> bool {
+        self.frames.iter().any(|f| f.duration_ms.is_some())
+    }
+}
+
+/// A frame-stepping key press
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameStep {
+    Forward(usize),
+    Backward(usize),
+    First,
+    Last,
+    /// Straight to a frame (0-based), clamped to the last one
+    To(usize),
+}
+
+impl FrameStep {
+    /// The frame this step lands on from `current` in a sequence of
+    /// `frame_count` frames.  Steps wrap around either end, as playback does.
+    pub fn target(self, current: usize, frame_count: usize) -> usize {
+        if frame_count == 0 {
+            return 0;
+        }
+        match self {
+            Self::Forward(n) => (current + n % frame_count) % frame_count,
+            Self::Backward(n) => (current + frame_count - n % frame_count) % frame_count,
+            Self::First => 0,
+            Self::Last => frame_count - 1,
+            Self::To(frame) => frame.min(frame_count - 1),
+        }
+    }
+}
+
+/// What playback does at the end of an animation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LoopMode {
+    /// Start over from the first frame
+    #[default]
+    Loop,
+    /// Stop on the last frame
+    PlayOnce,
+    /// Turn around and play back to the first frame, then forward again
+    PingPong,
+}
+
+impl LoopMode {
+    /// The mode `CycleLoopMode` goes to from this one
+    pub fn next(self) -> Self {
+        match self {
+            Self::Loop => Self::PlayOnce,
+            Self::PlayOnce => Self::PingPong,
+            Self::PingPong => Self::Loop,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Loop => "Loop",
+            Self::PlayOnce => "Play once",
+            Self::PingPong => "Ping-pong",
+        }
+    }
+}
+
+/// The frame playback shows after `current`, and whether it's then running
+/// backwards (ping-pong's return trip).  None when a play-once animation
+/// has reached its last frame and should stop.
+pub fn playback_step(
+    current: usize,
+    frame_count: usize,
+    mode: LoopMode,
+    reversed: bool,
+) -> Option<(usize, bool)> {
+    if frame_count <= 1 {
+        return (mode != LoopMode::PlayOnce).then_some((0, false));
+    }
+    let last = frame_count - 1;
+    match mode {
+        LoopMode::Loop => Some(((current + 1) % frame_count, false)),
+        LoopMode::PlayOnce => (current < last).then_some((current + 1, false)),
+        LoopMode::PingPong => Some(match (reversed, current) {
+            (true, 0) => (1, false),
+            (true, _) => (current - 1, true),
+            (false, c) if c >= last => (last - 1, true),
+            (false, _) => (current + 1, false),
+        }),
     }
 }
 
@@ -57,13 +175,35 @@ pub fn is_animated_webp(path: &Path) -> Result<bool, AppError> {
     }
 }
 
-/// Collect decoded frames into an `AnimationData`.
-fn collect_animation_frames(frames: image::Frames<'_>) -> Result<AnimationData, AppError> {
+/// Check if a PNG file is an APNG (has an animation control chunk)
+pub fn is_apng(path: &Path) -> Result<bool, AppError> {
+    let file = File::open(path).map_err(AppError::Io)?;
+    let reader = BufReader::new(file);
+
+    match PngDecoder::new(reader) {
+        Ok(decoder) => Ok(decoder.is_apng().unwrap_or(false)),
+        Err(_) => Ok(false),
+    }
+}
+
+/// Check if a TIFF file has more than one page (reads directory headers only)
+pub fn is_multipage_tiff(path: &Path) -> Result<bool, AppError> {
+    let file = File::open(path).map_err(AppError::Io)?;
+    let reader = BufReader::new(file);
+
+    match tiff::decoder::Decoder::new(reader) {
+        Ok(decoder) => Ok(decoder.more_images()),
+        Err(_) => Ok(false),
+    }
+}
+
+/// Collect decoded animation frames into a `FrameSequence`.
+fn collect_animation_frames(frames: image::Frames<'_>) -> Result<FrameSequence, AppError> {
     let raw_frames = frames
         .collect_frames()
         .map_err(|e| AppError::Generic(e.to_string()))?;
 
-    let animation_frames: Vec<AnimationFrame> = raw_frames
+    let frames: Vec<SequenceFrame> = raw_frames
         .into_iter()
         .map(|frame| {
             let delay = frame.delay();
@@ -73,27 +213,41 @@ fn collect_animation_frames(frames: image::Frames<'_>) -> Result<AnimationData,
             let buffer = frame.into_buffer();
             let image = DynamicImage::ImageRgba8(buffer);
 
-            AnimationFrame { image, duration_ms }
+            SequenceFrame {
+                image,
+                duration_ms: Some(duration_ms),
+            }
         })
         .collect();
 
-    let frame_count = animation_frames.len();
-    Ok(AnimationData {
-        frames: animation_frames,
-        frame_count,
-    })
+    Ok(FrameSequence::new(frames))
 }
 
 /// Load animation frames from a GIF file
-pub fn load_gif_animation(path: &Path) -> Result<AnimationData, AppError> {
+pub fn load_gif_animation(path: &Path) -> Result<FrameSequence, AppError> {
     let file = File::open(path).map_err(AppError::Io)?;
     let reader = BufReader::new(file);
     let decoder = GifDecoder::new(reader).map_err(|e| AppError::Generic(e.to_string()))?;
-    collect_animation_frames(decoder.into_frames())
+    let mut sequence = collect_animation_frames(decoder.into_frames())?;
+    sequence.plays = gif_plays(path);
+    Ok(sequence)
+}
+
+/// A GIF's play count from its NETSCAPE loop extension, which counts the
+/// repeats after the first pass; a GIF without one plays once
+fn gif_plays(path: &Path) -> Option<u32> {
+    let file = File::open(path).ok()?;
+    let decoder = gif::DecodeOptions::new()
+        .read_info(BufReader::new(file))
+        .ok()?;
+    match decoder.repeat() {
+        gif::Repeat::Finite(repeats) => Some(u32::from(repeats) + 1),
+        gif::Repeat::Infinite => None,
+    }
 }
 
 /// Load animation frames from a WEBP file
-pub fn load_webp_animation(path: &Path) -> Result<AnimationData, AppError> {
+pub fn load_webp_animation(path: &Path) -> Result<FrameSequence, AppError> {
     let file = File::open(path).map_err(AppError::Io)?;
     let reader = BufReader::new(file);
     let decoder = WebPDecoder::new(reader).map_err(|e| AppError::Generic(e.to_string()))?;
@@ -102,12 +256,118 @@ pub fn load_webp_animation(path: &Path) -> Result<AnimationData, AppError> {
         return Err(AppError::Generic("WEBP is not animated".to_string()));
     }
 
-    collect_animation_frames(decoder.into_frames())
+    let mut sequence = collect_animation_frames(decoder.into_frames())?;
+    sequence.plays = webp_plays(path);
+    Ok(sequence)
+}
+
+/// An animated WEBP's play count from its ANIM chunk
+fn webp_plays(path: &Path) -> Option<u32> {
+    let file = File::open(path).ok()?;
+    let decoder = image_webp::WebPDecoder::new(BufReader::new(file)).ok()?;
+    match decoder.loop_count() {
+        image_webp::LoopCount::Times(plays) => Some(u32::from(plays.get())),
+        image_webp::LoopCount::Forever => None,
+    }
+}
+
+/// Load animation frames from an APNG file
+pub fn load_apng_animation(path: &Path) -> Result<FrameSequence, AppError> {
+    let file = File::open(path).map_err(AppError::Io)?;
+    let reader = BufReader::new(file);
+    let decoder = PngDecoder::new(reader)
+        .and_then(|d| d.apng())
+        .map_err(|e| AppError::Generic(e.to_string()))?;
+    let mut sequence = collect_animation_frames(decoder.into_frames())?;
+    sequence.plays = apng_plays(path);
+    Ok(sequence)
+}
+
+/// An APNG's play count from its acTL chunk (0 there means forever)
+fn apng_plays(path: &Path) -> Option<u32> {
+    let file = File::open(path).ok()?;
+    let reader = png::Decoder::new(BufReader::new(file)).read_info().ok()?;
+    let plays = reader.info().animation_control?.num_plays;
+    (plays > 0).then_some(plays)
 }
 
-/// Detect and load animation from a file
-/// Returns None if the file is not animated
-pub fn load_animation(path: &Path) -> Result<Option<AnimationData>, AppError> {
+/// Load every page of a TIFF file.  Pages have no timing.  Decoding stops
+/// at the first page in a pixel layout we can't convert, keeping the pages
+/// before it.
+pub fn load_tiff_pages(path: &Path) -> Result<FrameSequence, AppError> {
+    let file = File::open(path).map_err(AppError::Io)?;
+    let reader = BufReader::new(file);
+    let tiff_err = |e: tiff::TiffError| AppError::Generic(e.to_string());
+    let mut decoder = tiff::decoder::Decoder::new(reader).map_err(tiff_err)?;
+
+    let mut frames = Vec::new();
+    while let Some(page) = decode_tiff_page(&mut decoder).map_err(tiff_err)? {
+        frames.push(SequenceFrame {
+            image: DynamicImage::ImageRgba8(page),
+            duration_ms: None,
+        });
+        if !decoder.more_images() {
+            break;
+        }
+        decoder.next_image().map_err(tiff_err)?;
+    }
+
+    Ok(FrameSequence::new(frames))
+}
+
+/// Decode the decoder's current TIFF page to RGBA.  Handles 8/16-bit gray,
+/// gray+alpha, RGB and RGBA; returns None for anything else.
+fn decode_tiff_page<R: std::io::Read + std::io::Seek>(
+    decoder: &mut tiff::decoder::Decoder<R>,
+) -> tiff::TiffResult<Option<RgbaImage>> {
+    use tiff::ColorType;
+    use tiff::decoder::DecodingResult;
+
+    let (width, height) = decoder.dimensions()?;
+    let channels = match decoder.colortype()? {
+        ColorType::Gray(8 | 16) => 1,
+        ColorType::GrayA(8 | 16) => 2,
+        ColorType::RGB(8 | 16) => 3,
+        ColorType::RGBA(8 | 16) => 4,
+        _ => return Ok(None),
+    };
+    let samples: Vec<u8> = match decoder.read_image()? {
+        DecodingResult::U8(v) => v,
+        DecodingResult::U16(v) => v.iter().map(|s| (s >> 8) as u8).collect(),
+        _ => return Ok(None),
+    };
+    if samples.len() < width as usize * height as usize * channels {
+        return Ok(None);
+    }
+
+    let rgba: Vec<u8> = samples
+        .chunks_exact(channels)
+        .flat_map(|px| match *px {
+            [g] => [g, g, g, 255],
+            [g, a] => [g, g, g, a],
+            [r, g, b] => [r, g, b, 255],
+            [r, g, b, a] => [r, g, b, a],
+            _ => unreachable!("chunks_exact yields 1..=4 samples"),
+        })
+        .collect();
+    Ok(RgbaImage::from_raw(width, height, rgba))
+}
+
+/// Keep a loaded sequence only if it really has several frames.  Decode
+/// failures other than I/O mean "show it as a still image".
+fn multi_frame(result: Result<FrameSequence, AppError>) -> Result<Option<FrameSequence>, AppError> {
+    match result {
+        Ok(sequence) if sequence.frame_count > 1 => Ok(Some(sequence)),
+        Ok(_) => Ok(None),
+        Err(AppError::Io(e)) => Err(AppError::Io(e)),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Detect and load the frames of a multi-frame image (animated GIF / WEBP
+/// / APNG, multi-page TIFF).
+/// Returns None if the file has a single frame
+pub fn load_frame_sequence(path: &Path) -> Result<Option<FrameSequence>, AppError> {
     // Determine format from extension
     let extension = path
         .extension()
@@ -115,12 +375,7 @@ pub fn load_animation(path: &Path) -> Result<Option<AnimationData>, AppError> {
         .map(|e| e.to_lowercase());
 
     match extension.as_deref() {
-        Some("gif") => match load_gif_animation(path) {
-            Ok(data) if data.frame_count > 1 => Ok(Some(data)),
-            Ok(_) => Ok(None),
-            Err(AppError::Io(e)) => Err(AppError::Io(e)),
-            Err(_) => Ok(None),
-        },
+        Some("gif") => multi_frame(load_gif_animation(path)),
         Some("webp") => {
             if is_animated_webp(path)? {
                 Ok(Some(load_webp_animation(path)?))
@@ -128,6 +383,20 @@ pub fn load_animation(path: &Path) -> Result<Option<AnimationData>, AppError> {
                 Ok(None)
             }
         }
+        Some("png") => {
+            if is_apng(path)? {
+                multi_frame(load_apng_animation(path))
+            } else {
+                Ok(None)
+            }
+        }
+        Some("tif" | "tiff") => {
+            if is_multipage_tiff(path)? {
+                multi_frame(load_tiff_pages(path))
+            } else {
+                Ok(None)
+            }
+        }
         _ => Ok(None),
     }
 }
@@ -144,6 +413,102 @@ mod tests {
     const DEFAULT_FRAME_DURATION_MS: u32 = 100;
     const TEST_FRAME_COUNT: usize = 3;
 
+    #[test]
+    fn test_frame_step_single_and_multi() {
+        assert_eq!(FrameStep::Forward(1).target(3, 20), 4);
+        assert_eq!(FrameStep::Backward(1).target(3, 20), 2);
+        assert_eq!(FrameStep::Forward(10).target(3, 20), 13);
+        assert_eq!(FrameStep::Backward(10).target(13, 20), 3);
+    }
+
+    #[test]
+    fn test_frame_step_wraps_around() {
+        // Past the end lands as far into the start as it overshot
+        assert_eq!(FrameStep::Forward(10).target(15, 20), 5);
+        assert_eq!(FrameStep::Backward(10).target(4, 20), 14);
+        assert_eq!(FrameStep::Forward(1).target(19, 20), 0);
+        assert_eq!(FrameStep::Backward(1).target(0, 20), 19);
+        // A step longer than the sequence goes round more than once
+        assert_eq!(FrameStep::Forward(10).target(1, 3), 2);
+        assert_eq!(FrameStep::Backward(10).target(1, 3), 0);
+    }
+
+    #[test]
+    fn test_frame_step_first_and_last() {
+        assert_eq!(FrameStep::First.target(7, 20), 0);
+        assert_eq!(FrameStep::Last.target(7, 20), 19);
+        assert_eq!(FrameStep::Last.target(0, 1), 0);
+        assert_eq!(FrameStep::Forward(10).target(0, 0), 0);
+    }
+
+    #[test]
+    fn test_frame_step_to_clamps() {
+        assert_eq!(FrameStep::To(6).target(0, 48), 6);
+        assert_eq!(FrameStep::To(0).target(30, 48), 0);
+        assert_eq!(FrameStep::To(48).target(3, 48), 47);
+        assert_eq!(FrameStep::To(usize::MAX).target(3, 48), 47);
+        assert_eq!(FrameStep::To(5).target(0, 1), 0);
+        assert_eq!(FrameStep::To(5).target(0, 0), 0);
+    }
+
+    #[test]
+    fn test_playback_step_loop_and_play_once() {
+        // Loop wraps to the start
+        assert_eq!(playback_step(1, 3, LoopMode::Loop, false), Some((2, false)));
+        assert_eq!(playback_step(2, 3, LoopMode::Loop, false), Some((0, false)));
+
+        // Play once stops on the last frame
+        assert_eq!(
+            playback_step(1, 3, LoopMode::PlayOnce, false),
+            Some((2, false))
+        );
+        assert_eq!(playback_step(2, 3, LoopMode::PlayOnce, false), None);
+    }
+
+    #[test]
+    fn test_playback_step_ping_pong_turns_at_both_ends() {
+        // Arrange
+        let mut at = (0, false);
+        let mut visited = vec![at.0];
+
+        // Act - two full trips
+        for _ in 0..8 {
+            at = playback_step(at.0, 4, LoopMode::PingPong, at.1).unwrap();
+            visited.push(at.0);
+        }
+
+        // Assert
+        assert_eq!(visited, vec![0, 1, 2, 3, 2, 1, 0, 1, 2]);
+    }
+
+    #[test]
+    fn test_playback_step_short_sequences() {
+        assert_eq!(playback_step(0, 1, LoopMode::Loop, false), Some((0, false)));
+        assert_eq!(
+            playback_step(0, 1, LoopMode::PingPong, true),
+            Some((0, false))
+        );
+        assert_eq!(playback_step(0, 1, LoopMode::PlayOnce, false), None);
+        assert_eq!(playback_step(0, 0, LoopMode::Loop, false), Some((0, false)));
+        // Two frames ping-pong back and forth
+        assert_eq!(
+            playback_step(1, 2, LoopMode::PingPong, false),
+            Some((0, true))
+        );
+        assert_eq!(
+            playback_step(0, 2, LoopMode::PingPong, true),
+            Some((1, false))
+        );
+    }
+
+    #[test]
+    fn test_loop_mode_cycles_through_all() {
+        assert_eq!(LoopMode::default(), LoopMode::Loop);
+        assert_eq!(LoopMode::Loop.next(), LoopMode::PlayOnce);
+        assert_eq!(LoopMode::PlayOnce.next(), LoopMode::PingPong);
+        assert_eq!(LoopMode::PingPong.next(), LoopMode::Loop);
+    }
+
     #[test]
     fn test_animation_frame_creation() {
         // Arrange
@@ -151,10 +516,13 @@ mod tests {
         let duration_ms = 50;
 
         // Act
-        let frame = AnimationFrame { image, duration_ms };
+        let frame = SequenceFrame {
+            image,
+            duration_ms: Some(duration_ms),
+        };
 
         // Assert
-        assert_eq!(frame.duration_ms, 50);
+        assert_eq!(frame.duration_ms, Some(50));
         assert_eq!(frame.image.width(), 10);
         assert_eq!(frame.image.height(), 10);
     }
@@ -163,23 +531,24 @@ mod tests {
     fn test_animation_data_frame_durations() {
         // Arrange
         let frames = vec![
-            AnimationFrame {
+            SequenceFrame {
                 image: image::DynamicImage::new_rgba8(10, 10),
-                duration_ms: 50,
+                duration_ms: Some(50),
             },
-            AnimationFrame {
+            SequenceFrame {
                 image: image::DynamicImage::new_rgba8(10, 10),
-                duration_ms: 100,
+                duration_ms: Some(100),
             },
-            AnimationFrame {
+            SequenceFrame {
                 image: image::DynamicImage::new_rgba8(10, 10),
-                duration_ms: 75,
+                duration_ms: Some(75),
             },
         ];
 
-        let animation = AnimationData {
+        let animation = FrameSequence {
             frames,
             frame_count: TEST_FRAME_COUNT,
+            plays: None,
         };
 
         // Act
@@ -195,9 +564,10 @@ mod tests {
     #[test]
     fn test_animation_data_empty_frames() {
         // Arrange
-        let animation = AnimationData {
+        let animation = FrameSequence {
             frames: vec![],
             frame_count: 0,
+            plays: None,
         };
 
         // Act
@@ -259,10 +629,10 @@ mod tests {
     #[test]
     fn test_load_animation_unsupported_extension() {
         // Arrange
-        let path = PathBuf::from("/some/path/to/image.png");
+        let path = PathBuf::from("/some/path/to/image.jpg");
 
         // Act
-        let result = load_animation(&path);
+        let result = load_frame_sequence(&path);
 
         // Assert - should return Ok(None) for unsupported extensions
         assert!(result.is_ok());
@@ -275,7 +645,7 @@ mod tests {
         let path = PathBuf::from("/some/path/to/file_without_extension");
 
         // Act
-        let result = load_animation(&path);
+        let result = load_frame_sequence(&path);
 
         // Assert - should return Ok(None) for files without extension
         assert!(result.is_ok());
@@ -289,8 +659,8 @@ mod tests {
         let path_mixed = PathBuf::from("/nonexistent/Image.Gif");
 
         // Act - these will fail because files don't exist, but tests extension handling
-        let result_upper = load_animation(&path_upper);
-        let result_mixed = load_animation(&path_mixed);
+        let result_upper = load_frame_sequence(&path_upper);
+        let result_mixed = load_frame_sequence(&path_mixed);
 
         // Assert - both should attempt to load as GIF (error due to nonexistent)
         assert!(result_upper.is_err());
@@ -332,13 +702,14 @@ mod tests {
     #[test]
     fn test_animation_data_clone() {
         // Arrange
-        let frames = vec![AnimationFrame {
+        let frames = vec![SequenceFrame {
             image: image::DynamicImage::new_rgba8(5, 5),
-            duration_ms: DEFAULT_FRAME_DURATION_MS,
+            duration_ms: Some(DEFAULT_FRAME_DURATION_MS),
         }];
-        let animation = AnimationData {
+        let animation = FrameSequence {
             frames,
             frame_count: 1,
+            plays: None,
         };
 
         // Act
@@ -348,4 +719,234 @@ mod tests {
         assert_eq!(cloned.frame_count, animation.frame_count);
         assert_eq!(cloned.frames.len(), animation.frames.len());
     }
+
+    fn solid_frame(shade: u8) -> image::RgbaImage {
+        image::RgbaImage::from_pixel(4, 3, image::Rgba([shade, 0, 255 - shade, 255]))
+    }
+
+    fn write_gif(path: &Path, shades: &[u8], delay_ms: u32) {
+        use image::codecs::gif::GifEncoder;
+        use image::{Delay, Frame};
+
+        let mut encoder = GifEncoder::new(File::create(path).unwrap());
+        let frames = shades.iter().map(|&s| {
+            Frame::from_parts(
+                solid_frame(s),
+                0,
+                0,
+                Delay::from_numer_denom_ms(delay_ms, 1),
+            )
+        });
+        encoder.encode_frames(frames).unwrap();
+    }
+
+    /// Build an animated WEBP by wrapping the VP8L bitstream of individually
+    /// encoded lossless stills in VP8X/ANIM/ANMF chunks.
+    fn write_animated_webp(path: &Path, shades: &[u8], delay_ms: u32) {
+        use image::ExtendedColorType;
+        use image::codecs::webp::WebPEncoder;
+
+        fn chunk(fourcc: &[u8], payload: &[u8]) -> Vec<u8> {
+            let mut out = fourcc.to_vec();
+            out.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+            out.extend_from_slice(payload);
+            if payload.len() % 2 == 1 {
+                out.push(0);
+            }
+            out
+        }
+        fn u24(v: u32) -> [u8; 3] {
+            let b = v.to_le_bytes();
+            [b[0], b[1], b[2]]
+        }
+
+        let (w, h) = (4u32, 3u32);
+        let mut vp8x = vec![0x02 | 0x10, 0, 0, 0];
+        vp8x.extend_from_slice(&u24(w - 1));
+        vp8x.extend_from_slice(&u24(h - 1));
+        let mut body = b"WEBP".to_vec();
+        body.extend(chunk(b"VP8X", &vp8x));
+        body.extend(chunk(b"ANIM", &[0, 0, 0, 0, 0, 0]));
+
+        for &shade in shades {
+            let mut still = Vec::new();
+            WebPEncoder::new_lossless(&mut still)
+                .encode(solid_frame(shade).as_raw(), w, h, ExtendedColorType::Rgba8)
+                .unwrap();
+            // Skip "RIFF" + size + "WEBP"; what remains is the VP8L chunk
+            assert_eq!(&still[12..16], b"VP8L");
+            let mut anmf = Vec::new();
+            anmf.extend_from_slice(&u24(0));
+            anmf.extend_from_slice(&u24(0));
+            anmf.extend_from_slice(&u24(w - 1));
+            anmf.extend_from_slice(&u24(h - 1));
+            anmf.extend_from_slice(&u24(delay_ms));
+            anmf.push(0x02); // no blending, no disposal
+            anmf.extend_from_slice(&still[12..]);
+            body.extend(chunk(b"ANMF", &anmf));
+        }
+
+        let mut file = File::create(path).unwrap();
+        file.write_all(b"RIFF").unwrap();
+        file.write_all(&(body.len() as u32).to_le_bytes()).unwrap();
+        file.write_all(&body).unwrap();
+    }
+
+    fn write_apng(path: &Path, shades: &[u8], delay_ms: u16) {
+        let mut encoder = png::Encoder::new(File::create(path).unwrap(), 4, 3);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.set_animated(shades.len() as u32, 0).unwrap();
+        encoder.set_frame_delay(delay_ms, 1000).unwrap();
+        let mut writer = encoder.write_header().unwrap();
+        for &shade in shades {
+            writer
+                .write_image_data(solid_frame(shade).as_raw())
+                .unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    fn write_tiff(path: &Path, shades: &[u8]) {
+        use tiff::encoder::{TiffEncoder, colortype};
+
+        let mut encoder = TiffEncoder::new(File::create(path).unwrap()).unwrap();
+        for &shade in shades {
+            encoder
+                .write_image::<colortype::RGBA8>(4, 3, solid_frame(shade).as_raw())
+                .unwrap();
+        }
+    }
+
+    fn assert_frames(sequence: &FrameSequence, shades: &[u8], duration_ms: Option<u32>) {
+        assert_eq!(sequence.frame_count, shades.len());
+        assert_eq!(sequence.frames.len(), shades.len());
+        for (frame, &shade) in sequence.frames.iter().zip(shades) {
+            assert_eq!(frame.duration_ms, duration_ms);
+            assert_eq!(frame.image.to_rgba8(), solid_frame(shade));
+        }
+    }
+
+    #[test]
+    fn test_frame_sequence_gif() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("anim.gif");
+        write_gif(&path, &[0, 128, 255], 70);
+
+        // Act
+        let sequence = load_frame_sequence(&path).unwrap().unwrap();
+
+        // Assert
+        assert_frames(&sequence, &[0, 128, 255], Some(70));
+        assert!(sequence.is_timed());
+    }
+
+    #[test]
+    fn test_frame_sequence_webp() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("anim.webp");
+        write_animated_webp(&path, &[0, 255], 40);
+
+        // Act
+        let sequence = load_frame_sequence(&path).unwrap().unwrap();
+
+        // Assert
+        assert_frames(&sequence, &[0, 255], Some(40));
+        assert_eq!(sequence.frame_durations(), vec![40, 40]);
+    }
+
+    #[test]
+    fn test_frame_sequence_apng() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("anim.png");
+        write_apng(&path, &[0, 64, 192], 50);
+
+        // Act
+        let sequence = load_frame_sequence(&path).unwrap().unwrap();
+
+        // Assert
+        assert_frames(&sequence, &[0, 64, 192], Some(50));
+        // acTL's 0 plays is "forever"
+        assert_eq!(sequence.plays, None);
+    }
+
+    #[test]
+    fn test_frame_sequence_gif_play_count() {
+        use image::codecs::gif::{GifEncoder, Repeat};
+        use image::{Delay, Frame};
+
+        // Arrange - one GIF with no loop extension, one repeating twice,
+        // one looping forever
+        let temp_dir = TempDir::new().unwrap();
+        let write = |name: &str, repeat: Option<Repeat>| {
+            let path = temp_dir.path().join(name);
+            let mut encoder = GifEncoder::new(File::create(&path).unwrap());
+            if let Some(repeat) = repeat {
+                encoder.set_repeat(repeat).unwrap();
+            }
+            let frames = [0, 255].map(|s| {
+                Frame::from_parts(solid_frame(s), 0, 0, Delay::from_numer_denom_ms(50, 1))
+            });
+            encoder.encode_frames(frames).unwrap();
+            path
+        };
+        let once = write("once.gif", None);
+        let thrice = write("thrice.gif", Some(Repeat::Finite(2)));
+        let forever = write("forever.gif", Some(Repeat::Infinite));
+
+        // Act
+        let plays = |path: &Path| load_frame_sequence(path).unwrap().unwrap().plays;
+
+        // Assert - the count is repeats after the first pass
+        assert_eq!(plays(&once), Some(1));
+        assert_eq!(plays(&thrice), Some(3));
+        assert_eq!(plays(&forever), None);
+    }
+
+    #[test]
+    fn test_frame_sequence_static_png_is_none() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("still.png");
+        solid_frame(10).save(&path).unwrap();
+
+        // Act
+        let result = load_frame_sequence(&path).unwrap();
+
+        // Assert
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_frame_sequence_multipage_tiff() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("pages.tiff");
+        write_tiff(&path, &[0, 100, 200]);
+
+        // Act
+        let sequence = load_frame_sequence(&path).unwrap().unwrap();
+
+        // Assert - pages are untimed and play back at the fallback rate
+        assert_frames(&sequence, &[0, 100, 200], None);
+        assert!(!sequence.is_timed());
+        assert_eq!(sequence.frame_durations(), vec![UNTIMED_FRAME_MS; 3]);
+    }
+
+    #[test]
+    fn test_frame_sequence_single_page_tiff_is_none() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("page.tif");
+        write_tiff(&path, &[50]);
+
+        // Act
+        let result = load_frame_sequence(&path).unwrap();
+
+        // Assert
+        assert!(result.is_none());
+    }
 }
@@ -0,0 +1,178 @@
+//! Comic-book archives (ZIP / CBZ) opened as an image list.
+//!
+//! The image entries are unpacked into a private temp directory and
+//! browsed like any folder: each page becomes an ordinary file, so the
+//! loader, caches, and navigation need no archive awareness.  Pages are
+//! ordered naturally by entry name ("page2" before "page10") and written
+//! with a zero-padded page-number prefix, so they stay in reading order
+//! under the alphabetical sort.  The directory is deleted when the
+//! `ExtractedArchive` is dropped.
+
+use crate::error::{AppError, AppResult};
+use crate::utils::file_scanner::{is_supported_image, natural_cmp};
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+/// Archive extensions opened as a page list
+pub const ARCHIVE_EXTENSIONS: &[&str] = &["zip", "cbz"];
+
+/// Check if a file is a ZIP / CBZ archive
+pub fn is_archive(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|ext| {
+            ARCHIVE_EXTENSIONS
+                .iter()
+                .any(|a| ext.eq_ignore_ascii_case(a))
+        })
+}
+
+/// An archive's pages, unpacked; the files go away with this value
+pub struct ExtractedArchive {
+    /// Archive the pages came from
+    pub source: PathBuf,
+    /// Unpacked pages, in reading order
+    pub pages: Vec<PathBuf>,
+    /// Keeps the temp directory alive
+    _dir: TempDir,
+}
+
+/// Unpack the image entries of the archive at `path`.  Directories,
+/// non-image entries, and macOS resource-fork clutter (`__MACOSX/`,
+/// dot-files) are skipped.
+pub fn extract_archive(path: &Path) -> AppResult<ExtractedArchive> {
+    let invalid =
+        |e: zip::result::ZipError| AppError::InvalidFormat(path.to_path_buf(), e.to_string());
+    let mut archive = zip::ZipArchive::new(File::open(path)?).map_err(invalid)?;
+
+    // (entry index, entry path) for every page, then put them in order
+    let mut entries = Vec::new();
+    for index in 0..archive.len() {
+        let entry = archive.by_index(index).map_err(invalid)?;
+        let Some(name) = entry.enclosed_name() else {
+            continue;
+        };
+        let hidden = name.components().any(|c| {
+            let c = c.as_os_str().to_string_lossy();
+            c == "__MACOSX" || c.starts_with('.')
+        });
+        if entry.is_file() && !hidden && is_supported_image(&name) {
+            entries.push((index, name));
+        }
+    }
+    if entries.is_empty() {
+        return Err(AppError::NoImagesFound(path.to_path_buf()));
+    }
+    entries.sort_by(|(_, a), (_, b)| natural_cmp(&a.to_string_lossy(), &b.to_string_lossy()));
+
+    let dir = tempfile::Builder::new()
+        .prefix("rpview-archive-")
+        .tempdir_in(super::scratch::scratch_dir())?;
+    let mut pages = Vec::with_capacity(entries.len());
+    for (page, (index, name)) in entries.iter().enumerate() {
+        let file_name = name.file_name().unwrap_or_default().to_string_lossy();
+        let out_path = dir.path().join(format!("{:04}_{}", page + 1, file_name));
+        let mut entry = archive.by_index(*index).map_err(invalid)?;
+        std::io::copy(&mut entry, &mut File::create(&out_path)?)?;
+        pages.push(out_path);
+    }
+
+    Ok(ExtractedArchive {
+        source: path.to_path_buf(),
+        pages,
+        _dir: dir,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+
+    /// Write a CBZ holding `entries` (name, bytes) to `path`
+    fn write_cbz(path: &Path, entries: &[(&str, &[u8])]) {
+        let mut zip = zip::ZipWriter::new(File::create(path).unwrap());
+        let options =
+            SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+        for (name, bytes) in entries {
+            if name.ends_with('/') {
+                zip.add_directory(*name, options).unwrap();
+            } else {
+                zip.start_file(*name, options).unwrap();
+                zip.write_all(bytes).unwrap();
+            }
+        }
+        zip.finish().unwrap();
+    }
+
+    #[test]
+    fn is_archive_matches_zip_and_cbz() {
+        assert!(is_archive(Path::new("comic.cbz")));
+        assert!(is_archive(Path::new("COMIC.ZIP")));
+        assert!(!is_archive(Path::new("page.png")));
+        assert!(!is_archive(Path::new("archive")));
+    }
+
+    #[test]
+    fn extract_archive_yields_pages_in_natural_order() {
+        // Arrange
+        let temp = tempfile::TempDir::new().unwrap();
+        let cbz = temp.path().join("comic.cbz");
+        write_cbz(
+            &cbz,
+            &[
+                ("page10.png", b"ten"),
+                ("page2.png", b"two"),
+                ("notes.txt", b"not a page"),
+                ("extras/", b""),
+                ("__MACOSX/._page1.png", b"resource fork"),
+                ("Page1.png", b"one"),
+            ],
+        );
+
+        // Act
+        let archive = extract_archive(&cbz).unwrap();
+
+        // Assert
+        let names: Vec<String> = archive
+            .pages
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(
+            names,
+            ["0001_Page1.png", "0002_page2.png", "0003_page10.png"]
+        );
+        assert_eq!(std::fs::read(&archive.pages[2]).unwrap(), b"ten");
+    }
+
+    #[test]
+    fn dropping_the_archive_removes_its_pages() {
+        // Arrange
+        let temp = tempfile::TempDir::new().unwrap();
+        let cbz = temp.path().join("comic.zip");
+        write_cbz(&cbz, &[("a.jpg", b"a")]);
+        let archive = extract_archive(&cbz).unwrap();
+        let page = archive.pages[0].clone();
+        assert!(page.exists());
+
+        // Act
+        drop(archive);
+
+        // Assert
+        assert!(!page.exists());
+    }
+
+    #[test]
+    fn archive_without_images_is_an_error() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let cbz = temp.path().join("empty.cbz");
+        write_cbz(&cbz, &[("readme.txt", b"hi")]);
+        assert!(matches!(
+            extract_archive(&cbz),
+            Err(AppError::NoImagesFound(_))
+        ));
+    }
+}
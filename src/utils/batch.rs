@@ -0,0 +1,584 @@
+//! Background batch jobs (export / convert over many files).
+//!
+//! A job runs its per-file work on a worker thread and reports progress back
+//! over a channel, the same shape as `image_loader::LoaderHandle`.  The render
+//! loop polls the handle each frame; cancelling sets a flag the worker checks
+//! between files, so the file in flight always finishes cleanly.
+//!
+//! Resumable jobs keep a [`BatchManifest`] in the output folder listing the
+//! sources already done, so re-running a cancelled or crashed export skips
+//! straight to the files it hadn't reached.
+
+use std::collections::HashSet;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Snapshot of a running job's progress
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchProgress {
+    /// Files finished so far (successfully or not)
+    pub done: usize,
+    /// Files in the job
+    pub total: usize,
+    /// Name of the file being processed now
+    pub current: Option<String>,
+}
+
+impl BatchProgress {
+    pub fn new(total: usize) -> Self {
+        Self {
+            done: 0,
+            total,
+            current: None,
+        }
+    }
+
+    /// Overlay text, e.g. "Processing 34/200 — photo.jpg"
+    pub fn label(&self) -> String {
+        let position = (self.done + 1).min(self.total);
+        match self.current {
+            Some(ref name) => format!("Processing {}/{} \u{2014} {}", position, self.total, name),
+            None => format!("Processing {}/{}", position, self.total),
+        }
+    }
+}
+
+/// Final tally of a job
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchSummary {
+    pub completed: usize,
+    pub failed: Vec<(PathBuf, String)>,
+    pub total: usize,
+    /// Files left out because an earlier run's manifest already lists them
+    pub skipped: usize,
+    /// Whether the job stopped early because it was cancelled
+    pub cancelled: bool,
+}
+
+/// Message sent from the batch worker thread
+#[derive(Debug)]
+pub enum BatchMessage {
+    Progress(BatchProgress),
+    Finished(BatchSummary),
+}
+
+/// Handle to a running batch job
+pub struct BatchHandle {
+    receiver: Receiver<BatchMessage>,
+    cancel_flag: Arc<Mutex<bool>>,
+    /// Latest progress reported by the worker
+    pub progress: BatchProgress,
+}
+
+impl BatchHandle {
+    /// Drain pending worker messages (non-blocking), updating `progress`.
+    /// Returns the summary once the job has finished.
+    pub fn poll(&mut self) -> Option<BatchSummary> {
+        while let Ok(message) = self.receiver.try_recv() {
+            match message {
+                BatchMessage::Progress(progress) => self.progress = progress,
+                BatchMessage::Finished(summary) => return Some(summary),
+            }
+        }
+        None
+    }
+
+    /// Ask the worker to stop once the current file is done
+    pub fn cancel(&self) {
+        if let Ok(mut flag) = self.cancel_flag.lock() {
+            *flag = true;
+        }
+    }
+
+    /// Whether cancellation has been requested
+    pub fn is_cancelled(&self) -> bool {
+        is_cancelled(&self.cancel_flag)
+    }
+}
+
+fn is_cancelled(flag: &Arc<Mutex<bool>>) -> bool {
+    flag.lock().map(|f| *f).unwrap_or(false)
+}
+
+/// Name of the completed-files log written into a batch's output folder
+pub const MANIFEST_FILE_NAME: &str = ".rpview-batch-manifest";
+
+/// Completed-files log for a resumable batch job.
+///
+/// The first line is a key describing the job's settings (format, filters);
+/// every other line is the path of a source that finished, escaped by
+/// [`manifest_entry`].  A manifest left by a job with a different key is
+/// ignored and overwritten, so changing the export settings redoes
+/// everything.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchManifest {
+    path: PathBuf,
+    job_key: String,
+    /// Entries of the sources done so far
+    completed: HashSet<String>,
+    /// Whether the file on disk already belongs to this job
+    on_disk: bool,
+}
+
+impl BatchManifest {
+    /// Read the manifest in `output_dir`, if there is one for this job
+    pub fn load(output_dir: &Path, job_key: &str) -> Self {
+        let path = output_dir.join(MANIFEST_FILE_NAME);
+        let completed: Option<HashSet<String>> = fs::read_to_string(&path).ok().and_then(|text| {
+            let mut lines = text.lines();
+            (lines.next() == Some(job_key))
+                .then(|| lines.filter(|l| !l.is_empty()).map(String::from).collect())
+        });
+        Self {
+            path,
+            job_key: job_key.to_string(),
+            on_disk: completed.is_some(),
+            completed: completed.unwrap_or_default(),
+        }
+    }
+
+    /// `paths` minus the ones already done, in their original order
+    pub fn remaining(&self, paths: &[PathBuf]) -> Vec<PathBuf> {
+        paths
+            .iter()
+            .filter(|p| !self.completed.contains(&manifest_entry(p)))
+            .cloned()
+            .collect()
+    }
+
+    /// Log `source` as done.  Each entry is written straight away so a
+    /// crash loses at most the file in flight.
+    pub fn record(&mut self, source: &Path) -> std::io::Result<()> {
+        let mut file = if self.on_disk {
+            fs::OpenOptions::new().append(true).open(&self.path)?
+        } else {
+            let mut file = fs::File::create(&self.path)?;
+            writeln!(file, "{}", self.job_key)?;
+            self.on_disk = true;
+            file
+        };
+        let entry = manifest_entry(source);
+        writeln!(file, "{}", entry)?;
+        self.completed.insert(entry);
+        Ok(())
+    }
+
+    /// Remove the manifest once the whole job is done
+    pub fn clear(&mut self) {
+        let _ = fs::remove_file(&self.path);
+        self.completed.clear();
+        self.on_disk = false;
+    }
+}
+
+/// `path` as one manifest line: printable ASCII as is, every other byte
+/// (newlines, non-UTF-8 names) and `%` itself as `%XX`, so no two paths
+/// share a line and none can break one
+fn manifest_entry(path: &Path) -> String {
+    let mut entry = String::new();
+    for &byte in path.as_os_str().as_encoded_bytes() {
+        if (0x20..0x7f).contains(&byte) && byte != b'%' {
+            entry.push(byte as char);
+        } else {
+            entry.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    entry
+}
+
+/// Output file names for a batch, one per `(stem, extension)` in order.
+/// A name already given out (ignoring case, for case-insensitive file
+/// systems) gets `_1`, `_2`, … added to its stem until it's free, so
+/// `a.png` and `a.jpg` exported as PNG don't overwrite each other.
+pub fn unique_file_names(names: &[(String, String)]) -> Vec<String> {
+    let mut taken = HashSet::new();
+    names
+        .iter()
+        .map(|(stem, ext)| {
+            let mut name = format!("{stem}.{ext}");
+            let mut n = 0;
+            while !taken.insert(name.to_lowercase()) {
+                n += 1;
+                name = format!("{stem}_{n}.{ext}");
+            }
+            name
+        })
+        .collect()
+}
+
+/// Start `work` over `paths` on a background thread.
+pub fn start_batch<F>(paths: Vec<PathBuf>, work: F) -> BatchHandle
+where
+    F: FnMut(&Path) -> Result<(), String> + Send + 'static,
+{
+    spawn_batch(paths, 0, work)
+}
+
+/// Like [`start_batch`], but skips the files `manifest` already lists and
+/// records each one that succeeds.  The manifest is removed once every file
+/// has gone through, so failures and cancelled runs leave it for next time.
+pub fn start_resumable_batch<F>(
+    paths: Vec<PathBuf>,
+    mut manifest: BatchManifest,
+    mut work: F,
+) -> BatchHandle
+where
+    F: FnMut(&Path) -> Result<(), String> + Send + 'static,
+{
+    let mut remaining = manifest.remaining(&paths);
+    if remaining.is_empty() {
+        // A finished job never leaves a manifest behind, so one that lists
+        // everything is stale; start over rather than doing nothing
+        manifest.clear();
+        remaining = paths.clone();
+    }
+    let skipped = paths.len() - remaining.len();
+    let mut left = remaining.len();
+    spawn_batch(remaining, skipped, move |path| {
+        work(path)?;
+        // A lost entry only means redoing this file on a re-run, so it
+        // doesn't count against the export itself
+        let _ = manifest.record(path);
+        left -= 1;
+        if left == 0 {
+            manifest.clear();
+        }
+        Ok(())
+    })
+}
+
+fn spawn_batch<F>(paths: Vec<PathBuf>, skipped: usize, work: F) -> BatchHandle
+where
+    F: FnMut(&Path) -> Result<(), String> + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel();
+    let cancel_flag = Arc::new(Mutex::new(false));
+    let cancel_flag_clone = cancel_flag.clone();
+
+    let progress = BatchProgress::new(paths.len());
+
+    thread::spawn(move || {
+        run_batch(&paths, skipped, &cancel_flag_clone, &tx, work);
+    });
+
+    BatchHandle {
+        receiver: rx,
+        cancel_flag,
+        progress,
+    }
+}
+
+/// The worker loop: process each file in order, reporting progress before
+/// each one and a summary at the end.  Cancellation is checked between
+/// files only.
+fn run_batch<F>(
+    paths: &[PathBuf],
+    skipped: usize,
+    cancel_flag: &Arc<Mutex<bool>>,
+    tx: &Sender<BatchMessage>,
+    mut work: F,
+) -> BatchSummary
+where
+    F: FnMut(&Path) -> Result<(), String>,
+{
+    let mut summary = BatchSummary {
+        completed: 0,
+        failed: Vec::new(),
+        total: paths.len(),
+        skipped,
+        cancelled: false,
+    };
+
+    for (i, path) in paths.iter().enumerate() {
+        if is_cancelled(cancel_flag) {
+            summary.cancelled = true;
+            break;
+        }
+
+        let _ = tx.send(BatchMessage::Progress(BatchProgress {
+            done: i,
+            total: paths.len(),
+            current: path.file_name().map(|n| n.to_string_lossy().into_owned()),
+        }));
+
+        match work(path) {
+            Ok(()) => summary.completed += 1,
+            Err(e) => summary.failed.push((path.clone(), e)),
+        }
+    }
+
+    let _ = tx.send(BatchMessage::Finished(summary.clone()));
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(n: usize) -> Vec<PathBuf> {
+        (0..n)
+            .map(|i| PathBuf::from(format!("img{i}.png")))
+            .collect()
+    }
+
+    #[test]
+    fn progress_label_shows_position_and_name() {
+        let progress = BatchProgress {
+            done: 33,
+            total: 200,
+            current: Some("photo.jpg".to_string()),
+        };
+        assert_eq!(progress.label(), "Processing 34/200 \u{2014} photo.jpg");
+    }
+
+    #[test]
+    fn progress_label_never_exceeds_total() {
+        let progress = BatchProgress {
+            done: 5,
+            total: 5,
+            current: None,
+        };
+        assert_eq!(progress.label(), "Processing 5/5");
+    }
+
+    #[test]
+    fn run_batch_reports_progress_for_every_file() {
+        // Arrange
+        let (tx, rx) = mpsc::channel();
+        let cancel = Arc::new(Mutex::new(false));
+
+        // Act
+        let summary = run_batch(&paths(3), 0, &cancel, &tx, |_| Ok(()));
+
+        // Assert
+        let messages: Vec<_> = rx.try_iter().collect();
+        let done: Vec<_> = messages
+            .iter()
+            .filter_map(|m| match m {
+                BatchMessage::Progress(p) => Some(p.done),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(done, vec![0, 1, 2]);
+        assert!(matches!(messages.last(), Some(BatchMessage::Finished(_))));
+        assert_eq!(summary.completed, 3);
+        assert!(!summary.cancelled);
+    }
+
+    #[test]
+    fn run_batch_records_failures_and_continues() {
+        // Arrange
+        let (tx, _rx) = mpsc::channel();
+        let cancel = Arc::new(Mutex::new(false));
+
+        // Act — the second file fails
+        let summary = run_batch(&paths(3), 0, &cancel, &tx, |p| {
+            if p == Path::new("img1.png") {
+                Err("bad".to_string())
+            } else {
+                Ok(())
+            }
+        });
+
+        // Assert
+        assert_eq!(summary.completed, 2);
+        assert_eq!(
+            summary.failed,
+            vec![(PathBuf::from("img1.png"), "bad".to_string())]
+        );
+    }
+
+    #[test]
+    fn run_batch_cancel_stops_after_current_file() {
+        // Arrange — cancel is requested while the second file is processing
+        let (tx, _rx) = mpsc::channel();
+        let cancel = Arc::new(Mutex::new(false));
+        let cancel_inner = cancel.clone();
+        let mut processed = Vec::new();
+
+        // Act
+        let summary = run_batch(&paths(5), 0, &cancel, &tx, |p| {
+            processed.push(p.to_path_buf());
+            if processed.len() == 2 {
+                *cancel_inner.lock().unwrap() = true;
+            }
+            Ok(())
+        });
+
+        // Assert — the in-flight file finished, nothing after it started
+        assert_eq!(processed.len(), 2);
+        assert_eq!(summary.completed, 2);
+        assert!(summary.cancelled);
+    }
+
+    #[test]
+    fn handle_poll_tracks_progress_until_finished() {
+        // Arrange — the worker waits for the test before each file
+        let (gate_tx, gate_rx) = mpsc::channel::<()>();
+        let mut handle = start_batch(paths(2), move |_| gate_rx.recv().map_err(|e| e.to_string()));
+        assert_eq!(handle.progress, BatchProgress::new(2));
+
+        // Act — let both files through, then poll until the summary arrives
+        gate_tx.send(()).unwrap();
+        gate_tx.send(()).unwrap();
+        let summary = loop {
+            if let Some(summary) = handle.poll() {
+                break summary;
+            }
+            thread::yield_now();
+        };
+
+        // Assert
+        assert_eq!(handle.progress.done, 1);
+        assert_eq!(handle.progress.current.as_deref(), Some("img1.png"));
+        assert_eq!(summary.completed, 2);
+    }
+
+    #[test]
+    fn handle_cancel_sets_flag() {
+        // Arrange
+        let handle = start_batch(Vec::new(), |_| Ok(()));
+
+        // Act
+        handle.cancel();
+
+        // Assert
+        assert!(handle.is_cancelled());
+    }
+
+    #[test]
+    fn manifest_remaining_skips_completed_files() {
+        // Arrange — an earlier run finished img0 and img2 before stopping
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join(MANIFEST_FILE_NAME),
+            "png\nimg0.png\nimg2.png\n",
+        )
+        .unwrap();
+
+        // Act
+        let manifest = BatchManifest::load(dir.path(), "png");
+
+        // Assert
+        assert_eq!(
+            manifest.remaining(&paths(4)),
+            vec![PathBuf::from("img1.png"), PathBuf::from("img3.png")]
+        );
+    }
+
+    #[test]
+    fn manifest_from_a_different_job_is_ignored() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(MANIFEST_FILE_NAME), "png\nimg0.png\n").unwrap();
+
+        // Act
+        let manifest = BatchManifest::load(dir.path(), "jpeg");
+
+        // Assert
+        assert_eq!(manifest.remaining(&paths(2)), paths(2));
+    }
+
+    #[test]
+    fn manifest_record_survives_reload() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let mut manifest = BatchManifest::load(dir.path(), "png");
+
+        // Act
+        manifest.record(Path::new("img1.png")).unwrap();
+        manifest.record(Path::new("img0.png")).unwrap();
+
+        // Assert
+        let reloaded = BatchManifest::load(dir.path(), "png");
+        assert!(reloaded.remaining(&paths(2)).is_empty());
+        assert_eq!(
+            reloaded.remaining(&paths(3)),
+            vec![PathBuf::from("img2.png")]
+        );
+    }
+
+    #[test]
+    fn manifest_keeps_odd_names_on_one_line() {
+        // Arrange - a newline and a percent sign in file names
+        let dir = tempfile::tempdir().unwrap();
+        let odd = PathBuf::from("two\nlines.png");
+        let mut manifest = BatchManifest::load(dir.path(), "png");
+
+        // Act
+        manifest.record(&odd).unwrap();
+        manifest.record(Path::new("100%.png")).unwrap();
+
+        // Assert - neither entry reads back as some other file
+        let text = fs::read_to_string(dir.path().join(MANIFEST_FILE_NAME)).unwrap();
+        assert_eq!(text, "png\ntwo%0Alines.png\n100%25.png\n");
+        let others = vec![
+            PathBuf::from("two"),
+            PathBuf::from("lines.png"),
+            PathBuf::from("100%25.png"),
+        ];
+        let reloaded = BatchManifest::load(dir.path(), "png");
+        assert_eq!(reloaded.remaining(&others), others);
+        assert!(
+            reloaded
+                .remaining(&[odd, PathBuf::from("100%.png")])
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn unique_file_names_number_repeats() {
+        // Arrange
+        let names: Vec<(String, String)> = [
+            ("a", "png"),
+            ("a", "png"),
+            ("A", "png"),
+            ("a_1", "png"),
+            ("b", "png"),
+        ]
+        .iter()
+        .map(|(s, e)| (s.to_string(), e.to_string()))
+        .collect();
+
+        // Act
+        let unique = unique_file_names(&names);
+
+        // Assert - a literal a_1 that comes later steps around the one taken
+        assert_eq!(
+            unique,
+            ["a.png", "a_1.png", "A_2.png", "a_1_1.png", "b.png"]
+        );
+    }
+
+    #[test]
+    fn resumable_batch_runs_only_remaining_and_clears_when_done() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(MANIFEST_FILE_NAME), "png\nimg0.png\n").unwrap();
+        let manifest = BatchManifest::load(dir.path(), "png");
+        let processed = Arc::new(Mutex::new(Vec::new()));
+        let worker_processed = processed.clone();
+
+        // Act
+        let mut handle = start_resumable_batch(paths(3), manifest, move |p| {
+            worker_processed.lock().unwrap().push(p.to_path_buf());
+            Ok(())
+        });
+        let summary = loop {
+            if let Some(summary) = handle.poll() {
+                break summary;
+            }
+            thread::yield_now();
+        };
+
+        // Assert
+        assert_eq!(*processed.lock().unwrap(), paths(3)[1..].to_vec());
+        assert_eq!(summary.completed, 2);
+        assert_eq!(summary.skipped, 1);
+        assert!(!dir.path().join(MANIFEST_FILE_NAME).exists());
+    }
+}
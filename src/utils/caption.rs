@@ -0,0 +1,57 @@
+//! The filename caption shown while presenting (fullscreen): it appears at
+//! full strength when an image opens, holds, then fades out.
+
+use std::time::Duration;
+
+/// How long the caption stays fully visible after an image appears
+pub const CAPTION_HOLD: Duration = Duration::from_secs(2);
+
+/// How long the caption takes to fade out after the hold
+pub const CAPTION_FADE: Duration = Duration::from_millis(600);
+
+/// Caption opacity `since_shown` after the image appeared: 1.0 through
+/// `hold`, falling linearly to 0.0 over `fade`, then 0.0.
+pub fn caption_opacity(since_shown: Duration, hold: Duration, fade: Duration) -> f32 {
+    if since_shown <= hold {
+        return 1.0;
+    }
+    let fading = since_shown - hold;
+    if fading >= fade {
+        0.0
+    } else {
+        1.0 - fading.as_secs_f32() / fade.as_secs_f32()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOLD: Duration = Duration::from_secs(2);
+    const FADE: Duration = Duration::from_millis(500);
+
+    #[test]
+    fn fully_visible_during_hold() {
+        assert_eq!(caption_opacity(Duration::ZERO, HOLD, FADE), 1.0);
+        assert_eq!(caption_opacity(HOLD, HOLD, FADE), 1.0);
+    }
+
+    #[test]
+    fn fades_linearly_after_hold() {
+        let halfway = HOLD + Duration::from_millis(250);
+        assert!((caption_opacity(halfway, HOLD, FADE) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn hidden_once_faded() {
+        assert_eq!(caption_opacity(HOLD + FADE, HOLD, FADE), 0.0);
+        assert_eq!(caption_opacity(Duration::from_secs(60), HOLD, FADE), 0.0);
+    }
+
+    #[test]
+    fn zero_fade_cuts_off_after_hold() {
+        assert_eq!(caption_opacity(HOLD, HOLD, Duration::ZERO), 1.0);
+        let just_after = HOLD + Duration::from_millis(1);
+        assert_eq!(caption_opacity(just_after, HOLD, Duration::ZERO), 0.0);
+    }
+}
@@ -0,0 +1,216 @@
+//! Crop selections in image pixels: snapping to a grid and holding an
+//! aspect ratio, so a selection lands on clean dimensions.
+
+/// A crop drag in progress, in viewport coordinates
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CropDrag {
+    pub start: (f32, f32),
+    pub end: (f32, f32),
+    /// Shift is held: keep the image's own aspect ratio
+    pub keep_aspect: bool,
+}
+
+/// A selection rectangle in image pixels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectionRect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl SelectionRect {
+    /// The rectangle spanning two corners given in image coordinates (in
+    /// any order), clamped to an image of `image_size`
+    pub fn from_corners(a: (f32, f32), b: (f32, f32), image_size: (u32, u32)) -> Self {
+        let clamp = |v: f32, max: u32| v.round().clamp(0.0, max as f32) as u32;
+        let (x0, x1) = (
+            clamp(a.0.min(b.0), image_size.0),
+            clamp(a.0.max(b.0), image_size.0),
+        );
+        let (y0, y1) = (
+            clamp(a.1.min(b.1), image_size.1),
+            clamp(a.1.max(b.1), image_size.1),
+        );
+        Self {
+            x: x0,
+            y: y0,
+            width: x1 - x0,
+            height: y1 - y0,
+        }
+    }
+
+    /// Whether the selection covers no pixels
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// "W × H px" for showing next to the selection
+    pub fn size_label(&self) -> String {
+        format!("{} \u{00d7} {} px", self.width, self.height)
+    }
+
+    /// Move every edge to the nearest multiple of `grid` pixels.  The image's
+    /// own right and bottom edges also count as grid lines, so a selection
+    /// can always reach them.  A grid of 0 or 1 leaves it as is.
+    pub fn snap_to_grid(&self, grid: u32, image_size: (u32, u32)) -> Self {
+        if grid <= 1 {
+            return *self;
+        }
+        let snap = |v: u32, max: u32| {
+            let nearest = (v + grid / 2) / grid * grid;
+            // The image edge is a line too; take whichever is closer
+            if max.abs_diff(v) < nearest.abs_diff(v) {
+                max
+            } else {
+                nearest.min(max)
+            }
+        };
+        let (x0, x1) = (
+            snap(self.x, image_size.0),
+            snap(self.x + self.width, image_size.0),
+        );
+        let (y0, y1) = (
+            snap(self.y, image_size.1),
+            snap(self.y + self.height, image_size.1),
+        );
+        Self {
+            x: x0,
+            y: y0,
+            width: x1 - x0,
+            height: y1 - y0,
+        }
+    }
+
+    /// Shrink one side so `width : height` matches `ratio` (width over
+    /// height), keeping the top-left corner.  A non-positive ratio leaves
+    /// it as is.
+    pub fn lock_aspect(&self, ratio: f32) -> Self {
+        if !(ratio.is_finite() && ratio > 0.0) || self.height == 0 {
+            return *self;
+        }
+        let mut locked = *self;
+        if self.width as f32 / self.height as f32 > ratio {
+            locked.width = (self.height as f32 * ratio).round() as u32;
+        } else {
+            locked.height = (self.width as f32 / ratio).round() as u32;
+        }
+        locked
+    }
+}
+
+/// The image pixels between screen points `a` and `b`, for an image of
+/// `image_size` drawn at `pan` and `zoom`.  Points off the image clamp to
+/// its edges; None when the selection covers no pixels.
+pub fn crop_region(
+    image_size: (u32, u32),
+    zoom: f32,
+    pan: (f32, f32),
+    a: (f32, f32),
+    b: (f32, f32),
+) -> Option<SelectionRect> {
+    if zoom <= 0.0 {
+        return None;
+    }
+    let to_image = |p: (f32, f32)| ((p.0 - pan.0) / zoom, (p.1 - pan.1) / zoom);
+    let selection = SelectionRect::from_corners(to_image(a), to_image(b), image_size);
+    (!selection.is_empty()).then_some(selection)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: u32, y: u32, width: u32, height: u32) -> SelectionRect {
+        SelectionRect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn test_from_corners_orders_and_clamps() {
+        let selection = SelectionRect::from_corners((50.4, 10.0), (-5.0, 300.0), (100, 200));
+        assert_eq!(selection, rect(0, 10, 50, 190));
+    }
+
+    #[test]
+    fn test_snap_to_grid_rounds_each_edge() {
+        // Arrange - edges at 13..117 × 5..60 on an 8 px grid
+        let selection = rect(13, 5, 104, 55);
+
+        // Act
+        let snapped = selection.snap_to_grid(8, (1000, 1000));
+
+        // Assert - 13 → 16, 117 → 120, 5 → 8, 60 → 64
+        assert_eq!(snapped, rect(16, 8, 104, 56));
+        assert_eq!(snapped.x % 8, 0);
+        assert_eq!(snapped.width % 8, 0);
+        assert_eq!(snapped.height % 8, 0);
+    }
+
+    #[test]
+    fn test_snap_to_grid_reaches_the_image_edge() {
+        // A 100 px wide image: the right edge (99) is nearer 100 than 96
+        let snapped = rect(0, 0, 99, 30).snap_to_grid(8, (100, 100));
+        assert_eq!(snapped, rect(0, 0, 100, 32));
+    }
+
+    #[test]
+    fn test_snap_to_grid_off() {
+        let selection = rect(13, 5, 104, 55);
+        assert_eq!(selection.snap_to_grid(0, (1000, 1000)), selection);
+        assert_eq!(selection.snap_to_grid(1, (1000, 1000)), selection);
+    }
+
+    #[test]
+    fn test_lock_aspect() {
+        assert_eq!(
+            rect(0, 0, 400, 100).lock_aspect(16.0 / 9.0),
+            rect(0, 0, 178, 100)
+        );
+        assert_eq!(rect(0, 0, 100, 400).lock_aspect(1.0), rect(0, 0, 100, 100));
+        assert_eq!(rect(0, 0, 100, 40).lock_aspect(0.0), rect(0, 0, 100, 40));
+    }
+
+    #[test]
+    fn test_crop_region_maps_through_zoom_and_pan() {
+        // Arrange - a 400 × 300 image at 2x, its corner 100 px in and 50 down
+        let (size, zoom, pan) = ((400, 300), 2.0, (100.0, 50.0));
+
+        // Act - dragged up and left, from (300, 250) to (140, 90) on screen
+        let region = crop_region(size, zoom, pan, (300.0, 250.0), (140.0, 90.0));
+
+        // Assert
+        assert_eq!(region, Some(rect(20, 20, 80, 80)));
+    }
+
+    #[test]
+    fn test_crop_region_clamps_and_rejects_empty() {
+        // Past the image's edges: clamped to the whole image
+        assert_eq!(
+            crop_region((400, 300), 1.0, (0.0, 0.0), (-50.0, -50.0), (900.0, 900.0)),
+            Some(rect(0, 0, 400, 300))
+        );
+        // A click without a drag, or a drag entirely off the image
+        assert_eq!(
+            crop_region((400, 300), 1.0, (0.0, 0.0), (10.0, 10.0), (10.0, 10.0)),
+            None
+        );
+        assert_eq!(
+            crop_region((400, 300), 1.0, (0.0, 0.0), (500.0, 0.0), (600.0, 100.0)),
+            None
+        );
+        assert_eq!(
+            crop_region((400, 300), 0.0, (0.0, 0.0), (0.0, 0.0), (10.0, 10.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_size_label() {
+        assert_eq!(rect(0, 0, 640, 480).size_label(), "640 \u{00d7} 480 px");
+    }
+}
@@ -0,0 +1,37 @@
+//! Auto-hiding the mouse cursor while presenting (fullscreen).
+
+use std::time::Duration;
+
+/// Whether the cursor should be hidden: only while presenting, and only
+/// once the mouse has been still for at least `delay`.
+pub fn should_hide_cursor(presenting: bool, since_last_move: Duration, delay: Duration) -> bool {
+    presenting && since_last_move >= delay
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DELAY: Duration = Duration::from_secs(3);
+
+    #[test]
+    fn hides_after_delay_while_presenting() {
+        assert!(should_hide_cursor(true, Duration::from_secs(3), DELAY));
+        assert!(should_hide_cursor(true, Duration::from_secs(10), DELAY));
+    }
+
+    #[test]
+    fn stays_visible_before_delay() {
+        assert!(!should_hide_cursor(
+            true,
+            Duration::from_millis(2999),
+            DELAY
+        ));
+        assert!(!should_hide_cursor(true, Duration::ZERO, DELAY));
+    }
+
+    #[test]
+    fn never_hides_when_not_presenting() {
+        assert!(!should_hide_cursor(false, Duration::from_secs(60), DELAY));
+    }
+}
@@ -0,0 +1,191 @@
+//! Decoded neighbours of the current image, kept ready so stepping to them
+//! skips the loader.  Entries are bounded by count and by total bytes; the
+//! least recently used go first.
+
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+
+/// How many images a preload `radius` keeps: that many either side
+pub fn entries_for_radius(radius: usize) -> usize {
+    radius * 2
+}
+
+struct Entry<V> {
+    path: PathBuf,
+    value: V,
+    bytes: u64,
+}
+
+/// A least-recently-used cache of decoded images keyed by path
+pub struct DecodeCache<V> {
+    /// Least recently used first
+    entries: VecDeque<Entry<V>>,
+    max_entries: usize,
+    max_bytes: u64,
+    total_bytes: u64,
+}
+
+impl<V> DecodeCache<V> {
+    pub fn new(max_entries: usize, max_bytes: u64) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            max_entries,
+            max_bytes,
+            total_bytes: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Bytes the cached images take up together
+    pub fn total_bytes(&self) -> u64 {
+        self.total_bytes
+    }
+
+    /// Hold at most `max_entries` images from now on, letting the least
+    /// recently used go if there are more
+    pub fn set_max_entries(&mut self, max_entries: usize) {
+        self.max_entries = max_entries;
+        while self.entries.len() > max_entries {
+            let Some(evicted) = self.entries.pop_front() else {
+                break;
+            };
+            self.total_bytes -= evicted.bytes;
+        }
+    }
+
+    /// Whether `path` is cached, counting as a use of it
+    pub fn touch(&mut self, path: &Path) -> bool {
+        let Some(at) = self.position(path) else {
+            return false;
+        };
+        let entry = self.entries.remove(at).expect("position is in range");
+        self.entries.push_back(entry);
+        true
+    }
+
+    /// Take `path`'s image out of the cache.  It leaves rather than being
+    /// copied, since the viewer takes ownership of what it shows.
+    pub fn take(&mut self, path: &Path) -> Option<V> {
+        let entry = self.entries.remove(self.position(path)?)?;
+        self.total_bytes -= entry.bytes;
+        Some(entry.value)
+    }
+
+    /// Cache `value` (taking up `bytes`) for `path`, replacing any earlier
+    /// entry and evicting the least recently used until both limits hold.
+    /// An image bigger than the whole byte budget isn't kept.
+    pub fn insert(&mut self, path: PathBuf, value: V, bytes: u64) {
+        self.take(&path);
+        if bytes > self.max_bytes || self.max_entries == 0 {
+            return;
+        }
+        while self.entries.len() >= self.max_entries || self.total_bytes + bytes > self.max_bytes {
+            let Some(evicted) = self.entries.pop_front() else {
+                break;
+            };
+            self.total_bytes -= evicted.bytes;
+        }
+        self.total_bytes += bytes;
+        self.entries.push_back(Entry { path, value, bytes });
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.total_bytes = 0;
+    }
+
+    fn position(&self, path: &Path) -> Option<usize> {
+        self.entries.iter().position(|entry| entry.path == path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path(name: &str) -> PathBuf {
+        PathBuf::from(name)
+    }
+
+    #[test]
+    fn test_count_limit_evicts_least_recently_used() {
+        // Arrange
+        let mut cache = DecodeCache::new(2, 1000);
+        cache.insert(path("a"), 'a', 10);
+        cache.insert(path("b"), 'b', 10);
+
+        // Act - using a makes b the oldest
+        assert!(cache.touch(&path("a")));
+        cache.insert(path("c"), 'c', 10);
+
+        // Assert
+        assert_eq!(cache.len(), 2);
+        assert!(!cache.touch(&path("b")));
+        assert_eq!(cache.take(&path("a")), Some('a'));
+        assert_eq!(cache.take(&path("c")), Some('c'));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_shrinking_the_count_limit_evicts_oldest() {
+        // Arrange
+        let mut cache = DecodeCache::new(entries_for_radius(2), 1000);
+        for name in ["a", "b", "c", "d"] {
+            cache.insert(path(name), name, 10);
+        }
+
+        // Act - the radius drops to 1
+        cache.set_max_entries(entries_for_radius(1));
+
+        // Assert
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.total_bytes(), 20);
+        assert!(!cache.touch(&path("a")));
+        assert!(cache.touch(&path("d")));
+    }
+
+    #[test]
+    fn test_byte_limit_evicts_until_it_fits() {
+        // Arrange
+        let mut cache = DecodeCache::new(10, 100);
+        cache.insert(path("a"), 1, 40);
+        cache.insert(path("b"), 2, 40);
+
+        // Act
+        cache.insert(path("c"), 3, 50);
+
+        // Assert - a had to go; b and c fit together
+        assert_eq!(cache.total_bytes(), 90);
+        assert_eq!(cache.take(&path("a")), None);
+        assert_eq!(cache.take(&path("b")), Some(2));
+        assert_eq!(cache.total_bytes(), 50);
+    }
+
+    #[test]
+    fn test_oversized_images_and_replacements() {
+        let mut cache = DecodeCache::new(4, 100);
+        cache.insert(path("a"), 1, 30);
+
+        // Bigger than the whole budget: not kept, and nothing evicted for it
+        cache.insert(path("huge"), 2, 101);
+        assert_eq!(cache.len(), 1);
+
+        // Re-inserting replaces rather than double-counting
+        cache.insert(path("a"), 3, 20);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.total_bytes(), 20);
+        assert_eq!(cache.take(&path("a")), Some(3));
+
+        cache.insert(path("b"), 4, 10);
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.total_bytes(), 0);
+    }
+}
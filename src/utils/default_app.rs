@@ -0,0 +1,67 @@
+//! Handing a file to the operating system's default app for its type,
+//! bypassing the configured external viewer list.
+
+use std::ffi::OsString;
+use std::path::Path;
+use std::process::Command;
+
+/// The program and arguments that open `path` in the default app on `os`
+/// (a `std::env::consts::OS` name), or `None` where there is no such
+/// launcher
+pub fn default_open_command(os: &str, path: &Path) -> Option<(&'static str, Vec<OsString>)> {
+    let path = path.as_os_str().to_owned();
+    match os {
+        "macos" => Some(("open", vec![path])),
+        // `start` takes its first quoted argument as a window title, so
+        // give it an empty one before the path
+        "windows" => Some(("cmd", vec!["/C".into(), "start".into(), "".into(), path])),
+        "linux" | "freebsd" | "dragonfly" | "netbsd" | "openbsd" => Some(("xdg-open", vec![path])),
+        _ => None,
+    }
+}
+
+/// Open `path` in the default app for this platform
+pub fn open_in_default_app(path: &Path) -> Result<(), String> {
+    let (program, args) = default_open_command(std::env::consts::OS, path)
+        .ok_or_else(|| "No default app launcher on this platform".to_string())?;
+    Command::new(program)
+        .args(args)
+        .spawn()
+        .map(drop)
+        .map_err(|e| format!("Couldn't run {}: {}", program, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_open_command_per_platform() {
+        let path = Path::new("/shots/a b.png");
+        let expect = |program: &'static str, args: &[&str]| {
+            Some((program, args.iter().map(OsString::from).collect::<Vec<_>>()))
+        };
+
+        assert_eq!(
+            default_open_command("macos", path),
+            expect("open", &["/shots/a b.png"])
+        );
+        assert_eq!(
+            default_open_command("windows", path),
+            expect("cmd", &["/C", "start", "", "/shots/a b.png"])
+        );
+        assert_eq!(
+            default_open_command("linux", path),
+            expect("xdg-open", &["/shots/a b.png"])
+        );
+        assert_eq!(
+            default_open_command("freebsd", path),
+            expect("xdg-open", &["/shots/a b.png"])
+        );
+    }
+
+    #[test]
+    fn test_default_open_command_unknown_platform() {
+        assert_eq!(default_open_command("ios", Path::new("a.png")), None);
+    }
+}
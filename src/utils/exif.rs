@@ -0,0 +1,437 @@
+//! Capture dates and camera settings from EXIF metadata.
+//!
+//! The `image` crate hands back a file's raw EXIF block (a little TIFF
+//! structure) for JPEG, PNG, WebP and TIFF, and `kamadak-exif` parses it.
+//! The capture date is `DateTimeOriginal`, falling back to
+//! `DateTimeDigitized` and then the plain `DateTime` of IFD0; the debug
+//! overlay also shows a handful of camera tags (camera, lens, ISO,
+//! shutter, aperture).
+
+use exif::{In, Tag, Value};
+use image::ImageDecoder;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A calendar date and time as the camera recorded it (no time zone)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CaptureDate {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+impl CaptureDate {
+    /// Parse EXIF's "YYYY:MM:DD HH:MM:SS".  Cameras without a clock write
+    /// zeros or blanks, which are None.
+    pub fn parse_exif(text: &str) -> Option<Self> {
+        let text = text.trim_end_matches(['\0', ' ']);
+        let (date, time) = text.split_once(' ')?;
+        let mut date = date.split(':').map(|p| p.parse::<u16>().ok());
+        let mut time = time.split(':').map(|p| p.parse::<u8>().ok());
+        let parsed = Self {
+            year: date.next()??,
+            month: date.next()?? as u8,
+            day: date.next()?? as u8,
+            hour: time.next()??,
+            minute: time.next()??,
+            second: time.next()??,
+        };
+        parsed.is_valid().then_some(parsed)
+    }
+
+    /// UTC date of a file time (used when there's no EXIF date)
+    pub fn from_system_time(time: SystemTime) -> Option<Self> {
+        let secs = time.duration_since(UNIX_EPOCH).ok()?.as_secs();
+        let (year, month, day) = civil_from_days((secs / 86_400) as i64);
+        let of_day = secs % 86_400;
+        Some(Self {
+            year: u16::try_from(year).ok()?,
+            month,
+            day,
+            hour: (of_day / 3600) as u8,
+            minute: (of_day / 60 % 60) as u8,
+            second: (of_day % 60) as u8,
+        })
+    }
+
+    fn is_valid(&self) -> bool {
+        self.year > 0
+            && (1..=12).contains(&self.month)
+            && (1..=31).contains(&self.day)
+            && self.hour < 24
+            && self.minute < 60
+            && self.second < 61
+    }
+}
+
+/// What the camera recorded about a shot; each field is None when the
+/// tag is missing
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PhotoInfo {
+    /// Make and model, e.g. "Canon EOS R5"
+    pub camera: Option<String>,
+    pub lens: Option<String>,
+    pub iso: Option<u16>,
+    /// Exposure time in seconds, as (numerator, denominator)
+    pub exposure_time: Option<(u32, u32)>,
+    /// Aperture as (numerator, denominator) of the f-number
+    pub f_number: Option<(u32, u32)>,
+    pub date: Option<CaptureDate>,
+}
+
+impl PhotoInfo {
+    /// "1/250 s", or "2.5 s" for long exposures
+    pub fn shutter_label(&self) -> Option<String> {
+        let (num, den) = self.exposure_time.filter(|&(n, d)| n > 0 && d > 0)?;
+        let secs = num as f64 / den as f64;
+        Some(if secs < 1.0 {
+            format!("1/{} s", (1.0 / secs).round())
+        } else {
+            format!("{} s", (secs * 10.0).round() / 10.0)
+        })
+    }
+
+    /// "f/2.8"
+    pub fn aperture_label(&self) -> Option<String> {
+        let (num, den) = self.f_number.filter(|&(n, d)| n > 0 && d > 0)?;
+        Some(format!(
+            "f/{}",
+            (num as f64 / den as f64 * 10.0).round() / 10.0
+        ))
+    }
+
+    /// "2023-05-14 09:08:07"
+    pub fn date_label(&self) -> Option<String> {
+        self.date.map(|d| {
+            format!(
+                "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+                d.year, d.month, d.day, d.hour, d.minute, d.second
+            )
+        })
+    }
+}
+
+/// The raw EXIF block of `path`, if its format carries one
+fn read_exif_block(path: &Path) -> Option<Vec<u8>> {
+    let mut decoder = image::ImageReader::open(path)
+        .ok()?
+        .with_guessed_format()
+        .ok()?
+        .into_decoder()
+        .ok()?;
+    decoder.exif_metadata().ok()?
+}
+
+/// When `path` was taken according to its EXIF data, if it has any
+pub fn read_capture_date(path: &Path) -> Option<CaptureDate> {
+    capture_date(&read_exif_block(path)?)
+}
+
+/// Camera details of `path`, or None when it has no EXIF data
+pub fn read_photo_info(path: &Path) -> Option<PhotoInfo> {
+    photo_info(&read_exif_block(path)?)
+}
+
+/// Camera details in a raw EXIF block.  None when the block isn't EXIF or
+/// holds none of the tags.
+pub fn photo_info(exif: &[u8]) -> Option<PhotoInfo> {
+    let exif = parse(exif)?;
+    let make = ascii(&exif, Tag::Make);
+    let model = ascii(&exif, Tag::Model);
+    // Most models already start with the make ("Canon" + "Canon EOS R5")
+    let camera = match (make, model) {
+        (Some(make), Some(model)) if model.starts_with(&make) => Some(model),
+        (Some(make), Some(model)) => Some(format!("{} {}", make, model)),
+        (make, model) => model.or(make),
+    };
+    let info = PhotoInfo {
+        camera,
+        lens: ascii(&exif, Tag::LensModel),
+        iso: field(&exif, Tag::PhotographicSensitivity)
+            .and_then(|value| value.get_uint(0))
+            .and_then(|iso| u16::try_from(iso).ok()),
+        exposure_time: rational(&exif, Tag::ExposureTime),
+        f_number: rational(&exif, Tag::FNumber),
+        date: date_of(&exif),
+    };
+    (info != PhotoInfo::default()).then_some(info)
+}
+
+/// Capture date in a raw EXIF block (with or without the JPEG "Exif\0\0"
+/// prefix)
+pub fn capture_date(exif: &[u8]) -> Option<CaptureDate> {
+    date_of(&parse(exif)?)
+}
+
+fn parse(exif: &[u8]) -> Option<exif::Exif> {
+    let tiff = exif.strip_prefix(b"Exif\0\0").unwrap_or(exif);
+    exif::Reader::new().read_raw(tiff.to_vec()).ok()
+}
+
+fn date_of(exif: &exif::Exif) -> Option<CaptureDate> {
+    [Tag::DateTimeOriginal, Tag::DateTimeDigitized, Tag::DateTime]
+        .into_iter()
+        .find_map(|tag| CaptureDate::parse_exif(&ascii(exif, tag)?))
+}
+
+/// `tag` of the main image (IFD0 and its Exif IFD)
+fn field(exif: &exif::Exif, tag: Tag) -> Option<&Value> {
+    exif.get_field(tag, In::PRIMARY).map(|field| &field.value)
+}
+
+/// The trimmed text of an ASCII tag
+fn ascii(exif: &exif::Exif, tag: Tag) -> Option<String> {
+    let Value::Ascii(strings) = field(exif, tag)? else {
+        return None;
+    };
+    let text = String::from_utf8_lossy(strings.first()?);
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+fn rational(exif: &exif::Exif, tag: Tag) -> Option<(u32, u32)> {
+    match field(exif, tag)? {
+        Value::Rational(values) => values.first().map(|r| (r.num, r.denom)),
+        _ => None,
+    }
+}
+
+/// (year, month, day) of a day count since 1970-01-01 (proleptic Gregorian)
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    /// Little-endian EXIF block: IFD0 with `DateTime`, pointing at an Exif
+    /// IFD with `DateTimeOriginal` (when given)
+    fn exif_block(date_time: &str, original: Option<&str>) -> Vec<u8> {
+        let entry = |tag: Tag, kind: u16, count: u32, value: u32| {
+            let mut e = Vec::new();
+            e.extend_from_slice(&tag.number().to_le_bytes());
+            e.extend_from_slice(&kind.to_le_bytes());
+            e.extend_from_slice(&count.to_le_bytes());
+            e.extend_from_slice(&value.to_le_bytes());
+            e
+        };
+        // Header (8) + IFD0 with two entries (2 + 24 + 4) = 38, then the
+        // Exif IFD with one entry (2 + 12 + 4) = 56, then the strings
+        let mut data = b"II\x2a\x00\x08\x00\x00\x00".to_vec();
+        data.extend_from_slice(&2u16.to_le_bytes());
+        data.extend(entry(Tag::DateTime, 2, 20, 56));
+        data.extend(entry(Tag::ExifIFDPointer, 4, 1, 38));
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(&1u16.to_le_bytes());
+        data.extend(entry(Tag::DateTimeOriginal, 2, 20, 76));
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(date_time.as_bytes());
+        data.push(0);
+        data.extend_from_slice(original.unwrap_or("0000:00:00 00:00:00").as_bytes());
+        data.push(0);
+        data
+    }
+
+    fn date(year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> CaptureDate {
+        CaptureDate {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        }
+    }
+
+    #[test]
+    fn test_parse_exif_date() {
+        assert_eq!(
+            CaptureDate::parse_exif("2023:05:14 09:08:07\0"),
+            Some(date(2023, 5, 14, 9, 8, 7))
+        );
+        assert_eq!(CaptureDate::parse_exif("0000:00:00 00:00:00"), None);
+        assert_eq!(CaptureDate::parse_exif("    :  :     :  :  "), None);
+        assert_eq!(CaptureDate::parse_exif("2023-05-14"), None);
+    }
+
+    #[test]
+    fn test_capture_date_prefers_date_time_original() {
+        // Arrange
+        let exif = exif_block("2024:01:02 03:04:05", Some("2023:05:14 09:08:07"));
+
+        // Act / Assert
+        assert_eq!(capture_date(&exif), Some(date(2023, 5, 14, 9, 8, 7)));
+    }
+
+    #[test]
+    fn test_capture_date_falls_back_to_date_time() {
+        // Arrange - the Exif IFD's original date is blank
+        let mut exif = b"Exif\0\0".to_vec();
+        exif.extend(exif_block("2024:01:02 03:04:05", None));
+
+        // Act / Assert
+        assert_eq!(capture_date(&exif), Some(date(2024, 1, 2, 3, 4, 5)));
+    }
+
+    #[test]
+    fn test_capture_date_rejects_garbage() {
+        assert_eq!(capture_date(b""), None);
+        assert_eq!(capture_date(b"not a tiff header"), None);
+        assert_eq!(
+            capture_date(&exif_block("2024:01:02 03:04:05", None)[..20]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_from_system_time() {
+        // 2023-05-14 09:08:07 UTC
+        let time = UNIX_EPOCH + Duration::from_secs(1_684_055_287);
+        assert_eq!(
+            CaptureDate::from_system_time(time),
+            Some(date(2023, 5, 14, 9, 8, 7))
+        );
+        assert_eq!(
+            CaptureDate::from_system_time(UNIX_EPOCH),
+            Some(date(1970, 1, 1, 0, 0, 0))
+        );
+    }
+
+    /// A tag value for `camera_block`
+    enum TagValue<'a> {
+        Ascii(&'a str),
+        Short(u16),
+        Rational(u32, u32),
+    }
+
+    /// Little-endian EXIF block with `ifd0` tags and an Exif IFD holding
+    /// `exif` tags, out-of-line values packed after both IFDs
+    fn camera_block(ifd0: &[(Tag, TagValue)], exif: &[(Tag, TagValue)]) -> Vec<u8> {
+        let ifd_len = |n: usize| 2 + 12 * n + 4;
+        let exif_ifd = 8 + ifd_len(ifd0.len() + 1);
+        let mut data_at = exif_ifd + ifd_len(exif.len());
+        let mut out_of_line = Vec::new();
+        let mut write_ifd = |block: &mut Vec<u8>, tags: &[(Tag, TagValue)], next: Option<usize>| {
+            let count = tags.len() + usize::from(next.is_some());
+            block.extend_from_slice(&(count as u16).to_le_bytes());
+            for (tag, value) in tags {
+                let (kind, count, inline) = match value {
+                    TagValue::Ascii(text) => {
+                        let mut bytes = text.as_bytes().to_vec();
+                        bytes.push(0);
+                        let len = bytes.len() as u32;
+                        let inline = if bytes.len() <= 4 {
+                            bytes.resize(4, 0);
+                            u32::from_le_bytes(bytes.try_into().unwrap())
+                        } else {
+                            let at = data_at as u32;
+                            data_at += bytes.len();
+                            out_of_line.extend(bytes);
+                            at
+                        };
+                        (2u16, len, inline)
+                    }
+                    TagValue::Short(v) => (3, 1, u32::from(*v)),
+                    TagValue::Rational(n, d) => {
+                        let at = data_at as u32;
+                        data_at += 8;
+                        out_of_line.extend_from_slice(&n.to_le_bytes());
+                        out_of_line.extend_from_slice(&d.to_le_bytes());
+                        (5, 1, at)
+                    }
+                };
+                block.extend_from_slice(&tag.number().to_le_bytes());
+                block.extend_from_slice(&kind.to_le_bytes());
+                block.extend_from_slice(&count.to_le_bytes());
+                block.extend_from_slice(&inline.to_le_bytes());
+            }
+            if let Some(next) = next {
+                block.extend_from_slice(&Tag::ExifIFDPointer.number().to_le_bytes());
+                block.extend_from_slice(&4u16.to_le_bytes());
+                block.extend_from_slice(&1u32.to_le_bytes());
+                block.extend_from_slice(&(next as u32).to_le_bytes());
+            }
+            block.extend_from_slice(&0u32.to_le_bytes());
+        };
+        let mut block = b"II\x2a\x00\x08\x00\x00\x00".to_vec();
+        write_ifd(&mut block, ifd0, Some(exif_ifd));
+        write_ifd(&mut block, exif, None);
+        block.extend(out_of_line);
+        block
+    }
+
+    #[test]
+    fn test_photo_info_reads_camera_settings() {
+        // Arrange
+        let exif = camera_block(
+            &[
+                (Tag::Make, TagValue::Ascii("Canon")),
+                (Tag::Model, TagValue::Ascii("Canon EOS R5")),
+            ],
+            &[
+                (Tag::ExposureTime, TagValue::Rational(1, 250)),
+                (Tag::FNumber, TagValue::Rational(28, 10)),
+                (Tag::PhotographicSensitivity, TagValue::Short(400)),
+                (
+                    Tag::DateTimeOriginal,
+                    TagValue::Ascii("2023:05:14 09:08:07"),
+                ),
+                (Tag::LensModel, TagValue::Ascii("RF24-105mm F4 L IS USM")),
+            ],
+        );
+
+        // Act
+        let info = photo_info(&exif).unwrap();
+
+        // Assert
+        assert_eq!(info.camera.as_deref(), Some("Canon EOS R5"));
+        assert_eq!(info.lens.as_deref(), Some("RF24-105mm F4 L IS USM"));
+        assert_eq!(info.iso, Some(400));
+        assert_eq!(info.shutter_label().as_deref(), Some("1/250 s"));
+        assert_eq!(info.aperture_label().as_deref(), Some("f/2.8"));
+        assert_eq!(info.date_label().as_deref(), Some("2023-05-14 09:08:07"));
+    }
+
+    #[test]
+    fn test_photo_info_joins_make_and_model() {
+        let exif = camera_block(
+            &[
+                (Tag::Make, TagValue::Ascii("SONY")),
+                (Tag::Model, TagValue::Ascii("ILCE-7M3")),
+            ],
+            &[(Tag::ExposureTime, TagValue::Rational(25, 10))],
+        );
+        let info = photo_info(&exif).unwrap();
+        assert_eq!(info.camera.as_deref(), Some("SONY ILCE-7M3"));
+        assert_eq!(info.shutter_label().as_deref(), Some("2.5 s"));
+        assert_eq!(info.lens, None);
+    }
+
+    #[test]
+    fn test_photo_info_none_without_tags() {
+        assert_eq!(photo_info(&camera_block(&[], &[])), None);
+        assert_eq!(photo_info(b"not a tiff header"), None);
+    }
+
+    #[test]
+    fn test_read_photo_info_of_png_without_exif() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("plain.png");
+        image::RgbaImage::new(1, 1).save(&path).unwrap();
+        assert_eq!(read_photo_info(&path), None);
+    }
+}
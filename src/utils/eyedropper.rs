@@ -0,0 +1,59 @@
+//! Eyedropper: read the color of one displayed pixel.
+
+/// The color under the cursor and where it was read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColorSample {
+    /// Pixel position in the displayed image
+    pub pixel: (u32, u32),
+    pub rgb: [u8; 3],
+}
+
+impl ColorSample {
+    /// "#1A2B3C"
+    pub fn hex(&self) -> String {
+        let [r, g, b] = self.rgb;
+        format!("#{:02X}{:02X}{:02X}", r, g, b)
+    }
+
+    /// "RGB 26, 43, 60"
+    pub fn rgb_label(&self) -> String {
+        let [r, g, b] = self.rgb;
+        format!("RGB {}, {}, {}", r, g, b)
+    }
+}
+
+/// RGB of pixel `(x, y)` in a BGRA buffer `width` pixels wide (a render
+/// image's layout)
+pub fn sample_bgra(bytes: &[u8], width: u32, (x, y): (u32, u32)) -> Option<[u8; 3]> {
+    let i = (y as usize * width as usize + x as usize) * 4;
+    let px = bytes.get(i..i + 4)?;
+    (x < width).then_some([px[2], px[1], px[0]])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_and_rgb_labels() {
+        let sample = ColorSample {
+            pixel: (3, 4),
+            rgb: [26, 43, 255],
+        };
+        assert_eq!(sample.hex(), "#1A2BFF");
+        assert_eq!(sample.rgb_label(), "RGB 26, 43, 255");
+    }
+
+    #[test]
+    fn test_sample_bgra_swaps_channels() {
+        // Arrange - 2x2 BGRA: the bottom-right pixel is pure red
+        let mut bytes = vec![0u8; 16];
+        bytes[12..16].copy_from_slice(&[0, 0, 255, 255]);
+
+        // Act / Assert
+        assert_eq!(sample_bgra(&bytes, 2, (1, 1)), Some([255, 0, 0]));
+        assert_eq!(sample_bgra(&bytes, 2, (0, 0)), Some([0, 0, 0]));
+        assert_eq!(sample_bgra(&bytes, 2, (2, 0)), None);
+        assert_eq!(sample_bgra(&bytes, 2, (0, 2)), None);
+    }
+}
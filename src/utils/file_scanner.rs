@@ -1,4 +1,6 @@
 use crate::error::{AppError, AppResult};
+use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -49,9 +51,140 @@ pub fn scan_directory(dir: &Path) -> AppResult<Vec<PathBuf>> {
     Ok(images)
 }
 
-/// Sort image paths alphabetically (case-insensitive)
+/// Scan a directory and every folder below it for supported image files.
+///
+/// Folders are visited in name order, each one's images listed before its
+/// subfolders'.  Symlinked folders are followed, but never into a folder
+/// already visited, so a link back up the tree can't loop.  A subfolder
+/// that can't be read is skipped rather than failing the whole scan.
+pub fn scan_directory_recursive(dir: &Path) -> AppResult<Vec<PathBuf>> {
+    let mut images = Vec::new();
+    let mut visited = HashSet::new();
+    scan_tree(dir, &mut visited, &mut images)?;
+    Ok(images)
+}
+
+/// `scan_directory_recursive` when `recursive`, otherwise `scan_directory`
+pub fn scan_directory_with(dir: &Path, recursive: bool) -> AppResult<Vec<PathBuf>> {
+    if recursive {
+        scan_directory_recursive(dir)
+    } else {
+        scan_directory(dir)
+    }
+}
+
+/// The nearest sibling of `dir` (after it in name order when `forward`,
+/// before it otherwise) that holds images, with those images.  Hidden
+/// folders and folders without images are passed over; None when there
+/// are no more.
+pub fn adjacent_image_folder(
+    dir: &Path,
+    forward: bool,
+    recursive: bool,
+) -> Option<(PathBuf, Vec<PathBuf>)> {
+    let name = dir.file_name()?;
+    let parent = dir.parent()?;
+    let mut siblings: Vec<PathBuf> = fs::read_dir(parent)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .filter(|path| {
+            path.file_name()
+                .is_some_and(|n| n == name || !n.to_string_lossy().starts_with('.'))
+        })
+        .collect();
+    sort_alphabetically(&mut siblings);
+    let at = siblings
+        .iter()
+        .position(|path| path.file_name() == Some(name))?;
+    let candidates: Box<dyn Iterator<Item = &PathBuf>> = if forward {
+        Box::new(siblings[at + 1..].iter())
+    } else {
+        Box::new(siblings[..at].iter().rev())
+    };
+    candidates.into_iter().find_map(|folder| {
+        let images = scan_directory_with(folder, recursive).ok()?;
+        (!images.is_empty()).then(|| (folder.clone(), images))
+    })
+}
+
+fn scan_tree(
+    dir: &Path,
+    visited: &mut HashSet<PathBuf>,
+    images: &mut Vec<PathBuf>,
+) -> AppResult<()> {
+    if !visited.insert(dir.canonicalize()?) {
+        return Ok(());
+    }
+    let mut found = scan_directory(dir)?;
+    sort_alphabetically(&mut found);
+    images.extend(found);
+
+    let mut subdirs: Vec<PathBuf> = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect();
+    sort_alphabetically(&mut subdirs);
+    for subdir in subdirs {
+        if let Err(e) = scan_tree(&subdir, visited, images) {
+            log::warn!("Skipping {}: {}", subdir.display(), e);
+        }
+    }
+    Ok(())
+}
+
+/// Sort image paths alphabetically, in natural order (see `natural_cmp`)
 pub fn sort_alphabetically(paths: &mut [PathBuf]) {
-    paths.sort_by_cached_key(|p| p.to_string_lossy().to_lowercase());
+    paths.sort_by(|a, b| natural_path_cmp(a, b));
+}
+
+/// `natural_cmp` of two whole paths, falling back to a plain comparison
+/// so names that differ only in case or leading zeros still sort the same
+/// way every time
+pub fn natural_path_cmp(a: &Path, b: &Path) -> Ordering {
+    let (a, b) = (a.to_string_lossy(), b.to_string_lossy());
+    natural_cmp(&a, &b).then_with(|| a.cmp(&b))
+}
+
+/// Compare two names the way people read them: case-insensitively, with
+/// each run of digits compared by its value, so "page2" sorts before
+/// "page10".
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a = a.chars().peekable();
+    let mut b = b.chars().peekable();
+    loop {
+        let ordering = match (a.peek().copied(), b.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let x = take_digits(&mut a);
+                let y = take_digits(&mut b);
+                // No leading zeros, so the longer run is the bigger number
+                let (x, y) = (x.trim_start_matches('0'), y.trim_start_matches('0'));
+                x.len().cmp(&y.len()).then_with(|| x.cmp(y))
+            }
+            (Some(x), Some(y)) => {
+                a.next();
+                b.next();
+                x.to_lowercase().cmp(y.to_lowercase())
+            }
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+/// Consume and return the run of ASCII digits at the front of `chars`
+fn take_digits(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+    let mut digits = String::new();
+    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+        digits.push(c);
+    }
+    digits
 }
 
 /// Process a dropped file or directory and return a list of images with the index to display
@@ -64,7 +197,9 @@ pub fn sort_alphabetically(paths: &mut [PathBuf]) {
 /// If a directory is dropped:
 /// - Scan the directory for all images
 /// - Return (all_images, 0)
-pub fn process_dropped_path(path: &Path) -> AppResult<(Vec<PathBuf>, usize)> {
+///
+/// With `recursive`, the directory scanned includes its subfolders.
+pub fn process_dropped_path(path: &Path, recursive: bool) -> AppResult<(Vec<PathBuf>, usize)> {
     if !path.exists() {
         return Err(AppError::FileNotFound(path.to_path_buf()));
     }
@@ -87,7 +222,7 @@ pub fn process_dropped_path(path: &Path) -> AppResult<(Vec<PathBuf>, usize)> {
         })?;
 
         // Scan the parent directory for all images
-        let mut all_images = scan_directory(parent_dir)?;
+        let mut all_images = scan_directory_with(parent_dir, recursive)?;
 
         if all_images.is_empty() {
             return Err(AppError::NoImagesFound(parent_dir.to_path_buf()));
@@ -102,7 +237,7 @@ pub fn process_dropped_path(path: &Path) -> AppResult<(Vec<PathBuf>, usize)> {
         Ok((all_images, start_index))
     } else if path.is_dir() {
         // Scan the directory for all images
-        let mut all_images = scan_directory(path)?;
+        let mut all_images = scan_directory_with(path, recursive)?;
 
         if all_images.is_empty() {
             return Err(AppError::NoImagesFound(path.to_path_buf()));
@@ -211,6 +346,122 @@ mod tests {
         assert!(result.is_err());
     }
 
+    // -- scan_directory_recursive ---------------------------------------------
+
+    #[test]
+    fn scan_directory_recursive_walks_nested_folders_in_order() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("b/deep")).unwrap();
+        std::fs::create_dir(root.join("A")).unwrap();
+        for file in ["z.png", "b/deep/d.gif", "b/c.jpg", "b/notes.txt", "A/y.png"] {
+            std::fs::write(root.join(file), b"fake").unwrap();
+        }
+
+        // Act
+        let result = scan_directory_recursive(root).unwrap();
+
+        // Assert - a folder's own images first, then its subfolders by name
+        let relative: Vec<_> = result
+            .iter()
+            .map(|p| p.strip_prefix(root).unwrap().to_path_buf())
+            .collect();
+        assert_eq!(
+            relative,
+            ["z.png", "A/y.png", "b/c.jpg", "b/deep/d.gif"].map(PathBuf::from)
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn scan_directory_recursive_survives_symlink_loops() {
+        // Arrange - sub/up points back at the root
+        let dir = TempDir::new().unwrap();
+        let sub = dir.path().join("sub");
+        std::fs::create_dir(&sub).unwrap();
+        std::fs::write(dir.path().join("a.png"), b"fake").unwrap();
+        std::fs::write(sub.join("b.png"), b"fake").unwrap();
+        std::os::unix::fs::symlink(dir.path(), sub.join("up")).unwrap();
+
+        // Act
+        let result = scan_directory_recursive(dir.path()).unwrap();
+
+        // Assert
+        assert_eq!(result, vec![dir.path().join("a.png"), sub.join("b.png")]);
+    }
+
+    #[test]
+    fn scan_directory_with_only_recurses_when_asked() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("top.png"), b"fake").unwrap();
+        std::fs::create_dir(dir.path().join("sub")).unwrap();
+        std::fs::write(dir.path().join("sub/nested.png"), b"fake").unwrap();
+
+        assert_eq!(scan_directory_with(dir.path(), false).unwrap().len(), 1);
+        assert_eq!(scan_directory_with(dir.path(), true).unwrap().len(), 2);
+    }
+
+    // -- adjacent_image_folder ------------------------------------------------
+
+    #[test]
+    fn adjacent_image_folder_skips_empty_and_hidden_folders() {
+        // Arrange - siblings in natural order: day1, day2 (empty), .cache,
+        // day10
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        for folder in ["day1", "day2", ".cache", "day10"] {
+            std::fs::create_dir(root.join(folder)).unwrap();
+        }
+        for file in [
+            "day1/a.png",
+            ".cache/thumb.png",
+            "day10/b.png",
+            "day10/c.jpg",
+        ] {
+            std::fs::write(root.join(file), b"fake").unwrap();
+        }
+
+        // Act
+        let next = adjacent_image_folder(&root.join("day1"), true, false);
+        let previous = adjacent_image_folder(&root.join("day10"), false, false);
+
+        // Assert
+        let (folder, images) = next.unwrap();
+        assert_eq!(folder, root.join("day10"));
+        assert_eq!(images.len(), 2);
+        assert_eq!(previous.unwrap().0, root.join("day1"));
+    }
+
+    #[test]
+    fn adjacent_image_folder_none_past_the_last() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        for folder in ["a", "b"] {
+            std::fs::create_dir(dir.path().join(folder)).unwrap();
+            std::fs::write(dir.path().join(folder).join("x.png"), b"fake").unwrap();
+        }
+
+        // Act & Assert
+        assert!(adjacent_image_folder(&dir.path().join("b"), true, false).is_none());
+        assert!(adjacent_image_folder(&dir.path().join("a"), false, false).is_none());
+    }
+
+    #[test]
+    fn adjacent_image_folder_recursive_counts_nested_images() {
+        let dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join("b/deep")).unwrap();
+        std::fs::create_dir(dir.path().join("a")).unwrap();
+        std::fs::write(dir.path().join("b/deep/x.png"), b"fake").unwrap();
+
+        let from = dir.path().join("a");
+        assert!(adjacent_image_folder(&from, true, false).is_none());
+        assert_eq!(
+            adjacent_image_folder(&from, true, true).unwrap().1,
+            vec![dir.path().join("b/deep/x.png")]
+        );
+    }
+
     // -- sort_alphabetically --------------------------------------------------
 
     #[test]
@@ -225,6 +476,43 @@ mod tests {
         assert_eq!(names, vec!["apple.jpg", "BANANA.gif", "Zebra.png"]);
     }
 
+    #[test]
+    fn natural_cmp_orders_numbers_by_value() {
+        let mut names = vec!["page10", "Page2", "page1", "page02b", "cover"];
+        names.sort_by(|a, b| natural_cmp(a, b));
+        assert_eq!(names, ["cover", "page1", "Page2", "page02b", "page10"]);
+    }
+
+    #[test]
+    fn sort_alphabetically_orders_numbers_by_value() {
+        // Arrange
+        let mut paths = [
+            "img100.png",
+            "IMG2.png",
+            "img10.png",
+            "Img1.png",
+            "img2.png",
+        ]
+        .map(PathBuf::from)
+        .to_vec();
+
+        // Act
+        sort_alphabetically(&mut paths);
+
+        // Assert - case-only twins fall back to a fixed order
+        assert_eq!(
+            paths,
+            [
+                "Img1.png",
+                "IMG2.png",
+                "img2.png",
+                "img10.png",
+                "img100.png"
+            ]
+            .map(PathBuf::from)
+        );
+    }
+
     #[test]
     fn sort_alphabetically_empty_is_noop() {
         let mut paths: Vec<PathBuf> = vec![];
@@ -248,7 +536,7 @@ mod tests {
         std::fs::write(dir.path().join("b.jpg"), b"fake").unwrap();
         std::fs::write(dir.path().join("c.txt"), b"fake").unwrap();
 
-        let (images, idx) = process_dropped_path(&dir.path().join("b.jpg")).unwrap();
+        let (images, idx) = process_dropped_path(&dir.path().join("b.jpg"), false).unwrap();
         assert_eq!(images.len(), 2);
         // Result is sorted alphabetically, so a.png is at 0 and b.jpg is at 1
         assert_eq!(idx, 1);
@@ -259,14 +547,14 @@ mod tests {
         let dir = TempDir::new().unwrap();
         std::fs::write(dir.path().join("x.png"), b"fake").unwrap();
 
-        let (images, idx) = process_dropped_path(dir.path()).unwrap();
+        let (images, idx) = process_dropped_path(dir.path(), false).unwrap();
         assert_eq!(images.len(), 1);
         assert_eq!(idx, 0);
     }
 
     #[test]
     fn process_dropped_nonexistent_returns_error() {
-        let result = process_dropped_path(Path::new("/no_such_file_12345.png"));
+        let result = process_dropped_path(Path::new("/no_such_file_12345.png"), false);
         assert!(result.is_err());
     }
 
@@ -276,14 +564,14 @@ mod tests {
         let txt = dir.path().join("notes.txt");
         std::fs::write(&txt, b"hello").unwrap();
 
-        let result = process_dropped_path(&txt);
+        let result = process_dropped_path(&txt, false);
         assert!(result.is_err());
     }
 
     #[test]
     fn process_dropped_empty_dir_returns_error() {
         let dir = TempDir::new().unwrap();
-        let result = process_dropped_path(dir.path());
+        let result = process_dropped_path(dir.path(), false);
         assert!(result.is_err());
     }
 }
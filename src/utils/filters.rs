@@ -1,27 +1,26 @@
+use crate::state::image_state::FilterSettings;
 use image::{DynamicImage, ImageBuffer};
 
 /// Apply all filters to an image in a single pass using a combined LUT.
 /// This is more efficient than applying filters sequentially, as it:
-/// 1. Only iterates through pixels once instead of up to 3 times
-/// 2. Only allocates one output buffer instead of up to 3
-/// 3. Pre-computes all transformations into a single 256-entry lookup table
+/// 1. Only iterates through pixels once instead of up to 4 times
+/// 2. Only allocates one output buffer instead of up to 4
+/// 3. Pre-computes the per-channel transformations into 256-entry lookup tables
 ///
-/// Currently exercised only by the test suite — the production save path
-/// extracts BGRA bytes from the cached `filtered_render` instead of
-/// re-applying filters on disk.  Kept around for symmetry with
-/// `apply_filters_to_bgra` and as a `DynamicImage`-friendly entry point.
-#[allow(dead_code)]
-pub fn apply_filters(
-    img: &DynamicImage,
-    brightness: f32,
-    contrast: f32,
-    gamma: f32,
-) -> DynamicImage {
-    let Some(lut) = build_filter_lut(brightness, contrast, gamma) else {
+/// Order is grayscale, channel offsets, invert, brightness, contrast,
+/// saturation, gamma — converting and inverting first means the tone
+/// adjustments work on the picture as it will be shown (a gray print, or
+/// the positive of a scanned negative).
+///
+/// The interactive save path extracts BGRA bytes from the cached
+/// `filtered_render` instead; this is the entry point for batch export,
+/// which works on freshly decoded images that were never displayed.
+pub fn apply_filters(img: &DynamicImage, filters: &FilterSettings) -> DynamicImage {
+    let Some(pass) = FilterPass::new(filters) else {
         return img.clone();
     };
 
-    // Apply combined LUT in a single pass using direct slice access
+    // Apply the pass in a single sweep using direct slice access
     let owned;
     let rgba_img: &image::RgbaImage = match img.as_rgba8() {
         Some(buf) => buf,
@@ -39,22 +38,136 @@ pub fn apply_filters(
         .chunks_exact(4)
         .zip(output_bytes.chunks_exact_mut(4))
     {
-        dst[0] = lut[src[0] as usize];
-        dst[1] = lut[src[1] as usize];
-        dst[2] = lut[src[2] as usize];
+        let [r, g, b] = pass.apply([src[0], src[1], src[2]]);
+        dst[0] = r;
+        dst[1] = g;
+        dst[2] = b;
         dst[3] = src[3];
     }
 
     DynamicImage::ImageRgba8(output)
 }
 
-/// Build the combined brightness/contrast/gamma LUT (256 entries).
-/// Returns `None` if all three values are no-ops, signalling "pass-through."
-fn build_filter_lut(brightness: f32, contrast: f32, gamma: f32) -> Option<[u8; 256]> {
+/// Invert one color channel (255 - value).  Alpha is never passed through
+/// it, so transparency survives inversion.
+pub fn apply_invert(value: u8) -> u8 {
+    255 - value
+}
+
+/// Convert one pixel to its luminance, using the Rec. 601 weights
+/// (0.299 / 0.587 / 0.114).  The weights sum to one, so a pixel that is
+/// already gray — including every pixel of a single-channel image — comes
+/// back unchanged.  Alpha is never passed through it.
+pub fn apply_grayscale(rgb: [u8; 3]) -> [u8; 3] {
+    let [r, g, b] = rgb.map(|c| c as f32);
+    let luma = (0.299 * r + 0.587 * g + 0.114 * b)
+        .round()
+        .clamp(0.0, 255.0) as u8;
+    [luma; 3]
+}
+
+/// Shift each of a pixel's red, green and blue values by its own offset
+/// (-100..100 levels, rounded), saturating at 0 and 255 — for nudging out
+/// a color cast without touching the other channels.
+pub fn apply_channel_offsets(rgb: [u8; 3], offsets: [f32; 3]) -> [u8; 3] {
+    let mut out = rgb;
+    for (c, offset) in out.iter_mut().zip(offsets) {
+        *c = c.saturating_add_signed(offset.clamp(-100.0, 100.0).round() as i8);
+    }
+    out
+}
+
+/// Adjust one pixel's saturation (-100 = grayscale, 0 = unchanged, +100 =
+/// double the distance from gray).  Each channel's distance from the
+/// pixel's luminance is scaled, with Rec. 709 luma weights, so perceived
+/// brightness stays where it was.
+pub fn apply_saturation(rgb: [u8; 3], saturation: f32) -> [u8; 3] {
+    let factor = 1.0 + saturation.clamp(-100.0, 100.0) / 100.0;
+    let [r, g, b] = rgb.map(|c| c as f32);
+    let luma = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+    [r, g, b].map(|c| (luma + (c - luma) * factor).round().clamp(0.0, 255.0) as u8)
+}
+
+/// Everything `apply_filters` does to one pixel, precomputed.  Without a
+/// saturation change the whole thing collapses into one LUT; with one, the
+/// LUT is split around it (invert/brightness/contrast before, gamma after).
+/// Grayscale mixes channels and the offsets differ per channel, so both run
+/// ahead of the shared LUTs rather than in them.
+struct FilterPass {
+    grayscale: bool,
+    channel_offsets: Option<[f32; 3]>,
+    before: Option<[u8; 256]>,
+    saturation: Option<f32>,
+    after: Option<[u8; 256]>,
+}
+
+impl FilterPass {
+    /// Returns `None` if every value is a no-op, signalling "pass-through."
+    fn new(filters: &FilterSettings) -> Option<Self> {
+        let FilterSettings {
+            brightness,
+            contrast,
+            gamma,
+            saturation,
+            invert,
+            grayscale,
+            ..
+        } = *filters;
+        // Offsets are whole levels; anything that rounds to zero is a no-op
+        let channel_offsets = filters.channel_offsets();
+        let channel_offsets = channel_offsets
+            .iter()
+            .any(|offset| offset.abs() >= 0.5)
+            .then_some(channel_offsets);
+        if saturation.abs() < 0.001 {
+            let before = build_filter_lut(invert, brightness, contrast, gamma);
+            if before.is_none() && !grayscale && channel_offsets.is_none() {
+                return None;
+            }
+            return Some(Self {
+                grayscale,
+                channel_offsets,
+                before,
+                saturation: None,
+                after: None,
+            });
+        }
+        Some(Self {
+            grayscale,
+            channel_offsets,
+            before: build_filter_lut(invert, brightness, contrast, 1.0),
+            saturation: Some(saturation),
+            after: build_filter_lut(false, 0.0, 0.0, gamma),
+        })
+    }
+
+    fn apply(&self, mut rgb: [u8; 3]) -> [u8; 3] {
+        if self.grayscale {
+            rgb = apply_grayscale(rgb);
+        }
+        if let Some(offsets) = self.channel_offsets {
+            rgb = apply_channel_offsets(rgb, offsets);
+        }
+        if let Some(lut) = &self.before {
+            rgb = rgb.map(|c| lut[c as usize]);
+        }
+        if let Some(saturation) = self.saturation {
+            rgb = apply_saturation(rgb, saturation);
+        }
+        if let Some(lut) = &self.after {
+            rgb = rgb.map(|c| lut[c as usize]);
+        }
+        rgb
+    }
+}
+
+/// Build the combined invert/brightness/contrast/gamma LUT (256 entries).
+/// Returns `None` if all four values are no-ops, signalling "pass-through."
+fn build_filter_lut(invert: bool, brightness: f32, contrast: f32, gamma: f32) -> Option<[u8; 256]> {
     let has_brightness = brightness.abs() >= 0.001;
     let has_contrast = contrast.abs() >= 0.001;
     let has_gamma = (gamma - 1.0).abs() >= 0.001;
-    if !has_brightness && !has_contrast && !has_gamma {
+    if !invert && !has_brightness && !has_contrast && !has_gamma {
         return None;
     }
 
@@ -72,7 +185,11 @@ fn build_filter_lut(brightness: f32, contrast: f32, gamma: f32) -> Option<[u8; 2
 
     let mut lut = [0u8; 256];
     for (i, entry) in lut.iter_mut().enumerate() {
-        let mut v = i as f32;
+        let mut v = if invert {
+            apply_invert(i as u8) as f32
+        } else {
+            i as f32
+        };
         if has_brightness {
             v = (v + brightness_adjustment).clamp(0.0, 255.0);
         }
@@ -88,31 +205,27 @@ fn build_filter_lut(brightness: f32, contrast: f32, gamma: f32) -> Option<[u8; 2
     Some(lut)
 }
 
-/// Apply brightness / contrast / gamma to an RGBA source, writing the result into a
-/// freshly-allocated **BGRA** buffer of identical dimensions. This is the layout GPUI
-/// expects for `RenderImage`, so callers can feed the result directly to `Frame::new`
-/// without a separate channel swap pass.
+/// Apply grayscale / channel offsets / invert / brightness / contrast / saturation / gamma to an RGBA source, writing the
+/// result into a freshly-allocated **BGRA** buffer of identical dimensions. This is
+/// the layout GPUI expects for `RenderImage`, so callers can feed the result directly
+/// to `Frame::new` without a separate channel swap pass.
 ///
-/// If all three values are no-ops, returns a plain RGBA→BGRA copy (no LUT applied).
-pub fn apply_filters_to_bgra(
-    src: &image::RgbaImage,
-    brightness: f32,
-    contrast: f32,
-    gamma: f32,
-) -> image::RgbaImage {
+/// If every value is a no-op, returns a plain RGBA→BGRA copy (no LUT applied).
+pub fn apply_filters_to_bgra(src: &image::RgbaImage, filters: &FilterSettings) -> image::RgbaImage {
     let (width, height) = src.dimensions();
     let mut output = image::RgbaImage::new(width, height);
 
     let src_bytes = src.as_raw();
     let dst_bytes: &mut [u8] = &mut output;
 
-    match build_filter_lut(brightness, contrast, gamma) {
-        Some(lut) => {
+    match FilterPass::new(filters) {
+        Some(pass) => {
             for (s, d) in src_bytes.chunks_exact(4).zip(dst_bytes.chunks_exact_mut(4)) {
-                // RGBA source → BGRA dest, with LUT applied to RGB channels.
-                d[0] = lut[s[2] as usize];
-                d[1] = lut[s[1] as usize];
-                d[2] = lut[s[0] as usize];
+                // RGBA source → BGRA dest, with the pass applied to RGB channels.
+                let [r, g, b] = pass.apply([s[0], s[1], s[2]]);
+                d[0] = b;
+                d[1] = g;
+                d[2] = r;
                 d[3] = s[3];
             }
         }
@@ -130,6 +243,32 @@ pub fn apply_filters_to_bgra(
     output
 }
 
+/// Filter panel visibility and the filters on/off switch, as changed
+/// together by the `cmd-f` panel toggle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FilterPanelState {
+    pub panel_open: bool,
+    pub filters_enabled: bool,
+}
+
+/// State after pressing the panel toggle.  Opening the panel turns
+/// filters on when `auto_enable` is set (so the sliders visibly do
+/// something); closing only hides the panel and leaves filters as they
+/// are.
+pub fn toggle_filter_panel(current: FilterPanelState, auto_enable: bool) -> FilterPanelState {
+    if current.panel_open {
+        FilterPanelState {
+            panel_open: false,
+            ..current
+        }
+    } else {
+        FilterPanelState {
+            panel_open: true,
+            filters_enabled: current.filters_enabled || auto_enable,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -138,7 +277,6 @@ mod tests {
     // Test constants
     const MID_GRAY: u8 = 128;
     const WHITE: u8 = 255;
-    const DEFAULT_GAMMA: f32 = 1.0;
 
     /// Helper function to create a 1x1 test image with a specific color
     fn create_test_image(r: u8, g: u8, b: u8, a: u8) -> DynamicImage {
@@ -151,7 +289,7 @@ mod tests {
         let img = create_test_image(100, 150, 200, WHITE);
 
         // Act - all default values should not modify image
-        let result = apply_filters(&img, 0.0, 0.0, DEFAULT_GAMMA);
+        let result = apply_filters(&img, &FilterSettings::default());
         let result_rgba = result.to_rgba8();
         let pixel = result_rgba.get_pixel(0, 0);
 
@@ -167,7 +305,15 @@ mod tests {
         let img = create_test_image(MID_GRAY, MID_GRAY, MID_GRAY, WHITE);
 
         // Act - apply all filters
-        let result = apply_filters(&img, 20.0, 30.0, 1.5);
+        let result = apply_filters(
+            &img,
+            &FilterSettings {
+                brightness: 20.0,
+                contrast: 30.0,
+                gamma: 1.5,
+                ..FilterSettings::default()
+            },
+        );
         let result_rgba = result.to_rgba8();
         let pixel = result_rgba.get_pixel(0, 0);
 
@@ -182,7 +328,15 @@ mod tests {
         let img = create_test_image(MID_GRAY, MID_GRAY, MID_GRAY, alpha_value);
 
         // Act
-        let result = apply_filters(&img, 25.0, 25.0, 1.5);
+        let result = apply_filters(
+            &img,
+            &FilterSettings {
+                brightness: 25.0,
+                contrast: 25.0,
+                gamma: 1.5,
+                ..FilterSettings::default()
+            },
+        );
         let result_rgba = result.to_rgba8();
         let pixel = result_rgba.get_pixel(0, 0);
 
@@ -196,7 +350,15 @@ mod tests {
         let img = create_test_image(100, 150, 200, WHITE);
 
         // Act - values very close to default should be treated as no-op
-        let result = apply_filters(&img, 0.0005, 0.0005, 1.0005);
+        let result = apply_filters(
+            &img,
+            &FilterSettings {
+                brightness: 0.0005,
+                contrast: 0.0005,
+                gamma: 1.0005,
+                ..FilterSettings::default()
+            },
+        );
         let result_rgba = result.to_rgba8();
         let pixel = result_rgba.get_pixel(0, 0);
 
@@ -210,7 +372,7 @@ mod tests {
     fn test_apply_filters_to_bgra_noop_swaps_channels() {
         // RGBA (10, 20, 30, 200) → BGRA (30, 20, 10, 200)
         let img = ImageBuffer::from_pixel(2, 1, Rgba([10u8, 20, 30, 200]));
-        let out = apply_filters_to_bgra(&img, 0.0, 0.0, 1.0);
+        let out = apply_filters_to_bgra(&img, &FilterSettings::default());
         let p = out.get_pixel(0, 0);
         assert_eq!(p.0, [30, 20, 10, 200]);
     }
@@ -219,8 +381,295 @@ mod tests {
     fn test_apply_filters_to_bgra_applies_lut_and_swaps() {
         // With brightness +100 (full range +255 mapped), all RGB clamp to 255 regardless of input.
         let img = ImageBuffer::from_pixel(1, 1, Rgba([10u8, 20, 30, 77]));
-        let out = apply_filters_to_bgra(&img, 100.0, 0.0, 1.0);
+        let out = apply_filters_to_bgra(
+            &img,
+            &FilterSettings {
+                brightness: 100.0,
+                ..FilterSettings::default()
+            },
+        );
         let p = out.get_pixel(0, 0);
         assert_eq!(p.0, [255, 255, 255, 77]);
     }
+
+    #[test]
+    fn test_apply_saturation_zero_is_noop() {
+        // Arrange
+        let img = create_test_image(100, 150, 200, 123);
+
+        // Act
+        let result = apply_filters(&img, &FilterSettings::default()).to_rgba8();
+
+        // Assert - both through the pipeline and the pixel function itself
+        assert_eq!(result.get_pixel(0, 0).0, [100, 150, 200, 123]);
+        assert_eq!(apply_saturation([100, 150, 200], 0.0), [100, 150, 200]);
+    }
+
+    #[test]
+    fn test_apply_saturation_minus_100_is_grayscale() {
+        // Arrange
+        let img = create_test_image(200, 50, 100, 90);
+
+        // Act
+        let result = apply_filters(
+            &img,
+            &FilterSettings {
+                saturation: -100.0,
+                ..FilterSettings::default()
+            },
+        )
+        .to_rgba8();
+        let pixel = result.get_pixel(0, 0);
+
+        // Assert - every channel lands on the luminance; alpha untouched
+        assert_eq!(pixel[0], pixel[1]);
+        assert_eq!(pixel[1], pixel[2]);
+        assert_eq!(pixel[3], 90);
+    }
+
+    #[test]
+    fn test_apply_saturation_boost_spreads_channels() {
+        // Arrange
+        let img = create_test_image(140, 120, 100, WHITE);
+
+        // Act
+        let result = apply_filters(
+            &img,
+            &FilterSettings {
+                saturation: 50.0,
+                ..FilterSettings::default()
+            },
+        )
+        .to_rgba8();
+        let pixel = result.get_pixel(0, 0);
+
+        // Assert - further from gray than before, same channel order
+        assert!(pixel[0] > 140);
+        assert!(pixel[2] < 100);
+    }
+
+    #[test]
+    fn test_apply_saturation_gray_unchanged() {
+        // Gray has no color to boost
+        let img = create_test_image(MID_GRAY, MID_GRAY, MID_GRAY, WHITE);
+        let result = apply_filters(
+            &img,
+            &FilterSettings {
+                saturation: 100.0,
+                ..FilterSettings::default()
+            },
+        )
+        .to_rgba8();
+        assert_eq!(
+            result.get_pixel(0, 0).0,
+            [MID_GRAY, MID_GRAY, MID_GRAY, WHITE]
+        );
+    }
+
+    #[test]
+    fn test_apply_filters_to_bgra_matches_apply_filters_with_saturation() {
+        // Arrange
+        let rgba = ImageBuffer::from_pixel(1, 1, Rgba([180u8, 90, 40, 255]));
+        let img = DynamicImage::ImageRgba8(rgba.clone());
+
+        // Act
+        let rgba_out = apply_filters(
+            &img,
+            &FilterSettings {
+                brightness: 10.0,
+                contrast: 20.0,
+                gamma: 1.4,
+                saturation: 60.0,
+                ..FilterSettings::default()
+            },
+        )
+        .to_rgba8();
+        let bgra_out = apply_filters_to_bgra(
+            &rgba,
+            &FilterSettings {
+                brightness: 10.0,
+                contrast: 20.0,
+                gamma: 1.4,
+                saturation: 60.0,
+                ..FilterSettings::default()
+            },
+        );
+
+        // Assert
+        let [r, g, b, a] = rgba_out.get_pixel(0, 0).0;
+        assert_eq!(bgra_out.get_pixel(0, 0).0, [b, g, r, a]);
+    }
+
+    #[test]
+    fn test_apply_filters_invert_flips_rgb_keeps_alpha() {
+        // Arrange
+        let img = create_test_image(0, 100, 255, 42);
+
+        // Act
+        let result = apply_filters(
+            &img,
+            &FilterSettings {
+                invert: true,
+                ..FilterSettings::default()
+            },
+        )
+        .to_rgba8();
+
+        // Assert
+        assert_eq!(result.get_pixel(0, 0).0, [255, 155, 0, 42]);
+    }
+
+    #[test]
+    fn test_apply_invert_twice_is_identity() {
+        assert!((0..=255u8).all(|v| apply_invert(apply_invert(v)) == v));
+    }
+
+    #[test]
+    fn test_apply_filters_invert_runs_before_brightness() {
+        // Black inverts to white, so a brightness drop then darkens white
+        let img = create_test_image(0, 0, 0, WHITE);
+        let result = apply_filters(
+            &img,
+            &FilterSettings {
+                brightness: -20.0,
+                invert: true,
+                ..FilterSettings::default()
+            },
+        )
+        .to_rgba8();
+        assert_eq!(result.get_pixel(0, 0)[0], 204);
+    }
+
+    #[test]
+    fn test_apply_filters_grayscale_uses_luma_weights_keeps_alpha() {
+        // Arrange
+        let img = create_test_image(200, 100, 50, 77);
+
+        // Act
+        let result = apply_filters(
+            &img,
+            &FilterSettings {
+                grayscale: true,
+                ..FilterSettings::default()
+            },
+        )
+        .to_rgba8();
+
+        // Assert - 0.299*200 + 0.587*100 + 0.114*50 = 124.2
+        assert_eq!(result.get_pixel(0, 0).0, [124, 124, 124, 77]);
+    }
+
+    #[test]
+    fn test_apply_grayscale_gray_is_unchanged() {
+        assert!((0..=255u8).all(|v| apply_grayscale([v, v, v]) == [v, v, v]));
+    }
+
+    #[test]
+    fn test_apply_filters_grayscale_on_luma_image_is_noop() {
+        // Arrange - a single-channel image with every gray level
+        let luma = image::GrayImage::from_fn(256, 1, |x, _| image::Luma([x as u8]));
+        let img = DynamicImage::ImageLuma8(luma);
+
+        // Act
+        let result = apply_filters(
+            &img,
+            &FilterSettings {
+                grayscale: true,
+                ..FilterSettings::default()
+            },
+        );
+
+        // Assert
+        assert_eq!(result.to_rgba8(), img.to_rgba8());
+    }
+
+    #[test]
+    fn test_apply_filters_grayscale_runs_before_brightness() {
+        // Converted to 124 first, then brightened by 20 * 2.55 = 51
+        let img = create_test_image(200, 100, 50, WHITE);
+        let result = apply_filters(
+            &img,
+            &FilterSettings {
+                brightness: 20.0,
+                grayscale: true,
+                ..FilterSettings::default()
+            },
+        )
+        .to_rgba8();
+        assert_eq!(result.get_pixel(0, 0).0, [175, 175, 175, WHITE]);
+    }
+
+    #[test]
+    fn test_apply_filters_red_offset_leaves_green_and_blue() {
+        // Arrange
+        let img = create_test_image(MID_GRAY, MID_GRAY, MID_GRAY, WHITE);
+
+        // Act
+        let result = apply_filters(
+            &img,
+            &FilterSettings {
+                red_offset: 30.0,
+                ..FilterSettings::default()
+            },
+        )
+        .to_rgba8();
+
+        // Assert
+        assert_eq!(
+            result.get_pixel(0, 0).0,
+            [MID_GRAY + 30, MID_GRAY, MID_GRAY, WHITE]
+        );
+    }
+
+    #[test]
+    fn test_apply_channel_offsets_saturate() {
+        assert_eq!(
+            apply_channel_offsets([250, 5, 100], [100.0, -100.0, -20.0]),
+            [255, 0, 80]
+        );
+    }
+
+    fn panel(panel_open: bool, filters_enabled: bool) -> FilterPanelState {
+        FilterPanelState {
+            panel_open,
+            filters_enabled,
+        }
+    }
+
+    #[test]
+    fn toggle_filter_panel_open_enables_disabled_filters() {
+        assert_eq!(
+            toggle_filter_panel(panel(false, false), true),
+            panel(true, true)
+        );
+    }
+
+    #[test]
+    fn toggle_filter_panel_open_without_auto_enable_keeps_filters_off() {
+        assert_eq!(
+            toggle_filter_panel(panel(false, false), false),
+            panel(true, false)
+        );
+    }
+
+    #[test]
+    fn toggle_filter_panel_close_keeps_filters_active() {
+        // Arrange — panel opened with filters auto-enabled
+        let opened = toggle_filter_panel(panel(false, false), true);
+
+        // Act
+        let closed = toggle_filter_panel(opened, true);
+
+        // Assert
+        assert_eq!(closed, panel(false, true));
+    }
+
+    #[test]
+    fn toggle_filter_panel_close_keeps_filters_disabled() {
+        // cmd-1 while the panel is open, then close it
+        assert_eq!(
+            toggle_filter_panel(panel(true, false), true),
+            panel(false, false)
+        );
+    }
 }
@@ -0,0 +1,222 @@
+//! Generation settings that AI image tools store in PNG text chunks.
+//!
+//! Stable Diffusion web UIs (AUTOMATIC1111, Forge) write a `parameters`
+//! chunk: the prompt, an optional `Negative prompt:` line, then one line of
+//! comma-separated `Key: value` settings.  ComfyUI writes its node graph as
+//! JSON under `prompt`; the sampler node links to the text encoders that
+//! hold the prompts.
+
+use serde_json::Value;
+
+/// The fields shown in the generation info panel; each is None when the
+/// metadata doesn't record it
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GenerationParams {
+    pub prompt: Option<String>,
+    pub negative_prompt: Option<String>,
+    pub seed: Option<u64>,
+    pub sampler: Option<String>,
+    pub model: Option<String>,
+}
+
+/// What an image says about how it was generated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GenerationInfo {
+    Parsed(GenerationParams),
+    /// Generation metadata in a layout that isn't recognized, as stored
+    Raw(String),
+}
+
+/// Generation info from an image's PNG text chunks (see
+/// `image_loader::read_png_text`), or None when it has none
+pub fn generation_info(png_text: &[(String, String)]) -> Option<GenerationInfo> {
+    let find = |keyword: &str| {
+        png_text
+            .iter()
+            .find(|(k, _)| k == keyword)
+            .map(|(_, text)| text)
+    };
+    let (text, parsed) = if let Some(text) = find("parameters") {
+        (text, parse_parameters(text))
+    } else {
+        let text = find("prompt")?;
+        (text, parse_comfyui_prompt(text))
+    };
+    Some(parsed.map_or_else(|| GenerationInfo::Raw(text.clone()), GenerationInfo::Parsed))
+}
+
+/// Parse an AUTOMATIC1111-style `parameters` string.  None unless it has
+/// the settings line (which always starts with `Steps:`).
+pub fn parse_parameters(text: &str) -> Option<GenerationParams> {
+    let text = text.trim();
+    let (body, settings) = match text.rsplit_once('\n') {
+        Some((body, last)) if last.trim_start().starts_with("Steps: ") => (body, last),
+        None if text.starts_with("Steps: ") => ("", text),
+        _ => return None,
+    };
+    let (prompt, negative) = match body.split_once("Negative prompt:") {
+        Some((prompt, negative)) => (prompt, Some(negative)),
+        None => (body, None),
+    };
+    let settings = split_settings(settings);
+    let setting = |key: &str| {
+        settings
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.clone())
+    };
+    Some(GenerationParams {
+        prompt: non_empty(prompt),
+        negative_prompt: negative.and_then(non_empty),
+        seed: setting("Seed").and_then(|seed| seed.parse().ok()),
+        sampler: setting("Sampler"),
+        model: setting("Model").or_else(|| setting("Model hash")),
+    })
+}
+
+/// `Key: value` pairs of a settings line.  Values may be double-quoted to
+/// hold commas of their own.
+fn split_settings(line: &str) -> Vec<(String, String)> {
+    let mut pairs = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    for c in line.chars().chain(std::iter::once(',')) {
+        match c {
+            '"' => quoted = !quoted,
+            ',' if !quoted => {
+                if let Some((key, value)) = field.split_once(':') {
+                    pairs.push((key.trim().to_string(), value.trim().to_string()));
+                }
+                field.clear();
+            }
+            _ => field.push(c),
+        }
+    }
+    pairs
+}
+
+/// Parse ComfyUI's `prompt` graph: the first sampler node's seed and
+/// sampler, the text of the nodes feeding its positive and negative
+/// inputs, and the checkpoint that was loaded.  None unless there's a
+/// sampler.
+pub fn parse_comfyui_prompt(json: &str) -> Option<GenerationParams> {
+    let graph: Value = serde_json::from_str(json).ok()?;
+    let nodes = graph.as_object()?;
+    let sampler = nodes.values().find(|node| {
+        node["class_type"]
+            .as_str()
+            .is_some_and(|class| class.starts_with("KSampler"))
+    })?;
+    let inputs = &sampler["inputs"];
+    // Links are [source node id, output index]
+    let linked_text = |input: &str| {
+        let id = inputs[input].get(0)?.as_str()?;
+        non_empty(nodes.get(id)?["inputs"]["text"].as_str()?)
+    };
+    let model = nodes
+        .values()
+        .find_map(|node| node["inputs"]["ckpt_name"].as_str())
+        .map(str::to_string);
+    Some(GenerationParams {
+        prompt: linked_text("positive"),
+        negative_prompt: linked_text("negative"),
+        seed: inputs["seed"]
+            .as_u64()
+            .or_else(|| inputs["noise_seed"].as_u64()),
+        sampler: inputs["sampler_name"].as_str().map(str::to_string),
+        model,
+    })
+}
+
+fn non_empty(text: &str) -> Option<String> {
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const A1111: &str = "masterpiece, a fox in the snow,\n\
+        soft light\n\
+        Negative prompt: blurry, lowres\n\
+        Steps: 20, Sampler: DPM++ 2M Karras, CFG scale: 7, Seed: 1234567890, \
+        Size: 512x768, Model hash: 6ce0161689, Model: v1-5-pruned-emaonly, \
+        Lora hashes: \"fox: abc123, snow: def456\", Version: v1.6.0";
+
+    #[test]
+    fn test_parse_parameters_fields() {
+        // Act
+        let params = parse_parameters(A1111).unwrap();
+
+        // Assert
+        assert_eq!(
+            params,
+            GenerationParams {
+                prompt: Some("masterpiece, a fox in the snow,\nsoft light".to_string()),
+                negative_prompt: Some("blurry, lowres".to_string()),
+                seed: Some(1234567890),
+                sampler: Some("DPM++ 2M Karras".to_string()),
+                model: Some("v1-5-pruned-emaonly".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_parameters_without_negative_or_model_name() {
+        let params =
+            parse_parameters("a cat\nSteps: 30, Sampler: Euler a, Seed: 7, Model hash: abc")
+                .unwrap();
+        assert_eq!(params.prompt.as_deref(), Some("a cat"));
+        assert_eq!(params.negative_prompt, None);
+        assert_eq!(params.seed, Some(7));
+        assert_eq!(params.model.as_deref(), Some("abc"));
+    }
+
+    #[test]
+    fn test_parse_parameters_rejects_free_text() {
+        assert_eq!(parse_parameters("Made with love\nby me"), None);
+    }
+
+    #[test]
+    fn test_parse_comfyui_prompt_follows_links() {
+        // Arrange
+        let json = r#"{
+            "3": {"class_type": "KSampler", "inputs": {
+                "seed": 42, "sampler_name": "euler", "steps": 20,
+                "model": ["4", 0], "positive": ["6", 0], "negative": ["7", 0]}},
+            "4": {"class_type": "CheckpointLoaderSimple",
+                "inputs": {"ckpt_name": "sdxl_base.safetensors"}},
+            "6": {"class_type": "CLIPTextEncode", "inputs": {"text": "a lighthouse"}},
+            "7": {"class_type": "CLIPTextEncode", "inputs": {"text": "fog"}}
+        }"#;
+
+        // Act
+        let params = parse_comfyui_prompt(json).unwrap();
+
+        // Assert
+        assert_eq!(params.prompt.as_deref(), Some("a lighthouse"));
+        assert_eq!(params.negative_prompt.as_deref(), Some("fog"));
+        assert_eq!(params.seed, Some(42));
+        assert_eq!(params.sampler.as_deref(), Some("euler"));
+        assert_eq!(params.model.as_deref(), Some("sdxl_base.safetensors"));
+    }
+
+    #[test]
+    fn test_generation_info_falls_back_to_raw_text() {
+        let text = |k: &str, v: &str| vec![(k.to_string(), v.to_string())];
+        assert!(matches!(
+            generation_info(&text("parameters", A1111)),
+            Some(GenerationInfo::Parsed(_))
+        ));
+        assert_eq!(
+            generation_info(&text("parameters", "hand-drawn")),
+            Some(GenerationInfo::Raw("hand-drawn".to_string()))
+        );
+        assert_eq!(
+            generation_info(&text("prompt", "{not json")),
+            Some(GenerationInfo::Raw("{not json".to_string()))
+        );
+        assert_eq!(generation_info(&text("Software", "GIMP")), None);
+    }
+}
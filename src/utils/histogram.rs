@@ -0,0 +1,197 @@
+//! Luminance and per-channel histograms for the histogram overlay.
+//!
+//! Large images are sampled rather than read in full: at most
+//! `MAX_SAMPLES` evenly spaced pixels are counted, which keeps the shape
+//! of the histogram while bounding the cost of a recompute on every
+//! filter change.
+
+/// Upper bound on pixels counted per histogram
+pub const MAX_SAMPLES: usize = 1 << 20;
+
+/// Luminance levels either side of mid-gray that auto exposure leaves alone
+const AUTO_EXPOSURE_DEAD_ZONE: f32 = 32.0;
+
+/// Largest brightness (in filter units, -100..100) auto exposure applies
+pub const AUTO_EXPOSURE_MAX: f32 = 25.0;
+
+/// Byte order of the pixels handed to `compute_histogram`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PixelLayout {
+    /// Decoded images
+    Rgba,
+    /// GPUI render buffers (the filtered image)
+    Bgra,
+}
+
+/// 256-bucket counts for luminance and each color channel
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    pub luma: [u32; 256],
+    pub red: [u32; 256],
+    pub green: [u32; 256],
+    pub blue: [u32; 256],
+    /// Pixels actually counted (after sampling)
+    pub samples: u32,
+}
+
+impl Histogram {
+    /// The four histograms, labelled, in display order
+    pub fn channels(&self) -> [(&'static str, &[u32; 256]); 4] {
+        [
+            ("Luminance", &self.luma),
+            ("Red", &self.red),
+            ("Green", &self.green),
+            ("Blue", &self.blue),
+        ]
+    }
+
+    /// Average luminance level, 0-255 (mid-gray for an empty histogram)
+    pub fn mean_luma(&self) -> f32 {
+        if self.samples == 0 {
+            return 128.0;
+        }
+        let total: u64 = self
+            .luma
+            .iter()
+            .enumerate()
+            .map(|(level, &count)| level as u64 * count as u64)
+            .sum();
+        total as f32 / self.samples as f32
+    }
+}
+
+/// The brightness filter value that nudges an image toward mid-tones:
+/// positive for dark images, negative for bright ones, 0 when the mean
+/// luminance is already within the dead zone around mid-gray.  The
+/// correction grows with the distance past the dead zone and is capped at
+/// `AUTO_EXPOSURE_MAX` so it stays gentle.
+pub fn auto_exposure_brightness(histogram: &Histogram) -> f32 {
+    let offset = 128.0 - histogram.mean_luma();
+    let excess = (offset.abs() - AUTO_EXPOSURE_DEAD_ZONE).max(0.0);
+    // The brightness filter shifts levels by 2.55 per unit
+    (excess / 2.55).min(AUTO_EXPOSURE_MAX).copysign(offset)
+}
+
+/// Count the 4-byte pixels in `pixels`.  Luminance uses the Rec. 601
+/// weights, matching the grayscale filter, so a grayscale image's
+/// luminance histogram equals its channel histograms.  Alpha is ignored.
+pub fn compute_histogram(pixels: &[u8], layout: PixelLayout) -> Histogram {
+    let mut histogram = Histogram {
+        luma: [0; 256],
+        red: [0; 256],
+        green: [0; 256],
+        blue: [0; 256],
+        samples: 0,
+    };
+    let pixel_count = pixels.len() / 4;
+    let step = pixel_count.div_ceil(MAX_SAMPLES).max(1);
+    for px in pixels.chunks_exact(4).step_by(step) {
+        let (r, g, b) = match layout {
+            PixelLayout::Rgba => (px[0], px[1], px[2]),
+            PixelLayout::Bgra => (px[2], px[1], px[0]),
+        };
+        let luma = (299 * r as u32 + 587 * g as u32 + 114 * b as u32 + 500) / 1000;
+        histogram.luma[luma as usize] += 1;
+        histogram.red[r as usize] += 1;
+        histogram.green[g as usize] += 1;
+        histogram.blue[b as usize] += 1;
+        histogram.samples += 1;
+    }
+    histogram
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solid_color_fills_one_bucket_per_channel() {
+        // Arrange - 10 pixels of (200, 100, 50)
+        let pixels = [200u8, 100, 50, 255].repeat(10);
+
+        // Act
+        let histogram = compute_histogram(&pixels, PixelLayout::Rgba);
+
+        // Assert - 0.299*200 + 0.587*100 + 0.114*50 = 124.2
+        assert_eq!(histogram.samples, 10);
+        assert_eq!(histogram.red[200], 10);
+        assert_eq!(histogram.green[100], 10);
+        assert_eq!(histogram.blue[50], 10);
+        assert_eq!(histogram.luma[124], 10);
+        assert_eq!(histogram.luma.iter().sum::<u32>(), 10);
+    }
+
+    #[test]
+    fn bgra_layout_swaps_red_and_blue() {
+        let pixels = [10u8, 20, 30, 255];
+        let histogram = compute_histogram(&pixels, PixelLayout::Bgra);
+        assert_eq!(histogram.red[30], 1);
+        assert_eq!(histogram.blue[10], 1);
+    }
+
+    #[test]
+    fn gray_pixels_put_luma_on_their_level() {
+        let pixels: Vec<u8> = (0..=255u8).flat_map(|v| [v, v, v, 255]).collect();
+        let histogram = compute_histogram(&pixels, PixelLayout::Rgba);
+        assert!(histogram.luma.iter().all(|&count| count == 1));
+    }
+
+    #[test]
+    fn large_images_are_sampled() {
+        // Arrange - just over twice the sample budget
+        let pixels = vec![0u8; (MAX_SAMPLES * 2 + 10) * 4];
+
+        // Act
+        let histogram = compute_histogram(&pixels, PixelLayout::Rgba);
+
+        // Assert
+        assert!(histogram.samples as usize <= MAX_SAMPLES);
+        assert!(histogram.samples as usize > MAX_SAMPLES / 2);
+    }
+
+    #[test]
+    fn mean_luma_averages_levels() {
+        let pixels: Vec<u8> = [10u8, 30].iter().flat_map(|&v| [v, v, v, 255]).collect();
+        let histogram = compute_histogram(&pixels, PixelLayout::Rgba);
+        assert_eq!(histogram.mean_luma(), 20.0);
+    }
+
+    #[test]
+    fn auto_exposure_brightens_dark_and_darkens_bright() {
+        // Arrange
+        let dark = compute_histogram(&[15u8, 15, 15, 255].repeat(100), PixelLayout::Rgba);
+        let bright = compute_histogram(&[245u8, 245, 245, 255].repeat(100), PixelLayout::Rgba);
+        let dim = compute_histogram(&[80u8, 80, 80, 255].repeat(100), PixelLayout::Rgba);
+
+        // Act
+        let dark = auto_exposure_brightness(&dark);
+        let bright = auto_exposure_brightness(&bright);
+        let dim = auto_exposure_brightness(&dim);
+
+        // Assert - far from mid-gray hits the cap; a little past the dead
+        // zone gets a small nudge
+        assert_eq!(dark, AUTO_EXPOSURE_MAX);
+        assert_eq!(bright, -AUTO_EXPOSURE_MAX);
+        assert!(dim > 0.0 && dim < 10.0, "dim = {}", dim);
+    }
+
+    #[test]
+    fn auto_exposure_leaves_normal_images_alone() {
+        // A full gray ramp averages mid-gray
+        let ramp: Vec<u8> = (0..=255u8).flat_map(|v| [v, v, v, 255]).collect();
+        let ramp = compute_histogram(&ramp, PixelLayout::Rgba);
+        let mid = compute_histogram(&[150u8, 110, 140, 255].repeat(10), PixelLayout::Rgba);
+        assert!(auto_exposure_brightness(&ramp).abs() < 0.001);
+        assert!(auto_exposure_brightness(&mid).abs() < 0.001);
+        assert_eq!(
+            auto_exposure_brightness(&compute_histogram(&[], PixelLayout::Rgba)),
+            0.0
+        );
+    }
+
+    #[test]
+    fn empty_buffer_counts_nothing() {
+        let histogram = compute_histogram(&[], PixelLayout::Rgba);
+        assert_eq!(histogram.samples, 0);
+    }
+}
@@ -1,9 +1,9 @@
 #![allow(clippy::collapsible_if)]
 
-use super::debug_eprintln;
 use crate::error::{AppError, AppResult};
-use crate::utils::animation::AnimationData;
+use crate::utils::animation::FrameSequence;
 use image::DynamicImage;
+use std::io::{BufReader, Read};
 use std::path::{Path, PathBuf};
 use std::sync::mpsc::{self, Receiver};
 use std::sync::{Arc, Mutex};
@@ -17,7 +17,9 @@ pub struct LoadedImageData {
     pub path: PathBuf,
     pub width: u32,
     pub height: u32,
-    pub animation_data: Option<AnimationData>,
+    pub frame_sequence: Option<FrameSequence>,
+    /// Pixel aspect ratio from the file's metadata (None = square pixels)
+    pub pixel_aspect: Option<f32>,
     /// Stable cache key for this image (`{path_fnv}_{mtime}`), or `None` if
     /// the image is uncacheable (path canonicalization or mtime read failed).
     pub image_key: Option<String>,
@@ -27,6 +29,22 @@ pub struct LoadedImageData {
     pub rasterized_path: Option<PathBuf>,
     /// Parsed SVG tree for dynamic re-rendering at different zoom levels
     pub svg_tree: Option<Arc<usvg::Tree>>,
+    /// What an SVG was rasterized onto (None = transparency)
+    pub svg_background: Option<[u8; 3]>,
+}
+
+impl LoadedImageData {
+    /// Memory the decoded data holds, counting animation frames (still
+    /// images are decoded later, when drawn)
+    pub fn decoded_bytes(&self) -> u64 {
+        self.frame_sequence.as_ref().map_or(0, |sequence| {
+            sequence
+                .frames
+                .iter()
+                .map(|frame| frame.image.as_bytes().len() as u64)
+                .sum()
+        })
+    }
 }
 
 /// Message sent from the background loader thread
@@ -35,6 +53,9 @@ pub enum LoaderMessage {
     Success(LoadedImageData),
     /// Image loading failed
     Error(PathBuf, String),
+    /// The file stops short of what its headers promise, e.g. a download
+    /// still in progress (path, message)
+    Incomplete(PathBuf, String),
     /// Image exceeds size limit (path, width, height, max_dimension)
     OversizedImage(PathBuf, u32, u32, u32),
 }
@@ -46,6 +67,17 @@ pub struct LoaderHandle {
 }
 
 impl LoaderHandle {
+    /// A handle whose load has already finished with `message`, for data
+    /// that was ready before it was asked for
+    pub fn ready(message: LoaderMessage) -> Self {
+        let (tx, rx) = mpsc::channel();
+        let _ = tx.send(message);
+        Self {
+            receiver: rx,
+            cancel_flag: Arc::new(Mutex::new(false)),
+        }
+    }
+
     /// Try to receive a result from the loader (non-blocking)
     pub fn try_recv(&self) -> Option<LoaderMessage> {
         self.receiver.try_recv().ok()
@@ -63,152 +95,192 @@ impl LoaderHandle {
 /// Returns a handle that can be used to check for completion or cancel
 ///
 /// If max_dimension is Some(n) and either width or height exceeds n,
-/// returns OversizedImage instead of Success
+/// returns OversizedImage instead of Success.  SVGs are rasterized onto
+/// `svg_background` if given.
 pub fn load_image_async(
     path: PathBuf,
     max_dimension: Option<u32>,
     force_load: bool,
+    svg_background: Option<[u8; 3]>,
 ) -> LoaderHandle {
     let (tx, rx) = mpsc::channel();
     let cancel_flag = Arc::new(Mutex::new(false));
     let cancel_flag_clone = cancel_flag.clone();
 
     thread::spawn(move || {
-        // Check cancellation before starting
-        if is_cancelled(&cancel_flag_clone) {
-            return;
+        if let Some(message) = load_image_data(
+            path,
+            max_dimension,
+            force_load,
+            svg_background,
+            &cancel_flag_clone,
+        ) {
+            let _ = tx.send(message);
         }
+    });
 
-        // Load image dimensions first (fast)
-        let (width, height) = match get_image_dimensions(&path) {
-            Ok(dims) => dims,
-            Err(e) => {
-                let _ = tx.send(LoaderMessage::Error(path, e.to_string()));
-                return;
-            }
-        };
+    LoaderHandle {
+        receiver: rx,
+        cancel_flag,
+    }
+}
 
-        // Check cancellation after dimensions
-        if is_cancelled(&cancel_flag_clone) {
-            return;
-        }
+/// Load an image off the UI thread the way `load_image_async` does, for
+/// the preloader: the loaded data, or None when the file can't be shown
+/// as it is (unreadable, incomplete, or over `max_dimension`).
+pub fn preload_image_data(
+    path: PathBuf,
+    max_dimension: Option<u32>,
+    svg_background: Option<[u8; 3]>,
+) -> Option<LoadedImageData> {
+    let never_cancelled = Arc::new(Mutex::new(false));
+    match load_image_data(path, max_dimension, false, svg_background, &never_cancelled)? {
+        LoaderMessage::Success(data) => Some(data),
+        _ => None,
+    }
+}
 
-        // Check if image exceeds size limit (unless force_load is true)
-        if !force_load {
-            if let Some(max_dim) = max_dimension {
-                if width > max_dim || height > max_dim {
-                    let _ = tx.send(LoaderMessage::OversizedImage(path, width, height, max_dim));
-                    return;
-                }
-            }
-        }
+/// The loader's work, done on the calling thread.  None when cancelled
+/// part way.
+fn load_image_data(
+    path: PathBuf,
+    max_dimension: Option<u32>,
+    force_load: bool,
+    svg_background: Option<[u8; 3]>,
+    cancel_flag: &Arc<Mutex<bool>>,
+) -> Option<LoaderMessage> {
+    // Check cancellation before starting
+    if is_cancelled(cancel_flag) {
+        return None;
+    }
 
-        // Rasterize SVGs to temp PNGs (2x for Retina) and keep parsed tree for re-rendering
-        let (rasterized_path, svg_tree) = if crate::utils::file_scanner::is_svg(&path) {
-            match crate::utils::svg::parse_svg(&path) {
-                Ok(tree) => match crate::utils::svg::rerasterize_svg_full(&tree, 2.0) {
-                    Ok(temp_path) => (Some(temp_path), Some(Arc::new(tree))),
-                    Err(e) => {
-                        let _ = tx.send(LoaderMessage::Error(path, e));
-                        return;
-                    }
-                },
-                Err(e) => {
-                    let _ = tx.send(LoaderMessage::Error(path, e.to_string()));
-                    return;
-                }
+    // Load image dimensions first (fast)
+    let (width, height) = match get_image_dimensions(&path) {
+        Ok(dims) => dims,
+        Err(e) => return Some(LoaderMessage::Error(path, e.to_string())),
+    };
+
+    // Headers can be intact while the data after them is missing;
+    // catch that before it decodes to garbage
+    if let Err(e) = check_complete(&path) {
+        let message = e.to_string();
+        return Some(match e {
+            AppError::IncompleteFile(..) => LoaderMessage::Incomplete(path, message),
+            _ => LoaderMessage::Error(path, message),
+        });
+    }
+
+    // Check cancellation after dimensions
+    if is_cancelled(cancel_flag) {
+        return None;
+    }
+
+    // Check if image exceeds size limit (unless force_load is true)
+    if !force_load {
+        if let Some(max_dim) = max_dimension {
+            if width > max_dim || height > max_dim {
+                return Some(LoaderMessage::OversizedImage(path, width, height, max_dim));
             }
-        } else {
-            (None, None)
-        };
+        }
+    }
 
-        // Check cancellation after SVG rasterization
-        if is_cancelled(&cancel_flag_clone) {
-            return;
+    // Rasterize SVGs to temp PNGs (2x for Retina) and keep parsed tree for re-rendering
+    let (rasterized_path, svg_tree) = if crate::utils::file_scanner::is_svg(&path) {
+        match crate::utils::svg::parse_svg(&path) {
+            Ok(tree) => match crate::utils::svg::rerasterize_svg_full(&tree, 2.0, svg_background) {
+                Ok(temp_path) => (Some(temp_path), Some(Arc::new(tree))),
+                Err(e) => return Some(LoaderMessage::Error(path, e)),
+            },
+            Err(e) => return Some(LoaderMessage::Error(path, e.to_string())),
         }
+    } else {
+        (None, None)
+    };
 
-        // Try to load animation data if it's an animated image
-        let animation_data = crate::utils::animation::load_animation(&path)
-            .ok()
-            .flatten();
+    // Check cancellation after SVG rasterization
+    if is_cancelled(cancel_flag) {
+        return None;
+    }
 
-        // Check cancellation after animation detection
-        if is_cancelled(&cancel_flag_clone) {
-            return;
-        }
+    // Try to load animation data if it's an animated image
+    let frame_sequence = crate::utils::animation::load_frame_sequence(&path)
+        .ok()
+        .flatten();
 
-        // Compute the persistent cache key for this image (path + mtime).
-        let image_key = crate::utils::frame_cache::image_key(&path);
-
-        // Cache first 3 frames for animated images using the persistent
-        // disk cache (or skip if no key — the image is transient).
-        let mut initial_frame_paths = Vec::new();
-        if let (Some(anim_data), Some(key)) = (&animation_data, &image_key) {
-            let initial_cache_count = std::cmp::min(3, anim_data.frames.len());
-            debug_eprintln!(
-                "[ASYNC LOAD] Caching first {} frames...",
-                initial_cache_count
-            );
-
-            for i in 0..initial_cache_count {
-                if is_cancelled(&cancel_flag_clone) {
-                    return;
-                }
+    // Check cancellation after animation detection
+    if is_cancelled(cancel_flag) {
+        return None;
+    }
 
-                let dest = match crate::utils::frame_cache::raw_frame_path(key, i) {
-                    Ok(p) => p,
-                    Err(_e) => {
-                        debug_eprintln!(
-                            "[ASYNC LOAD ERROR] Failed to resolve cache path for frame {}: {}",
-                            i,
-                            _e
-                        );
-                        initial_frame_paths.push(PathBuf::new());
-                        continue;
-                    }
-                };
-
-                if dest.exists() {
-                    debug_eprintln!("[ASYNC LOAD] Frame {} already cached on disk", i);
-                    initial_frame_paths.push(dest);
+    let pixel_aspect = crate::utils::pixel_aspect::read_pixel_aspect(&path);
+
+    // Compute the persistent cache key for this image (path + mtime).
+    let image_key = crate::utils::frame_cache::image_key(&path);
+
+    // Cache first 3 frames for animated images using the persistent
+    // disk cache (or skip if no key — the image is transient).
+    let mut initial_frame_paths = Vec::new();
+    if let (Some(sequence), Some(key)) = (&frame_sequence, &image_key) {
+        let initial_cache_count = std::cmp::min(3, sequence.frames.len());
+        log::debug!(
+            "[ASYNC LOAD] Caching first {} frames...",
+            initial_cache_count
+        );
+
+        for i in 0..initial_cache_count {
+            if is_cancelled(cancel_flag) {
+                return None;
+            }
+
+            let dest = match crate::utils::frame_cache::raw_frame_path(key, i) {
+                Ok(p) => p,
+                Err(_e) => {
+                    log::debug!(
+                        "[ASYNC LOAD ERROR] Failed to resolve cache path for frame {}: {}",
+                        i,
+                        _e
+                    );
+                    initial_frame_paths.push(PathBuf::new());
                     continue;
                 }
+            };
 
-                match anim_data.frames[i].image.save(&dest) {
-                    Ok(_) => {
-                        debug_eprintln!("[ASYNC LOAD] Cached frame {}", i);
-                        initial_frame_paths.push(dest);
-                    }
-                    Err(_e) => {
-                        debug_eprintln!("[ASYNC LOAD ERROR] Failed to cache frame {}: {}", i, _e);
-                        initial_frame_paths.push(PathBuf::new());
-                    }
-                }
+            if dest.exists() {
+                log::debug!("[ASYNC LOAD] Frame {} already cached on disk", i);
+                initial_frame_paths.push(dest);
+                continue;
             }
-        }
 
-        // Check cancellation before sending result
-        if is_cancelled(&cancel_flag_clone) {
-            return;
+            match sequence.frames[i].image.save(&dest) {
+                Ok(_) => {
+                    log::debug!("[ASYNC LOAD] Cached frame {}", i);
+                    initial_frame_paths.push(dest);
+                }
+                Err(_e) => {
+                    log::debug!("[ASYNC LOAD ERROR] Failed to cache frame {}: {}", i, _e);
+                    initial_frame_paths.push(PathBuf::new());
+                }
+            }
         }
+    }
 
-        // Send success message
-        let _ = tx.send(LoaderMessage::Success(LoadedImageData {
-            path,
-            width,
-            height,
-            animation_data,
-            image_key,
-            initial_frame_paths,
-            rasterized_path,
-            svg_tree,
-        }));
-    });
-
-    LoaderHandle {
-        receiver: rx,
-        cancel_flag,
+    // Check cancellation before sending result
+    if is_cancelled(cancel_flag) {
+        return None;
     }
+
+    Some(LoaderMessage::Success(LoadedImageData {
+        path,
+        width,
+        height,
+        frame_sequence,
+        pixel_aspect,
+        image_key,
+        initial_frame_paths,
+        rasterized_path,
+        svg_tree,
+        svg_background,
+    }))
 }
 
 fn is_cancelled(flag: &Arc<Mutex<bool>>) -> bool {
@@ -222,12 +294,66 @@ pub fn load_image(path: &Path) -> AppResult<DynamicImage> {
         return Err(AppError::FileNotFound(path.to_path_buf()));
     }
 
-    // Try to load the image
+    // Try to load the image; running out of data means a short file
     image::open(path).map_err(|e| {
-        AppError::ImageLoadError(path.to_path_buf(), format!("Failed to load image: {}", e))
+        if is_truncation(&e) {
+            AppError::IncompleteFile(path.to_path_buf(), e.to_string())
+        } else {
+            AppError::ImageLoadError(path.to_path_buf(), format!("Failed to load image: {}", e))
+        }
     })
 }
 
+/// Load an image for processing the way it's shown: SVGs are rendered at
+/// their intrinsic size onto `svg_background` (if given), which `load_image`
+/// can't read at all
+pub fn load_source_image(path: &Path, svg_background: Option<[u8; 3]>) -> AppResult<DynamicImage> {
+    if crate::utils::file_scanner::is_svg(path) {
+        return crate::utils::svg::render_svg_image(path, svg_background);
+    }
+    load_image(path)
+}
+
+/// Decode frame `index` (0-based) of an animated GIF, WEBP or APNG, or
+/// page `index` of a multi-page TIFF, as a full-canvas still.  Frames are
+/// decoded in order up to the one asked for.
+pub fn load_animation_frame(path: &Path, index: usize) -> AppResult<DynamicImage> {
+    use image::codecs::gif::GifDecoder;
+    use image::codecs::png::PngDecoder;
+    use image::codecs::webp::WebPDecoder;
+    use image::{AnimationDecoder, ImageFormat};
+
+    let fail = |detail: String| AppError::ImageLoadError(path.to_path_buf(), detail);
+    let format = image::ImageReader::open(path)?
+        .with_guessed_format()?
+        .format();
+    let reader = BufReader::new(std::fs::File::open(path)?);
+    let frames = match format {
+        Some(ImageFormat::Gif) => GifDecoder::new(reader).map(|d| d.into_frames()),
+        Some(ImageFormat::WebP) => WebPDecoder::new(reader).map(|d| d.into_frames()),
+        Some(ImageFormat::Png) => PngDecoder::new(reader)
+            .and_then(|d| d.apng())
+            .map(|d| d.into_frames()),
+        Some(ImageFormat::Tiff) => {
+            let pages = crate::utils::animation::load_tiff_pages(path)?;
+            return pages
+                .frames
+                .into_iter()
+                .nth(index)
+                .map(|page| page.image)
+                .ok_or_else(|| fail(format!("No page {}", index + 1)));
+        }
+        _ => return Err(fail("Not an animated image".to_string())),
+    }
+    .map_err(|e| fail(e.to_string()))?;
+    let frame = frames
+        .into_iter()
+        .nth(index)
+        .ok_or_else(|| fail(format!("No frame {}", index + 1)))?
+        .map_err(|e| fail(e.to_string()))?;
+    Ok(DynamicImage::ImageRgba8(frame.into_buffer()))
+}
+
 /// Get image dimensions without fully loading the image
 pub fn get_image_dimensions(path: &Path) -> AppResult<(u32, u32)> {
     // SVG files need special handling — image::ImageReader can't read them
@@ -256,6 +382,174 @@ pub fn get_image_dimensions(path: &Path) -> AppResult<(u32, u32)> {
     Ok(dimensions)
 }
 
+/// Check that the file holds all the data its structure promises.  PNG
+/// is walked chunk by chunk to `IEND`, JPEG through its header segments to
+/// the start of the image data (a missing end marker alone is fine, as
+/// decoders accept it), and BMP is held to the size in its header; other
+/// formats pass.  Only chunk and segment headers are read, skipping over
+/// the data between them.  A short file is `AppError::IncompleteFile`.
+pub fn check_complete(path: &Path) -> AppResult<()> {
+    const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+    let file = std::fs::File::open(path)?;
+    let len = file.metadata()?.len();
+    let mut reader = BufReader::new(file);
+    let mut magic = [0u8; 8];
+    let read = read_up_to(&mut reader, &mut magic)?;
+    let magic = &magic[..read];
+    let problem = if magic.starts_with(PNG_SIGNATURE) {
+        png_problem(&mut reader, len)?
+    } else if magic.starts_with(&[0xFF, 0xD8]) {
+        reader.seek_relative(2 - read as i64)?;
+        jpeg_problem(&mut reader, len)?
+    } else if magic.starts_with(b"BM") && magic.len() >= 6 {
+        let claimed = u32::from_le_bytes([magic[2], magic[3], magic[4], magic[5]]) as u64;
+        (len < claimed).then(|| format!("{} of {} bytes present", len, claimed))
+    } else {
+        None
+    };
+    match problem {
+        Some(detail) => Err(AppError::IncompleteFile(path.to_path_buf(), detail)),
+        None => Ok(()),
+    }
+}
+
+/// Fill as much of `buf` as the reader has, returning how much that was
+fn read_up_to(reader: &mut impl Read, buf: &mut [u8]) -> std::io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..])? {
+            0 => break,
+            n => filled += n,
+        }
+    }
+    Ok(filled)
+}
+
+/// Whether a decoder gave up because the data ran out, rather than
+/// because it's malformed
+fn is_truncation(error: &image::ImageError) -> bool {
+    match error {
+        image::ImageError::IoError(e) => e.kind() == std::io::ErrorKind::UnexpectedEof,
+        image::ImageError::Decoding(e) => {
+            let message = e.to_string().to_lowercase();
+            ["not enough bytes", "unexpected eof", "unexpected end"]
+                .iter()
+                .any(|sign| message.contains(sign))
+        }
+        _ => false,
+    }
+}
+
+/// Keyword / text pairs from a PNG's `tEXt`, `zTXt` and `iTXt` chunks
+/// (Description, Author, Software, or the prompt an image generator
+/// stored), compressed ones inflated.  Empty for other formats or when
+/// the file can't be read.
+pub fn read_png_text(path: &Path) -> Vec<(String, String)> {
+    let Ok(file) = std::fs::File::open(path) else {
+        return Vec::new();
+    };
+    let Ok(mut reader) = png::Decoder::new(BufReader::new(file)).read_info() else {
+        return Vec::new();
+    };
+    // Text chunks after the image data only turn up once the rest of the
+    // file has been read
+    if let Err(e) = reader.finish() {
+        log::debug!("PNG text after the image data of {}: {}", path.display(), e);
+    }
+    let info = reader.info();
+    let latin1 = info
+        .uncompressed_latin1_text
+        .iter()
+        .map(|chunk| (chunk.keyword.clone(), Ok(chunk.text.clone())));
+    let compressed = info
+        .compressed_latin1_text
+        .iter()
+        .map(|chunk| (chunk.keyword.clone(), chunk.get_text()));
+    let utf8 = info
+        .utf8_text
+        .iter()
+        .map(|chunk| (chunk.keyword.clone(), chunk.get_text()));
+    latin1
+        .chain(compressed)
+        .chain(utf8)
+        .filter_map(|(keyword, text)| match text {
+            Ok(text) => Some((keyword, text)),
+            Err(e) => {
+                log::debug!("Unreadable {} text in {}: {}", keyword, path.display(), e);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Walk PNG chunks (after the signature) to `IEND` in a `len`-byte file
+fn png_problem(reader: &mut BufReader<std::fs::File>, len: u64) -> std::io::Result<Option<String>> {
+    let mut at = 8u64;
+    let mut header = [0u8; 8];
+    while at + 8 <= len {
+        reader.read_exact(&mut header)?;
+        let data_len = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as u64;
+        let kind = String::from_utf8_lossy(&header[4..8]).into_owned();
+        // Length + type + data + CRC
+        let chunk_len = data_len + 12;
+        if at + chunk_len > len {
+            return Ok(Some(format!("ends partway through the {} chunk", kind)));
+        }
+        if kind == "IEND" {
+            return Ok(None);
+        }
+        reader.seek_relative(data_len as i64 + 4)?;
+        at += chunk_len;
+    }
+    Ok(Some("missing the end-of-image chunk".to_string()))
+}
+
+/// Walk JPEG segments (after SOI) in a `len`-byte file up to the start of
+/// scan.  What follows is entropy-coded data the decoder judges itself;
+/// bytes that aren't a marker where one belongs are for it to reject too.
+fn jpeg_problem(
+    reader: &mut BufReader<std::fs::File>,
+    len: u64,
+) -> std::io::Result<Option<String>> {
+    let mut at = 2u64;
+    let mut marker = [0u8; 2];
+    while at + 2 <= len {
+        reader.read_exact(&mut marker)?;
+        match marker {
+            [0xFF, 0xD9] => return Ok(None),
+            // Fill byte: the marker starts at the next FF
+            [0xFF, 0xFF] => {
+                reader.seek_relative(-1)?;
+                at += 1;
+                continue;
+            }
+            // Restart markers: no length
+            [0xFF, 0x01 | 0xD0..=0xD7] => {
+                at += 2;
+                continue;
+            }
+            [0xFF, _] => {}
+            _ => return Ok(None),
+        }
+        let mut length = [0u8; 2];
+        if at + 4 > len {
+            return Ok(Some("ends partway through a header segment".to_string()));
+        }
+        reader.read_exact(&mut length)?;
+        let end = at + 2 + u16::from_be_bytes(length) as u64;
+        if end > len {
+            return Ok(Some("ends partway through a header segment".to_string()));
+        }
+        if marker[1] == 0xDA {
+            // The scan header is whole; the image data follows
+            return Ok(None);
+        }
+        reader.seek_relative((end - at - 4) as i64)?;
+        at = end;
+    }
+    Ok(Some("ends before the image data".to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -275,6 +569,66 @@ mod tests {
         assert!(matches!(result, Err(AppError::FileNotFound(_))));
     }
 
+    /// A 2-frame 4×3 GIF, red then blue
+    fn write_two_frame_gif(path: &Path) {
+        use image::codecs::gif::GifEncoder;
+        use image::{Frame, Rgba, RgbaImage};
+
+        let mut encoder = GifEncoder::new(std::fs::File::create(path).unwrap());
+        for color in [[255, 0, 0, 255], [0, 0, 255, 255]] {
+            let frame = Frame::new(RgbaImage::from_pixel(4, 3, Rgba(color)));
+            encoder.encode_frame(frame).unwrap();
+        }
+    }
+
+    #[test]
+    fn load_animation_frame_picks_the_frame() {
+        // Arrange
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("anim.gif");
+        write_two_frame_gif(&path);
+
+        // Act
+        let frame = load_animation_frame(&path, 1).unwrap();
+
+        // Assert - the second frame, at the animation's size
+        assert_eq!((frame.width(), frame.height()), (4, 3));
+        assert_eq!(frame.to_rgba8().get_pixel(0, 0).0, [0, 0, 255, 255]);
+    }
+
+    #[test]
+    fn load_animation_frame_exports_at_frame_size() {
+        // Arrange
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("anim.gif");
+        write_two_frame_gif(&path);
+        let out = dir.path().join("frame.png");
+
+        // Act
+        load_animation_frame(&path, 0).unwrap().save(&out).unwrap();
+
+        // Assert
+        assert_eq!(
+            get_image_dimensions(&out).unwrap(),
+            get_image_dimensions(&path).unwrap()
+        );
+    }
+
+    #[test]
+    fn load_animation_frame_rejects_missing_frames_and_stills() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let gif = dir.path().join("anim.gif");
+        write_two_frame_gif(&gif);
+        let still = dir.path().join("still.bmp");
+        image::DynamicImage::new_rgba8(2, 2).save(&still).unwrap();
+
+        assert!(matches!(
+            load_animation_frame(&gif, 2),
+            Err(AppError::ImageLoadError(..))
+        ));
+        assert!(load_animation_frame(&still, 0).is_err());
+    }
+
     #[test]
     fn load_real_png_succeeds() {
         // Create a minimal valid PNG in a temp file
@@ -326,7 +680,7 @@ mod tests {
         let img = image::DynamicImage::new_rgba8(1, 1);
         img.save(&path).unwrap();
 
-        let handle = load_image_async(path, None, false);
+        let handle = load_image_async(path, None, false, None);
         handle.cancel();
         // After cancel, the flag should be set (regardless of whether
         // the thread already finished)
@@ -340,7 +694,7 @@ mod tests {
         let img = image::DynamicImage::new_rgba8(10, 10);
         img.save(&path).unwrap();
 
-        let handle = load_image_async(path, None, false);
+        let handle = load_image_async(path, None, false, None);
         // Wait for result (with timeout)
         let mut result = None;
         for _ in 0..100 {
@@ -356,6 +710,7 @@ mod tests {
             result.as_ref().map(|m| match m {
                 LoaderMessage::Success(_) => "Success",
                 LoaderMessage::Error(_, _) => "Error",
+                LoaderMessage::Incomplete(_, _) => "Incomplete",
                 LoaderMessage::OversizedImage(_, _, _, _) => "OversizedImage",
             })
         );
@@ -369,7 +724,7 @@ mod tests {
         img.save(&path).unwrap();
 
         let max_dim = 100;
-        let handle = load_image_async(path, Some(max_dim), false);
+        let handle = load_image_async(path, Some(max_dim), false, None);
         let mut result = None;
         for _ in 0..100 {
             if let Some(msg) = handle.try_recv() {
@@ -392,7 +747,7 @@ mod tests {
         img.save(&path).unwrap();
 
         let max_dim = 100;
-        let handle = load_image_async(path, Some(max_dim), true);
+        let handle = load_image_async(path, Some(max_dim), true, None);
         let mut result = None;
         for _ in 0..100 {
             if let Some(msg) = handle.try_recv() {
@@ -409,7 +764,7 @@ mod tests {
 
     #[test]
     fn async_load_error_for_nonexistent_file() {
-        let handle = load_image_async(PathBuf::from("/no_such_file_12345.png"), None, false);
+        let handle = load_image_async(PathBuf::from("/no_such_file_12345.png"), None, false, None);
         let mut result = None;
         for _ in 0..100 {
             if let Some(msg) = handle.try_recv() {
@@ -420,4 +775,155 @@ mod tests {
         }
         assert!(matches!(result, Some(LoaderMessage::Error(_, _))));
     }
+
+    #[test]
+    fn check_complete_accepts_whole_files() {
+        let dir = tempfile::TempDir::new().unwrap();
+        for name in ["whole.png", "whole.jpg", "whole.bmp"] {
+            let path = dir.path().join(name);
+            image::DynamicImage::new_rgb8(16, 16).save(&path).unwrap();
+            assert!(check_complete(&path).is_ok(), "{name}");
+        }
+    }
+
+    #[test]
+    fn truncated_png_is_incomplete_file() {
+        // Arrange - a PNG cut off halfway, as a download in progress would be
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("partial.png");
+        let img = image::RgbaImage::from_fn(64, 64, |x, y| image::Rgba([x as u8, y as u8, 7, 255]));
+        img.save(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::write(&path, &bytes[..bytes.len() / 2]).unwrap();
+
+        // Act
+        let result = check_complete(&path);
+
+        // Assert - the header is intact, so only the completeness check
+        // notices
+        assert!(get_image_dimensions(&path).is_ok());
+        assert!(matches!(result, Err(AppError::IncompleteFile(_, _))));
+    }
+
+    #[test]
+    fn truncated_jpeg_is_incomplete_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("partial.jpg");
+        image::DynamicImage::new_rgb8(64, 64).save(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        // Cut inside the quantization tables, ahead of the image data
+        std::fs::write(&path, &bytes[..100]).unwrap();
+
+        assert!(matches!(
+            check_complete(&path),
+            Err(AppError::IncompleteFile(_, _))
+        ));
+    }
+
+    #[test]
+    fn jpeg_without_end_marker_passes() {
+        // Arrange - plenty of cameras and tools leave off the EOI marker
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("no_eoi.jpg");
+        image::DynamicImage::new_rgb8(64, 64).save(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::write(&path, &bytes[..bytes.len() - 2]).unwrap();
+
+        // Act / Assert - it still decodes, so it isn't held back
+        assert!(check_complete(&path).is_ok());
+        assert!(load_image(&path).is_ok());
+    }
+
+    #[test]
+    fn decoder_running_out_of_data_is_incomplete_file() {
+        // Arrange
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("partial.png");
+        let img = image::RgbaImage::from_fn(64, 64, |x, y| image::Rgba([x as u8, y as u8, 7, 255]));
+        img.save(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::write(&path, &bytes[..bytes.len() / 2]).unwrap();
+
+        // Act
+        let result = load_image(&path);
+
+        // Assert
+        assert!(matches!(result, Err(AppError::IncompleteFile(_, _))));
+    }
+
+    #[test]
+    fn async_load_reports_truncated_file_as_incomplete() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("partial.png");
+        image::DynamicImage::new_rgba8(32, 32).save(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::write(&path, &bytes[..bytes.len() - 12]).unwrap();
+
+        let handle = load_image_async(path, None, false, None);
+        let mut result = None;
+        for _ in 0..100 {
+            if let Some(msg) = handle.try_recv() {
+                result = Some(msg);
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        assert!(matches!(result, Some(LoaderMessage::Incomplete(_, _))));
+    }
+
+    #[test]
+    fn read_png_text_finds_every_kind_of_text_chunk() {
+        // Arrange - one of each chunk kind before the image data, and a
+        // prompt written after it
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("generated.png");
+        let mut encoder = png::Encoder::new(std::fs::File::create(&path).unwrap(), 1, 1);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder
+            .add_text_chunk("Software".to_string(), "rpview tests".to_string())
+            .unwrap();
+        encoder
+            .add_ztxt_chunk("Description".to_string(), "A red fox ".repeat(50))
+            .unwrap();
+        encoder
+            .add_itxt_chunk("Author".to_string(), "Zoë".to_string())
+            .unwrap();
+        let mut writer = encoder.write_header().unwrap();
+        writer.write_image_data(&[255, 0, 0, 255]).unwrap();
+        writer
+            .write_text_chunk(&png::text_metadata::TEXtChunk::new(
+                "parameters",
+                "a fox, oil painting, Steps: 20",
+            ))
+            .unwrap();
+        writer.finish().unwrap();
+
+        // Act
+        let text = read_png_text(&path);
+
+        // Assert
+        let get = |keyword: &str| {
+            text.iter()
+                .find(|(k, _)| k == keyword)
+                .map(|(_, v)| v.as_str())
+        };
+        assert_eq!(text.len(), 4);
+        assert_eq!(get("Software"), Some("rpview tests"));
+        assert_eq!(get("Description"), Some("A red fox ".repeat(50).as_str()));
+        assert_eq!(get("Author"), Some("Zoë"));
+        assert_eq!(get("parameters"), Some("a fox, oil painting, Steps: 20"));
+    }
+
+    #[test]
+    fn read_png_text_is_empty_without_text_or_for_other_formats() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let png = dir.path().join("plain.png");
+        let jpg = dir.path().join("photo.jpg");
+        image::DynamicImage::new_rgba8(2, 2).save(&png).unwrap();
+        image::DynamicImage::new_rgb8(2, 2).save(&jpg).unwrap();
+
+        assert!(read_png_text(&png).is_empty());
+        assert!(read_png_text(&jpg).is_empty());
+        assert!(read_png_text(Path::new("/no_such_file_12345.png")).is_empty());
+    }
 }
@@ -0,0 +1,207 @@
+//! Diagnostic logging through the `log` facade.
+//!
+//! Warnings and errors always go to stderr (debug builds show `debug` too).
+//! `--log-level <LEVEL>` additionally writes everything at or above LEVEL to
+//! `rpview.log` beside the settings file, for attaching to bug reports.
+//! Per-frame render diagnostics are logged at `trace`, so in normal use they
+//! are filtered out before their arguments are even formatted.
+
+use log::{LevelFilter, Log, Metadata, Record};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// Name of the log file written in the config directory
+pub const LOG_FILE_NAME: &str = "rpview.log";
+
+/// The most any other crate (gpui, wgpu, ...) gets to log: their debug
+/// chatter would drown out ours
+const DEPENDENCY_LEVEL: LevelFilter = LevelFilter::Warn;
+
+/// What reaches stderr regardless of `--log-level`
+const STDERR_LEVEL: LevelFilter = if cfg!(debug_assertions) {
+    LevelFilter::Debug
+} else {
+    LevelFilter::Warn
+};
+
+/// Where the log file goes: next to `settings.json`
+pub fn log_file_path() -> PathBuf {
+    super::settings_io::get_settings_path().with_file_name(LOG_FILE_NAME)
+}
+
+/// Sends records to stderr and, when enabled, a log file, each with its
+/// own level threshold.
+pub struct Logger {
+    stderr_level: LevelFilter,
+    file_level: LevelFilter,
+    file: Option<Mutex<File>>,
+    started: Instant,
+}
+
+impl Logger {
+    pub fn new(stderr_level: LevelFilter, file_level: LevelFilter, file: Option<File>) -> Self {
+        Self {
+            stderr_level,
+            // Without a file the file threshold can't let anything through
+            file_level: if file.is_some() {
+                file_level
+            } else {
+                LevelFilter::Off
+            },
+            file: file.map(Mutex::new),
+            started: Instant::now(),
+        }
+    }
+
+    /// The most verbose level any sink wants
+    pub fn max_level(&self) -> LevelFilter {
+        self.stderr_level.max(self.file_level)
+    }
+
+    /// `level` capped at `DEPENDENCY_LEVEL` for records from other crates
+    fn level_for(level: LevelFilter, target: &str) -> LevelFilter {
+        if target == "rpview" || target.starts_with("rpview::") {
+            level
+        } else {
+            level.min(DEPENDENCY_LEVEL)
+        }
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= Self::level_for(self.max_level(), metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let line = format!(
+            "{:>9.3} {:<5} {}: {}",
+            self.started.elapsed().as_secs_f64(),
+            record.level(),
+            record.target(),
+            record.args()
+        );
+        let target = record.target();
+        if record.level() <= Self::level_for(self.stderr_level, target) {
+            eprintln!("{}", line);
+        }
+        if record.level() <= Self::level_for(self.file_level, target)
+            && let Some(file) = &self.file
+            && let Ok(mut file) = file.lock()
+        {
+            let _ = writeln!(file, "{}", line);
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(file) = &self.file
+            && let Ok(mut file) = file.lock()
+        {
+            let _ = file.flush();
+        }
+    }
+}
+
+/// Install the process logger.  With `file_level`, also append to
+/// [`log_file_path`] at that level; returns the file path when it opened.
+pub fn init(file_level: Option<LevelFilter>) -> Option<PathBuf> {
+    let path = log_file_path();
+    let file = file_level.and_then(|_| {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| eprintln!("Could not open log file {}: {}", path.display(), e))
+            .ok()
+    });
+    let opened = file.is_some();
+    let logger = Logger::new(STDERR_LEVEL, file_level.unwrap_or(LevelFilter::Off), file);
+    log::set_max_level(logger.max_level());
+    if log::set_boxed_logger(Box::new(logger)).is_err() {
+        return None;
+    }
+    opened.then_some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use log::Level;
+    use std::io::Read;
+
+    fn metadata(level: Level) -> Metadata<'static> {
+        Metadata::builder().level(level).target("rpview").build()
+    }
+
+    #[test]
+    fn level_gates_what_is_enabled() {
+        // Arrange
+        let logger = Logger::new(LevelFilter::Warn, LevelFilter::Off, None);
+
+        // Act & Assert - warn and above only
+        assert!(logger.enabled(&metadata(Level::Error)));
+        assert!(logger.enabled(&metadata(Level::Warn)));
+        assert!(!logger.enabled(&metadata(Level::Info)));
+        assert!(!logger.enabled(&metadata(Level::Trace)));
+    }
+
+    #[test]
+    fn file_level_only_counts_with_a_file() {
+        // A trace file level without a file must not enable per-frame logs
+        let logger = Logger::new(LevelFilter::Warn, LevelFilter::Trace, None);
+        assert_eq!(logger.max_level(), LevelFilter::Warn);
+        assert!(!logger.enabled(&metadata(Level::Trace)));
+    }
+
+    #[test]
+    fn dependencies_capped_at_warn() {
+        // Arrange
+        let logger = Logger::new(LevelFilter::Debug, LevelFilter::Off, None);
+        let from = |target| {
+            Metadata::builder()
+                .level(Level::Debug)
+                .target(target)
+                .build()
+        };
+
+        // Act & Assert
+        assert!(logger.enabled(&from("rpview::utils::svg")));
+        assert!(!logger.enabled(&from("gpui::window")));
+    }
+
+    #[test]
+    fn file_receives_records_at_its_level() {
+        // Arrange
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join(LOG_FILE_NAME);
+        let file = File::create(&path).unwrap();
+        let logger = Logger::new(LevelFilter::Off, LevelFilter::Debug, Some(file));
+
+        // Act
+        for (level, message) in [(Level::Debug, "kept"), (Level::Trace, "dropped")] {
+            logger.log(
+                &Record::builder()
+                    .level(level)
+                    .target("rpview")
+                    .args(format_args!("{}", message))
+                    .build(),
+            );
+        }
+        logger.flush();
+
+        // Assert
+        let mut contents = String::new();
+        File::open(&path)
+            .unwrap()
+            .read_to_string(&mut contents)
+            .unwrap();
+        assert!(contents.contains("DEBUG rpview: kept"));
+        assert!(!contents.contains("dropped"));
+    }
+}
@@ -1,20 +1,35 @@
 pub mod animation;
+pub mod archive;
+pub mod batch;
+pub mod caption;
+pub mod crop;
+pub mod cursor;
+pub mod decode_cache;
+pub mod default_app;
+pub mod exif;
+pub mod eyedropper;
 pub mod file_scanner;
 pub mod filters;
 pub mod frame_cache;
+pub mod generation_params;
 pub mod gpu_presets;
+pub mod histogram;
 pub mod image_loader;
+pub mod logging;
+pub mod montage;
+pub mod navigator;
+pub mod organize;
+pub mod pan_inertia;
+pub mod pixel_aspect;
+pub mod rename;
+pub mod reveal;
+pub mod rotation;
+pub mod scratch;
 pub mod settings_io;
+pub mod slideshow;
+pub mod stack;
 pub mod style;
 pub mod svg;
+pub mod undo;
 pub mod window_level;
 pub mod zoom;
-
-/// Like `eprintln!`, but only emits output in debug builds.
-macro_rules! debug_eprintln {
-    ($($arg:tt)*) => {
-        #[cfg(debug_assertions)]
-        eprintln!($($arg)*);
-    };
-}
-pub(crate) use debug_eprintln;
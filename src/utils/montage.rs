@@ -0,0 +1,207 @@
+//! Montage: two to four images side by side, for choosing among similar
+//! shots.  Each pane keeps its own zoom and pan, or they all move together
+//! when the views are linked.
+
+/// Most images a montage shows at once
+pub const MAX_PANES: usize = 4;
+
+/// Space between panes, in pixels
+pub const PANE_GAP: f32 = 4.0;
+
+/// Zoom limits relative to fitting the pane
+pub const MIN_PANE_ZOOM: f32 = 0.5;
+pub const MAX_PANE_ZOOM: f32 = 32.0;
+
+/// A rectangle in pixels: a pane within the montage area, or an image
+/// within its pane
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect {
+    pub x: f32,
+    pub y: f32,
+    pub w: f32,
+    pub h: f32,
+}
+
+/// Where `count` panes go in a `width` × `height` area, left to right then
+/// top to bottom.  Two sit side by side (stacked when the area is taller
+/// than wide); three or four share a 2×2 grid, three leaving the last cell
+/// empty.  Past `MAX_PANES` the extras are left out.
+pub fn pane_rects(count: usize, width: f32, height: f32, gap: f32) -> Vec<Rect> {
+    let count = count.min(MAX_PANES);
+    let (cols, rows) = match count {
+        0 => return Vec::new(),
+        1 => (1, 1),
+        2 if height > width => (1, 2),
+        2 => (2, 1),
+        _ => (2, 2),
+    };
+    let cell =
+        |extent: f32, cells: usize| ((extent - gap * (cells - 1) as f32) / cells as f32).max(0.0);
+    let (w, h) = (cell(width, cols), cell(height, rows));
+    (0..count)
+        .map(|i| Rect {
+            x: (i % cols) as f32 * (w + gap),
+            y: (i / cols) as f32 * (h + gap),
+            w,
+            h,
+        })
+        .collect()
+}
+
+/// How one pane shows its image: zoom relative to fitting the pane
+/// (1.0 = fit) and how far the image's center sits from the pane's
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PaneView {
+    pub zoom: f32,
+    pub pan: (f32, f32),
+}
+
+impl Default for PaneView {
+    fn default() -> Self {
+        Self {
+            zoom: 1.0,
+            pan: (0.0, 0.0),
+        }
+    }
+}
+
+impl PaneView {
+    /// Where an image of `size` draws inside a `pane`-sized pane, in the
+    /// pane's own coordinates
+    pub fn image_rect(&self, size: (u32, u32), pane: Rect) -> Rect {
+        let (iw, ih) = (size.0.max(1) as f32, size.1.max(1) as f32);
+        let scale = (pane.w / iw).min(pane.h / ih) * self.zoom;
+        let (w, h) = (iw * scale, ih * scale);
+        Rect {
+            x: (pane.w - w) / 2.0 + self.pan.0,
+            y: (pane.h - h) / 2.0 + self.pan.1,
+            w,
+            h,
+        }
+    }
+
+    /// Zoom in or out by `step` about the pane's center, within
+    /// `MIN_PANE_ZOOM`..=`MAX_PANE_ZOOM`
+    pub fn zoom_by(&mut self, zoom_in: bool, step: f32) {
+        let zoom = if zoom_in {
+            self.zoom * step
+        } else {
+            self.zoom / step
+        }
+        .clamp(MIN_PANE_ZOOM, MAX_PANE_ZOOM);
+        // Scale the pan with the image so the point at the center stays put
+        let ratio = zoom / self.zoom;
+        self.pan = (self.pan.0 * ratio, self.pan.1 * ratio);
+        self.zoom = zoom;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: f32, y: f32, w: f32, h: f32) -> Rect {
+        Rect { x, y, w, h }
+    }
+
+    #[test]
+    fn test_pane_rects_two_side_by_side() {
+        // Act
+        let rects = pane_rects(2, 1004.0, 600.0, 4.0);
+
+        // Assert
+        assert_eq!(
+            rects,
+            vec![rect(0.0, 0.0, 500.0, 600.0), rect(504.0, 0.0, 500.0, 600.0)]
+        );
+    }
+
+    #[test]
+    fn test_pane_rects_two_stack_in_a_tall_area() {
+        let rects = pane_rects(2, 600.0, 1004.0, 4.0);
+        assert_eq!(
+            rects,
+            vec![rect(0.0, 0.0, 600.0, 500.0), rect(0.0, 504.0, 600.0, 500.0)]
+        );
+    }
+
+    #[test]
+    fn test_pane_rects_grid_for_three_and_four() {
+        // Arrange
+        let grid = [
+            rect(0.0, 0.0, 400.0, 300.0),
+            rect(404.0, 0.0, 400.0, 300.0),
+            rect(0.0, 304.0, 400.0, 300.0),
+            rect(404.0, 304.0, 400.0, 300.0),
+        ];
+
+        // Act / Assert - three fill the grid but the last cell
+        assert_eq!(pane_rects(3, 804.0, 604.0, 4.0), grid[..3].to_vec());
+        assert_eq!(pane_rects(4, 804.0, 604.0, 4.0), grid.to_vec());
+
+        // Act / Assert - extras past four are left out
+        assert_eq!(pane_rects(6, 804.0, 604.0, 4.0), grid.to_vec());
+    }
+
+    #[test]
+    fn test_pane_rects_degenerate_counts_and_areas() {
+        assert!(pane_rects(0, 800.0, 600.0, 4.0).is_empty());
+        assert_eq!(
+            pane_rects(1, 800.0, 600.0, 4.0),
+            vec![rect(0.0, 0.0, 800.0, 600.0)]
+        );
+        // Too small for the gaps: the panes shrink to nothing, not below
+        assert!(
+            pane_rects(4, 2.0, 2.0, 4.0)
+                .iter()
+                .all(|r| r.w == 0.0 && r.h == 0.0)
+        );
+    }
+
+    #[test]
+    fn test_image_rect_fits_then_follows_zoom_and_pan() {
+        // Arrange - a 2:1 image in a square pane
+        let pane = rect(0.0, 0.0, 400.0, 400.0);
+        let mut view = PaneView::default();
+
+        // Act / Assert - fit, centered
+        assert_eq!(
+            view.image_rect((1000, 500), pane),
+            rect(0.0, 100.0, 400.0, 200.0)
+        );
+
+        // Act / Assert - doubled and shifted right
+        view.zoom = 2.0;
+        view.pan = (50.0, 0.0);
+        assert_eq!(
+            view.image_rect((1000, 500), pane),
+            rect(-150.0, 0.0, 800.0, 400.0)
+        );
+    }
+
+    #[test]
+    fn test_zoom_by_keeps_the_center_point_and_clamps() {
+        // Arrange
+        let mut view = PaneView {
+            zoom: 2.0,
+            pan: (40.0, -20.0),
+        };
+
+        // Act
+        view.zoom_by(true, 2.0);
+
+        // Assert
+        assert_eq!(view.zoom, 4.0);
+        assert_eq!(view.pan, (80.0, -40.0));
+
+        // Act / Assert - limits
+        for _ in 0..10 {
+            view.zoom_by(false, 2.0);
+        }
+        assert_eq!(view.zoom, MIN_PANE_ZOOM);
+        for _ in 0..10 {
+            view.zoom_by(true, 2.0);
+        }
+        assert_eq!(view.zoom, MAX_PANE_ZOOM);
+    }
+}
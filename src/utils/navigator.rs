@@ -0,0 +1,176 @@
+//! Navigator: a thumbnail of the whole image in a corner of a zoomed-in
+//! view, with a rectangle marking the part on screen.  Clicking or
+//! dragging in the thumbnail moves the view there.
+//!
+//! Sizes are in layout pixels (the image's size as drawn at zoom 1); the
+//! pan is where the image's top-left corner sits in the viewport.
+
+/// Longest side of the navigator thumbnail, in pixels
+pub const THUMBNAIL_SIDE: f32 = 160.0;
+
+/// A rectangle in thumbnail pixels
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThumbRect {
+    pub x: f32,
+    pub y: f32,
+    pub w: f32,
+    pub h: f32,
+}
+
+/// The thumbnail size for an `image`-sized image: its proportions with
+/// the longer side `max_side` long
+pub fn thumbnail_size(image: (u32, u32), max_side: f32) -> (f32, f32) {
+    let (w, h) = (image.0.max(1) as f32, image.1.max(1) as f32);
+    let scale = max_side / w.max(h);
+    (w * scale, h * scale)
+}
+
+/// The part of the image on screen, as a rectangle within a `thumb`-sized
+/// thumbnail.  None while the whole image is in view, when there's
+/// nothing for the navigator to show.
+pub fn visible_rect(
+    image: (u32, u32),
+    zoom: f32,
+    pan: (f32, f32),
+    viewport: (f32, f32),
+    thumb: (f32, f32),
+) -> Option<ThumbRect> {
+    let (w, h) = (image.0 as f32 * zoom, image.1 as f32 * zoom);
+    if w <= 0.0 || h <= 0.0 {
+        return None;
+    }
+    // A half-pixel allowance so a fitted image never counts as cut off
+    let fits = |pan: f32, size: f32, view: f32| pan >= -0.5 && pan + size <= view + 0.5;
+    if fits(pan.0, w, viewport.0) && fits(pan.1, h, viewport.1) {
+        return None;
+    }
+    // The viewport's edges as fractions of the image, kept to the image
+    let span = |pan: f32, size: f32, view: f32| {
+        let start = (-pan / size).clamp(0.0, 1.0);
+        let end = ((view - pan) / size).clamp(0.0, 1.0);
+        (start, end)
+    };
+    let (x0, x1) = span(pan.0, w, viewport.0);
+    let (y0, y1) = span(pan.1, h, viewport.1);
+    Some(ThumbRect {
+        x: x0 * thumb.0,
+        y: y0 * thumb.1,
+        w: (x1 - x0) * thumb.0,
+        h: (y1 - y0) * thumb.1,
+    })
+}
+
+/// The pan that centers the view on `point` of a `thumb`-sized thumbnail
+/// (the inverse of `visible_rect`).  The caller keeps it to the usual pan
+/// limits.
+pub fn pan_to_center(
+    image: (u32, u32),
+    zoom: f32,
+    viewport: (f32, f32),
+    thumb: (f32, f32),
+    point: (f32, f32),
+) -> (f32, f32) {
+    let fraction = |at: f32, size: f32| {
+        if size > 0.0 {
+            (at / size).clamp(0.0, 1.0)
+        } else {
+            0.5
+        }
+    };
+    let (fx, fy) = (fraction(point.0, thumb.0), fraction(point.1, thumb.1));
+    (
+        viewport.0 / 2.0 - fx * image.0 as f32 * zoom,
+        viewport.1 / 2.0 - fy * image.1 as f32 * zoom,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thumbnail_size_keeps_proportions() {
+        assert_eq!(thumbnail_size((2000, 1000), 160.0), (160.0, 80.0));
+        assert_eq!(thumbnail_size((500, 1000), 160.0), (80.0, 160.0));
+        // An empty image still gets a square rather than dividing by zero
+        assert_eq!(thumbnail_size((0, 0), 160.0), (160.0, 160.0));
+    }
+
+    #[test]
+    fn test_visible_rect_hidden_while_the_image_fits() {
+        // Arrange - a 1000×500 image fitted, centered, in 1000×800
+        let rect = visible_rect(
+            (1000, 500),
+            1.0,
+            (0.0, 150.0),
+            (1000.0, 800.0),
+            (160.0, 80.0),
+        );
+
+        // Assert
+        assert_eq!(rect, None);
+    }
+
+    #[test]
+    fn test_visible_rect_marks_the_part_on_screen() {
+        // Arrange - 1000×500 at 4× is 4000×2000; the viewport is 1000×500
+        // showing from (1000, 500) in image pixels at zoom
+        let rect = visible_rect(
+            (1000, 500),
+            4.0,
+            (-1000.0, -500.0),
+            (1000.0, 500.0),
+            (160.0, 80.0),
+        );
+
+        // Assert - a quarter of each side, a quarter of the way in
+        assert_eq!(
+            rect,
+            Some(ThumbRect {
+                x: 40.0,
+                y: 20.0,
+                w: 40.0,
+                h: 20.0,
+            })
+        );
+    }
+
+    #[test]
+    fn test_visible_rect_clamps_to_the_image() {
+        // Arrange - zoomed 2× and panned so the left edge shows with a
+        // gap beside it
+        let rect = visible_rect(
+            (1000, 500),
+            2.0,
+            (200.0, -250.0),
+            (1000.0, 500.0),
+            (160.0, 80.0),
+        )
+        .unwrap();
+
+        // Assert - starts at the image's edge, not before it
+        assert_eq!(rect.x, 0.0);
+        assert_eq!(rect.w, 64.0);
+        assert_eq!((rect.y, rect.h), (20.0, 40.0));
+    }
+
+    #[test]
+    fn test_pan_to_center_inverts_visible_rect() {
+        // Arrange
+        let (image, zoom, viewport, thumb) = ((1000, 500), 4.0, (1000.0, 500.0), (160.0, 80.0));
+
+        // Act - center on the thumbnail's middle
+        let pan = pan_to_center(image, zoom, viewport, thumb, (80.0, 40.0));
+
+        // Assert - the rectangle lands centered on that point
+        assert_eq!(pan, (-1500.0, -750.0));
+        let rect = visible_rect(image, zoom, pan, viewport, thumb).unwrap();
+        assert_eq!((rect.x + rect.w / 2.0, rect.y + rect.h / 2.0), (80.0, 40.0));
+
+        // Act / Assert - points past the thumbnail count as its edge
+        assert_eq!(
+            pan_to_center(image, zoom, viewport, thumb, (-10.0, 500.0)),
+            (500.0, -1750.0)
+        );
+    }
+}
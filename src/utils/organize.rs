@@ -0,0 +1,246 @@
+//! Organize by Date: copy or move images into folders named after when they
+//! were taken, e.g. `2023/05/14/IMG_0001.jpg`.
+//!
+//! The destination of each file comes from a pattern with `{placeholders}`
+//! filled from its EXIF capture date, or its modified time when it has none.
+//! The whole list is planned first (shown as a dry-run preview), then applied
+//! as a batch job.
+
+use crate::utils::exif::{self, CaptureDate};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Pattern used until one is set in settings
+pub const DEFAULT_PATTERN: &str = "{year}/{month}/{day}/{filename}";
+
+/// Where one file goes
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlannedMove {
+    pub source: PathBuf,
+    pub destination: PathBuf,
+    /// Whether the date came from the file's modified time (no EXIF date)
+    pub from_mtime: bool,
+}
+
+/// An organize run between planning and applying
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrganizePlan {
+    pub output_dir: PathBuf,
+    /// Move the files instead of copying them
+    pub move_files: bool,
+    pub moves: Vec<PlannedMove>,
+}
+
+impl OrganizePlan {
+    /// How many files use their modified time for lack of an EXIF date
+    pub fn mtime_count(&self) -> usize {
+        self.moves.iter().filter(|m| m.from_mtime).count()
+    }
+
+    /// Up to `limit` "source → destination" lines for the preview, with the
+    /// destination relative to the output folder
+    pub fn preview_lines(&self, limit: usize) -> Vec<String> {
+        self.moves
+            .iter()
+            .take(limit)
+            .map(|m| {
+                let name = m
+                    .source
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                let destination = m
+                    .destination
+                    .strip_prefix(&self.output_dir)
+                    .unwrap_or(&m.destination);
+                format!("{} \u{2192} {}", name, destination.display())
+            })
+            .collect()
+    }
+}
+
+/// Fill `pattern` for `source` taken at `date`.
+///
+/// Placeholders: `{year}`, `{month}`, `{day}`, `{hour}`, `{minute}`,
+/// `{second}` (zero-padded), `{filename}` (name with extension), `{stem}`
+/// and `{ext}`.  `/` separates folders.  When the pattern doesn't name the
+/// file, the original file name is appended so a file never becomes a
+/// folder.
+pub fn expand_pattern(pattern: &str, date: &CaptureDate, source: &Path) -> PathBuf {
+    let name = |part: Option<&std::ffi::OsStr>| {
+        part.map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    };
+    let filename = name(source.file_name());
+    let expanded = pattern
+        .replace("{year}", &format!("{:04}", date.year))
+        .replace("{month}", &format!("{:02}", date.month))
+        .replace("{day}", &format!("{:02}", date.day))
+        .replace("{hour}", &format!("{:02}", date.hour))
+        .replace("{minute}", &format!("{:02}", date.minute))
+        .replace("{second}", &format!("{:02}", date.second))
+        .replace("{filename}", &filename)
+        .replace("{stem}", &name(source.file_stem()))
+        .replace("{ext}", &name(source.extension()));
+
+    let mut path: PathBuf = expanded
+        .split(['/', '\\'])
+        .filter(|part| !part.is_empty() && *part != "." && *part != "..")
+        .collect();
+    let names_file = ["{filename}", "{stem}"].iter().any(|p| pattern.contains(p));
+    if !names_file {
+        path.push(&filename);
+    }
+    path
+}
+
+/// Plan where `source` goes under `output_dir`
+pub fn plan_move(source: &Path, output_dir: &Path, pattern: &str) -> Result<PlannedMove, String> {
+    let (date, from_mtime) = match exif::read_capture_date(source) {
+        Some(date) => (date, false),
+        None => {
+            let modified = fs::metadata(source)
+                .and_then(|m| m.modified())
+                .map_err(|e| e.to_string())?;
+            let date =
+                CaptureDate::from_system_time(modified).ok_or("Modified time is before 1970")?;
+            (date, true)
+        }
+    };
+    Ok(PlannedMove {
+        source: source.to_path_buf(),
+        destination: output_dir.join(expand_pattern(pattern, &date, source)),
+        from_mtime,
+    })
+}
+
+/// Copy or move one file to its planned destination, creating folders as
+/// needed.  An existing file is never overwritten.
+pub fn apply_move(planned: &PlannedMove, move_files: bool) -> Result<(), String> {
+    if planned.destination == planned.source {
+        return Ok(());
+    }
+    if planned.destination.exists() {
+        return Err(format!("{} already exists", planned.destination.display()));
+    }
+    if let Some(parent) = planned.destination.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    if move_files {
+        // Rename fails across volumes; fall back to copy + delete there
+        if fs::rename(&planned.source, &planned.destination).is_ok() {
+            return Ok(());
+        }
+        fs::copy(&planned.source, &planned.destination).map_err(|e| e.to_string())?;
+        fs::remove_file(&planned.source).map_err(|e| e.to_string())
+    } else {
+        fs::copy(&planned.source, &planned.destination)
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn may_14() -> CaptureDate {
+        CaptureDate {
+            year: 2023,
+            month: 5,
+            day: 14,
+            hour: 9,
+            minute: 8,
+            second: 7,
+        }
+    }
+
+    #[test]
+    fn test_expand_default_pattern() {
+        let path = expand_pattern(DEFAULT_PATTERN, &may_14(), Path::new("/dump/IMG_0001.JPG"));
+        assert_eq!(path, PathBuf::from("2023/05/14/IMG_0001.JPG"));
+    }
+
+    #[test]
+    fn test_expand_pattern_with_time_and_name_parts() {
+        let path = expand_pattern(
+            "{year}-{month}/{year}{month}{day}_{hour}{minute}{second}_{stem}.{ext}",
+            &may_14(),
+            Path::new("photo.jpeg"),
+        );
+        assert_eq!(path, PathBuf::from("2023-05/20230514_090807_photo.jpeg"));
+    }
+
+    #[test]
+    fn test_expand_pattern_without_file_name_appends_it() {
+        let path = expand_pattern("{year}/{month}", &may_14(), Path::new("a.png"));
+        assert_eq!(path, PathBuf::from("2023/05/a.png"));
+    }
+
+    #[test]
+    fn test_expand_pattern_stays_inside_the_output_folder() {
+        let path = expand_pattern("/../{year}//./{filename}", &may_14(), Path::new("a.png"));
+        assert_eq!(path, PathBuf::from("2023/a.png"));
+    }
+
+    #[test]
+    fn test_plan_falls_back_to_mtime_and_apply_copies() {
+        // Arrange - a PNG has no EXIF date
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("shot.png");
+        image::RgbaImage::new(1, 1).save(&source).unwrap();
+        let out = dir.path().join("out");
+
+        // Act
+        let planned = plan_move(&source, &out, "{year}/{filename}").unwrap();
+        apply_move(&planned, false).unwrap();
+
+        // Assert
+        assert!(planned.from_mtime);
+        assert!(planned.destination.starts_with(&out));
+        assert!(planned.destination.exists());
+        assert!(source.exists());
+        assert!(apply_move(&planned, false).is_err(), "never overwrites");
+    }
+
+    #[test]
+    fn test_apply_move_removes_the_source() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("a.png");
+        fs::write(&source, b"x").unwrap();
+        let planned = PlannedMove {
+            source: source.clone(),
+            destination: dir.path().join("2023/05/a.png"),
+            from_mtime: true,
+        };
+
+        // Act
+        apply_move(&planned, true).unwrap();
+
+        // Assert
+        assert!(!source.exists());
+        assert_eq!(fs::read(&planned.destination).unwrap(), b"x");
+    }
+
+    #[test]
+    fn test_preview_lines_are_relative_to_output() {
+        let plan = OrganizePlan {
+            output_dir: PathBuf::from("/out"),
+            move_files: false,
+            moves: vec![PlannedMove {
+                source: PathBuf::from("/dump/a.jpg"),
+                destination: PathBuf::from("/out/2023/05/14/a.jpg"),
+                from_mtime: false,
+            }],
+        };
+        assert_eq!(
+            plan.preview_lines(5),
+            vec![format!(
+                "a.jpg \u{2192} {}",
+                Path::new("2023/05/14/a.jpg").display()
+            )]
+        );
+        assert_eq!(plan.mtime_count(), 0);
+    }
+}
@@ -0,0 +1,171 @@
+//! Pan inertia: after a drag-pan is let go while still moving, the image
+//! keeps gliding and slows to a stop.
+//!
+//! The drag's last few movements give the speed at release; each frame
+//! then moves the image by that speed and bleeds some of it away.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// How far back the release speed is measured over
+pub const VELOCITY_WINDOW: Duration = Duration::from_millis(80);
+
+/// Share of the speed left after each second of gliding
+pub const DECAY_PER_SECOND: f32 = 0.004;
+
+/// Below this speed (pixels per second) a glide stops, and a release
+/// slower than it doesn't start one
+pub const MIN_SPEED: f32 = 40.0;
+
+/// Fastest a glide starts, in pixels per second, so a flick can't fling
+/// the image across several screens
+pub const MAX_SPEED: f32 = 6000.0;
+
+/// Recent drag movements, for the speed at release
+#[derive(Debug, Default)]
+pub struct VelocityTracker {
+    samples: VecDeque<(Instant, (f32, f32))>,
+}
+
+impl VelocityTracker {
+    /// Note that the drag moved by `delta` at `at`
+    pub fn record(&mut self, at: Instant, delta: (f32, f32)) {
+        self.samples.push_back((at, delta));
+        while let Some(&(oldest, _)) = self.samples.front() {
+            if at.duration_since(oldest) <= VELOCITY_WINDOW {
+                break;
+            }
+            self.samples.pop_front();
+        }
+    }
+
+    /// The drag's speed at `now` in pixels per second: its movement over
+    /// the last `VELOCITY_WINDOW`.  Zero when it had come to rest.
+    pub fn velocity(&self, now: Instant) -> (f32, f32) {
+        let recent = || {
+            self.samples
+                .iter()
+                .filter(move |(at, _)| now.duration_since(*at) <= VELOCITY_WINDOW)
+        };
+        let Some(&(first, _)) = recent().next() else {
+            return (0.0, 0.0);
+        };
+        let (dx, dy) = recent().fold((0.0, 0.0), |(x, y), (_, (dx, dy))| (x + dx, y + dy));
+        // Movements a single event apart still span at least a frame
+        let seconds = now
+            .duration_since(first)
+            .max(Duration::from_millis(16))
+            .as_secs_f32();
+        (dx / seconds, dy / seconds)
+    }
+
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+}
+
+/// A glide in progress
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Glide {
+    /// Pixels per second
+    velocity: (f32, f32),
+}
+
+impl Glide {
+    /// A glide carrying on at `velocity`, capped at `MAX_SPEED`; None when
+    /// it's too slow to bother with
+    pub fn new(velocity: (f32, f32)) -> Option<Self> {
+        let speed = velocity.0.hypot(velocity.1);
+        if !speed.is_finite() || speed < MIN_SPEED {
+            return None;
+        }
+        let scale = (MAX_SPEED / speed).min(1.0);
+        Some(Self {
+            velocity: (velocity.0 * scale, velocity.1 * scale),
+        })
+    }
+
+    /// Move on by `elapsed`: how far the image goes, or None once the
+    /// glide has slowed to a stop
+    pub fn step(&mut self, elapsed: Duration) -> Option<(f32, f32)> {
+        let seconds = elapsed.as_secs_f32();
+        let delta = (self.velocity.0 * seconds, self.velocity.1 * seconds);
+        let decay = DECAY_PER_SECOND.powf(seconds);
+        self.velocity = (self.velocity.0 * decay, self.velocity.1 * decay);
+        (self.velocity.0.hypot(self.velocity.1) >= MIN_SPEED).then_some(delta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAME: Duration = Duration::from_millis(16);
+
+    #[test]
+    fn test_velocity_from_recent_movement() {
+        // Arrange - 10 px right every 10 ms
+        let start = Instant::now();
+        let mut tracker = VelocityTracker::default();
+        for i in 1..=5 {
+            tracker.record(start + Duration::from_millis(10 * i), (10.0, 0.0));
+        }
+
+        // Act
+        let (vx, vy) = tracker.velocity(start + Duration::from_millis(50));
+
+        // Assert - 50 px over the 40 ms since the first sample
+        assert!((vx - 1250.0).abs() < 0.5, "vx = {vx}");
+        assert_eq!(vy, 0.0);
+    }
+
+    #[test]
+    fn test_velocity_is_zero_after_coming_to_rest() {
+        let start = Instant::now();
+        let mut tracker = VelocityTracker::default();
+        tracker.record(start, (30.0, 30.0));
+
+        // Held still past the window before letting go
+        assert_eq!(
+            tracker.velocity(start + Duration::from_millis(200)),
+            (0.0, 0.0)
+        );
+
+        tracker.clear();
+        assert_eq!(tracker.velocity(start), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_glide_needs_speed_and_is_capped() {
+        assert_eq!(Glide::new((10.0, 10.0)), None);
+        assert_eq!(Glide::new((f32::NAN, 0.0)), None);
+
+        // Act - far past the cap
+        let mut glide = Glide::new((60000.0, 0.0)).unwrap();
+
+        // Assert - the first frame moves at MAX_SPEED
+        let (dx, dy) = glide.step(FRAME).unwrap();
+        assert!((dx - MAX_SPEED * FRAME.as_secs_f32()).abs() < 0.01);
+        assert_eq!(dy, 0.0);
+    }
+
+    #[test]
+    fn test_glide_slows_to_a_stop() {
+        // Arrange
+        let mut glide = Glide::new((0.0, -2000.0)).unwrap();
+
+        // Act - run frames until it stops
+        let mut previous = f32::INFINITY;
+        let mut frames = 0;
+        while let Some((_, dy)) = glide.step(FRAME) {
+            // Assert - always the same way, and slowing
+            assert!(dy < 0.0);
+            assert!(dy.abs() < previous);
+            previous = dy.abs();
+            frames += 1;
+        }
+
+        // Assert - a second or so, not forever
+        assert!((10..200).contains(&frames), "frames = {frames}");
+    }
+}
@@ -0,0 +1,214 @@
+//! Non-square pixels: reading a pixel aspect ratio (PAR) from file metadata
+//! and the stretched size an image should be laid out at.
+//!
+//! PAR here is pixel width / pixel height.  It comes from the horizontal and
+//! vertical densities a file records — PNG `pHYs`, the JFIF header of a
+//! JPEG, TIFF `XResolution`/`YResolution` — so a file with twice as many
+//! pixels per inch across as down has PAR 0.5 (tall, narrow pixels).
+
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+
+/// Ratios outside this range are treated as broken metadata, not as
+/// anamorphic content.
+const MAX_PLAUSIBLE_PAR: f32 = 8.0;
+
+/// PAR recorded in `path`'s metadata, or None when the pixels are square,
+/// the format carries no densities, or they can't be read.
+pub fn read_pixel_aspect(path: &Path) -> Option<f32> {
+    let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+    let (x_density, y_density) = match ext.as_str() {
+        "png" => png_densities(path)?,
+        "jpg" | "jpeg" => jfif_densities(path)?,
+        "tif" | "tiff" => tiff_densities(path)?,
+        _ => return None,
+    };
+    pixel_aspect_from_densities(x_density, y_density)
+}
+
+/// PAR for `x_density` / `y_density` pixels per unit length, or None when
+/// square (to within rounding) or implausible.
+pub fn pixel_aspect_from_densities(x_density: f64, y_density: f64) -> Option<f32> {
+    if !(x_density > 0.0 && y_density > 0.0) {
+        return None;
+    }
+    let par = (y_density / x_density) as f32;
+    let plausible = (1.0 / MAX_PLAUSIBLE_PAR..=MAX_PLAUSIBLE_PAR).contains(&par);
+    (plausible && (par - 1.0).abs() > 0.001).then_some(par)
+}
+
+/// Size to lay out a `width` x `height` image with pixel aspect `par` at,
+/// so it appears with its intended proportions.  Stretches the shorter
+/// pixel dimension rather than squeezing the longer one, so no source
+/// pixel is drawn smaller than 1:1 at 100% zoom.
+pub fn display_size(width: u32, height: u32, par: f32) -> (u32, u32) {
+    if par > 1.0 {
+        (((width as f32 * par).round() as u32).max(1), height)
+    } else if par > 0.0 && par < 1.0 {
+        (width, ((height as f32 / par).round() as u32).max(1))
+    } else {
+        (width, height)
+    }
+}
+
+/// Pixels per unit from a PNG's `pHYs` chunk (which must precede `IDAT`).
+fn png_densities(path: &Path) -> Option<(f64, f64)> {
+    let mut reader = BufReader::new(File::open(path).ok()?);
+    let mut signature = [0u8; 8];
+    reader.read_exact(&mut signature).ok()?;
+    if signature != *b"\x89PNG\r\n\x1a\n" {
+        return None;
+    }
+    loop {
+        let mut header = [0u8; 8];
+        reader.read_exact(&mut header).ok()?;
+        let length = u32::from_be_bytes([header[0], header[1], header[2], header[3]]);
+        match &header[4..8] {
+            b"pHYs" if length >= 9 => {
+                let mut data = [0u8; 9];
+                reader.read_exact(&mut data).ok()?;
+                let x = u32::from_be_bytes([data[0], data[1], data[2], data[3]]);
+                let y = u32::from_be_bytes([data[4], data[5], data[6], data[7]]);
+                return Some((f64::from(x), f64::from(y)));
+            }
+            b"IDAT" | b"IEND" => return None,
+            // Skip the chunk body and its CRC
+            _ => {
+                reader.seek(SeekFrom::Current(i64::from(length) + 4)).ok()?;
+            }
+        }
+    }
+}
+
+/// Densities from a JPEG's JFIF APP0 segment.  With units 0 they are
+/// specifically the pixel aspect ratio; with dpi/dpcm they imply it.
+fn jfif_densities(path: &Path) -> Option<(f64, f64)> {
+    let mut header = [0u8; 18];
+    File::open(path).ok()?.read_exact(&mut header).ok()?;
+    // SOI, APP0 marker, 2-byte length, "JFIF\0", version, units, densities
+    if header[0..4] != [0xFF, 0xD8, 0xFF, 0xE0] || &header[6..11] != b"JFIF\0" {
+        return None;
+    }
+    let x = u16::from_be_bytes([header[14], header[15]]);
+    let y = u16::from_be_bytes([header[16], header[17]]);
+    Some((f64::from(x), f64::from(y)))
+}
+
+/// `XResolution` / `YResolution` of a TIFF's first page.
+fn tiff_densities(path: &Path) -> Option<(f64, f64)> {
+    use tiff::decoder::ifd::Value;
+    use tiff::tags::Tag;
+
+    fn as_f64(value: Value) -> Option<f64> {
+        match value {
+            Value::Rational(n, d) if d != 0 => Some(f64::from(n) / f64::from(d)),
+            Value::List(mut values) if !values.is_empty() => as_f64(values.swap_remove(0)),
+            other => other.into_u64().ok().map(|v| v as f64),
+        }
+    }
+
+    let reader = BufReader::new(File::open(path).ok()?);
+    let mut decoder = tiff::decoder::Decoder::new(reader).ok()?;
+    let x = as_f64(decoder.find_tag(Tag::XResolution).ok()??)?;
+    let y = as_f64(decoder.find_tag(Tag::YResolution).ok()??)?;
+    Some((x, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::TempDir;
+
+    #[test]
+    fn display_size_stretches_the_short_pixel_side() {
+        // Wide pixels widen the image, tall pixels heighten it
+        assert_eq!(display_size(720, 480, 1.5), (1080, 480));
+        assert_eq!(display_size(720, 480, 0.5), (720, 960));
+        assert_eq!(display_size(720, 576, 16.0 / 15.0), (768, 576));
+    }
+
+    #[test]
+    fn display_size_square_or_invalid_par_is_native() {
+        assert_eq!(display_size(640, 480, 1.0), (640, 480));
+        assert_eq!(display_size(640, 480, 0.0), (640, 480));
+        assert_eq!(display_size(640, 480, -2.0), (640, 480));
+    }
+
+    #[test]
+    fn densities_to_par() {
+        assert_eq!(pixel_aspect_from_densities(72.0, 72.0), None);
+        assert_eq!(pixel_aspect_from_densities(200.0, 100.0), Some(0.5));
+        assert_eq!(pixel_aspect_from_densities(100.0, 150.0), Some(1.5));
+        // Missing or absurd densities are ignored
+        assert_eq!(pixel_aspect_from_densities(0.0, 72.0), None);
+        assert_eq!(pixel_aspect_from_densities(1.0, 300.0), None);
+    }
+
+    #[test]
+    fn reads_png_phys() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("anamorphic.png");
+        let mut encoder = png::Encoder::new(File::create(&path).unwrap(), 2, 2);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_pixel_dims(Some(png::PixelDimensions {
+            xppu: 2000,
+            yppu: 3000,
+            unit: png::Unit::Meter,
+        }));
+        let mut writer = encoder.write_header().unwrap();
+        writer.write_image_data(&[0u8; 16]).unwrap();
+        writer.finish().unwrap();
+
+        // Act / Assert
+        assert_eq!(read_pixel_aspect(&path), Some(1.5));
+    }
+
+    #[test]
+    fn reads_jfif_density() {
+        // Arrange - just the header; nothing past it is read
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("aspect.jpg");
+        let mut file = File::create(&path).unwrap();
+        file.write_all(&[0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x10])
+            .unwrap();
+        file.write_all(b"JFIF\0\x01\x02\x00").unwrap();
+        file.write_all(&[0x00, 0x0B, 0x00, 0x0A, 0x00, 0x00])
+            .unwrap();
+
+        // Act / Assert - densities 11:10 give NTSC DV's 10:11 pixels
+        let par = read_pixel_aspect(&path).unwrap();
+        assert!((par - 10.0 / 11.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn reads_tiff_resolution() {
+        use tiff::encoder::{Rational, TiffEncoder, colortype};
+
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("aspect.tiff");
+        let mut encoder = TiffEncoder::new(File::create(&path).unwrap()).unwrap();
+        let mut image = encoder.new_image::<colortype::RGB8>(2, 2).unwrap();
+        image.x_resolution(Rational { n: 300, d: 1 });
+        image.y_resolution(Rational { n: 150, d: 1 });
+        image.write_data(&[0u8; 12]).unwrap();
+
+        // Act / Assert - tall pixels
+        assert_eq!(read_pixel_aspect(&path), Some(0.5));
+    }
+
+    #[test]
+    fn square_pixels_and_unknown_formats_are_none() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let png_path = dir.path().join("square.png");
+        image::RgbaImage::new(2, 2).save(&png_path).unwrap();
+
+        // Act / Assert
+        assert_eq!(read_pixel_aspect(&png_path), None);
+        assert_eq!(read_pixel_aspect(&dir.path().join("missing.gif")), None);
+    }
+}
@@ -0,0 +1,131 @@
+//! Renaming an image file in place, keeping its extension.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Where `path` goes when its stem becomes `new_stem`: same folder, same
+/// extension.  Names that would leave the folder or are empty are refused.
+pub fn renamed_path(path: &Path, new_stem: &str) -> Result<PathBuf, String> {
+    let stem = new_stem.trim();
+    if stem.is_empty() || stem == "." || stem == ".." {
+        return Err("Enter a file name".to_string());
+    }
+    if stem.contains(['/', '\\']) {
+        return Err("File names can't contain / or \\".to_string());
+    }
+    let mut name = stem.to_string();
+    if let Some(ext) = path.extension() {
+        name.push('.');
+        name.push_str(&ext.to_string_lossy());
+    }
+    Ok(path.with_file_name(name))
+}
+
+/// Rename `path` to `new_stem` (see `renamed_path`), returning the new
+/// path.  An existing file is never overwritten.
+pub fn rename_file(path: &Path, new_stem: &str) -> Result<PathBuf, String> {
+    let destination = renamed_path(path, new_stem)?;
+    if destination == path {
+        return Ok(destination);
+    }
+    // Let a case-only change through on case-insensitive file systems,
+    // where the "existing" file is the source itself
+    if destination.exists() && !same_file(path, &destination) {
+        let name = destination
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy();
+        return Err(format!("{} already exists", name));
+    }
+    fs::rename(path, &destination).map_err(|e| e.to_string())?;
+    Ok(destination)
+}
+
+/// Whether `a` and `b` are the same file on disk rather than two files
+/// whose names happen to match
+fn same_file(a: &Path, b: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        match (fs::metadata(a), fs::metadata(b)) {
+            (Ok(a), Ok(b)) => a.dev() == b.dev() && a.ino() == b.ino(),
+            _ => false,
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        match (a.canonicalize(), b.canonicalize()) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_renamed_path_keeps_folder_and_extension() {
+        assert_eq!(
+            renamed_path(Path::new("/shots/IMG_0001.JPG"), " beach "),
+            Ok(PathBuf::from("/shots/beach.JPG"))
+        );
+        assert_eq!(
+            renamed_path(Path::new("/shots/README"), "notes"),
+            Ok(PathBuf::from("/shots/notes"))
+        );
+    }
+
+    #[test]
+    fn test_renamed_path_rejects_bad_names() {
+        let path = Path::new("/shots/a.png");
+        assert!(renamed_path(path, "").is_err());
+        assert!(renamed_path(path, "   ").is_err());
+        assert!(renamed_path(path, "..").is_err());
+        assert!(renamed_path(path, "sub/b").is_err());
+        assert!(renamed_path(path, "..\\b").is_err());
+    }
+
+    #[test]
+    fn test_rename_file_moves_and_refuses_collisions() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("a.png");
+        fs::write(&source, b"a").unwrap();
+        fs::write(dir.path().join("taken.png"), b"t").unwrap();
+
+        // Act
+        let collision = rename_file(&source, "taken");
+        let renamed = rename_file(&source, "b").unwrap();
+
+        // Assert
+        assert!(collision.is_err());
+        assert_eq!(fs::read(dir.path().join("taken.png")).unwrap(), b"t");
+        assert_eq!(renamed, dir.path().join("b.png"));
+        assert!(!source.exists());
+        assert_eq!(fs::read(&renamed).unwrap(), b"a");
+    }
+
+    #[test]
+    fn test_rename_file_refuses_a_different_file_differing_in_case() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let lower = dir.path().join("a.png");
+        let upper = dir.path().join("A.png");
+        fs::write(&lower, b"a").unwrap();
+        fs::write(&upper, b"A").unwrap();
+        if fs::read(&lower).unwrap() != b"a" {
+            // Case-insensitive file system: these are one file
+            return;
+        }
+
+        // Act
+        let result = rename_file(&lower, "A");
+
+        // Assert - both files are still there, untouched
+        assert!(result.is_err());
+        assert_eq!(fs::read(&lower).unwrap(), b"a");
+        assert_eq!(fs::read(&upper).unwrap(), b"A");
+    }
+}
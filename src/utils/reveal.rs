@@ -0,0 +1,66 @@
+//! Showing a file in the operating system's file manager, selected where
+//! the file manager supports that.
+
+use std::ffi::OsString;
+use std::path::Path;
+use std::process::Command;
+
+/// The program and arguments that reveal `path` on `os` (a
+/// `std::env::consts::OS` name), or `None` where there is no way to
+pub fn reveal_command(os: &str, path: &Path) -> Option<(&'static str, Vec<OsString>)> {
+    match os {
+        "macos" => Some(("open", vec!["-R".into(), path.as_os_str().to_owned()])),
+        "windows" => Some((
+            "explorer",
+            vec!["/select,".into(), path.as_os_str().to_owned()],
+        )),
+        // xdg-open can't select a file, so open the folder holding it
+        "linux" | "freebsd" | "dragonfly" | "netbsd" | "openbsd" => {
+            Some(("xdg-open", vec![path.parent()?.as_os_str().to_owned()]))
+        }
+        _ => None,
+    }
+}
+
+/// Reveal `path` in this platform's file manager
+pub fn reveal_in_file_manager(path: &Path) -> Result<(), String> {
+    let (program, args) = reveal_command(std::env::consts::OS, path)
+        .ok_or_else(|| "Reveal in file manager not supported here".to_string())?;
+    Command::new(program)
+        .args(args)
+        .spawn()
+        .map(drop)
+        .map_err(|e| format!("Couldn't run {}: {}", program, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reveal_command_per_platform() {
+        let path = Path::new("/shots/a b.png");
+        let expect = |program: &'static str, args: &[&str]| {
+            Some((program, args.iter().map(OsString::from).collect::<Vec<_>>()))
+        };
+
+        assert_eq!(
+            reveal_command("macos", path),
+            expect("open", &["-R", "/shots/a b.png"])
+        );
+        assert_eq!(
+            reveal_command("windows", path),
+            expect("explorer", &["/select,", "/shots/a b.png"])
+        );
+        assert_eq!(
+            reveal_command("linux", path),
+            expect("xdg-open", &["/shots"])
+        );
+    }
+
+    #[test]
+    fn test_reveal_command_without_a_folder_or_launcher() {
+        assert_eq!(reveal_command("linux", Path::new("/")), None);
+        assert_eq!(reveal_command("ios", Path::new("a.png")), None);
+    }
+}
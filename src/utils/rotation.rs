@@ -0,0 +1,99 @@
+//! Quarter-turn rotation of displayed and exported images.
+//!
+//! Rotation is stored per image in `ImageState::rotation` (clockwise
+//! degrees) and applied on the CPU: the viewer bakes it into the filtered
+//! render, and saving writes the rotated pixels.
+
+use image::DynamicImage;
+
+/// `(width, height)` after a `rotation`-degree turn: swapped for 90 and 270
+pub fn rotated_size((width, height): (u32, u32), rotation: u16) -> (u32, u32) {
+    if rotation % 180 == 90 {
+        (height, width)
+    } else {
+        (width, height)
+    }
+}
+
+/// Turn `image` clockwise by `rotation` degrees (0, 90, 180 or 270;
+/// anything else leaves it as is)
+pub fn rotate_image(image: DynamicImage, rotation: u16) -> DynamicImage {
+    match rotation {
+        90 => image.rotate90(),
+        180 => image.rotate180(),
+        270 => image.rotate270(),
+        _ => image,
+    }
+}
+
+/// The pixel of a `(width, height)` source that a `rotation`-degree turn
+/// puts at `(x, y)`
+pub fn source_point((x, y): (u32, u32), (width, height): (u32, u32), rotation: u16) -> (u32, u32) {
+    match rotation {
+        90 => (y, height - 1 - x),
+        180 => (width - 1 - x, height - 1 - y),
+        270 => (width - 1 - y, x),
+        _ => (x, y),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgba, RgbaImage};
+
+    /// 2×1 image: red on the left, blue on the right
+    fn red_blue() -> DynamicImage {
+        let mut img = RgbaImage::new(2, 1);
+        img.put_pixel(0, 0, Rgba([255, 0, 0, 255]));
+        img.put_pixel(1, 0, Rgba([0, 0, 255, 255]));
+        DynamicImage::ImageRgba8(img)
+    }
+
+    #[test]
+    fn test_rotated_size_swaps_for_quarter_turns() {
+        assert_eq!(rotated_size((4, 3), 0), (4, 3));
+        assert_eq!(rotated_size((4, 3), 90), (3, 4));
+        assert_eq!(rotated_size((4, 3), 180), (4, 3));
+        assert_eq!(rotated_size((4, 3), 270), (3, 4));
+    }
+
+    #[test]
+    fn test_rotate_image_clockwise() {
+        // Act - a clockwise quarter turn puts the left pixel on top
+        let rotated = rotate_image(red_blue(), 90).into_rgba8();
+
+        // Assert
+        assert_eq!(rotated.dimensions(), (1, 2));
+        assert_eq!(rotated.get_pixel(0, 0), &Rgba([255, 0, 0, 255]));
+        assert_eq!(rotated.get_pixel(0, 1), &Rgba([0, 0, 255, 255]));
+    }
+
+    #[test]
+    fn test_rotate_image_counter_clockwise_and_half_turn() {
+        let ccw = rotate_image(red_blue(), 270).into_rgba8();
+        assert_eq!(ccw.get_pixel(0, 0), &Rgba([0, 0, 255, 255]));
+
+        let half = rotate_image(red_blue(), 180).into_rgba8();
+        assert_eq!(half.dimensions(), (2, 1));
+        assert_eq!(half.get_pixel(0, 0), &Rgba([0, 0, 255, 255]));
+    }
+
+    #[test]
+    fn test_source_point_undoes_each_turn() {
+        // Arrange - a 3×2 image with every pixel distinct
+        let source = RgbaImage::from_fn(3, 2, |x, y| Rgba([x as u8, y as u8, 0, 255]));
+
+        for rotation in [0, 90, 180, 270] {
+            // Act
+            let turned = rotate_image(DynamicImage::ImageRgba8(source.clone()), rotation);
+            let turned = turned.into_rgba8();
+
+            // Assert - each turned pixel came from the point we map it to
+            for (x, y, px) in turned.enumerate_pixels() {
+                let (sx, sy) = source_point((x, y), (3, 2), rotation);
+                assert_eq!(*px, *source.get_pixel(sx, sy), "{rotation}° at ({x}, {y})");
+            }
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//! Scratch directory for temporary files (SVG rasterizations).
+//!
+//! Defaults to the OS temp dir.  Users with a small `/tmp` can point
+//! `file_operations.scratch_directory` somewhere roomier; the choice is
+//! validated when applied and silently falls back to the OS temp dir if the
+//! directory is missing or not writable.
+
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+/// Resolved scratch directory (None = not yet applied, use the OS temp dir)
+static SCRATCH_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Whether `dir` is an existing directory we can create files in.
+pub fn is_writable_dir(dir: &Path) -> bool {
+    dir.is_dir() && tempfile::tempfile_in(dir).is_ok()
+}
+
+/// The directory to use for `configured`: itself if writable, otherwise the
+/// OS temp dir.
+pub fn resolve_scratch_dir(configured: Option<&Path>) -> PathBuf {
+    match configured {
+        Some(dir) if is_writable_dir(dir) => dir.to_path_buf(),
+        _ => std::env::temp_dir(),
+    }
+}
+
+/// Apply the configured scratch directory for the rest of the process.
+/// Returns the directory actually in use.
+pub fn set_scratch_dir(configured: Option<&Path>) -> PathBuf {
+    let resolved = resolve_scratch_dir(configured);
+    if let Some(dir) = configured
+        && resolved != dir
+    {
+        log::warn!(
+            "Scratch directory {} is not writable; using {}",
+            dir.display(),
+            resolved.display()
+        );
+    }
+    if let Ok(mut slot) = SCRATCH_DIR.write() {
+        *slot = Some(resolved.clone());
+    }
+    resolved
+}
+
+/// The directory temp files should be created in.
+pub fn scratch_dir() -> PathBuf {
+    SCRATCH_DIR
+        .read()
+        .ok()
+        .and_then(|slot| slot.clone())
+        .unwrap_or_else(std::env::temp_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_uses_writable_configured_dir() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+
+        // Act
+        let resolved = resolve_scratch_dir(Some(dir.path()));
+
+        // Assert
+        assert_eq!(resolved, dir.path());
+    }
+
+    #[test]
+    fn resolve_falls_back_when_dir_missing() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let missing = dir.path().join("does_not_exist");
+
+        // Act
+        let resolved = resolve_scratch_dir(Some(&missing));
+
+        // Assert
+        assert_eq!(resolved, std::env::temp_dir());
+    }
+
+    #[test]
+    fn resolve_falls_back_when_path_is_a_file() {
+        // Arrange
+        let file = tempfile::NamedTempFile::new().unwrap();
+
+        // Act
+        let resolved = resolve_scratch_dir(Some(file.path()));
+
+        // Assert
+        assert_eq!(resolved, std::env::temp_dir());
+    }
+
+    #[test]
+    fn resolve_unset_uses_os_temp_dir() {
+        assert_eq!(resolve_scratch_dir(None), std::env::temp_dir());
+    }
+}
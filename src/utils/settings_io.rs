@@ -9,10 +9,14 @@
 //! For high-frequency callers (window-bounds observers fire on every drag
 //! tick), use `save_settings_debounced`, which coalesces requests and writes
 //! at most once per `DEBOUNCE_INTERVAL`.
+//!
+//! The last session's image list lives alongside the settings in
+//! `session.json` (see `SessionState`).
 
 use crate::state::settings::AppSettings;
+use serde::{Deserialize, Serialize};
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 use std::sync::mpsc::{self, Sender};
 use std::time::{Duration, Instant};
@@ -32,25 +36,35 @@ const DEBOUNCE_INTERVAL: Duration = Duration::from_millis(250);
 /// Falls back to ~/.rpview/settings.json if config directory is unavailable,
 /// or ./rpview_settings.json as a last resort.
 pub fn get_settings_path() -> PathBuf {
+    config_dir().join("settings.json")
+}
+
+/// Get the path to the session file (next to the settings file)
+pub fn get_session_path() -> PathBuf {
+    config_dir().join("session.json")
+}
+
+/// The rpview config directory, created if missing
+fn config_dir() -> PathBuf {
     // Try platform config directory first
     let config_dir = if let Some(config) = dirs::config_dir() {
         config.join("rpview")
     } else if let Some(home) = dirs::home_dir() {
         // Fallback to home directory
-        eprintln!("Warning: Could not find config directory, using home directory");
+        log::warn!("Could not find config directory, using home directory");
         home.join(".rpview")
     } else {
         // Last resort: current directory
-        eprintln!("Warning: Could not find config or home directory, using current directory");
+        log::warn!("Could not find config or home directory, using current directory");
         PathBuf::from(".")
     };
 
     // Create directory if it doesn't exist
     if let Err(e) = std::fs::create_dir_all(&config_dir) {
-        eprintln!("Warning: Could not create config directory: {}", e);
+        log::warn!("Could not create config directory: {}", e);
     }
 
-    config_dir.join("settings.json")
+    config_dir
 }
 
 /// Save settings to disk
@@ -69,20 +83,25 @@ pub fn save_settings(settings: &AppSettings) -> Result<(), String> {
 pub fn save_settings_to_path(settings: &AppSettings, path: &std::path::Path) -> Result<(), String> {
     let json = serde_json::to_string_pretty(settings)
         .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+    write_atomically(&json, path, "settings")
+}
 
+/// Stage `contents` into a sibling temp file and rename it over `path`.
+/// `what` names the file in error messages.
+fn write_atomically(contents: &str, path: &Path, what: &str) -> Result<(), String> {
     let parent = path
         .parent()
-        .ok_or_else(|| "Settings path has no parent directory".to_string())?;
+        .ok_or_else(|| format!("{} path has no parent directory", what))?;
 
     let mut tmp = tempfile::NamedTempFile::new_in(parent)
-        .map_err(|e| format!("Failed to create temp settings file: {}", e))?;
-    tmp.write_all(json.as_bytes())
-        .map_err(|e| format!("Failed to write temp settings file: {}", e))?;
+        .map_err(|e| format!("Failed to create temp {} file: {}", what, e))?;
+    tmp.write_all(contents.as_bytes())
+        .map_err(|e| format!("Failed to write temp {} file: {}", what, e))?;
     tmp.as_file()
         .sync_all()
-        .map_err(|e| format!("Failed to sync temp settings file: {}", e))?;
+        .map_err(|e| format!("Failed to sync temp {} file: {}", what, e))?;
     tmp.persist(path)
-        .map_err(|e| format!("Failed to persist settings file: {}", e))?;
+        .map_err(|e| format!("Failed to persist {} file: {}", what, e))?;
 
     Ok(())
 }
@@ -128,7 +147,7 @@ fn spawn_debounce_worker() -> Sender<AppSettings> {
                 }
 
                 if let Err(e) = save_settings(&latest) {
-                    eprintln!("Warning: debounced settings write failed: {}", e);
+                    log::warn!("debounced settings write failed: {}", e);
                 }
                 last_write = Some(Instant::now());
             }
@@ -157,8 +176,8 @@ pub fn load_settings_from_path(path: &std::path::Path) -> AppSettings {
     let json = match std::fs::read_to_string(path) {
         Ok(json) => json,
         Err(e) => {
-            eprintln!("Warning: Failed to read settings file: {}", e);
-            eprintln!("Using default settings");
+            log::warn!("Failed to read settings file: {}", e);
+            log::warn!("Using default settings");
             return AppSettings::default();
         }
     };
@@ -167,33 +186,231 @@ pub fn load_settings_from_path(path: &std::path::Path) -> AppSettings {
     match serde_json::from_str(&json) {
         Ok(settings) => settings,
         Err(e) => {
-            eprintln!("Warning: Failed to parse settings file: {}", e);
+            log::warn!("Failed to parse settings file: {}", e);
 
             // Back up the corrupt file
             let backup_path = path.with_extension("json.backup");
             if let Err(backup_err) = std::fs::copy(path, &backup_path) {
-                eprintln!(
-                    "Warning: Failed to backup corrupt settings file: {}",
-                    backup_err
-                );
+                log::warn!("Failed to backup corrupt settings file: {}", backup_err);
             } else {
-                eprintln!(
+                log::warn!(
                     "Corrupt settings file backed up to: {}",
                     backup_path.display()
                 );
             }
 
-            eprintln!("Using default settings");
+            log::warn!("Using default settings");
             AppSettings::default()
         }
     }
 }
 
+/// Read settings exported from another machine.  Unlike
+/// `load_settings_from_path` this never falls back to defaults: a file
+/// that can't be read or isn't valid settings JSON is an error, so the
+/// caller can keep what it has.
+pub fn import_settings_from_path(path: &Path) -> Result<AppSettings, String> {
+    let json = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    serde_json::from_str(&json).map_err(|e| format!("Not a valid settings file: {}", e))
+}
+
+/// The image list a window was showing when the app last quit, so an
+/// arbitrary hand-assembled list (not just one folder) survives a restart.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionState {
+    pub image_paths: Vec<PathBuf>,
+    pub current_index: usize,
+}
+
+impl SessionState {
+    /// Drop paths that no longer exist.  The index follows the image that
+    /// was current if it survived, otherwise the next surviving one.
+    /// `None` if nothing is left to restore.
+    pub fn pruned(self) -> Option<SessionState> {
+        let mut current_index = 0;
+        let mut image_paths = Vec::with_capacity(self.image_paths.len());
+        for (i, path) in self.image_paths.into_iter().enumerate() {
+            if !path.exists() {
+                continue;
+            }
+            if i < self.current_index {
+                current_index = image_paths.len() + 1;
+            } else if i == self.current_index {
+                current_index = image_paths.len();
+            }
+            image_paths.push(path);
+        }
+        if image_paths.is_empty() {
+            return None;
+        }
+        Some(SessionState {
+            current_index: current_index.min(image_paths.len() - 1),
+            image_paths,
+        })
+    }
+}
+
+/// Save the session to disk.  An empty list is not saved, so quitting from
+/// an empty window keeps the previous session available.
+pub fn save_session(session: &SessionState) -> Result<(), String> {
+    save_session_to_path(session, &get_session_path())
+}
+
+/// Save the session to a specific path (used for testing)
+pub fn save_session_to_path(session: &SessionState, path: &Path) -> Result<(), String> {
+    if session.image_paths.is_empty() {
+        return Ok(());
+    }
+    let json = serde_json::to_string_pretty(session)
+        .map_err(|e| format!("Failed to serialize session: {}", e))?;
+    write_atomically(&json, path, "session")
+}
+
+/// Load the last session, pruned of files that have since disappeared.
+/// `None` if there is no session file, it can't be read, or nothing in it
+/// still exists.
+pub fn load_session() -> Option<SessionState> {
+    load_session_from_path(&get_session_path())
+}
+
+/// Load the session from a specific path (used for testing)
+pub fn load_session_from_path(path: &Path) -> Option<SessionState> {
+    let json = std::fs::read_to_string(path).ok()?;
+    match serde_json::from_str::<SessionState>(&json) {
+        Ok(session) => session.pruned(),
+        Err(e) => {
+            log::warn!("Failed to parse session file: {}", e);
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use tempfile::TempDir;
 
+    /// Create `names` as empty files in `dir`, returning their paths.
+    fn touch_all(dir: &Path, names: &[&str]) -> Vec<PathBuf> {
+        names
+            .iter()
+            .map(|name| {
+                let path = dir.join(name);
+                std::fs::write(&path, b"").unwrap();
+                path
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_session_roundtrip() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let session = SessionState {
+            image_paths: touch_all(temp_dir.path(), &["a.png", "b.png", "c.png"]),
+            current_index: 2,
+        };
+        let path = temp_dir.path().join("session.json");
+
+        // Act
+        save_session_to_path(&session, &path).unwrap();
+        let loaded = load_session_from_path(&path);
+
+        // Assert
+        assert_eq!(loaded, Some(session));
+    }
+
+    #[test]
+    fn test_session_restore_prunes_missing_files() {
+        // Arrange — c.png (current) and a.png disappear after saving
+        let temp_dir = TempDir::new().unwrap();
+        let paths = touch_all(temp_dir.path(), &["a.png", "b.png", "c.png", "d.png"]);
+        let session = SessionState {
+            image_paths: paths.clone(),
+            current_index: 2,
+        };
+        let path = temp_dir.path().join("session.json");
+        save_session_to_path(&session, &path).unwrap();
+        std::fs::remove_file(&paths[0]).unwrap();
+        std::fs::remove_file(&paths[2]).unwrap();
+
+        // Act
+        let loaded = load_session_from_path(&path).unwrap();
+
+        // Assert — the index moves to the next surviving image
+        assert_eq!(loaded.image_paths, vec![paths[1].clone(), paths[3].clone()]);
+        assert_eq!(loaded.current_index, 1);
+    }
+
+    #[test]
+    fn test_session_prune_keeps_current_image_selected() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let mut paths = touch_all(temp_dir.path(), &["b.png", "c.png"]);
+        paths.insert(0, temp_dir.path().join("gone.png"));
+        let session = SessionState {
+            image_paths: paths.clone(),
+            current_index: 2,
+        };
+
+        // Act
+        let pruned = session.pruned().unwrap();
+
+        // Assert
+        assert_eq!(pruned.image_paths[pruned.current_index], paths[2]);
+    }
+
+    #[test]
+    fn test_session_prune_last_image_missing_clamps_index() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let mut paths = touch_all(temp_dir.path(), &["a.png"]);
+        paths.push(temp_dir.path().join("gone.png"));
+        let session = SessionState {
+            image_paths: paths,
+            current_index: 1,
+        };
+
+        // Act
+        let pruned = session.pruned().unwrap();
+
+        // Assert
+        assert_eq!(pruned.current_index, 0);
+    }
+
+    #[test]
+    fn test_session_all_missing_restores_nothing() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("session.json");
+        let session = SessionState {
+            image_paths: vec![temp_dir.path().join("gone.png")],
+            current_index: 0,
+        };
+        save_session_to_path(&session, &path).unwrap();
+
+        // Act & Assert
+        assert_eq!(load_session_from_path(&path), None);
+    }
+
+    #[test]
+    fn test_empty_session_is_not_saved() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("session.json");
+        let session = SessionState {
+            image_paths: Vec::new(),
+            current_index: 0,
+        };
+
+        // Act
+        save_session_to_path(&session, &path).unwrap();
+
+        // Assert
+        assert!(!path.exists());
+    }
+
     #[test]
     fn test_get_settings_path() {
         let path = get_settings_path();
@@ -267,6 +484,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_last_sort_mode_roundtrip() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let test_path = temp_dir.path().join("sort_settings.json");
+        let mut settings = AppSettings::default();
+        settings.sort_navigation.last_sort_mode = Some(crate::state::SortMode::ModifiedDate);
+
+        // Act
+        save_settings_to_path(&settings, &test_path).unwrap();
+        let loaded = load_settings_from_path(&test_path);
+
+        // Assert - the runtime choice wins over the (alphabetical) default
+        assert_eq!(
+            loaded.sort_navigation.startup_sort_mode(),
+            crate::state::SortMode::ModifiedDate
+        );
+        assert_eq!(
+            loaded.sort_navigation.default_sort_mode,
+            crate::state::SortMode::Alphabetical
+        );
+    }
+
+    #[test]
+    fn test_channel_offset_defaults_roundtrip() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let test_path = temp_dir.path().join("offset_settings.json");
+        let mut settings = AppSettings::default();
+        settings.filters.default_red_offset = 12.0;
+        settings.filters.default_green_offset = -7.0;
+        settings.filters.default_blue_offset = 100.0;
+
+        // Act
+        save_settings_to_path(&settings, &test_path).unwrap();
+        let loaded = load_settings_from_path(&test_path);
+
+        // Assert
+        assert_eq!(loaded.filters, settings.filters);
+    }
+
     #[test]
     fn test_load_empty_file_returns_defaults() {
         let temp_dir = TempDir::new().unwrap();
@@ -311,6 +569,36 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_import_settings_roundtrip() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("exported.json");
+        let mut settings = AppSettings::default();
+        settings.appearance.font_size_scale = 1.5;
+        save_settings_to_path(&settings, &path).unwrap();
+
+        // Act
+        let imported = import_settings_from_path(&path);
+
+        // Assert
+        assert_eq!(imported, Ok(settings));
+    }
+
+    #[test]
+    fn test_import_settings_rejects_bad_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("exported.json");
+
+        // Missing file
+        assert!(import_settings_from_path(&path).is_err());
+
+        // Malformed JSON: an error, and no backup left behind
+        std::fs::write(&path, "{ invalid json }").unwrap();
+        assert!(import_settings_from_path(&path).is_err());
+        assert!(!path.with_extension("json.backup").exists());
+    }
+
     #[test]
     fn test_corrupt_backup_preserves_original_content() {
         let temp_dir = TempDir::new().unwrap();
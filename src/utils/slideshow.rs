@@ -0,0 +1,264 @@
+//! Slideshow: advance to the next image every few seconds.
+
+use std::time::{Duration, Instant};
+
+/// Shortest interval accepted, so a zero or negative setting can't spin
+pub const MIN_INTERVAL_SECS: f32 = 0.5;
+
+/// What the slideshow should do on this frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlideshowTick {
+    /// Keep showing the current image
+    Wait,
+    /// Time's up: move to the next image
+    Advance,
+    /// Someone else changed the image; hand control back to the user
+    Stop,
+}
+
+/// A running slideshow: which image it put up, and when
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slideshow {
+    pub index: usize,
+    pub shown_at: Instant,
+}
+
+impl Slideshow {
+    pub fn start(index: usize, now: Instant) -> Self {
+        Self {
+            index,
+            shown_at: now,
+        }
+    }
+
+    /// Decide the next step.  Landing on any index other than the one the
+    /// slideshow put up means the user navigated by hand.  While
+    /// `animation_pending` the interval running out isn't enough: the
+    /// slideshow waits for the animation to finish.
+    pub fn tick(
+        &self,
+        current_index: usize,
+        interval_secs: f32,
+        animation_pending: bool,
+        now: Instant,
+    ) -> SlideshowTick {
+        if current_index != self.index {
+            SlideshowTick::Stop
+        } else if now.duration_since(self.shown_at) >= interval(interval_secs) && !animation_pending
+        {
+            SlideshowTick::Advance
+        } else {
+            SlideshowTick::Wait
+        }
+    }
+}
+
+/// Whether an animation still has passes to play: it's running and asks
+/// for `plays` passes, `passes_played` of them done.  One that loops
+/// forever (`plays` None) never holds the slideshow up.
+pub fn animation_pending(plays: Option<u32>, passes_played: u32, playing: bool) -> bool {
+    playing && plays.is_some_and(|plays| passes_played < plays)
+}
+
+/// Whether a slideshow should start by itself: nothing has been touched
+/// for `timeout_secs` since `last_activity`, and there's been input since
+/// the last time it started this way (`last_idle_start`), so one that ran
+/// to the end of the list doesn't immediately start over
+pub fn idle_start_due(
+    last_activity: Instant,
+    last_idle_start: Option<Instant>,
+    timeout_secs: f32,
+    now: Instant,
+) -> bool {
+    let rearmed = last_idle_start.is_none_or(|started| last_activity > started);
+    rearmed && now.duration_since(last_activity) >= interval(timeout_secs)
+}
+
+/// Calls `on_input` for every keystroke typed into `window`, including
+/// the ones a key binding turns into an action, which never reach the
+/// focused element's key handler
+pub fn observe_window_keystrokes<T: 'static>(
+    window: &gpui::Window,
+    cx: &mut gpui::Context<T>,
+    mut on_input: impl FnMut(&mut T) + 'static,
+) -> gpui::Subscription {
+    let handle = window.window_handle();
+    cx.observe_keystrokes(move |view, _event, window, _cx| {
+        if window.window_handle() == handle {
+            on_input(view);
+        }
+    })
+}
+
+/// The setting as a duration, clamped to `MIN_INTERVAL_SECS`
+pub fn interval(secs: f32) -> Duration {
+    Duration::from_secs_f32(if secs.is_finite() {
+        secs.max(MIN_INTERVAL_SECS)
+    } else {
+        MIN_INTERVAL_SECS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn waits_until_the_interval_elapses() {
+        let start = Instant::now();
+        let show = Slideshow::start(3, start);
+        assert_eq!(
+            show.tick(3, 5.0, false, start + Duration::from_millis(4999)),
+            SlideshowTick::Wait
+        );
+        assert_eq!(
+            show.tick(3, 5.0, false, start + Duration::from_secs(5)),
+            SlideshowTick::Advance
+        );
+    }
+
+    #[test]
+    fn holds_for_an_animation_to_finish() {
+        // Arrange
+        let start = Instant::now();
+        let show = Slideshow::start(3, start);
+        let late = start + Duration::from_secs(30);
+
+        // Act / Assert - past the interval, but two of three passes played
+        let pending = animation_pending(Some(3), 2, true);
+        assert!(pending);
+        assert_eq!(show.tick(3, 5.0, pending, late), SlideshowTick::Wait);
+
+        // Act / Assert - the last pass done
+        let pending = animation_pending(Some(3), 3, true);
+        assert!(!pending);
+        assert_eq!(show.tick(3, 5.0, pending, late), SlideshowTick::Advance);
+
+        // Act / Assert - navigating by hand still wins
+        assert_eq!(show.tick(4, 5.0, true, start), SlideshowTick::Stop);
+    }
+
+    #[test]
+    fn endless_or_paused_animations_dont_hold() {
+        assert!(!animation_pending(None, 0, true));
+        assert!(!animation_pending(Some(3), 0, false));
+    }
+
+    #[test]
+    fn stops_when_navigated_by_hand() {
+        let start = Instant::now();
+        let show = Slideshow::start(3, start);
+        assert_eq!(show.tick(4, 5.0, false, start), SlideshowTick::Stop);
+        assert_eq!(
+            show.tick(2, 5.0, false, start + Duration::from_secs(60)),
+            SlideshowTick::Stop
+        );
+    }
+
+    #[test]
+    fn idle_start_waits_for_the_timeout() {
+        // Arrange
+        let last_activity = Instant::now();
+
+        // Act
+        let early = idle_start_due(
+            last_activity,
+            None,
+            60.0,
+            last_activity + Duration::from_secs(59),
+        );
+        let due = idle_start_due(
+            last_activity,
+            None,
+            60.0,
+            last_activity + Duration::from_secs(60),
+        );
+
+        // Assert
+        assert!(!early);
+        assert!(due);
+    }
+
+    #[test]
+    fn idle_start_needs_input_since_the_last_one() {
+        // Arrange
+        let last_activity = Instant::now();
+        let started = last_activity + Duration::from_secs(60);
+        let later = started + Duration::from_secs(600);
+
+        // Act / Assert - no input since it last started on its own
+        assert!(!idle_start_due(last_activity, Some(started), 60.0, later));
+
+        // Act / Assert - input after that start rearms it
+        let touched = started + Duration::from_secs(1);
+        assert!(idle_start_due(touched, Some(started), 60.0, later));
+        assert!(!idle_start_due(
+            touched,
+            Some(started),
+            60.0,
+            touched + Duration::from_secs(30)
+        ));
+    }
+
+    #[test]
+    fn interval_is_clamped() {
+        assert_eq!(interval(0.0), Duration::from_secs_f32(MIN_INTERVAL_SECS));
+        assert_eq!(interval(-3.0), Duration::from_secs_f32(MIN_INTERVAL_SECS));
+        assert_eq!(
+            interval(f32::NAN),
+            Duration::from_secs_f32(MIN_INTERVAL_SECS)
+        );
+        assert_eq!(interval(2.5), Duration::from_millis(2500));
+    }
+
+    struct KeyCounter {
+        focus: gpui::FocusHandle,
+        keystrokes: usize,
+        navigated: bool,
+        _keystrokes: gpui::Subscription,
+    }
+
+    impl gpui::Render for KeyCounter {
+        fn render(
+            &mut self,
+            _window: &mut gpui::Window,
+            cx: &mut gpui::Context<Self>,
+        ) -> impl gpui::IntoElement {
+            use gpui::{InteractiveElement, ParentElement};
+            gpui::div()
+                .track_focus(&self.focus)
+                .on_action(cx.listener(|this, _: &crate::NextImage, _, _| {
+                    this.navigated = true;
+                }))
+                .child("")
+        }
+    }
+
+    #[gpui::test]
+    fn a_bound_key_counts_as_input(cx: &mut gpui::TestAppContext) {
+        // Arrange
+        cx.update(|cx| cx.bind_keys([gpui::KeyBinding::new("right", crate::NextImage, None)]));
+        let window = cx.add_window(|window, cx| {
+            let focus = cx.focus_handle();
+            window.focus(&focus);
+            KeyCounter {
+                focus,
+                keystrokes: 0,
+                navigated: false,
+                _keystrokes: observe_window_keystrokes(window, cx, |this: &mut KeyCounter| {
+                    this.keystrokes += 1;
+                }),
+            }
+        });
+
+        // Act
+        cx.simulate_keystrokes(window.into(), "right");
+
+        // Assert
+        let (keystrokes, navigated) = window
+            .read_with(cx, |counter, _| (counter.keystrokes, counter.navigated))
+            .unwrap();
+        assert!(navigated);
+        assert_eq!(keystrokes, 1);
+    }
+}
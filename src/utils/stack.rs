@@ -0,0 +1,126 @@
+//! Frame stacking: per-pixel average of a sequence of same-sized images.
+//!
+//! Used for noise reduction and astrophotography, where many exposures of
+//! the same scene average out sensor noise.  Images are folded in one at a
+//! time so a whole folder never has to sit decoded in memory at once.  The
+//! first image fixes the output size; any image of a different size is
+//! skipped rather than resampled, since stretching would misalign the
+//! frames anyway.
+
+use image::{DynamicImage, RgbaImage};
+
+/// Running per-channel sums for an average stack
+pub struct StackAccumulator {
+    width: u32,
+    height: u32,
+    /// RGBA sums, one per channel per pixel (room for 16 million frames)
+    sums: Vec<u32>,
+    /// Images folded in so far
+    count: u64,
+}
+
+impl StackAccumulator {
+    pub fn new() -> Self {
+        Self {
+            width: 0,
+            height: 0,
+            sums: Vec::new(),
+            count: 0,
+        }
+    }
+
+    /// Images added so far
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Fold `image` into the stack.  Fails, leaving the stack untouched,
+    /// when its size differs from the first image's.
+    pub fn add(&mut self, image: &DynamicImage) -> Result<(), String> {
+        let rgba = image.to_rgba8();
+        if self.count == 0 {
+            self.width = rgba.width();
+            self.height = rgba.height();
+            self.sums = vec![0; rgba.as_raw().len()];
+        } else if rgba.dimensions() != (self.width, self.height) {
+            return Err(format!(
+                "{}\u{00D7}{} doesn't match the stack's {}\u{00D7}{}",
+                rgba.width(),
+                rgba.height(),
+                self.width,
+                self.height
+            ));
+        }
+        for (sum, &value) in self.sums.iter_mut().zip(rgba.as_raw()) {
+            *sum += value as u32;
+        }
+        self.count += 1;
+        Ok(())
+    }
+
+    /// The averaged image, or `None` if nothing was added
+    pub fn finish(&self) -> Option<DynamicImage> {
+        if self.count == 0 {
+            return None;
+        }
+        let half = self.count / 2;
+        let pixels = self
+            .sums
+            .iter()
+            .map(|&sum| ((sum as u64 + half) / self.count) as u8)
+            .collect();
+        RgbaImage::from_raw(self.width, self.height, pixels).map(DynamicImage::ImageRgba8)
+    }
+}
+
+impl Default for StackAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    fn solid(width: u32, height: u32, color: [u8; 4]) -> DynamicImage {
+        DynamicImage::ImageRgba8(RgbaImage::from_pixel(width, height, Rgba(color)))
+    }
+
+    #[test]
+    fn averaging_two_solid_colors_gives_midpoint() {
+        // Arrange
+        let mut stack = StackAccumulator::new();
+        stack.add(&solid(4, 3, [100, 0, 200, 255])).unwrap();
+        stack.add(&solid(4, 3, [200, 100, 0, 55])).unwrap();
+
+        // Act
+        let result = stack.finish().unwrap().to_rgba8();
+
+        // Assert
+        assert_eq!(result.dimensions(), (4, 3));
+        assert!(result.pixels().all(|p| p.0 == [150, 50, 100, 155]));
+    }
+
+    #[test]
+    fn mismatched_sizes_are_skipped() {
+        // Arrange
+        let mut stack = StackAccumulator::new();
+        stack.add(&solid(2, 2, [10, 10, 10, 255])).unwrap();
+
+        // Act
+        let added = stack.add(&solid(3, 2, [250, 250, 250, 255]));
+
+        // Assert - the odd one out leaves the average alone
+        assert!(added.is_err());
+        assert_eq!(stack.count(), 1);
+        let result = stack.finish().unwrap().to_rgba8();
+        assert_eq!(result.get_pixel(0, 0).0, [10, 10, 10, 255]);
+    }
+
+    #[test]
+    fn empty_stack_has_no_result() {
+        assert!(StackAccumulator::new().finish().is_none());
+    }
+}
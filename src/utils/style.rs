@@ -1,4 +1,5 @@
 use gpui::*;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 /// Get the platform modifier key glyph/name.
 /// Returns "⌘" on macOS, "Ctrl" on Windows/Linux.
@@ -61,10 +62,93 @@ pub fn format_shortcut(key: &str, shift: bool, option: bool) -> String {
     }
 }
 
+/// Overlay colors (RGB) and border weight for one theme.  `Colors` serves
+/// whichever is active.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OverlayTheme {
+    /// Primary overlay text
+    pub text: u32,
+    /// Key names, dimensions and other secondary text
+    pub secondary_text: u32,
+    /// Labels and fine print
+    pub muted_text: u32,
+    /// Opaque panels (help box, cards)
+    pub panel: u32,
+    /// Inset panels (help shortcut list)
+    pub inset_panel: u32,
+    /// Overlay borders and dividers
+    pub border: u32,
+    /// Overlay border width in pixels
+    pub border_width: f32,
+    /// Draw overlay backgrounds fully opaque, ignoring `overlay_transparency`
+    pub opaque_overlays: bool,
+}
+
+impl OverlayTheme {
+    /// The regular dark overlays
+    pub const STANDARD: Self = Self {
+        text: 0xffffff,
+        secondary_text: 0xaaaaaa,
+        muted_text: 0x888888,
+        panel: 0x1e1e1e,
+        inset_panel: 0x313244,
+        border: 0x444444,
+        border_width: 1.0,
+        opaque_overlays: false,
+    };
+
+    /// White on black with heavy white borders, for low-vision users
+    pub const HIGH_CONTRAST: Self = Self {
+        text: 0xffffff,
+        secondary_text: 0xffffff,
+        muted_text: 0xffff00,
+        panel: 0x000000,
+        inset_panel: 0x000000,
+        border: 0xffffff,
+        border_width: 2.0,
+        opaque_overlays: true,
+    };
+
+    /// The high-contrast theme if `high_contrast`, else the standard one
+    pub fn for_high_contrast(high_contrast: bool) -> Self {
+        if high_contrast {
+            Self::HIGH_CONTRAST
+        } else {
+            Self::STANDARD
+        }
+    }
+
+    /// Overlay background at `alpha` (0-255); always opaque when
+    /// `opaque_overlays` is set
+    pub fn overlay_bg(&self, alpha: u8) -> Hsla {
+        let mut color: Hsla = rgb(0x000000).into();
+        color.a = if self.opaque_overlays {
+            1.0
+        } else {
+            (alpha as f32) / 255.0
+        };
+        color
+    }
+}
+
+/// Whether the high-contrast overlay theme is active
+static HIGH_CONTRAST: AtomicBool = AtomicBool::new(false);
+
 /// Common color palette
 pub struct Colors;
 
 impl Colors {
+    /// Switch overlays to (or back from) the high-contrast theme for the
+    /// rest of the process (`appearance.high_contrast`).
+    pub fn set_high_contrast(on: bool) {
+        HIGH_CONTRAST.store(on, Ordering::Relaxed);
+    }
+
+    /// The active overlay theme
+    pub fn theme() -> OverlayTheme {
+        OverlayTheme::for_high_contrast(HIGH_CONTRAST.load(Ordering::Relaxed))
+    }
+
     /// Background color for the main view
     pub fn background() -> Hsla {
         rgb(0x1e1e1e).into()
@@ -72,7 +156,27 @@ impl Colors {
 
     /// Text color
     pub fn text() -> Hsla {
-        rgb(0xffffff).into()
+        rgb(Self::theme().text).into()
+    }
+
+    /// Secondary overlay text (key names, dimensions)
+    pub fn secondary_text() -> Hsla {
+        rgb(Self::theme().secondary_text).into()
+    }
+
+    /// Muted overlay text (labels, fine print)
+    pub fn muted_text() -> Hsla {
+        rgb(Self::theme().muted_text).into()
+    }
+
+    /// Opaque overlay panel background
+    pub fn panel() -> Hsla {
+        rgb(Self::theme().panel).into()
+    }
+
+    /// Inset panel background, one step off `panel`
+    pub fn inset_panel() -> Hsla {
+        rgb(Self::theme().inset_panel).into()
     }
 
     /// Text color that contrasts with the given background RGB
@@ -107,16 +211,19 @@ impl Colors {
 
     /// Overlay background with custom alpha value
     /// alpha: 0-255, where 0 is fully transparent and 255 is fully opaque
+    /// (always opaque in the high-contrast theme)
     pub fn overlay_bg_alpha(alpha: u8) -> Hsla {
-        let mut color: Hsla = rgb(0x000000).into();
-        color.a = (alpha as f32) / 255.0;
-        color
+        Self::theme().overlay_bg(alpha)
     }
 
-    #[allow(dead_code)]
     /// Border color
     pub fn border() -> Hsla {
-        rgb(0x444444).into()
+        rgb(Self::theme().border).into()
+    }
+
+    /// Overlay border width
+    pub fn border_width() -> Pixels {
+        px(Self::theme().border_width)
     }
 }
 
@@ -185,3 +292,39 @@ impl TextSize {
 pub fn scaled_text_size(base_size: f32, scale: f32) -> Pixels {
     px(base_size * scale)
 }
+
+#[cfg(test)]
+mod tests {
+    // Named imports: `super::*` would pull in gpui's `test` attribute
+    use super::OverlayTheme;
+
+    #[test]
+    fn high_contrast_theme_is_white_on_black() {
+        // Arrange & Act
+        let theme = OverlayTheme::for_high_contrast(true);
+        let standard = OverlayTheme::for_high_contrast(false);
+
+        // Assert - maximum-contrast text and borders, heavier and opaque
+        assert_eq!(theme.text, 0xffffff);
+        assert_eq!(theme.secondary_text, 0xffffff);
+        assert_eq!(theme.panel, 0x000000);
+        assert_eq!(theme.inset_panel, 0x000000);
+        assert_eq!(theme.border, 0xffffff);
+        assert!(theme.border_width > standard.border_width);
+        assert!(theme.opaque_overlays);
+        assert!(!standard.opaque_overlays);
+    }
+
+    #[test]
+    fn overlay_bg_follows_alpha_unless_opaque() {
+        // Arrange
+        let standard = OverlayTheme::for_high_contrast(false);
+        let high_contrast = OverlayTheme::for_high_contrast(true);
+
+        // Act & Assert - each stepper value is the background's alpha
+        assert_eq!(standard.overlay_bg(0).a, 0.0);
+        assert_eq!(standard.overlay_bg(51).a, 0.2);
+        assert_eq!(standard.overlay_bg(255).a, 1.0);
+        assert_eq!(high_contrast.overlay_bg(51).a, 1.0);
+    }
+}
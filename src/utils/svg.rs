@@ -1,4 +1,3 @@
-use super::debug_eprintln;
 use crate::error::{AppError, AppResult};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, OnceLock};
@@ -12,7 +11,7 @@ fn fontdb() -> Arc<resvg::usvg::fontdb::Database> {
         .get_or_init(|| {
             let mut db = resvg::usvg::fontdb::Database::new();
             db.load_system_fonts();
-            debug_eprintln!("[SVG] Loaded {} font faces from system", db.len());
+            log::debug!("[SVG] Loaded {} font faces from system", db.len());
             Arc::new(db)
         })
         .clone()
@@ -46,15 +45,35 @@ pub struct SvgRerasterRegion {
     pub svg_h: f32,
 }
 
-/// Create a secure temp file with an unpredictable name (no TOCTOU race).
+/// Create a secure temp file with an unpredictable name (no TOCTOU race)
+/// in the configured scratch directory.
 fn secure_temp_file(prefix: &str) -> Result<tempfile::NamedTempFile, String> {
     tempfile::Builder::new()
         .prefix(prefix)
         .suffix(".png")
-        .tempfile()
+        .tempfile_in(super::scratch::scratch_dir())
         .map_err(|e| format!("Failed to create temp file: {}", e))
 }
 
+/// A `width` × `height` pixmap, filled with `background` if given so the
+/// SVG is drawn onto it rather than onto transparency
+fn new_pixmap(
+    width: u32,
+    height: u32,
+    background: Option<[u8; 3]>,
+) -> Result<resvg::tiny_skia::Pixmap, String> {
+    let mut pixmap = resvg::tiny_skia::Pixmap::new(width, height).ok_or_else(|| {
+        format!(
+            "Failed to create pixmap ({}x{} may be too large)",
+            width, height
+        )
+    })?;
+    if let Some([r, g, b]) = background {
+        pixmap.fill(resvg::tiny_skia::Color::from_rgba8(r, g, b, 0xff));
+    }
+    Ok(pixmap)
+}
+
 /// Parse an SVG file into a usvg::Tree. The tree is self-contained after parsing
 /// (fontdb is not needed for subsequent renders).
 pub fn parse_svg(path: &Path) -> AppResult<resvg::usvg::Tree> {
@@ -72,9 +91,13 @@ pub fn parse_svg(path: &Path) -> AppResult<resvg::usvg::Tree> {
     Ok(tree)
 }
 
-/// Render an entire SVG tree at the given scale factor to a temp PNG.
-/// Returns the path to the temp PNG file.
-pub fn rerasterize_svg_full(tree: &resvg::usvg::Tree, scale: f32) -> Result<PathBuf, String> {
+/// Render an entire SVG tree at the given scale factor to a temp PNG, onto
+/// `background` if given.  Returns the path to the temp PNG file.
+pub fn rerasterize_svg_full(
+    tree: &resvg::usvg::Tree,
+    scale: f32,
+    background: Option<[u8; 3]>,
+) -> Result<PathBuf, String> {
     let size = tree.size();
     let scaled_w = (size.width() * scale).ceil() as u32;
     let scaled_h = (size.height() * scale).ceil() as u32;
@@ -83,12 +106,7 @@ pub fn rerasterize_svg_full(tree: &resvg::usvg::Tree, scale: f32) -> Result<Path
         return Err("SVG has zero dimensions at this scale".to_string());
     }
 
-    let mut pixmap = resvg::tiny_skia::Pixmap::new(scaled_w, scaled_h).ok_or_else(|| {
-        format!(
-            "Failed to create pixmap ({}x{} may be too large)",
-            scaled_w, scaled_h
-        )
-    })?;
+    let mut pixmap = new_pixmap(scaled_w, scaled_h, background)?;
 
     let transform = resvg::tiny_skia::Transform::from_scale(scale, scale);
     resvg::render(tree, transform, &mut pixmap.as_mut());
@@ -102,7 +120,7 @@ pub fn rerasterize_svg_full(tree: &resvg::usvg::Tree, scale: f32) -> Result<Path
         .keep()
         .map_err(|e| format!("Failed to persist temp file: {}", e))?;
 
-    debug_eprintln!(
+    log::debug!(
         "[SVG] Full re-raster at {:.1}x -> {} ({}x{})",
         scale,
         kept_path.display(),
@@ -118,12 +136,14 @@ pub fn rerasterize_svg_full(tree: &resvg::usvg::Tree, scale: f32) -> Result<Path
 ///
 /// `viewport_in_svg` is `(x, y, w, h)` in SVG coordinate space describing the
 /// currently visible area. `padding_factor` adds extra around each side
-/// (e.g. 0.5 = 50% extra). `scale` is the zoom level.
+/// (e.g. 0.5 = 50% extra). `scale` is the zoom level.  The region is drawn
+/// onto `background` if given.
 pub fn rerasterize_svg_viewport(
     tree: &resvg::usvg::Tree,
     viewport_in_svg: (f32, f32, f32, f32),
     padding_factor: f32,
     scale: f32,
+    background: Option<[u8; 3]>,
 ) -> Result<(PathBuf, SvgRerasterRegion), String> {
     let svg_size = tree.size();
     let (vx, vy, vw, vh) = viewport_in_svg;
@@ -151,12 +171,7 @@ pub fn rerasterize_svg_viewport(
         return Err("Viewport region too small at this scale".to_string());
     }
 
-    let mut pixmap = resvg::tiny_skia::Pixmap::new(pixel_w, pixel_h).ok_or_else(|| {
-        format!(
-            "Failed to create pixmap ({}x{} may be too large)",
-            pixel_w, pixel_h
-        )
-    })?;
+    let mut pixmap = new_pixmap(pixel_w, pixel_h, background)?;
 
     // Scale then translate so that the region origin maps to pixel (0,0)
     let transform =
@@ -179,7 +194,7 @@ pub fn rerasterize_svg_viewport(
         svg_h: region_h,
     };
 
-    debug_eprintln!(
+    log::debug!(
         "[SVG] Viewport re-raster at {:.1}x, region ({:.0},{:.0} {}x{}) -> {} ({}x{})",
         scale,
         region_x,
@@ -194,6 +209,35 @@ pub fn rerasterize_svg_viewport(
     Ok((kept_path, region))
 }
 
+/// Render an SVG file at its intrinsic size, onto `background` if given,
+/// as an image the filters and savers can work on
+pub fn render_svg_image(
+    path: &Path,
+    background: Option<[u8; 3]>,
+) -> AppResult<image::DynamicImage> {
+    let tree = parse_svg(path)?;
+    let (width, height) = get_svg_dimensions(path)?;
+    let fail = |e: String| AppError::ImageLoadError(path.to_path_buf(), e);
+    let mut pixmap = new_pixmap(width, height, background).map_err(fail)?;
+    resvg::render(
+        &tree,
+        resvg::tiny_skia::Transform::default(),
+        &mut pixmap.as_mut(),
+    );
+    // tiny-skia keeps premultiplied alpha; the image crate wants it straight
+    let pixels = pixmap
+        .pixels()
+        .iter()
+        .flat_map(|px| {
+            let c = px.demultiply();
+            [c.red(), c.green(), c.blue(), c.alpha()]
+        })
+        .collect();
+    image::RgbaImage::from_raw(width, height, pixels)
+        .map(image::DynamicImage::ImageRgba8)
+        .ok_or_else(|| fail("Rendered SVG has the wrong size".to_string()))
+}
+
 /// Get the intrinsic dimensions of an SVG file
 pub fn get_svg_dimensions(path: &Path) -> AppResult<(u32, u32)> {
     let svg_data = std::fs::read(path).map_err(|e| {
@@ -240,13 +284,13 @@ pub fn rasterize_svg(path: &Path, scale_factor: f32) -> AppResult<(PathBuf, u32,
         ));
     }
 
-    let temp_path = rerasterize_svg_full(&tree, scale_factor)
+    let temp_path = rerasterize_svg_full(&tree, scale_factor, None)
         .map_err(|e| AppError::ImageLoadError(path.to_path_buf(), e))?;
 
     let _scaled_w = (size.width() * scale_factor).ceil() as u32;
     let _scaled_h = (size.height() * scale_factor).ceil() as u32;
 
-    debug_eprintln!(
+    log::debug!(
         "[SVG] Rasterized {} ({}x{}) at {}x to {} ({}x{})",
         path.display(),
         intrinsic_w,
@@ -0,0 +1,158 @@
+//! Bounded undo/redo history of whole values, used for filter adjustments.
+//! Each entry is the value as it was before a change; a slider drag comes
+//! in as many small changes and is merged into one step.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Most steps kept; older ones are forgotten
+pub const MAX_UNDO_DEPTH: usize = 50;
+
+/// Changes closer together than this through `record_merging` undo as one
+pub const MERGE_WINDOW: Duration = Duration::from_millis(750);
+
+pub struct UndoStack<T> {
+    /// Oldest first
+    undo: VecDeque<T>,
+    redo: Vec<T>,
+    max_depth: usize,
+    /// When the last merging change came in
+    last_merge: Option<Instant>,
+}
+
+impl<T> UndoStack<T> {
+    pub fn new(max_depth: usize) -> Self {
+        Self {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            max_depth,
+            last_merge: None,
+        }
+    }
+
+    /// Note a change away from `before`.  Anything undone so far can no
+    /// longer be redone.
+    pub fn record(&mut self, before: T) {
+        self.last_merge = None;
+        self.redo.clear();
+        if self.max_depth == 0 {
+            return;
+        }
+        if self.undo.len() == self.max_depth {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(before);
+    }
+
+    /// Like `record`, but a change within `MERGE_WINDOW` of the previous
+    /// merging one extends that step instead of starting another
+    pub fn record_merging(&mut self, before: T, now: Instant) {
+        let merge = self
+            .last_merge
+            .is_some_and(|last| now.saturating_duration_since(last) < MERGE_WINDOW);
+        if !merge {
+            self.record(before);
+        }
+        self.last_merge = Some(now);
+    }
+
+    /// Step back from `current`, returning the value to restore
+    pub fn undo(&mut self, current: T) -> Option<T> {
+        let previous = self.undo.pop_back()?;
+        self.redo.push(current);
+        self.last_merge = None;
+        Some(previous)
+    }
+
+    /// Step forward again from `current` after an undo
+    pub fn redo(&mut self, current: T) -> Option<T> {
+        let next = self.redo.pop()?;
+        self.undo.push_back(current);
+        self.last_merge = None;
+        Some(next)
+    }
+
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.last_merge = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_undo_then_redo_walks_the_history() {
+        // Arrange - 1 → 2 → 3
+        let mut stack = UndoStack::new(10);
+        stack.record(1);
+        stack.record(2);
+
+        // Act / Assert
+        assert_eq!(stack.undo(3), Some(2));
+        assert_eq!(stack.undo(2), Some(1));
+        assert_eq!(stack.undo(1), None);
+        assert_eq!(stack.redo(1), Some(2));
+        assert_eq!(stack.redo(2), Some(3));
+        assert_eq!(stack.redo(3), None);
+    }
+
+    #[test]
+    fn test_a_new_change_clears_redo() {
+        // Arrange
+        let mut stack = UndoStack::new(10);
+        stack.record(1);
+        assert_eq!(stack.undo(2), Some(1));
+
+        // Act - change from 1 to something else
+        stack.record(1);
+
+        // Assert
+        assert_eq!(stack.redo(5), None);
+        assert_eq!(stack.undo(5), Some(1));
+    }
+
+    #[test]
+    fn test_depth_limit_forgets_the_oldest() {
+        let mut stack = UndoStack::new(2);
+        stack.record(1);
+        stack.record(2);
+        stack.record(3);
+        assert_eq!(stack.undo(4), Some(3));
+        assert_eq!(stack.undo(3), Some(2));
+        assert_eq!(stack.undo(2), None);
+    }
+
+    #[test]
+    fn test_record_merging_groups_quick_changes() {
+        // Arrange - a drag from 0 through 1 and 2, then a later one from 3
+        let start = Instant::now();
+        let mut stack = UndoStack::new(10);
+        stack.record_merging(0, start);
+        stack.record_merging(1, start + Duration::from_millis(100));
+        stack.record_merging(2, start + Duration::from_millis(200));
+        stack.record_merging(3, start + Duration::from_secs(5));
+
+        // Act / Assert - each drag undoes in one step
+        assert_eq!(stack.undo(4), Some(3));
+        assert_eq!(stack.undo(3), Some(0));
+        assert_eq!(stack.undo(0), None);
+    }
+
+    #[test]
+    fn test_undo_ends_a_merge() {
+        let start = Instant::now();
+        let mut stack = UndoStack::new(10);
+        stack.record_merging(0, start);
+        assert_eq!(stack.undo(1), Some(0));
+
+        // Straight after the undo, a change still gets its own step
+        stack.record_merging(0, start + Duration::from_millis(10));
+        assert_eq!(stack.undo(7), Some(0));
+
+        stack.clear();
+        assert_eq!(stack.redo(0), None);
+    }
+}
@@ -46,6 +46,37 @@ pub fn calculate_fit_to_window(
     clamp_zoom(width_ratio.min(height_ratio))
 }
 
+/// Like `calculate_fit_to_window`, but leaves `padding` pixels free on every
+/// side of the image.  Padding that would leave no room at all is ignored.
+pub fn calculate_fit_to_window_with_padding(
+    image_width: u32,
+    image_height: u32,
+    viewport_width: f32,
+    viewport_height: f32,
+    padding: f32,
+) -> f32 {
+    let padding = padding.max(0.0);
+    let inner_width = viewport_width - 2.0 * padding;
+    let inner_height = viewport_height - 2.0 * padding;
+    if inner_width < 1.0 || inner_height < 1.0 {
+        return calculate_fit_to_window(image_width, image_height, viewport_width, viewport_height);
+    }
+    calculate_fit_to_window(image_width, image_height, inner_width, inner_height)
+}
+
+/// The part of a `width` × `height` screen left after keeping `percent`
+/// of each dimension clear at every edge (5% on a 1000 px width leaves
+/// 900 px).  The percentage is capped so something always remains.
+pub fn safe_area(width: f32, height: f32, percent: f32) -> (f32, f32) {
+    let inset = if percent.is_finite() {
+        percent.clamp(0.0, 40.0)
+    } else {
+        0.0
+    };
+    let keep = 100.0 - 2.0 * inset;
+    (width * keep / 100.0, height * keep / 100.0)
+}
+
 /// Zoom in by the given step
 pub fn zoom_in(current_zoom: f32, step: f32) -> f32 {
     clamp_zoom(current_zoom * step)
@@ -56,11 +87,229 @@ pub fn zoom_out(current_zoom: f32, step: f32) -> f32 {
     clamp_zoom(current_zoom / step)
 }
 
+/// Parse a typed zoom level such as "250%", "250" or " 33.5 % " into a
+/// zoom factor (2.5).  Anything that isn't a positive number is `None`; the
+/// result is not clamped.
+pub fn parse_zoom_percent(text: &str) -> Option<f32> {
+    let number = text.trim().trim_end_matches('%').trim_end();
+    let percent: f32 = number.parse().ok()?;
+    (percent.is_finite() && percent > 0.0).then_some(percent / 100.0)
+}
+
+/// Pan that keeps the image pixel at screen position `anchor` in place when
+/// the zoom changes from `old_zoom` to `new_zoom` (before constraining).
+pub fn pan_keeping_point(
+    pan: (f32, f32),
+    anchor: (f32, f32),
+    old_zoom: f32,
+    new_zoom: f32,
+) -> (f32, f32) {
+    // Which image pixel is under the anchor now
+    let pixel_x = (anchor.0 - pan.0) / old_zoom;
+    let pixel_y = (anchor.1 - pan.1) / old_zoom;
+    (anchor.0 - pixel_x * new_zoom, anchor.1 - pixel_y * new_zoom)
+}
+
+/// The image pixel under screen point `point`, for an image of `size` drawn
+/// at `pan` and `zoom`; None off the image
+pub fn screen_to_image(
+    point: (f32, f32),
+    pan: (f32, f32),
+    zoom: f32,
+    (width, height): (u32, u32),
+) -> Option<(u32, u32)> {
+    if zoom <= 0.0 {
+        return None;
+    }
+    let x = (point.0 - pan.0) / zoom;
+    let y = (point.1 - pan.1) / zoom;
+    let inside = (0.0..width as f32).contains(&x) && (0.0..height as f32).contains(&y);
+    inside.then_some((x as u32, y as u32))
+}
+
 /// Format zoom level as percentage string
 pub fn format_zoom_percentage(zoom: f32) -> String {
     format!("{:.0}%", zoom * 100.0)
 }
 
+/// Format the zoom indicator label.
+///
+/// Fit-to-window is always called out, so a fitted image is never mistaken
+/// for actual-pixels view.  When the fit zoom happens to round to 100%, both
+/// are shown ("100% (Fit)"); otherwise fit reads "Fit (57%)".  Any other zoom
+/// is the plain percentage.
+pub fn format_zoom_label(zoom: f32, is_fit_to_window: bool) -> String {
+    let percentage = format_zoom_percentage(zoom);
+    if !is_fit_to_window {
+        percentage
+    } else if (zoom * 100.0).round() == 100.0 {
+        format!("{} (Fit)", percentage)
+    } else {
+        format!("Fit ({})", percentage)
+    }
+}
+
+/// The part of an `image_width` x `image_height` image that is visible in
+/// the viewport, as `(x, y, width, height)` in image pixels.  `pan` is where
+/// the image's top-left corner sits in viewport coordinates, matching how
+/// the viewer positions it.  Returns None when nothing is on screen.
+pub fn visible_image_region(
+    image_width: u32,
+    image_height: u32,
+    zoom: f32,
+    pan: (f32, f32),
+    viewport_width: f32,
+    viewport_height: f32,
+) -> Option<(u32, u32, u32, u32)> {
+    if zoom <= 0.0 || image_width == 0 || image_height == 0 {
+        return None;
+    }
+    let (pan_x, pan_y) = pan;
+    let x0 = (-pan_x / zoom).max(0.0).floor() as u32;
+    let y0 = (-pan_y / zoom).max(0.0).floor() as u32;
+    let x1 = ((viewport_width - pan_x) / zoom)
+        .ceil()
+        .clamp(0.0, image_width as f32) as u32;
+    let y1 = ((viewport_height - pan_y) / zoom)
+        .ceil()
+        .clamp(0.0, image_height as f32) as u32;
+    if x1 <= x0 || y1 <= y0 {
+        return None;
+    }
+    Some((x0, y0, x1 - x0, y1 - y0))
+}
+
+/// The part of an image on screen, as `visible_pixel_region` finds it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VisiblePixels {
+    /// Stored pixels on screen, as `(x, y, width, height)`
+    pub region: (u32, u32, u32, u32),
+    /// The size they're drawn at, in screen pixels
+    pub drawn: (u32, u32),
+}
+
+/// `visible_image_region` for an image whose `pixels` are laid out at
+/// `layout` size (non-square pixels).  `zoom` and `pan` work in layout
+/// units, as everywhere else in the viewer.
+pub fn visible_pixel_region(
+    pixels: (u32, u32),
+    layout: (u32, u32),
+    zoom: f32,
+    pan: (f32, f32),
+    viewport: (f32, f32),
+) -> Option<VisiblePixels> {
+    let (x, y, w, h) = visible_image_region(layout.0, layout.1, zoom, pan, viewport.0, viewport.1)?;
+    // Layout span -> stored pixel span, rounded outward
+    let span = |start: u32, len: u32, pixels: u32, laid_out: u32| {
+        let scale = |v: u32| v as u64 * pixels as u64;
+        let laid_out = laid_out.max(1) as u64;
+        let first = (scale(start) / laid_out) as u32;
+        let end = (scale(start + len).div_ceil(laid_out) as u32).min(pixels);
+        (first, end.saturating_sub(first).max(1))
+    };
+    let (px, pw) = span(x, w, pixels.0, layout.0);
+    let (py, ph) = span(y, h, pixels.1, layout.1);
+    let out_w = ((w as f32 * zoom).round() as u32).max(1);
+    let out_h = ((h as f32 * zoom).round() as u32).max(1);
+    Some(VisiblePixels {
+        region: (px, py, pw, ph),
+        drawn: (out_w, out_h),
+    })
+}
+
+/// Clamp `pan` so an image drawn at `zoomed_size` keeps at least a sliver
+/// (10% of its size, at most 50 px) inside a `viewport`-sized view on each
+/// axis, rather than being panned entirely off-screen.
+pub fn constrain_pan(pan: (f32, f32), zoomed_size: (f32, f32), viewport: (f32, f32)) -> (f32, f32) {
+    let (zoomed_width, zoomed_height) = zoomed_size;
+    let min_visible_x = (zoomed_width * 0.1).min(50.0);
+    let min_visible_y = (zoomed_height * 0.1).min(50.0);
+
+    // Image can be panned right/down until only the sliver shows on the
+    // left/top, and left/up until only the sliver shows on the right/bottom
+    let max_pan_x = viewport.0 - min_visible_x;
+    let min_pan_x = -(zoomed_width - min_visible_x);
+    let max_pan_y = viewport.1 - min_visible_y;
+    let min_pan_y = -(zoomed_height - min_visible_y);
+
+    (
+        pan.0.max(min_pan_x).min(max_pan_x),
+        pan.1.max(min_pan_y).min(max_pan_y),
+    )
+}
+
+/// Pan offset for a plain scroll when scrolling pans (document-viewer
+/// style): the image follows the wheel/trackpad on both axes, and with
+/// Shift a vertical wheel pans sideways instead.  Mice without a
+/// horizontal wheel report Shift+scroll as vertical on some platforms and
+/// as already-horizontal on others, so both axes are folded together.
+pub fn scroll_pan_delta(delta: (f32, f32), shift: bool) -> (f32, f32) {
+    if shift {
+        (delta.0 + delta.1, 0.0)
+    } else {
+        delta
+    }
+}
+
+/// A copied zoom/pan, pasted onto another image to line up the same view
+/// of two shots.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ViewSnapshot {
+    pub zoom: f32,
+    pub pan: (f32, f32),
+}
+
+impl ViewSnapshot {
+    /// The zoom and pan to show this view on an `image_size` image in a
+    /// `viewport`-sized view.  Zoom and pan carry over as-is, so two shots
+    /// of the same size line up pixel for pixel; the pan is then clamped
+    /// against the new image's size, so pasting onto a smaller image can't
+    /// leave it off-screen.
+    pub fn applied_to(self, image_size: (u32, u32), viewport: (f32, f32)) -> (f32, (f32, f32)) {
+        let zoom = clamp_zoom(self.zoom);
+        let zoomed_size = (image_size.0 as f32 * zoom, image_size.1 as f32 * zoom);
+        (zoom, constrain_pan(self.pan, zoomed_size, viewport))
+    }
+}
+
+/// A stop on the `0` key's zoom cycle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ZoomStop {
+    FitToWindow,
+    Zoom(f32),
+}
+
+/// The next stop when `0` is pressed, cycling fit-to-window → `stops[0]` →
+/// `stops[1]` → … → fit-to-window.  `position` is where the last press
+/// landed (0 = fit, i = `stops[i - 1]`); returns the new position with its
+/// stop.  A view that is neither fit nor still at the remembered stop (the
+/// user zoomed by hand since) goes back to fit.  With `stops == [1.0]` this
+/// is the plain fit ↔ 100% toggle.
+pub fn next_zoom_stop(
+    is_fit_to_window: bool,
+    zoom: f32,
+    position: usize,
+    stops: &[f32],
+) -> (usize, ZoomStop) {
+    let current = if is_fit_to_window {
+        0
+    } else if position > 0
+        && stops
+            .get(position - 1)
+            .is_some_and(|stop| (stop - zoom).abs() < 0.0005)
+    {
+        position
+    } else {
+        return (0, ZoomStop::FitToWindow);
+    };
+    let next = (current + 1) % (stops.len() + 1);
+    if next == 0 {
+        (0, ZoomStop::FitToWindow)
+    } else {
+        (next, ZoomStop::Zoom(clamp_zoom(stops[next - 1])))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -70,6 +319,19 @@ mod tests {
     const ZOOM_ABOVE_MAX: f32 = 25.0;
     const TOLERANCE: f32 = 0.001;
 
+    #[test]
+    fn test_scroll_pan_delta_follows_scroll() {
+        assert_eq!(scroll_pan_delta((0.0, -40.0), false), (0.0, -40.0));
+        assert_eq!(scroll_pan_delta((12.0, 30.0), false), (12.0, 30.0));
+    }
+
+    #[test]
+    fn test_scroll_pan_delta_shift_pans_horizontally() {
+        // Vertical wheel with Shift, and an OS that already turned it sideways
+        assert_eq!(scroll_pan_delta((0.0, -40.0), true), (-40.0, 0.0));
+        assert_eq!(scroll_pan_delta((-40.0, 0.0), true), (-40.0, 0.0));
+    }
+
     #[test]
     fn test_clamp_zoom() {
         // Arrange & Act & Assert
@@ -121,6 +383,58 @@ mod tests {
         assert_eq!(calculate_fit_to_window(800, 1600, 800.0, 600.0), 0.375);
     }
 
+    #[test]
+    fn test_calculate_fit_to_window_with_padding() {
+        // No padding matches the plain fit
+        assert_eq!(
+            calculate_fit_to_window_with_padding(1000, 1000, 500.0, 500.0, 0.0),
+            0.5
+        );
+        // 50px on each side leaves a 400x400 area
+        assert_eq!(
+            calculate_fit_to_window_with_padding(1000, 1000, 500.0, 500.0, 50.0),
+            0.4
+        );
+        // The tighter axis decides: 800x600 viewport, 20px padding -> 760x560
+        assert_eq!(
+            calculate_fit_to_window_with_padding(1600, 800, 800.0, 600.0, 20.0),
+            0.475
+        );
+    }
+
+    #[test]
+    fn test_calculate_fit_to_window_with_oversized_padding_ignores_it() {
+        assert_eq!(
+            calculate_fit_to_window_with_padding(1000, 1000, 500.0, 500.0, 300.0),
+            0.5
+        );
+        assert_eq!(
+            calculate_fit_to_window_with_padding(1000, 1000, 500.0, 500.0, -10.0),
+            0.5
+        );
+    }
+
+    #[test]
+    fn test_safe_area_shrinks_the_viewport_before_fitting() {
+        // Arrange - 5% off every edge of a 1000x500 screen
+        let (width, height) = safe_area(1000.0, 500.0, 5.0);
+
+        // Act
+        let fit = calculate_fit_to_window(2000, 1000, width, height);
+
+        // Assert - 900x450 remains, so a 2:1 image fits at 45% not 50%
+        assert_eq!((width, height), (900.0, 450.0));
+        assert!((fit - 0.45).abs() < 1e-6);
+        assert_eq!(safe_area(1000.0, 500.0, 0.0), (1000.0, 500.0));
+    }
+
+    #[test]
+    fn test_safe_area_caps_the_inset() {
+        assert_eq!(safe_area(1000.0, 500.0, 90.0), (200.0, 100.0));
+        assert_eq!(safe_area(1000.0, 500.0, -5.0), (1000.0, 500.0));
+        assert_eq!(safe_area(1000.0, 500.0, f32::NAN), (1000.0, 500.0));
+    }
+
     #[test]
     fn test_calculate_fit_to_window_zero_image_dimensions() {
         // Arrange & Act & Assert - zero dimensions should return 1.0
@@ -253,4 +567,224 @@ mod tests {
         assert_eq!(format_zoom_percentage(0.999), "100%");
         assert_eq!(format_zoom_percentage(1.234), "123%");
     }
+
+    #[test]
+    fn test_format_zoom_label_not_fit_is_plain_percentage() {
+        assert_eq!(format_zoom_label(1.0, false), "100%");
+        assert_eq!(format_zoom_label(2.5, false), "250%");
+    }
+
+    #[test]
+    fn test_format_zoom_label_fit_shows_fit_first() {
+        assert_eq!(format_zoom_label(0.57, true), "Fit (57%)");
+        assert_eq!(format_zoom_label(1.5, true), "Fit (150%)");
+    }
+
+    #[test]
+    fn test_format_zoom_label_fit_coinciding_with_100() {
+        // Arrange & Act & Assert - fit zoom that rounds to 100% shows both
+        assert_eq!(format_zoom_label(1.0, true), "100% (Fit)");
+        assert_eq!(format_zoom_label(0.998, true), "100% (Fit)");
+        assert_eq!(format_zoom_label(1.004, true), "100% (Fit)");
+        // Just outside the rounding window is an ordinary fit label
+        assert_eq!(format_zoom_label(0.99, true), "Fit (99%)");
+    }
+
+    #[test]
+    fn test_screen_to_image_follows_pan_and_zoom() {
+        // A 100x50 image at 200% drawn from (10, 20)
+        let at = |x, y| screen_to_image((x, y), (10.0, 20.0), 2.0, (100, 50));
+        assert_eq!(at(10.0, 20.0), Some((0, 0)));
+        assert_eq!(at(31.0, 25.9), Some((10, 2)));
+        assert_eq!(at(209.9, 119.9), Some((99, 49)));
+        assert_eq!(at(210.0, 20.0), None);
+        assert_eq!(at(9.9, 20.0), None);
+        assert_eq!(at(10.0, 120.0), None);
+    }
+
+    #[test]
+    fn test_visible_image_region_whole_image_fits() {
+        // 200x100 image at 100%, centered in a 400x300 viewport
+        assert_eq!(
+            visible_image_region(200, 100, 1.0, (100.0, 100.0), 400.0, 300.0),
+            Some((0, 0, 200, 100))
+        );
+    }
+
+    #[test]
+    fn test_visible_image_region_zoomed_in_and_panned() {
+        // Arrange - 1000x800 image at 200%, panned so image pixel (100, 50)
+        // is at the viewport's top-left corner
+        let pan = (-200.0, -100.0);
+
+        // Act
+        let region = visible_image_region(1000, 800, 2.0, pan, 400.0, 300.0);
+
+        // Assert - the viewport covers 200x150 image pixels
+        assert_eq!(region, Some((100, 50, 200, 150)));
+    }
+
+    #[test]
+    fn test_visible_image_region_clipped_at_image_edge() {
+        // Arrange - image pushed so only its right 50 px remain on screen
+        let region = visible_image_region(300, 200, 1.0, (-250.0, 0.0), 400.0, 300.0);
+
+        // Assert
+        assert_eq!(region, Some((250, 0, 50, 200)));
+    }
+
+    #[test]
+    fn test_visible_pixel_region_undoes_pixel_aspect() {
+        // Arrange - 100x100 stored pixels twice as wide as tall, laid out at
+        // 200x100; at 150% a 150x150 viewport shows the left half, panned
+        // so layout x 50 is at the left edge
+        let region =
+            visible_pixel_region((100, 100), (200, 100), 1.5, (-75.0, 0.0), (150.0, 150.0));
+
+        // Assert - layout 50..150 is stored pixels 25..75, drawn 150 wide;
+        // all 100 rows show, drawn 150 tall
+        assert_eq!(
+            region,
+            Some(VisiblePixels {
+                region: (25, 0, 50, 100),
+                drawn: (150, 150),
+            })
+        );
+    }
+
+    #[test]
+    fn test_visible_pixel_region_square_pixels_match_visible_image_region() {
+        let region = visible_pixel_region(
+            (1000, 800),
+            (1000, 800),
+            2.0,
+            (-200.0, -100.0),
+            (400.0, 300.0),
+        );
+        assert_eq!(
+            region,
+            Some(VisiblePixels {
+                region: (100, 50, 200, 150),
+                drawn: (400, 300),
+            })
+        );
+    }
+
+    #[test]
+    fn test_visible_image_region_zoomed_out_rounds_outward() {
+        // 50% zoom: a 401 px wide viewport shows 802 image px, clamped to 800
+        assert_eq!(
+            visible_image_region(800, 600, 0.5, (0.0, 0.0), 401.0, 300.0),
+            Some((0, 0, 800, 600))
+        );
+    }
+
+    #[test]
+    fn test_visible_image_region_off_screen() {
+        assert_eq!(
+            visible_image_region(100, 100, 1.0, (500.0, 0.0), 400.0, 300.0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_view_snapshot_same_size_is_exact() {
+        // Arrange
+        let snapshot = ViewSnapshot {
+            zoom: 2.0,
+            pan: (-1200.0, -800.0),
+        };
+
+        // Act
+        let applied = snapshot.applied_to((4000, 3000), (1000.0, 800.0));
+
+        // Assert
+        assert_eq!(applied, (2.0, (-1200.0, -800.0)));
+    }
+
+    #[test]
+    fn test_view_snapshot_clamped_on_smaller_image() {
+        // Arrange - panned deep into a large image
+        let snapshot = ViewSnapshot {
+            zoom: 2.0,
+            pan: (-5000.0, -3000.0),
+        };
+
+        // Act - a 400x300 image is only 800x600 at 2x
+        let (zoom, pan) = snapshot.applied_to((400, 300), (1000.0, 800.0));
+
+        // Assert - zoom kept, pan pulled back so 50 px stay on screen
+        assert_eq!(zoom, 2.0);
+        assert_eq!(pan, (-750.0, -550.0));
+    }
+
+    #[test]
+    fn test_next_zoom_stop_cycles_through_stops() {
+        // Arrange
+        let stops = [1.0, 2.0];
+
+        // Act - three presses starting from fit
+        let (p1, s1) = next_zoom_stop(true, 0.4, 0, &stops);
+        let (p2, s2) = next_zoom_stop(false, 1.0, p1, &stops);
+        let (p3, s3) = next_zoom_stop(false, 2.0, p2, &stops);
+
+        // Assert - fit → 100% → 200% → fit
+        assert_eq!((p1, s1), (1, ZoomStop::Zoom(1.0)));
+        assert_eq!((p2, s2), (2, ZoomStop::Zoom(2.0)));
+        assert_eq!((p3, s3), (0, ZoomStop::FitToWindow));
+    }
+
+    #[test]
+    fn test_next_zoom_stop_single_stop_is_toggle() {
+        assert_eq!(
+            next_zoom_stop(true, 0.4, 0, &[1.0]),
+            (1, ZoomStop::Zoom(1.0))
+        );
+        assert_eq!(
+            next_zoom_stop(false, 1.0, 1, &[1.0]),
+            (0, ZoomStop::FitToWindow)
+        );
+    }
+
+    #[test]
+    fn test_next_zoom_stop_manual_zoom_returns_to_fit() {
+        // Zoomed by hand away from the remembered 100% stop
+        assert_eq!(
+            next_zoom_stop(false, 1.44, 1, &[1.0, 2.0]),
+            (0, ZoomStop::FitToWindow)
+        );
+    }
+
+    #[test]
+    fn test_parse_zoom_percent() {
+        assert_eq!(parse_zoom_percent("250%"), Some(2.5));
+        assert_eq!(parse_zoom_percent(" 250 "), Some(2.5));
+        assert_eq!(parse_zoom_percent("50 %"), Some(0.5));
+        assert_eq!(parse_zoom_percent("12.5"), Some(0.125));
+    }
+
+    #[test]
+    fn test_parse_zoom_percent_rejects_invalid_input() {
+        assert_eq!(parse_zoom_percent(""), None);
+        assert_eq!(parse_zoom_percent("%"), None);
+        assert_eq!(parse_zoom_percent("abc"), None);
+        assert_eq!(parse_zoom_percent("0"), None);
+        assert_eq!(parse_zoom_percent("-50%"), None);
+        assert_eq!(parse_zoom_percent("inf"), None);
+        assert_eq!(parse_zoom_percent("NaN"), None);
+    }
+
+    #[test]
+    fn test_pan_keeping_point_holds_the_anchored_pixel() {
+        // Arrange — 1000x800 viewport, pixel (300, 200) at its center at 100%
+        let center = (500.0, 400.0);
+        let pan = (200.0, 200.0);
+
+        // Act — jump to 250%
+        let new_pan = pan_keeping_point(pan, center, 1.0, 2.5);
+
+        // Assert — the same pixel is still at the center
+        assert_eq!(new_pan, (500.0 - 300.0 * 2.5, 400.0 - 200.0 * 2.5));
+        assert_eq!(pan_keeping_point(pan, center, 1.0, 1.0), pan);
+    }
 }
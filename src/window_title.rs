@@ -1,14 +1,26 @@
 use crate::state::app_state::SortMode;
-use crate::state::settings::AppSettings;
+use crate::state::settings::{AppSettings, DEFAULT_WINDOW_TITLE_FORMAT};
+use crate::utils::zoom;
+
+/// How the current image is shown, for the `{zoom}` and `{dimensions}`
+/// placeholders
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct TitleView {
+    pub zoom: f32,
+    /// None until the image has loaded
+    pub dimensions: Option<(u32, u32)>,
+}
 
 /// Format the window title for the given image state.
 ///
-/// Returns `"rpview"` when `path` is `None`.
+/// Returns `"rpview"` when `path` is `None`.  A blank template falls back
+/// to the default one.
 pub(crate) fn format_window_title(
     path: Option<&std::path::Path>,
     index: usize,
     total: usize,
     sort_mode: SortMode,
+    view: TitleView,
     settings: &AppSettings,
 ) -> String {
     match path {
@@ -16,13 +28,13 @@ pub(crate) fn format_window_title(
             let filename = p.file_name().and_then(|n| n.to_str()).unwrap_or("Unknown");
             let position = index + 1;
             if settings.sort_navigation.show_image_counter {
-                expand_template(
-                    &settings.appearance.window_title_format,
-                    filename,
-                    position,
-                    total,
-                    sort_mode,
-                )
+                let template = settings.appearance.window_title_format.as_str();
+                let template = if template.trim().is_empty() {
+                    DEFAULT_WINDOW_TITLE_FORMAT
+                } else {
+                    template
+                };
+                expand_template(template, filename, position, total, sort_mode, view)
             } else {
                 filename.to_string()
             }
@@ -32,13 +44,15 @@ pub(crate) fn format_window_title(
 }
 
 /// Single-pass template expansion. Recognises `{filename}`, `{index}`,
-/// `{total}`, `{sortmode}`, and `{sm}`. Unknown placeholders are left as-is.
+/// `{total}`, `{sortmode}`, `{sm}`, `{zoom}` and `{dimensions}`.  Unknown
+/// placeholders are left as-is.
 fn expand_template(
     template: &str,
     filename: &str,
     position: usize,
     total: usize,
     sort_mode: SortMode,
+    view: TitleView,
 ) -> String {
     let mut result = String::with_capacity(template.len() + filename.len());
     let mut i = 0;
@@ -54,6 +68,12 @@ fn expand_template(
                     "total" => result.push_str(&total.to_string()),
                     "sortmode" => result.push_str(sort_mode.long_label()),
                     "sm" => result.push_str(sort_mode.short_label()),
+                    "zoom" => result.push_str(&zoom::format_zoom_percentage(view.zoom)),
+                    "dimensions" => {
+                        if let Some((width, height)) = view.dimensions {
+                            result.push_str(&format!("{}×{}", width, height));
+                        }
+                    }
                     _ => {
                         // Unknown placeholder — preserve verbatim
                         result.push_str(&template[i..i + end + 1]);
@@ -79,7 +99,7 @@ fn expand_template(
 
 #[cfg(test)]
 mod tests {
-    use super::format_window_title;
+    use super::{TitleView, format_window_title};
     use crate::state::app_state::SortMode;
     use crate::state::settings::AppSettings;
     use std::path::Path;
@@ -89,7 +109,14 @@ mod tests {
         let settings = AppSettings::default();
         let path = Path::new("/photos/sunset.png");
 
-        let title = format_window_title(Some(path), 2, 10, SortMode::Alphabetical, &settings);
+        let title = format_window_title(
+            Some(path),
+            2,
+            10,
+            SortMode::Alphabetical,
+            TitleView::default(),
+            &settings,
+        );
 
         // Default template: "{filename} ({sm}, {index}/{total})"
         assert_eq!(title, "sunset.png (A, 3/10)");
@@ -100,7 +127,14 @@ mod tests {
         let settings = AppSettings::default();
         let path = Path::new("image.jpg");
 
-        let title = format_window_title(Some(path), 0, 5, SortMode::ModifiedDate, &settings);
+        let title = format_window_title(
+            Some(path),
+            0,
+            5,
+            SortMode::ModifiedDate,
+            TitleView::default(),
+            &settings,
+        );
 
         assert_eq!(title, "image.jpg (M, 1/5)");
     }
@@ -110,7 +144,14 @@ mod tests {
         let settings = AppSettings::default();
         let path = Path::new("photo.webp");
 
-        let title = format_window_title(Some(path), 4, 20, SortMode::TypeModified, &settings);
+        let title = format_window_title(
+            Some(path),
+            4,
+            20,
+            SortMode::TypeModified,
+            TitleView::default(),
+            &settings,
+        );
 
         assert_eq!(title, "photo.webp (TM, 5/20)");
     }
@@ -119,7 +160,14 @@ mod tests {
     fn test_format_window_title_no_path_returns_rpview() {
         let settings = AppSettings::default();
 
-        let title = format_window_title(None, 0, 0, SortMode::Alphabetical, &settings);
+        let title = format_window_title(
+            None,
+            0,
+            0,
+            SortMode::Alphabetical,
+            TitleView::default(),
+            &settings,
+        );
 
         assert_eq!(title, "rpview");
     }
@@ -130,7 +178,14 @@ mod tests {
         settings.sort_navigation.show_image_counter = false;
         let path = Path::new("/dir/photo.png");
 
-        let title = format_window_title(Some(path), 3, 10, SortMode::Alphabetical, &settings);
+        let title = format_window_title(
+            Some(path),
+            3,
+            10,
+            SortMode::Alphabetical,
+            TitleView::default(),
+            &settings,
+        );
 
         assert_eq!(title, "photo.png");
     }
@@ -142,7 +197,14 @@ mod tests {
             "{filename} [{sortmode}] {index} of {total}".to_string();
         let path = Path::new("pic.gif");
 
-        let title = format_window_title(Some(path), 1, 3, SortMode::TypeAlpha, &settings);
+        let title = format_window_title(
+            Some(path),
+            1,
+            3,
+            SortMode::TypeAlpha,
+            TitleView::default(),
+            &settings,
+        );
 
         assert_eq!(title, "pic.gif [type+alphabetical] 2 of 3");
     }
@@ -154,7 +216,14 @@ mod tests {
             "{filename} [{sm}] {sortmode} {index}/{total}".to_string();
         let path = Path::new("photo.png");
 
-        let title = format_window_title(Some(path), 0, 5, SortMode::ModifiedDate, &settings);
+        let title = format_window_title(
+            Some(path),
+            0,
+            5,
+            SortMode::ModifiedDate,
+            TitleView::default(),
+            &settings,
+        );
 
         assert_eq!(title, "photo.png [M] modified 1/5");
     }
@@ -165,8 +234,66 @@ mod tests {
         settings.appearance.window_title_format = "{filename} {unknown} {index}".to_string();
         let path = Path::new("test.png");
 
-        let title = format_window_title(Some(path), 0, 1, SortMode::Alphabetical, &settings);
+        let title = format_window_title(
+            Some(path),
+            0,
+            1,
+            SortMode::Alphabetical,
+            TitleView::default(),
+            &settings,
+        );
 
         assert_eq!(title, "test.png {unknown} 1");
     }
+
+    #[test]
+    fn test_format_window_title_zoom_and_dimensions() {
+        let mut settings = AppSettings::default();
+        settings.appearance.window_title_format = "{filename} {zoom} {dimensions}".to_string();
+        let path = Path::new("big.tiff");
+        let view = TitleView {
+            zoom: 2.5,
+            dimensions: Some((6000, 4000)),
+        };
+
+        let title = format_window_title(Some(path), 0, 1, SortMode::Alphabetical, view, &settings);
+
+        assert_eq!(title, "big.tiff 250% 6000×4000");
+    }
+
+    #[test]
+    fn test_format_window_title_dimensions_empty_before_load() {
+        let mut settings = AppSettings::default();
+        settings.appearance.window_title_format = "{filename}{dimensions}".to_string();
+        let path = Path::new("loading.png");
+
+        let title = format_window_title(
+            Some(path),
+            0,
+            1,
+            SortMode::Alphabetical,
+            TitleView::default(),
+            &settings,
+        );
+
+        assert_eq!(title, "loading.png");
+    }
+
+    #[test]
+    fn test_format_window_title_blank_template_uses_default() {
+        let mut settings = AppSettings::default();
+        settings.appearance.window_title_format = "   ".to_string();
+        let path = Path::new("a.png");
+
+        let title = format_window_title(
+            Some(path),
+            0,
+            2,
+            SortMode::Alphabetical,
+            TitleView::default(),
+            &settings,
+        );
+
+        assert_eq!(title, "a.png (A, 1/2)");
+    }
 }
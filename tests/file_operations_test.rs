@@ -166,7 +166,7 @@ fn test_process_dropped_path_file() {
     let dropped_file = dir_path.join("image2.jpg");
 
     // Process the dropped file
-    let (all_images, start_index) = process_dropped_path(&dropped_file).unwrap();
+    let (all_images, start_index) = process_dropped_path(&dropped_file, false).unwrap();
 
     // Should find all 3 images
     assert_eq!(all_images.len(), 3);
@@ -185,7 +185,7 @@ fn test_process_dropped_path_directory() {
     fs::write(dir_path.join("image2.jpg"), b"fake jpg").unwrap();
 
     // Process the dropped directory
-    let (all_images, start_index) = process_dropped_path(dir_path).unwrap();
+    let (all_images, start_index) = process_dropped_path(dir_path, false).unwrap();
 
     // Should find 2 images
     assert_eq!(all_images.len(), 2);
@@ -194,12 +194,40 @@ fn test_process_dropped_path_directory() {
     assert_eq!(start_index, 0);
 }
 
+#[test]
+fn test_process_dropped_path_directory_recursive() {
+    // Create a directory with images one and two levels down
+    let temp_dir = TempDir::new().unwrap();
+    let dir_path = temp_dir.path();
+    fs::create_dir_all(dir_path.join("2024/summer")).unwrap();
+
+    fs::write(dir_path.join("cover.png"), b"fake png").unwrap();
+    fs::write(dir_path.join("2024/beach.jpg"), b"fake jpg").unwrap();
+    fs::write(dir_path.join("2024/summer/pier.gif"), b"fake gif").unwrap();
+
+    // Only the top level without recursion
+    let (flat, _) = process_dropped_path(dir_path, false).unwrap();
+    assert_eq!(flat.len(), 1);
+
+    // Every level with it, in sorted full-path order
+    let (all_images, start_index) = process_dropped_path(dir_path, true).unwrap();
+    assert_eq!(
+        all_images,
+        vec![
+            dir_path.join("2024/beach.jpg"),
+            dir_path.join("2024/summer/pier.gif"),
+            dir_path.join("cover.png"),
+        ]
+    );
+    assert_eq!(start_index, 0);
+}
+
 #[test]
 fn test_process_dropped_path_nonexistent() {
     let nonexistent = PathBuf::from("/nonexistent/path/image.png");
 
     // Should return FileNotFound error
-    let result = process_dropped_path(&nonexistent);
+    let result = process_dropped_path(&nonexistent, false);
     assert!(result.is_err());
 }
 
@@ -213,7 +241,7 @@ fn test_process_dropped_path_unsupported_format() {
     fs::write(&text_file, b"text content").unwrap();
 
     // Should return InvalidFormat error
-    let result = process_dropped_path(&text_file);
+    let result = process_dropped_path(&text_file, false);
     assert!(result.is_err());
 }
 
@@ -230,7 +258,7 @@ fn test_process_dropped_path_sorts_alphabetically() {
     let dropped_file = dir_path.join("zebra.png");
 
     // Process the dropped file
-    let (all_images, start_index) = process_dropped_path(&dropped_file).unwrap();
+    let (all_images, start_index) = process_dropped_path(&dropped_file, false).unwrap();
 
     // Should be sorted alphabetically
     assert_eq!(all_images[0].file_name().unwrap(), "apple.jpg");
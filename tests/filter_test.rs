@@ -1,16 +1,26 @@
 use image::{DynamicImage, ImageBuffer, Rgba};
+use rpview::state::image_state::FilterSettings;
 use rpview::utils::filters::*;
 
 fn create_test_image(r: u8, g: u8, b: u8) -> DynamicImage {
     DynamicImage::ImageRgba8(ImageBuffer::from_pixel(10, 10, Rgba([r, g, b, 255])))
 }
 
+fn tone(brightness: f32, contrast: f32, gamma: f32) -> FilterSettings {
+    FilterSettings {
+        brightness,
+        contrast,
+        gamma,
+        ..FilterSettings::default()
+    }
+}
+
 // -- Brightness (via apply_filters with contrast=0, gamma=1) --------------
 
 #[test]
 fn test_brightness_zero() {
     let img = create_test_image(128, 128, 128);
-    let result = apply_filters(&img, 0.0, 0.0, 1.0);
+    let result = apply_filters(&img, &tone(0.0, 0.0, 1.0));
 
     let rgba = result.to_rgba8();
     let pixel = rgba.get_pixel(0, 0);
@@ -22,7 +32,7 @@ fn test_brightness_zero() {
 #[test]
 fn test_brightness_positive() {
     let img = create_test_image(100, 100, 100);
-    let result = apply_filters(&img, 50.0, 0.0, 1.0);
+    let result = apply_filters(&img, &tone(50.0, 0.0, 1.0));
 
     let rgba = result.to_rgba8();
     let pixel = rgba.get_pixel(0, 0);
@@ -34,7 +44,7 @@ fn test_brightness_positive() {
 #[test]
 fn test_brightness_negative() {
     let img = create_test_image(200, 200, 200);
-    let result = apply_filters(&img, -50.0, 0.0, 1.0);
+    let result = apply_filters(&img, &tone(-50.0, 0.0, 1.0));
 
     let rgba = result.to_rgba8();
     let pixel = rgba.get_pixel(0, 0);
@@ -46,7 +56,7 @@ fn test_brightness_negative() {
 #[test]
 fn test_brightness_max() {
     let img = create_test_image(100, 100, 100);
-    let result = apply_filters(&img, 100.0, 0.0, 1.0);
+    let result = apply_filters(&img, &tone(100.0, 0.0, 1.0));
 
     let rgba = result.to_rgba8();
     let pixel = rgba.get_pixel(0, 0);
@@ -58,7 +68,7 @@ fn test_brightness_max() {
 #[test]
 fn test_brightness_min() {
     let img = create_test_image(100, 100, 100);
-    let result = apply_filters(&img, -100.0, 0.0, 1.0);
+    let result = apply_filters(&img, &tone(-100.0, 0.0, 1.0));
 
     let rgba = result.to_rgba8();
     let pixel = rgba.get_pixel(0, 0);
@@ -70,7 +80,7 @@ fn test_brightness_min() {
 #[test]
 fn test_brightness_preserves_alpha() {
     let img = DynamicImage::ImageRgba8(ImageBuffer::from_pixel(10, 10, Rgba([128, 128, 128, 100])));
-    let result = apply_filters(&img, 50.0, 0.0, 1.0);
+    let result = apply_filters(&img, &tone(50.0, 0.0, 1.0));
 
     let rgba = result.to_rgba8();
     let pixel = rgba.get_pixel(0, 0);
@@ -82,8 +92,8 @@ fn test_brightness_clamps_input() {
     let img = create_test_image(128, 128, 128);
 
     // Beyond ±100 clamps to ±100
-    let result1 = apply_filters(&img, 200.0, 0.0, 1.0);
-    let result2 = apply_filters(&img, 100.0, 0.0, 1.0);
+    let result1 = apply_filters(&img, &tone(200.0, 0.0, 1.0));
+    let result2 = apply_filters(&img, &tone(100.0, 0.0, 1.0));
 
     let rgba1 = result1.to_rgba8();
     let rgba2 = result2.to_rgba8();
@@ -95,7 +105,7 @@ fn test_brightness_clamps_input() {
 #[test]
 fn test_contrast_zero() {
     let img = create_test_image(128, 64, 192);
-    let result = apply_filters(&img, 0.0, 0.0, 1.0);
+    let result = apply_filters(&img, &tone(0.0, 0.0, 1.0));
 
     let rgba = result.to_rgba8();
     let pixel = rgba.get_pixel(0, 0);
@@ -107,7 +117,7 @@ fn test_contrast_zero() {
 #[test]
 fn test_contrast_positive_keeps_midtone_near_center() {
     let img = create_test_image(128, 128, 128);
-    let result = apply_filters(&img, 0.0, 50.0, 1.0);
+    let result = apply_filters(&img, &tone(0.0, 50.0, 1.0));
 
     let rgba = result.to_rgba8();
     let pixel = rgba.get_pixel(0, 0);
@@ -124,7 +134,7 @@ fn test_contrast_increases_difference() {
         buffer
     });
 
-    let result = apply_filters(&img, 0.0, 50.0, 1.0);
+    let result = apply_filters(&img, &tone(0.0, 50.0, 1.0));
     let rgba = result.to_rgba8();
 
     let dark = rgba.get_pixel(0, 0);
@@ -145,7 +155,7 @@ fn test_contrast_negative_decreases_difference() {
         buffer
     });
 
-    let result = apply_filters(&img, 0.0, -50.0, 1.0);
+    let result = apply_filters(&img, &tone(0.0, -50.0, 1.0));
     let rgba = result.to_rgba8();
 
     let dark = rgba.get_pixel(0, 0);
@@ -162,7 +172,7 @@ fn test_contrast_negative_decreases_difference() {
 #[test]
 fn test_gamma_one_no_change() {
     let img = create_test_image(128, 64, 192);
-    let result = apply_filters(&img, 0.0, 0.0, 1.0);
+    let result = apply_filters(&img, &tone(0.0, 0.0, 1.0));
 
     let rgba = result.to_rgba8();
     let pixel = rgba.get_pixel(0, 0);
@@ -174,7 +184,7 @@ fn test_gamma_one_no_change() {
 #[test]
 fn test_gamma_greater_than_one_brightens_midtones() {
     let img = create_test_image(100, 100, 100);
-    let result = apply_filters(&img, 0.0, 0.0, 2.0);
+    let result = apply_filters(&img, &tone(0.0, 0.0, 2.0));
 
     let rgba = result.to_rgba8();
     let pixel = rgba.get_pixel(0, 0);
@@ -184,7 +194,7 @@ fn test_gamma_greater_than_one_brightens_midtones() {
 #[test]
 fn test_gamma_less_than_one_darkens_midtones() {
     let img = create_test_image(150, 150, 150);
-    let result = apply_filters(&img, 0.0, 0.0, 0.5);
+    let result = apply_filters(&img, &tone(0.0, 0.0, 0.5));
 
     let rgba = result.to_rgba8();
     let pixel = rgba.get_pixel(0, 0);
@@ -200,7 +210,7 @@ fn test_gamma_preserves_black_and_white() {
         buffer
     });
 
-    let result = apply_filters(&img, 0.0, 0.0, 2.0);
+    let result = apply_filters(&img, &tone(0.0, 0.0, 2.0));
     let rgba = result.to_rgba8();
 
     assert_eq!(rgba.get_pixel(0, 0)[0], 0);
@@ -212,8 +222,8 @@ fn test_gamma_clamps_input() {
     let img = create_test_image(128, 128, 128);
 
     // Below 0.1 clamps to 0.1
-    let result1 = apply_filters(&img, 0.0, 0.0, 0.05);
-    let result2 = apply_filters(&img, 0.0, 0.0, 0.1);
+    let result1 = apply_filters(&img, &tone(0.0, 0.0, 0.05));
+    let result2 = apply_filters(&img, &tone(0.0, 0.0, 0.1));
 
     let rgba1 = result1.to_rgba8();
     let rgba2 = result2.to_rgba8();
@@ -224,8 +234,8 @@ fn test_gamma_clamps_input() {
 fn test_gamma_deterministic() {
     let img = create_test_image(50, 100, 150);
 
-    let r1 = apply_filters(&img, 0.0, 0.0, 2.0).to_rgba8();
-    let r2 = apply_filters(&img, 0.0, 0.0, 2.0).to_rgba8();
+    let r1 = apply_filters(&img, &tone(0.0, 0.0, 2.0)).to_rgba8();
+    let r2 = apply_filters(&img, &tone(0.0, 0.0, 2.0)).to_rgba8();
 
     assert_eq!(r1.get_pixel(0, 0), r2.get_pixel(0, 0));
 }
@@ -235,7 +245,7 @@ fn test_gamma_deterministic() {
 #[test]
 fn test_apply_filters_all_default() {
     let img = create_test_image(128, 128, 128);
-    let result = apply_filters(&img, 0.0, 0.0, 1.0);
+    let result = apply_filters(&img, &tone(0.0, 0.0, 1.0));
 
     let rgba = result.to_rgba8();
     let pixel = rgba.get_pixel(0, 0);
@@ -248,7 +258,7 @@ fn test_apply_filters_all_default() {
 fn test_apply_filters_combined() {
     let img = create_test_image(100, 100, 100);
 
-    let result = apply_filters(&img, 10.0, 20.0, 1.2);
+    let result = apply_filters(&img, &tone(10.0, 20.0, 1.2));
 
     let rgba = result.to_rgba8();
     let pixel = rgba.get_pixel(0, 0);
@@ -262,24 +272,24 @@ fn test_apply_filters_combined() {
 fn test_apply_filters_extreme_values_no_panic() {
     let img = create_test_image(100, 100, 100);
 
-    let result = apply_filters(&img, 100.0, 100.0, 10.0);
+    let result = apply_filters(&img, &tone(100.0, 100.0, 10.0));
     let rgba = result.to_rgba8();
     assert_ne!(rgba.get_pixel(0, 0)[0], 100);
 
-    let result = apply_filters(&img, -100.0, -100.0, 0.1);
+    let result = apply_filters(&img, &tone(-100.0, -100.0, 0.1));
     let rgba = result.to_rgba8();
     assert_ne!(rgba.get_pixel(0, 0)[0], 100);
 
     // Beyond-clamp values should not panic either
-    let _ = apply_filters(&img, 200.0, 200.0, 20.0);
-    let _ = apply_filters(&img, -200.0, -200.0, 0.01);
+    let _ = apply_filters(&img, &tone(200.0, 200.0, 20.0));
+    let _ = apply_filters(&img, &tone(-200.0, -200.0, 0.01));
 }
 
 #[test]
 fn test_filters_preserve_alpha() {
     let img = DynamicImage::ImageRgba8(ImageBuffer::from_pixel(10, 10, Rgba([128, 128, 128, 100])));
 
-    let result = apply_filters(&img, 20.0, 30.0, 1.3);
+    let result = apply_filters(&img, &tone(20.0, 30.0, 1.3));
 
     let rgba = result.to_rgba8();
     let pixel = rgba.get_pixel(0, 0);
@@ -295,7 +305,7 @@ fn test_brightness_on_edge_values() {
         buffer
     });
 
-    let result = apply_filters(&img, 50.0, 0.0, 1.0);
+    let result = apply_filters(&img, &tone(50.0, 0.0, 1.0));
     let rgba = result.to_rgba8();
 
     let black = rgba.get_pixel(0, 0);
@@ -309,7 +319,7 @@ fn test_brightness_on_edge_values() {
 fn test_filters_with_small_values() {
     let img = create_test_image(128, 128, 128);
 
-    let result = apply_filters(&img, 0.0001, 0.0001, 1.0001);
+    let result = apply_filters(&img, &tone(0.0001, 0.0001, 1.0001));
 
     let rgba = result.to_rgba8();
     let pixel = rgba.get_pixel(0, 0);
@@ -320,7 +330,7 @@ fn test_filters_with_small_values() {
 fn test_image_dimensions_preserved() {
     let img = DynamicImage::ImageRgba8(ImageBuffer::new(37, 53));
 
-    let result = apply_filters(&img, 50.0, 50.0, 1.5);
+    let result = apply_filters(&img, &tone(50.0, 50.0, 1.5));
 
     assert_eq!(result.width(), 37);
     assert_eq!(result.height(), 53);
@@ -62,7 +62,7 @@ fn test_cli_workflow_single_file() {
     let target_file = dir_path.join("image2.jpg");
 
     // Process as if it were dropped/opened
-    let (images, start_index) = process_dropped_path(&target_file).unwrap();
+    let (images, start_index) = process_dropped_path(&target_file, false).unwrap();
     let state = AppState::new_with_index(images, start_index);
 
     // Should scan parent dir and start at the specified file
@@ -174,7 +174,7 @@ fn test_file_loading_workflow_drag_and_drop() {
     let dropped_file = dir_path.join("dropped.png");
 
     // Process the drop
-    let (images, start_index) = process_dropped_path(&dropped_file).unwrap();
+    let (images, start_index) = process_dropped_path(&dropped_file, false).unwrap();
     let state = AppState::new_with_index(images, start_index);
 
     // Should start at dropped file
@@ -1,5 +1,9 @@
+use rpview::components::settings_window::{
+    AppearancePreview, SettingsSection, SettingsWindowEvent, appearance_change,
+};
 use rpview::state::app_state::{AppState, SortMode};
 use rpview::state::image_state::{FilterSettings, ImageState};
+use rpview::state::settings::{AppSettings, PersistedWindowBounds};
 use std::path::PathBuf;
 
 #[test]
@@ -301,3 +305,88 @@ fn test_cache_size_limit_via_save() {
     // Cache should have evicted the oldest, leaving exactly max_cache_size entries
     assert_eq!(state.image_states.len(), 2);
 }
+
+#[test]
+fn test_reset_section_leaves_other_sections_untouched() {
+    // Arrange — change something in several sections
+    let mut settings = AppSettings::default();
+    settings.keyboard_mouse.pan_speed_normal = 42.0;
+    settings.keyboard_mouse.spacebar_pan_accelerated = true;
+    settings.viewer_behavior.state_cache_size = 7;
+    settings.appearance.overlay_transparency = 10;
+    settings.sort_navigation.page_stride = 3;
+    let mut expected = settings.clone();
+    expected.keyboard_mouse = AppSettings::default().keyboard_mouse;
+
+    // Act
+    SettingsSection::KeyboardMouse.reset_settings(&mut settings);
+
+    // Assert
+    assert_eq!(settings, expected);
+    assert!(SettingsSection::KeyboardMouse.is_at_defaults(&settings));
+    assert!(!SettingsSection::ViewerBehavior.is_at_defaults(&settings));
+}
+
+#[test]
+fn test_reset_appearance_section_keeps_window_geometry() {
+    // Arrange
+    let mut settings = AppSettings::default();
+    settings.appearance.font_size_scale = 2.0;
+    settings.appearance.filter_window_open = true;
+    settings.appearance.filter_window_bounds = Some(PersistedWindowBounds {
+        x: 10.0,
+        y: 20.0,
+        width: 300.0,
+        height: 200.0,
+    });
+
+    // Act
+    SettingsSection::Appearance.reset_settings(&mut settings);
+
+    // Assert
+    let defaults = AppSettings::default();
+    assert_eq!(
+        settings.appearance.font_size_scale,
+        defaults.appearance.font_size_scale
+    );
+    assert!(settings.appearance.filter_window_open);
+    assert!(settings.appearance.filter_window_bounds.is_some());
+}
+
+#[test]
+fn test_appearance_change_event_carries_updated_values() {
+    // Arrange
+    let mut settings = AppSettings::default();
+    let previous = AppearancePreview::from_settings(&settings.appearance);
+    settings.appearance.overlay_transparency = 40;
+    settings.appearance.font_size_scale = 1.5;
+    settings.appearance.background_color_dark = [10, 20, 30];
+    settings.appearance.high_contrast = true;
+
+    // Act
+    let event = appearance_change(&previous, &settings);
+
+    // Assert
+    let Some(SettingsWindowEvent::AppearanceChanged(preview)) = event else {
+        panic!("expected an AppearanceChanged event");
+    };
+    assert_eq!(preview.overlay_transparency, 40);
+    assert_eq!(preview.font_size_scale, 1.5);
+    assert_eq!(preview.background_color_dark, [10, 20, 30]);
+    assert!(preview.high_contrast);
+    assert_eq!(
+        preview.background_color_light,
+        previous.background_color_light
+    );
+}
+
+#[test]
+fn test_appearance_change_ignores_other_settings() {
+    // Arrange
+    let mut settings = AppSettings::default();
+    let previous = AppearancePreview::from_settings(&settings.appearance);
+    settings.keyboard_mouse.pan_speed_normal = 42.0;
+
+    // Act & Assert
+    assert!(appearance_change(&previous, &settings).is_none());
+}